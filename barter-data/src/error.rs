@@ -24,6 +24,17 @@ pub enum DataError {
         prev_last_update_id: u64,
         first_update_id: u64,
     },
+
+    #[error(
+        "\
+        InvalidChecksum: exchange checksum {expected} does not match the local OrderBook \
+        checksum {actual} \
+    "
+    )]
+    InvalidChecksum { expected: u32, actual: u32 },
+
+    #[error("MissingSnapshot: received OrderBook update before the initial snapshot")]
+    MissingSnapshot,
 }
 
 impl DataError {
@@ -31,7 +42,9 @@ impl DataError {
     #[allow(clippy::match_like_matches_macro)]
     pub fn is_terminal(&self) -> bool {
         match self {
-            DataError::InvalidSequence { .. } => true,
+            DataError::InvalidSequence { .. }
+            | DataError::InvalidChecksum { .. }
+            | DataError::MissingSnapshot => true,
             _ => false,
         }
     }
@@ -58,7 +71,20 @@ mod tests {
                 expected: true,
             },
             TestCase {
-                // TC1: is not terminal w/ DataError::Socket
+                // TC1: is terminal w/ DataError::InvalidChecksum
+                input: DataError::InvalidChecksum {
+                    expected: 0,
+                    actual: 1,
+                },
+                expected: true,
+            },
+            TestCase {
+                // TC2: is terminal w/ DataError::MissingSnapshot
+                input: DataError::MissingSnapshot,
+                expected: true,
+            },
+            TestCase {
+                // TC3: is not terminal w/ DataError::Socket
                 input: DataError::Socket(SocketError::Sink),
                 expected: false,
             },
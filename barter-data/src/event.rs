@@ -3,7 +3,11 @@ use crate::{
     subscription::{
         book::{OrderBook, OrderBookL1},
         candle::Candle,
+        funding::FundingRate,
         liquidation::Liquidation,
+        open_interest::OpenInterest,
+        price::{IndexPrice, MarkPrice},
+        ticker::Ticker,
         trade::PublicTrade,
     },
 };
@@ -63,6 +67,11 @@ pub enum DataKind {
     OrderBook(OrderBook),
     Candle(Candle),
     Liquidation(Liquidation),
+    FundingRate(FundingRate),
+    MarkPrice(MarkPrice),
+    IndexPrice(IndexPrice),
+    Ticker(Ticker),
+    OpenInterest(OpenInterest),
 }
 
 impl<InstrumentId> From<MarketEvent<InstrumentId, PublicTrade>>
@@ -132,3 +141,71 @@ impl<InstrumentId> From<MarketEvent<InstrumentId, Liquidation>>
         }
     }
 }
+
+impl<InstrumentId> From<MarketEvent<InstrumentId, FundingRate>>
+    for MarketEvent<InstrumentId, DataKind>
+{
+    fn from(event: MarketEvent<InstrumentId, FundingRate>) -> Self {
+        Self {
+            exchange_time: event.exchange_time,
+            received_time: event.received_time,
+            exchange: event.exchange,
+            instrument: event.instrument,
+            kind: DataKind::FundingRate(event.kind),
+        }
+    }
+}
+
+impl<InstrumentId> From<MarketEvent<InstrumentId, MarkPrice>>
+    for MarketEvent<InstrumentId, DataKind>
+{
+    fn from(event: MarketEvent<InstrumentId, MarkPrice>) -> Self {
+        Self {
+            exchange_time: event.exchange_time,
+            received_time: event.received_time,
+            exchange: event.exchange,
+            instrument: event.instrument,
+            kind: DataKind::MarkPrice(event.kind),
+        }
+    }
+}
+
+impl<InstrumentId> From<MarketEvent<InstrumentId, IndexPrice>>
+    for MarketEvent<InstrumentId, DataKind>
+{
+    fn from(event: MarketEvent<InstrumentId, IndexPrice>) -> Self {
+        Self {
+            exchange_time: event.exchange_time,
+            received_time: event.received_time,
+            exchange: event.exchange,
+            instrument: event.instrument,
+            kind: DataKind::IndexPrice(event.kind),
+        }
+    }
+}
+
+impl<InstrumentId> From<MarketEvent<InstrumentId, Ticker>> for MarketEvent<InstrumentId, DataKind> {
+    fn from(event: MarketEvent<InstrumentId, Ticker>) -> Self {
+        Self {
+            exchange_time: event.exchange_time,
+            received_time: event.received_time,
+            exchange: event.exchange,
+            instrument: event.instrument,
+            kind: DataKind::Ticker(event.kind),
+        }
+    }
+}
+
+impl<InstrumentId> From<MarketEvent<InstrumentId, OpenInterest>>
+    for MarketEvent<InstrumentId, DataKind>
+{
+    fn from(event: MarketEvent<InstrumentId, OpenInterest>) -> Self {
+        Self {
+            exchange_time: event.exchange_time,
+            received_time: event.received_time,
+            exchange: event.exchange,
+            instrument: event.instrument,
+            kind: DataKind::OpenInterest(event.kind),
+        }
+    }
+}
@@ -1,10 +1,14 @@
 use super::{super::channel::BinanceChannel, BinanceLevel};
 use crate::{
+    error::DataError,
     exchange::subscription::ExchangeSub,
     subscription::book::{OrderBook, OrderBookSide},
     Identifier,
 };
-use barter_integration::model::{Side, SubscriptionId};
+use barter_integration::{
+    error::SocketError,
+    model::{Side, SubscriptionId},
+};
 use chrono::Utc;
 use serde::{Deserialize, Serialize};
 
@@ -75,6 +79,18 @@ where
         .map(|market| ExchangeSub::from((BinanceChannel::ORDER_BOOK_L2, market)).id())
 }
 
+/// Extract the [`Binance`](super::super::Binance) market (eg/ "BTCUSDT") from an OrderBook
+/// Level2 [`SubscriptionId`] (eg/ "@depth@100ms|BTCUSDT").
+///
+/// Used to construct the HTTP OrderBook Level2 snapshot request for any instrument type.
+pub fn ob_l2_subscription_id_market(subscription_id: &SubscriptionId) -> Result<&str, DataError> {
+    subscription_id
+        .as_ref()
+        .split_once('|')
+        .map(|(_channel, market)| market)
+        .ok_or_else(|| DataError::Socket(SocketError::Unidentifiable(subscription_id.clone())))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -166,4 +182,43 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_ob_l2_subscription_id_market() {
+        struct TestCase {
+            input: SubscriptionId,
+            expected: Result<&'static str, DataError>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: valid OrderBook Level2 SubscriptionId
+                input: SubscriptionId::from("@depth@100ms|BTCUSDT"),
+                expected: Ok("BTCUSDT"),
+            },
+            TestCase {
+                // TC1: invalid SubscriptionId w/o channel delimiter
+                input: SubscriptionId::from("BTCUSDT"),
+                expected: Err(DataError::Socket(SocketError::Unidentifiable(
+                    SubscriptionId::from("BTCUSDT"),
+                ))),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = ob_l2_subscription_id_market(&test.input);
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
 }
@@ -0,0 +1,235 @@
+use super::channel::BinanceChannel;
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::{ExchangeId, ExchangeSub},
+    subscription::candle::{Candle, CandleInterval},
+    Identifier,
+};
+use barter_integration::model::{Exchange, SubscriptionId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// [`Binance`](super::Binance) real-time kline (candle) message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#kline-candlestick-streams>
+/// ```json
+/// {
+///     "e": "kline",
+///     "E": 1672515782136,
+///     "s": "BTCUSDT",
+///     "k": {
+///         "t": 1672515780000,
+///         "T": 1672515839999,
+///         "s": "BTCUSDT",
+///         "i": "1m",
+///         "f": 100,
+///         "L": 200,
+///         "o": "16500.10",
+///         "c": "16502.00",
+///         "h": "16503.50",
+///         "l": "16499.90",
+///         "v": "12.5",
+///         "n": 101,
+///         "x": true,
+///         "q": "206262.50",
+///         "V": "6.2",
+///         "Q": "102301.20",
+///         "B": "0"
+///     }
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceCandle {
+    #[serde(alias = "k")]
+    pub kline: BinanceKline,
+}
+
+/// [`Binance`](super::Binance) kline data contained in a [`BinanceCandle`].
+///
+/// See [`BinanceCandle`] for full raw payload examples.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#kline-candlestick-streams>
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceKline {
+    #[serde(alias = "s")]
+    pub symbol: String,
+    #[serde(alias = "i")]
+    pub interval: CandleInterval,
+    #[serde(
+        alias = "T",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub close_time: DateTime<Utc>,
+    #[serde(alias = "o", deserialize_with = "barter_integration::de::de_str")]
+    pub open: f64,
+    #[serde(alias = "h", deserialize_with = "barter_integration::de::de_str")]
+    pub high: f64,
+    #[serde(alias = "l", deserialize_with = "barter_integration::de::de_str")]
+    pub low: f64,
+    #[serde(alias = "c", deserialize_with = "barter_integration::de::de_str")]
+    pub close: f64,
+    #[serde(alias = "v", deserialize_with = "barter_integration::de::de_str")]
+    pub volume: f64,
+    #[serde(alias = "n")]
+    pub trade_count: u64,
+    #[serde(alias = "x")]
+    pub closed: bool,
+}
+
+impl Identifier<Option<SubscriptionId>> for BinanceCandle {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(
+            ExchangeSub::from((
+                BinanceChannel::candles(self.kline.interval),
+                self.kline.symbol.as_str(),
+            ))
+            .id(),
+        )
+    }
+}
+
+impl<InstrumentId> From<(ExchangeId, InstrumentId, BinanceCandle)>
+    for MarketIter<InstrumentId, Candle>
+{
+    fn from((exchange_id, instrument, candle): (ExchangeId, InstrumentId, BinanceCandle)) -> Self {
+        let BinanceCandle { kline } = candle;
+
+        // Only yield closed candles, in-progress interval updates are ignored
+        if !kline.closed {
+            return Self(vec![]);
+        }
+
+        Self(vec![Ok(MarketEvent {
+            exchange_time: kline.close_time,
+            received_time: Utc::now(),
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: Candle {
+                close_time: kline.close_time,
+                open: kline.open,
+                high: kline.high,
+                low: kline.low,
+                close: kline.close,
+                volume: kline.volume,
+                trade_count: kline.trade_count,
+            },
+        })])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::{de::datetime_utc_from_epoch_duration, error::SocketError};
+        use serde::de::Error;
+        use std::time::Duration;
+
+        #[test]
+        fn test_binance_candle() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<BinanceCandle, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: closed 1m kline is valid
+                    input: r#"
+                    {
+                        "e":"kline","E":1672515782136,"s":"BTCUSDT",
+                        "k":{
+                            "t":1672515780000,"T":1672515839999,"s":"BTCUSDT","i":"1m","f":100,
+                            "L":200,"o":"16500.10","c":"16502.00","h":"16503.50","l":"16499.90",
+                            "v":"12.5","n":101,"x":true,"q":"206262.50","V":"6.2",
+                            "Q":"102301.20","B":"0"
+                        }
+                    }
+                    "#,
+                    expected: Ok(BinanceCandle {
+                        kline: BinanceKline {
+                            symbol: "BTCUSDT".to_string(),
+                            interval: CandleInterval::M1,
+                            close_time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                1672515839999,
+                            )),
+                            open: 16500.10,
+                            high: 16503.50,
+                            low: 16499.90,
+                            close: 16502.00,
+                            volume: 12.5,
+                            trade_count: 101,
+                            closed: true,
+                        },
+                    }),
+                },
+                TestCase {
+                    // TC1: kline with unsupported interval is invalid
+                    input: r#"
+                    {
+                        "e":"kline","E":1672515782136,"s":"BTCUSDT",
+                        "k":{
+                            "t":1672515780000,"T":1672515959999,"s":"BTCUSDT","i":"3m","f":100,
+                            "L":200,"o":"16500.10","c":"16502.00","h":"16503.50","l":"16499.90",
+                            "v":"12.5","n":101,"x":false,"q":"206262.50","V":"6.2",
+                            "Q":"102301.20","B":"0"
+                        }
+                    }
+                    "#,
+                    expected: Err(SocketError::Deserialise {
+                        error: serde_json::Error::custom(""),
+                        payload: "".to_owned(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<BinanceCandle>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_binance_candle_id_and_closed_filter() {
+        let candle = |closed| BinanceCandle {
+            kline: BinanceKline {
+                symbol: "BTCUSDT".to_string(),
+                interval: CandleInterval::H4,
+                close_time: DateTime::<Utc>::MIN_UTC,
+                open: 1.0,
+                high: 2.0,
+                low: 0.5,
+                close: 1.5,
+                volume: 10.0,
+                trade_count: 5,
+                closed,
+            },
+        };
+
+        assert_eq!(
+            candle(true).id(),
+            Some(SubscriptionId::from("@kline_4h|BTCUSDT"))
+        );
+
+        let closed = MarketIter::<(), Candle>::from((ExchangeId::BinanceSpot, (), candle(true)));
+        assert_eq!(closed.0.len(), 1);
+
+        let open = MarketIter::<(), Candle>::from((ExchangeId::BinanceSpot, (), candle(false)));
+        assert!(open.0.is_empty());
+    }
+}
@@ -2,7 +2,11 @@ use super::{futures::BinanceFuturesUsd, Binance};
 use crate::{
     subscription::{
         book::{OrderBooksL1, OrderBooksL2},
+        candle::{CandleInterval, Candles},
+        funding::FundingRates,
         liquidation::Liquidations,
+        price::{IndexPrices, MarkPrices},
+        ticker::Tickers,
         trade::PublicTrades,
         Subscription,
     },
@@ -42,10 +46,39 @@ impl BinanceChannel {
     /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#diff-book-depth-streams>
     pub const ORDER_BOOK_L2: Self = Self("@depth@100ms");
 
+    /// [`Binance`] rolling 24 hour ticker channel name.
+    ///
+    /// See docs: <https://binance-docs.github.io/apidocs/spot/en/#individual-symbol-ticker-streams>
+    /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#individual-symbol-ticker-streams>
+    pub const TICKERS: Self = Self("@ticker");
+
     /// [`BinanceFuturesUsd`] liquidation orders channel name.
     ///
     /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#liquidation-order-streams>
     pub const LIQUIDATIONS: Self = Self("@forceOrder");
+
+    /// [`BinanceFuturesUsd`] mark price channel name (1s updates), which also contains the index
+    /// price & funding rate.
+    ///
+    /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#mark-price-stream>
+    pub const MARK_PRICE: Self = Self("@markPrice@1s");
+
+    /// [`Binance`] kline (candle) channel name for the provided [`CandleInterval`].
+    ///
+    /// See docs: <https://binance-docs.github.io/apidocs/spot/en/#kline-candlestick-streams>
+    /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#kline-candlestick-streams>
+    pub const fn candles(interval: CandleInterval) -> Self {
+        match interval {
+            CandleInterval::M1 => Self("@kline_1m"),
+            CandleInterval::M5 => Self("@kline_5m"),
+            CandleInterval::M15 => Self("@kline_15m"),
+            CandleInterval::M30 => Self("@kline_30m"),
+            CandleInterval::H1 => Self("@kline_1h"),
+            CandleInterval::H4 => Self("@kline_4h"),
+            CandleInterval::D1 => Self("@kline_1d"),
+            CandleInterval::W1 => Self("@kline_1w"),
+        }
+    }
 }
 
 impl<Server, Instrument> Identifier<BinanceChannel>
@@ -72,6 +105,22 @@ impl<Server, Instrument> Identifier<BinanceChannel>
     }
 }
 
+impl<Server, Instrument> Identifier<BinanceChannel>
+    for Subscription<Binance<Server>, Instrument, Tickers>
+{
+    fn id(&self) -> BinanceChannel {
+        BinanceChannel::TICKERS
+    }
+}
+
+impl<Server, Instrument> Identifier<BinanceChannel>
+    for Subscription<Binance<Server>, Instrument, Candles>
+{
+    fn id(&self) -> BinanceChannel {
+        BinanceChannel::candles(self.kind.0)
+    }
+}
+
 impl<Instrument> Identifier<BinanceChannel>
     for Subscription<BinanceFuturesUsd, Instrument, Liquidations>
 {
@@ -80,6 +129,30 @@ impl<Instrument> Identifier<BinanceChannel>
     }
 }
 
+impl<Instrument> Identifier<BinanceChannel>
+    for Subscription<BinanceFuturesUsd, Instrument, FundingRates>
+{
+    fn id(&self) -> BinanceChannel {
+        BinanceChannel::MARK_PRICE
+    }
+}
+
+impl<Instrument> Identifier<BinanceChannel>
+    for Subscription<BinanceFuturesUsd, Instrument, MarkPrices>
+{
+    fn id(&self) -> BinanceChannel {
+        BinanceChannel::MARK_PRICE
+    }
+}
+
+impl<Instrument> Identifier<BinanceChannel>
+    for Subscription<BinanceFuturesUsd, Instrument, IndexPrices>
+{
+    fn id(&self) -> BinanceChannel {
+        BinanceChannel::MARK_PRICE
+    }
+}
+
 impl AsRef<str> for BinanceChannel {
     fn as_ref(&self) -> &str {
         self.0
@@ -1,4 +1,7 @@
-use super::super::book::{l2::BinanceOrderBookL2Snapshot, BinanceLevel};
+use super::super::book::{
+    l2::{ob_l2_subscription_id_market, BinanceOrderBookL2Snapshot},
+    BinanceLevel,
+};
 use crate::{
     error::DataError,
     subscription::book::OrderBook,
@@ -7,9 +10,7 @@ use crate::{
 };
 use async_trait::async_trait;
 use barter_integration::{
-    error::SocketError,
-    model::{instrument::Instrument, SubscriptionId},
-    protocol::websocket::WsMessage,
+    error::SocketError, model::SubscriptionId, protocol::websocket::WsMessage,
 };
 use chrono::Utc;
 use serde::{Deserialize, Serialize};
@@ -159,21 +160,23 @@ impl BinanceFuturesBookUpdater {
 impl OrderBookUpdater for BinanceFuturesBookUpdater {
     type OrderBook = OrderBook;
     type Update = BinanceFuturesOrderBookL2Delta;
+    type Output = OrderBook;
 
-    async fn init<Exchange, Kind>(
+    async fn init<Exchange, Kind, InstrumentId>(
         _: mpsc::UnboundedSender<WsMessage>,
-        instrument: Instrument,
-    ) -> Result<InstrumentOrderBook<Instrument, Self>, DataError>
+        subscription_id: SubscriptionId,
+        instrument: InstrumentId,
+    ) -> Result<InstrumentOrderBook<InstrumentId, Self>, DataError>
     where
         Exchange: Send,
         Kind: Send,
+        InstrumentId: Send,
     {
         // Construct initial OrderBook snapshot GET url
         let snapshot_url = format!(
-            "{}?symbol={}{}&limit=100",
+            "{}?symbol={}&limit=100",
             HTTP_BOOK_L2_SNAPSHOT_URL_BINANCE_SPOT,
-            instrument.base.as_ref().to_uppercase(),
-            instrument.quote.as_ref().to_uppercase()
+            ob_l2_subscription_id_market(&subscription_id)?,
         );
 
         // Fetch initial OrderBook snapshot via HTTP
@@ -195,7 +198,7 @@ impl OrderBookUpdater for BinanceFuturesBookUpdater {
         &mut self,
         book: &mut Self::OrderBook,
         update: Self::Update,
-    ) -> Result<Option<Self::OrderBook>, DataError> {
+    ) -> Result<Option<Self::Output>, DataError> {
         // BinanceFuturesUsd: How To Manage A Local OrderBook Correctly
         // See Self's Rust Docs for more information on each numbered step
         // See docs: <https://binance-docs.github.io/apidocs/futures/en/#how-to-manage-a-local-order-book-correctly>
@@ -406,10 +409,7 @@ mod tests {
             for (index, test) in tests.into_iter().enumerate() {
                 let actual = test.updater.validate_first_update(&test.input);
                 match (actual, test.expected) {
-                    (Ok(actual), Ok(expected)) => {
-                        assert_eq!(actual, expected, "TC{} failed", index)
-                    }
-                    (Err(_), Err(_)) => {
+                    (Ok(()), Ok(())) | (Err(_), Err(_)) => {
                         // Test passed
                     }
                     (actual, expected) => {
@@ -469,10 +469,7 @@ mod tests {
             for (index, test) in tests.into_iter().enumerate() {
                 let actual = test.updater.validate_next_update(&test.input);
                 match (actual, test.expected) {
-                    (Ok(actual), Ok(expected)) => {
-                        assert_eq!(actual, expected, "TC{} failed", index)
-                    }
-                    (Err(_), Err(_)) => {
+                    (Ok(()), Ok(())) | (Err(_), Err(_)) => {
                         // Test passed
                     }
                     (actual, expected) => {
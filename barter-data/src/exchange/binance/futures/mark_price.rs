@@ -0,0 +1,164 @@
+use super::super::BinanceChannel;
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::ExchangeId,
+    subscription::{
+        funding::FundingRate,
+        price::{IndexPrice, MarkPrice},
+    },
+    Identifier,
+};
+use barter_integration::model::{Exchange, SubscriptionId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// [`BinanceFuturesUsd`](super::BinanceFuturesUsd) mark price message, which also contains the
+/// index price & funding rate of the perpetual.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#mark-price-stream>
+/// ```json
+/// {
+///     "e": "markPriceUpdate",
+///     "E": 1562305380000,
+///     "s": "BTCUSDT",
+///     "p": "11794.15000000",
+///     "i": "11784.62659091",
+///     "P": "11784.25641265",
+///     "r": "0.00038167",
+///     "T": 1562306400000
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceMarkPrice {
+    #[serde(alias = "s", deserialize_with = "de_mark_price_subscription_id")]
+    pub subscription_id: SubscriptionId,
+    #[serde(
+        alias = "E",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    #[serde(alias = "p", deserialize_with = "barter_integration::de::de_str")]
+    pub mark_price: f64,
+    #[serde(alias = "i", deserialize_with = "barter_integration::de::de_str")]
+    pub index_price: f64,
+    #[serde(alias = "r", deserialize_with = "barter_integration::de::de_str")]
+    pub funding_rate: f64,
+    #[serde(
+        alias = "T",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub next_funding_time: DateTime<Utc>,
+}
+
+impl Identifier<Option<SubscriptionId>> for BinanceMarkPrice {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(self.subscription_id.clone())
+    }
+}
+
+impl<InstrumentId> From<(ExchangeId, InstrumentId, BinanceMarkPrice)>
+    for MarketIter<InstrumentId, FundingRate>
+{
+    fn from((exchange_id, instrument, mark): (ExchangeId, InstrumentId, BinanceMarkPrice)) -> Self {
+        Self(vec![Ok(MarketEvent {
+            exchange_time: mark.time,
+            received_time: Utc::now(),
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: FundingRate {
+                rate: mark.funding_rate,
+                time: mark.time,
+                next_funding_time: Some(mark.next_funding_time),
+            },
+        })])
+    }
+}
+
+impl<InstrumentId> From<(ExchangeId, InstrumentId, BinanceMarkPrice)>
+    for MarketIter<InstrumentId, MarkPrice>
+{
+    fn from((exchange_id, instrument, mark): (ExchangeId, InstrumentId, BinanceMarkPrice)) -> Self {
+        Self(vec![Ok(MarketEvent {
+            exchange_time: mark.time,
+            received_time: Utc::now(),
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: MarkPrice {
+                price: mark.mark_price,
+                time: mark.time,
+            },
+        })])
+    }
+}
+
+impl<InstrumentId> From<(ExchangeId, InstrumentId, BinanceMarkPrice)>
+    for MarketIter<InstrumentId, IndexPrice>
+{
+    fn from((exchange_id, instrument, mark): (ExchangeId, InstrumentId, BinanceMarkPrice)) -> Self {
+        Self(vec![Ok(MarketEvent {
+            exchange_time: mark.time,
+            received_time: Utc::now(),
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: IndexPrice {
+                price: mark.index_price,
+                time: mark.time,
+            },
+        })])
+    }
+}
+
+/// Deserialize a [`BinanceMarkPrice`] "s" (eg/ "BTCUSDT") as the associated
+/// [`SubscriptionId`].
+///
+/// eg/ "@markPrice@1s|BTCUSDT"
+pub fn de_mark_price_subscription_id<'de, D>(deserializer: D) -> Result<SubscriptionId, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    Deserialize::deserialize(deserializer).map(|market: String| {
+        SubscriptionId::from(format!("{}|{}", BinanceChannel::MARK_PRICE.0, market))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::de::datetime_utc_from_epoch_duration;
+        use std::time::Duration;
+
+        #[test]
+        fn test_binance_mark_price() {
+            let input = r#"
+            {
+                "e": "markPriceUpdate",
+                "E": 1562305380000,
+                "s": "BTCUSDT",
+                "p": "11794.15000000",
+                "i": "11784.62659091",
+                "P": "11784.25641265",
+                "r": "0.00038167",
+                "T": 1562306400000
+            }
+            "#;
+
+            assert_eq!(
+                serde_json::from_str::<BinanceMarkPrice>(input).unwrap(),
+                BinanceMarkPrice {
+                    subscription_id: SubscriptionId::from("@markPrice@1s|BTCUSDT"),
+                    time: datetime_utc_from_epoch_duration(Duration::from_millis(1562305380000)),
+                    mark_price: 11794.15,
+                    index_price: 11784.62659091,
+                    funding_rate: 0.00038167,
+                    next_funding_time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                        1562306400000
+                    )),
+                }
+            );
+        }
+    }
+}
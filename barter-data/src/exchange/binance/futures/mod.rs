@@ -1,13 +1,19 @@
-use self::{l2::BinanceFuturesBookUpdater, liquidation::BinanceLiquidation};
+use self::{
+    l2::BinanceFuturesBookUpdater, liquidation::BinanceLiquidation, mark_price::BinanceMarkPrice,
+};
 use super::{Binance, ExchangeServer};
 use crate::{
     exchange::{ExchangeId, StreamSelector},
     instrument::InstrumentData,
-    subscription::{book::OrderBooksL2, liquidation::Liquidations},
+    subscription::{
+        book::OrderBooksL2,
+        funding::FundingRates,
+        liquidation::Liquidations,
+        price::{IndexPrices, MarkPrices},
+    },
     transformer::{book::MultiBookTransformer, stateless::StatelessTransformer},
     ExchangeWsStream,
 };
-use barter_integration::model::instrument::Instrument;
 
 /// Level 2 OrderBook types (top of book) and perpetual
 /// [`OrderBookUpdater`](crate::transformer::book::OrderBookUpdater) implementation.
@@ -16,6 +22,9 @@ pub mod l2;
 /// Liquidation types.
 pub mod liquidation;
 
+/// Mark price types, which also contain the index price & funding rate.
+pub mod mark_price;
+
 /// [`BinanceFuturesUsd`] WebSocket server base url.
 ///
 /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#websocket-market-streams>
@@ -36,9 +45,12 @@ impl ExchangeServer for BinanceServerFuturesUsd {
     }
 }
 
-impl StreamSelector<Instrument, OrderBooksL2> for BinanceFuturesUsd {
+impl<Instrument> StreamSelector<Instrument, OrderBooksL2> for BinanceFuturesUsd
+where
+    Instrument: InstrumentData,
+{
     type Stream = ExchangeWsStream<
-        MultiBookTransformer<Self, Instrument, OrderBooksL2, BinanceFuturesBookUpdater>,
+        MultiBookTransformer<Self, Instrument::Id, OrderBooksL2, BinanceFuturesBookUpdater>,
     >;
 }
 
@@ -50,3 +62,28 @@ where
         StatelessTransformer<Self, Instrument::Id, Liquidations, BinanceLiquidation>,
     >;
 }
+
+impl<Instrument> StreamSelector<Instrument, FundingRates> for BinanceFuturesUsd
+where
+    Instrument: InstrumentData,
+{
+    type Stream = ExchangeWsStream<
+        StatelessTransformer<Self, Instrument::Id, FundingRates, BinanceMarkPrice>,
+    >;
+}
+
+impl<Instrument> StreamSelector<Instrument, MarkPrices> for BinanceFuturesUsd
+where
+    Instrument: InstrumentData,
+{
+    type Stream =
+        ExchangeWsStream<StatelessTransformer<Self, Instrument::Id, MarkPrices, BinanceMarkPrice>>;
+}
+
+impl<Instrument> StreamSelector<Instrument, IndexPrices> for BinanceFuturesUsd
+where
+    Instrument: InstrumentData,
+{
+    type Stream =
+        ExchangeWsStream<StatelessTransformer<Self, Instrument::Id, IndexPrices, BinanceMarkPrice>>;
+}
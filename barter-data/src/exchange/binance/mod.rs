@@ -1,12 +1,15 @@
 use self::{
-    book::l1::BinanceOrderBookL1, channel::BinanceChannel, market::BinanceMarket,
-    subscription::BinanceSubResponse, trade::BinanceTrade,
+    book::l1::BinanceOrderBookL1, candle::BinanceCandle, channel::BinanceChannel,
+    market::BinanceMarket, subscription::BinanceSubResponse, ticker::BinanceTicker,
+    trade::BinanceTrade,
 };
 use crate::{
     exchange::{Connector, ExchangeId, ExchangeServer, ExchangeSub, StreamSelector},
     instrument::InstrumentData,
     subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
-    subscription::{book::OrderBooksL1, trade::PublicTrades, Map},
+    subscription::{
+        book::OrderBooksL1, candle::Candles, ticker::Tickers, trade::PublicTrades, Map,
+    },
     transformer::stateless::StatelessTransformer,
     ExchangeWsStream,
 };
@@ -18,6 +21,10 @@ use url::Url;
 /// [`BinanceFuturesUsd`](futures::BinanceFuturesUsd).
 pub mod book;
 
+/// Candle types common to both [`BinanceSpot`](spot::BinanceSpot) and
+/// [`BinanceFuturesUsd`](futures::BinanceFuturesUsd).
+pub mod candle;
+
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
 pub mod channel;
@@ -39,6 +46,10 @@ pub mod spot;
 /// and [`BinanceFuturesUsd`](futures::BinanceFuturesUsd).
 pub mod subscription;
 
+/// Rolling 24 hour ticker types common to both [`BinanceSpot`](spot::BinanceSpot) and
+/// [`BinanceFuturesUsd`](futures::BinanceFuturesUsd).
+pub mod ticker;
+
 /// Public trade types common to both [`BinanceSpot`](spot::BinanceSpot) and
 /// [`BinanceFuturesUsd`](futures::BinanceFuturesUsd).
 pub mod trade;
@@ -117,6 +128,24 @@ where
     >;
 }
 
+impl<Instrument, Server> StreamSelector<Instrument, Candles> for Binance<Server>
+where
+    Instrument: InstrumentData,
+    Server: ExchangeServer + Debug + Send + Sync,
+{
+    type Stream =
+        ExchangeWsStream<StatelessTransformer<Self, Instrument::Id, Candles, BinanceCandle>>;
+}
+
+impl<Instrument, Server> StreamSelector<Instrument, Tickers> for Binance<Server>
+where
+    Instrument: InstrumentData,
+    Server: ExchangeServer + Debug + Send + Sync,
+{
+    type Stream =
+        ExchangeWsStream<StatelessTransformer<Self, Instrument::Id, Tickers, BinanceTicker>>;
+}
+
 impl<'de, Server> serde::Deserialize<'de> for Binance<Server>
 where
     Server: ExchangeServer,
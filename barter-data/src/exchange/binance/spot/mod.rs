@@ -2,11 +2,11 @@ use self::l2::BinanceSpotBookUpdater;
 use super::{Binance, ExchangeServer};
 use crate::{
     exchange::{ExchangeId, StreamSelector},
+    instrument::InstrumentData,
     subscription::book::OrderBooksL2,
     transformer::book::MultiBookTransformer,
     ExchangeWsStream,
 };
-use barter_integration::model::instrument::Instrument;
 
 /// Level 2 OrderBook types (top of book) and spot
 /// [`OrderBookUpdater`](crate::transformer::book::OrderBookUpdater) implementation.
@@ -32,8 +32,11 @@ impl ExchangeServer for BinanceServerSpot {
     }
 }
 
-impl StreamSelector<Instrument, OrderBooksL2> for BinanceSpot {
+impl<Instrument> StreamSelector<Instrument, OrderBooksL2> for BinanceSpot
+where
+    Instrument: InstrumentData,
+{
     type Stream = ExchangeWsStream<
-        MultiBookTransformer<Self, Instrument, OrderBooksL2, BinanceSpotBookUpdater>,
+        MultiBookTransformer<Self, Instrument::Id, OrderBooksL2, BinanceSpotBookUpdater>,
     >;
 }
@@ -0,0 +1,149 @@
+use super::BinanceChannel;
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::{ExchangeId, ExchangeSub},
+    subscription::ticker::Ticker,
+    Identifier,
+};
+use barter_integration::model::{Exchange, SubscriptionId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// [`Binance`](super::Binance) real-time rolling 24 hour ticker message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#individual-symbol-ticker-streams>
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#individual-symbol-ticker-streams>
+/// ```json
+/// {
+///     "e": "24hrTicker",
+///     "E": 1672515782136,
+///     "s": "BTCUSDT",
+///     "p": "261.50000000",
+///     "P": "1.580",
+///     "w": "16697.75431405",
+///     "c": "16810.11000000",
+///     "Q": "0.01500000",
+///     "o": "16548.61000000",
+///     "h": "16853.00000000",
+///     "l": "16510.00000000",
+///     "v": "220546.53580000",
+///     "q": "3682621548.71546570",
+///     "O": 1672429382136,
+///     "C": 1672515782136,
+///     "F": 2395218963,
+///     "L": 2398452066,
+///     "n": 3233104
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceTicker {
+    #[serde(alias = "s", deserialize_with = "de_ticker_subscription_id")]
+    pub subscription_id: SubscriptionId,
+    #[serde(
+        alias = "E",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    #[serde(alias = "c", deserialize_with = "barter_integration::de::de_str")]
+    pub last_price: f64,
+    #[serde(alias = "h", deserialize_with = "barter_integration::de::de_str")]
+    pub high: f64,
+    #[serde(alias = "l", deserialize_with = "barter_integration::de::de_str")]
+    pub low: f64,
+    #[serde(alias = "v", deserialize_with = "barter_integration::de::de_str")]
+    pub volume: f64,
+    #[serde(alias = "p", deserialize_with = "barter_integration::de::de_str")]
+    pub price_change: f64,
+    #[serde(alias = "P", deserialize_with = "barter_integration::de::de_str")]
+    pub price_change_percent: f64,
+}
+
+impl Identifier<Option<SubscriptionId>> for BinanceTicker {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(self.subscription_id.clone())
+    }
+}
+
+impl<InstrumentId> From<(ExchangeId, InstrumentId, BinanceTicker)>
+    for MarketIter<InstrumentId, Ticker>
+{
+    fn from((exchange_id, instrument, ticker): (ExchangeId, InstrumentId, BinanceTicker)) -> Self {
+        Self(vec![Ok(MarketEvent {
+            exchange_time: ticker.time,
+            received_time: Utc::now(),
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: Ticker {
+                last_price: ticker.last_price,
+                high_24h: ticker.high,
+                low_24h: ticker.low,
+                volume_24h: ticker.volume,
+                price_change_24h: ticker.price_change,
+                price_change_percent_24h: ticker.price_change_percent,
+                time: ticker.time,
+            },
+        })])
+    }
+}
+
+/// Deserialize a [`BinanceTicker`] "s" (eg/ "BTCUSDT") as the associated [`SubscriptionId`]
+/// (eg/ "@ticker|BTCUSDT").
+pub fn de_ticker_subscription_id<'de, D>(deserializer: D) -> Result<SubscriptionId, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    <&str as Deserialize>::deserialize(deserializer)
+        .map(|market| ExchangeSub::from((BinanceChannel::TICKERS, market)).id())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::de::datetime_utc_from_epoch_duration;
+        use std::time::Duration;
+
+        #[test]
+        fn test_binance_ticker() {
+            let input = r#"
+            {
+                "e": "24hrTicker",
+                "E": 1672515782136,
+                "s": "BTCUSDT",
+                "p": "261.50000000",
+                "P": "1.580",
+                "w": "16697.75431405",
+                "c": "16810.11000000",
+                "Q": "0.01500000",
+                "o": "16548.61000000",
+                "h": "16853.00000000",
+                "l": "16510.00000000",
+                "v": "220546.53580000",
+                "q": "3682621548.71546570",
+                "O": 1672429382136,
+                "C": 1672515782136,
+                "F": 2395218963,
+                "L": 2398452066,
+                "n": 3233104
+            }
+            "#;
+
+            assert_eq!(
+                serde_json::from_str::<BinanceTicker>(input).unwrap(),
+                BinanceTicker {
+                    subscription_id: SubscriptionId::from("@ticker|BTCUSDT"),
+                    time: datetime_utc_from_epoch_duration(Duration::from_millis(1672515782136)),
+                    last_price: 16810.11,
+                    high: 16853.0,
+                    low: 16510.0,
+                    volume: 220546.5358,
+                    price_change: 261.5,
+                    price_change_percent: 1.58,
+                }
+            );
+        }
+    }
+}
@@ -0,0 +1,513 @@
+use super::BitfinexOrderL3;
+use crate::{
+    error::DataError,
+    subscription::book::{OrderBookL3, OrderBookL3Side, OrderBookL3Update},
+    transformer::book::{InstrumentOrderBook, OrderBookUpdater},
+    Identifier,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    de::extract_next,
+    model::{Side, SubscriptionId},
+    protocol::websocket::WsMessage,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
+
+/// [`Bitfinex`](super::super::Bitfinex) raw OrderBook depth (number of orders) requested on each
+/// side of the OrderBook.
+///
+/// See docs: <https://docs.bitfinex.com/reference/ws-public-raw-books>
+pub const BITFINEX_ORDER_BOOK_L3_DEPTH: usize = 250;
+
+/// [`Bitfinex`](super::super::Bitfinex) real-time raw OrderBook WebSocket message.
+///
+/// The message is associated with the original [`Subscription`](crate::Subscription) using the
+/// `channel_id` field as the [`SubscriptionId`].
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.bitfinex.com/reference/ws-public-raw-books>
+/// #### Snapshot
+/// ```json
+/// [17082, [[34768486853, 21012, 0.05], [34768486854, 21013, -0.1]]]
+/// ```
+///
+/// #### Update
+/// ```json
+/// [17082, [34768486853, 21012, 0.02]]
+/// ```
+///
+/// #### Heartbeat
+/// ```json
+/// [17082, "hb"]
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Serialize)]
+pub struct BitfinexOrderBookL3 {
+    pub channel_id: u32,
+    pub payload: BitfinexOrderBookL3Payload,
+}
+
+/// [`Bitfinex`](super::super::Bitfinex) raw OrderBook variants associated with an active
+/// [`Subscription`](crate::Subscription).
+///
+/// See [`BitfinexOrderBookL3`] for full raw payload examples.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Serialize)]
+pub enum BitfinexOrderBookL3Payload {
+    Heartbeat,
+    Snapshot(Vec<BitfinexOrderL3>),
+    Update(BitfinexOrderL3),
+}
+
+impl Identifier<Option<SubscriptionId>> for BitfinexOrderBookL3 {
+    fn id(&self) -> Option<SubscriptionId> {
+        match self.payload {
+            BitfinexOrderBookL3Payload::Heartbeat => None,
+            BitfinexOrderBookL3Payload::Snapshot(_) | BitfinexOrderBookL3Payload::Update(_) => {
+                Some(SubscriptionId::from(self.channel_id.to_string()))
+            }
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for BitfinexOrderBookL3 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        struct SeqVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for SeqVisitor {
+            type Value = BitfinexOrderBookL3;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("BitfinexOrderBookL3 struct from the Bitfinex WebSocket API")
+            }
+
+            fn visit_seq<SeqAccessor>(
+                self,
+                mut seq: SeqAccessor,
+            ) -> Result<Self::Value, SeqAccessor::Error>
+            where
+                SeqAccessor: serde::de::SeqAccess<'de>,
+            {
+                // Snapshot: [CHANNEL_ID, [[ORDER_ID, PRICE, AMOUNT], ...]]
+                // Update: [CHANNEL_ID, [ORDER_ID, PRICE, AMOUNT]]
+                // Heartbeat: [CHANNEL_ID, "hb"]
+                // Checksum: [CHANNEL_ID, "cs", CHECKSUM]
+
+                // Extract CHANNEL_ID used to identify SubscriptionId: 1st element of the sequence
+                let channel_id: u32 = extract_next(&mut seq, "channel_id")?;
+
+                // Extract payload: 2nd element of the sequence
+                let payload = match extract_next(&mut seq, "payload")? {
+                    // Checksums are not requested, so treat as an additional Heartbeat
+                    BitfinexOrderBookL3Element::Tag(tag) if tag == "hb" || tag == "cs" => {
+                        BitfinexOrderBookL3Payload::Heartbeat
+                    }
+                    BitfinexOrderBookL3Element::Tag(other) => {
+                        return Err(serde::de::Error::unknown_variant(
+                            &other,
+                            &["heartbeat (hb)", "checksum (cs)"],
+                        ))
+                    }
+                    BitfinexOrderBookL3Element::Snapshot(orders) => {
+                        BitfinexOrderBookL3Payload::Snapshot(orders)
+                    }
+                    BitfinexOrderBookL3Element::Update(order) => {
+                        BitfinexOrderBookL3Payload::Update(order)
+                    }
+                };
+
+                // Ignore any additional elements or SerDe will fail
+                //  '--> Bitfinex may add fields without warning
+                while seq.next_element::<serde::de::IgnoredAny>()?.is_some() {}
+                Ok(BitfinexOrderBookL3 {
+                    channel_id,
+                    payload,
+                })
+            }
+        }
+
+        // Use Visitor implementation to deserialise the WebSocket BitfinexOrderBookL3
+        deserializer.deserialize_seq(SeqVisitor)
+    }
+}
+
+/// Second element of a [`BitfinexOrderBookL3`] message, used to determine the
+/// [`BitfinexOrderBookL3Payload`] variant.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum BitfinexOrderBookL3Element {
+    Tag(String),
+    Snapshot(Vec<BitfinexOrderL3>),
+    Update(BitfinexOrderL3),
+}
+
+/// [`Bitfinex`](super::super::Bitfinex) [`OrderBookUpdater`] for raw OrderBooks.
+///
+/// Bitfinex: How To Manage A Local Raw OrderBook Correctly
+///
+/// 1. Subscribe to the "book" channel with precision "R0".
+/// 2. The first message received is a snapshot of the OrderBook, which initialises the local
+///    OrderBook.
+/// 3. Subsequent updates contain the latest price & amount of each changed order.
+/// 4. If the price is 0, remove the order.
+/// 5. If the price is > 0, add the order or modify it if it already exists.
+///
+/// See docs: <https://docs.bitfinex.com/reference/ws-public-raw-books>
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub struct BitfinexBookL3Updater {
+    pub updates_processed: u64,
+}
+
+#[async_trait]
+impl OrderBookUpdater for BitfinexBookL3Updater {
+    type OrderBook = OrderBookL3;
+    type Update = BitfinexOrderBookL3;
+    type Output = (DateTime<Utc>, OrderBookL3Update);
+
+    async fn init<Exchange, Kind, InstrumentId>(
+        _: mpsc::UnboundedSender<WsMessage>,
+        _: SubscriptionId,
+        instrument: InstrumentId,
+    ) -> Result<InstrumentOrderBook<InstrumentId, Self>, DataError>
+    where
+        Exchange: Send,
+        Kind: Send,
+        InstrumentId: Send,
+    {
+        // Initial OrderBook snapshot is the first message received via the WebSocket
+        Ok(InstrumentOrderBook {
+            instrument,
+            updater: Self::default(),
+            book: OrderBookL3::default(),
+        })
+    }
+
+    fn update(
+        &mut self,
+        book: &mut Self::OrderBook,
+        update: Self::Update,
+    ) -> Result<Option<Self::Output>, DataError> {
+        // Bitfinex: How To Manage A Local Raw OrderBook Correctly
+        // See Self's Rust Docs for more information on each numbered step
+        let time = Utc::now();
+        let output = match update.payload {
+            BitfinexOrderBookL3Payload::Heartbeat => return Ok(None),
+            BitfinexOrderBookL3Payload::Snapshot(orders) => {
+                // 2. Snapshot initialises the local OrderBook
+                let (bids, asks): (Vec<_>, Vec<_>) = orders
+                    .into_iter()
+                    .partition(|order| order.side == Side::Buy);
+
+                book.bids = OrderBookL3Side::new(Side::Buy, bids);
+                book.asks = OrderBookL3Side::new(Side::Sell, asks);
+                book.last_update_time = time;
+                OrderBookL3Update::Snapshot(book.clone())
+            }
+            BitfinexOrderBookL3Payload::Update(_) if self.updates_processed == 0 => {
+                return Err(DataError::MissingSnapshot);
+            }
+            BitfinexOrderBookL3Payload::Update(order) => {
+                // 3. 4. & 5. Remove, add or modify the order
+                let exists = match order.side {
+                    Side::Buy => book.bids.order(&order.id.to_string()).is_some(),
+                    Side::Sell => book.asks.order(&order.id.to_string()).is_some(),
+                };
+                let event = order.event(exists);
+                book.apply(event.clone());
+                book.last_update_time = time;
+                OrderBookL3Update::Event(event)
+            }
+        };
+
+        // Update OrderBookUpdater metadata
+        self.updates_processed += 1;
+
+        Ok(Some((time, output)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::error::SocketError;
+
+        #[test]
+        fn test_bitfinex_order_book_l3() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<BitfinexOrderBookL3, SocketError>,
+            }
+
+            let order = |id, side, price, amount| BitfinexOrderL3 {
+                id,
+                side,
+                price,
+                amount,
+            };
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid snapshot
+                    input: r#"[17082, [[34768486853, 21012, 0.05], [34768486854, 21013, -0.1]]]"#,
+                    expected: Ok(BitfinexOrderBookL3 {
+                        channel_id: 17082,
+                        payload: BitfinexOrderBookL3Payload::Snapshot(vec![
+                            order(34768486853, Side::Buy, 21012.0, 0.05),
+                            order(34768486854, Side::Sell, 21013.0, 0.1),
+                        ]),
+                    }),
+                },
+                TestCase {
+                    // TC1: valid empty snapshot
+                    input: r#"[17082, []]"#,
+                    expected: Ok(BitfinexOrderBookL3 {
+                        channel_id: 17082,
+                        payload: BitfinexOrderBookL3Payload::Snapshot(vec![]),
+                    }),
+                },
+                TestCase {
+                    // TC2: valid update
+                    input: r#"[17082, [34768486853, 21012, 0.02]]"#,
+                    expected: Ok(BitfinexOrderBookL3 {
+                        channel_id: 17082,
+                        payload: BitfinexOrderBookL3Payload::Update(order(
+                            34768486853,
+                            Side::Buy,
+                            21012.0,
+                            0.02,
+                        )),
+                    }),
+                },
+                TestCase {
+                    // TC3: valid heartbeat
+                    input: r#"[17082, "hb"]"#,
+                    expected: Ok(BitfinexOrderBookL3 {
+                        channel_id: 17082,
+                        payload: BitfinexOrderBookL3Payload::Heartbeat,
+                    }),
+                },
+                TestCase {
+                    // TC4: invalid unknown tag
+                    input: r#"[17082, "te", [1, 2, 3, 4]]"#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "",
+                        item: "".to_string(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<BitfinexOrderBookL3>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+
+    mod bitfinex_book_l3_updater {
+        use super::*;
+        use crate::subscription::book::{OrderBookL3Event, OrderL3};
+
+        fn message(payload: BitfinexOrderBookL3Payload) -> BitfinexOrderBookL3 {
+            BitfinexOrderBookL3 {
+                channel_id: 17082,
+                payload,
+            }
+        }
+
+        #[test]
+        fn test_update() {
+            struct TestCase {
+                updater: BitfinexBookL3Updater,
+                book: OrderBookL3,
+                input_update: BitfinexOrderBookL3,
+                expected: Result<Option<(Vec<OrderL3>, Vec<OrderL3>)>, DataError>,
+            }
+
+            let order = |id, side, price, amount| BitfinexOrderL3 {
+                id,
+                side,
+                price,
+                amount,
+            };
+
+            let initialised = || OrderBookL3 {
+                last_update_time: Default::default(),
+                bids: OrderBookL3Side::new(Side::Buy, vec![OrderL3::new("1", 100.0, 1.0)]),
+                asks: OrderBookL3Side::new(Side::Sell, vec![OrderL3::new("2", 110.0, 1.0)]),
+            };
+
+            let tests = vec![
+                TestCase {
+                    // TC0: update received before the initial snapshot is invalid
+                    updater: BitfinexBookL3Updater::default(),
+                    book: OrderBookL3::default(),
+                    input_update: message(BitfinexOrderBookL3Payload::Update(order(
+                        1,
+                        Side::Buy,
+                        100.0,
+                        1.0,
+                    ))),
+                    expected: Err(DataError::MissingSnapshot),
+                },
+                TestCase {
+                    // TC1: snapshot initialises the OrderBook, partitioning orders by Side
+                    updater: BitfinexBookL3Updater::default(),
+                    book: OrderBookL3::default(),
+                    input_update: message(BitfinexOrderBookL3Payload::Snapshot(vec![
+                        order(1, Side::Buy, 100.0, 1.0),
+                        order(2, Side::Sell, 110.0, 1.0),
+                        order(3, Side::Buy, 101.0, 2.0),
+                    ])),
+                    expected: Ok(Some((
+                        vec![OrderL3::new("3", 101.0, 2.0), OrderL3::new("1", 100.0, 1.0)],
+                        vec![OrderL3::new("2", 110.0, 1.0)],
+                    ))),
+                },
+                TestCase {
+                    // TC2: update for a new order adds it to the back of the price level queue
+                    updater: BitfinexBookL3Updater {
+                        updates_processed: 1,
+                    },
+                    book: initialised(),
+                    input_update: message(BitfinexOrderBookL3Payload::Update(order(
+                        3,
+                        Side::Buy,
+                        100.0,
+                        5.0,
+                    ))),
+                    expected: Ok(Some((
+                        vec![OrderL3::new("1", 100.0, 1.0), OrderL3::new("3", 100.0, 5.0)],
+                        vec![OrderL3::new("2", 110.0, 1.0)],
+                    ))),
+                },
+                TestCase {
+                    // TC3: update for an existing order modifies it
+                    updater: BitfinexBookL3Updater {
+                        updates_processed: 1,
+                    },
+                    book: initialised(),
+                    input_update: message(BitfinexOrderBookL3Payload::Update(order(
+                        2,
+                        Side::Sell,
+                        110.0,
+                        0.5,
+                    ))),
+                    expected: Ok(Some((
+                        vec![OrderL3::new("1", 100.0, 1.0)],
+                        vec![OrderL3::new("2", 110.0, 0.5)],
+                    ))),
+                },
+                TestCase {
+                    // TC4: update w/ price of 0 removes the order
+                    updater: BitfinexBookL3Updater {
+                        updates_processed: 1,
+                    },
+                    book: initialised(),
+                    input_update: message(BitfinexOrderBookL3Payload::Update(order(
+                        1,
+                        Side::Buy,
+                        0.0,
+                        1.0,
+                    ))),
+                    expected: Ok(Some((vec![], vec![OrderL3::new("2", 110.0, 1.0)]))),
+                },
+                TestCase {
+                    // TC5: heartbeat does not generate an OrderBook snapshot
+                    updater: BitfinexBookL3Updater {
+                        updates_processed: 1,
+                    },
+                    book: initialised(),
+                    input_update: message(BitfinexOrderBookL3Payload::Heartbeat),
+                    expected: Ok(None),
+                },
+            ];
+
+            for (index, mut test) in tests.into_iter().enumerate() {
+                let actual = test
+                    .updater
+                    .update(&mut test.book, test.input_update)
+                    .map(|output| {
+                        output.map(|_| {
+                            (
+                                test.book.bids.orders().to_vec(),
+                                test.book.asks.orders().to_vec(),
+                            )
+                        })
+                    });
+
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn test_update_yields_snapshot_then_events() {
+            let mut updater = BitfinexBookL3Updater::default();
+            let mut book = OrderBookL3::default();
+
+            let order = |id, price, amount| BitfinexOrderL3 {
+                id,
+                side: Side::Buy,
+                price,
+                amount,
+            };
+
+            // Snapshot yields the full OrderBookL3
+            let (_, output) = updater
+                .update(
+                    &mut book,
+                    message(BitfinexOrderBookL3Payload::Snapshot(vec![order(
+                        1, 100.0, 1.0,
+                    )])),
+                )
+                .unwrap()
+                .unwrap();
+            assert_eq!(output, OrderBookL3Update::Snapshot(book.clone()));
+
+            // Subsequent update only yields the applied OrderBookL3Event
+            let (_, output) = updater
+                .update(
+                    &mut book,
+                    message(BitfinexOrderBookL3Payload::Update(order(2, 99.0, 2.0))),
+                )
+                .unwrap()
+                .unwrap();
+            assert_eq!(
+                output,
+                OrderBookL3Update::Event(OrderBookL3Event::Add {
+                    side: Side::Buy,
+                    order: OrderL3::new("2", 99.0, 2.0),
+                })
+            );
+        }
+    }
+}
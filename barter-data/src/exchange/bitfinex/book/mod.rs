@@ -0,0 +1,163 @@
+use crate::subscription::book::{OrderBookL3Event, OrderL3};
+use barter_integration::{de::extract_next, model::Side};
+use serde::Serialize;
+
+/// Level 3 OrderBook types.
+pub mod l3;
+
+/// [`Bitfinex`](super::Bitfinex) raw OrderBook order.
+///
+/// ### Raw Payload Examples
+/// Format: \[ORDER_ID, PRICE, AMOUNT\], <br> where +/- of amount indicates Side, and a price of
+/// 0 indicates the order has been removed from the OrderBook.
+///
+/// See docs: <https://docs.bitfinex.com/reference/ws-public-raw-books>
+/// #### Side::Buy Order
+/// ```json
+/// [34768486853, 21012, 0.05]
+/// ```
+///
+/// #### Side::Sell Order Removed
+/// ```json
+/// [34768486853, 0, -1]
+/// ```
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Serialize)]
+pub struct BitfinexOrderL3 {
+    pub id: u64,
+    pub side: Side,
+    pub price: f64,
+    pub amount: f64,
+}
+
+impl BitfinexOrderL3 {
+    /// Determine if this [`BitfinexOrderL3`] communicates the order has been removed.
+    pub fn is_removed(&self) -> bool {
+        self.price == 0.0
+    }
+
+    /// Map this [`BitfinexOrderL3`] to a normalised Barter [`OrderBookL3Event`], using `exists`
+    /// to communicate if the order is already resting in the local OrderBook.
+    pub fn event(self, exists: bool) -> OrderBookL3Event {
+        match (self.is_removed(), exists) {
+            (true, _) => OrderBookL3Event::Delete {
+                side: self.side,
+                id: self.id.to_string(),
+            },
+            (false, true) => OrderBookL3Event::Modify {
+                side: self.side,
+                order: OrderL3::from(self),
+            },
+            (false, false) => OrderBookL3Event::Add {
+                side: self.side,
+                order: OrderL3::from(self),
+            },
+        }
+    }
+}
+
+impl From<BitfinexOrderL3> for OrderL3 {
+    fn from(order: BitfinexOrderL3) -> Self {
+        Self {
+            id: order.id.to_string(),
+            price: order.price,
+            amount: order.amount,
+        }
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for BitfinexOrderL3 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        struct SeqVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for SeqVisitor {
+            type Value = BitfinexOrderL3;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("BitfinexOrderL3 struct from the Bitfinex WebSocket API")
+            }
+
+            fn visit_seq<SeqAccessor>(
+                self,
+                mut seq: SeqAccessor,
+            ) -> Result<Self::Value, SeqAccessor::Error>
+            where
+                SeqAccessor: serde::de::SeqAccess<'de>,
+            {
+                // Raw Order: [ORDER_ID, PRICE, AMOUNT]
+                let id = extract_next(&mut seq, "id")?;
+                let price = extract_next(&mut seq, "price")?;
+                let amount: f64 = extract_next(&mut seq, "amount")?;
+
+                // Ignore any additional elements or SerDe will fail
+                //  '--> Bitfinex may add fields without warning
+                while seq.next_element::<serde::de::IgnoredAny>()?.is_some() {}
+
+                Ok(BitfinexOrderL3 {
+                    id,
+                    side: if amount.is_sign_positive() {
+                        Side::Buy
+                    } else {
+                        Side::Sell
+                    },
+                    price,
+                    amount: amount.abs(),
+                })
+            }
+        }
+
+        // Use Visitor implementation to deserialise the BitfinexOrderL3
+        deserializer.deserialize_seq(SeqVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_bitfinex_order_l3() {
+            struct TestCase {
+                input: &'static str,
+                expected: BitfinexOrderL3,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: Side::Buy order
+                    input: r#"[34768486853, 21012, 0.05]"#,
+                    expected: BitfinexOrderL3 {
+                        id: 34768486853,
+                        side: Side::Buy,
+                        price: 21012.0,
+                        amount: 0.05,
+                    },
+                },
+                TestCase {
+                    // TC1: Side::Sell order removed
+                    input: r#"[34768486853, 0, -1]"#,
+                    expected: BitfinexOrderL3 {
+                        id: 34768486853,
+                        side: Side::Sell,
+                        price: 0.0,
+                        amount: 1.0,
+                    },
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                assert_eq!(
+                    serde_json::from_str::<BitfinexOrderL3>(test.input).unwrap(),
+                    test.expected,
+                    "TC{} failed",
+                    index
+                );
+            }
+        }
+    }
+}
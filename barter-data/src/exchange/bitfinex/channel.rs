@@ -1,6 +1,6 @@
 use super::Bitfinex;
 use crate::{
-    subscription::{trade::PublicTrades, Subscription},
+    subscription::{book::OrderBooksL3, trade::PublicTrades, Subscription},
     Identifier,
 };
 use serde::Serialize;
@@ -17,6 +17,11 @@ impl BitfinexChannel {
     ///
     /// See docs: <https://docs.bitfinex.com/reference/ws-public-trades>
     pub const TRADES: Self = Self("trades");
+
+    /// [`Bitfinex`] real-time raw OrderBook channel (market-by-order).
+    ///
+    /// See docs: <https://docs.bitfinex.com/reference/ws-public-raw-books>
+    pub const ORDER_BOOK_L3: Self = Self("book");
 }
 
 impl<Instrument> Identifier<BitfinexChannel> for Subscription<Bitfinex, Instrument, PublicTrades> {
@@ -25,6 +30,12 @@ impl<Instrument> Identifier<BitfinexChannel> for Subscription<Bitfinex, Instrume
     }
 }
 
+impl<Instrument> Identifier<BitfinexChannel> for Subscription<Bitfinex, Instrument, OrderBooksL3> {
+    fn id(&self) -> BitfinexChannel {
+        BitfinexChannel::ORDER_BOOK_L3
+    }
+}
+
 impl AsRef<str> for BitfinexChannel {
     fn as_ref(&self) -> &str {
         self.0
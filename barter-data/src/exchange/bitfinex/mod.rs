@@ -20,15 +20,19 @@
 //! - Therefore, tag="tu" trades are filtered out and considered only as additional Heartbeats.
 
 use self::{
-    channel::BitfinexChannel, market::BitfinexMarket, message::BitfinexMessage,
-    subscription::BitfinexPlatformEvent, validator::BitfinexWebSocketSubValidator,
+    book::l3::{BitfinexBookL3Updater, BITFINEX_ORDER_BOOK_L3_DEPTH},
+    channel::BitfinexChannel,
+    market::BitfinexMarket,
+    message::BitfinexMessage,
+    subscription::BitfinexPlatformEvent,
+    validator::BitfinexWebSocketSubValidator,
 };
 use crate::{
     exchange::{Connector, ExchangeId, ExchangeSub, StreamSelector},
     instrument::InstrumentData,
     subscriber::WebSocketSubscriber,
-    subscription::trade::PublicTrades,
-    transformer::stateless::StatelessTransformer,
+    subscription::{book::OrderBooksL3, trade::PublicTrades},
+    transformer::{book::MultiBookTransformer, stateless::StatelessTransformer},
     ExchangeWsStream,
 };
 use barter_integration::{error::SocketError, protocol::websocket::WsMessage};
@@ -36,6 +40,10 @@ use barter_macro::{DeExchange, SerExchange};
 use serde_json::json;
 use url::Url;
 
+/// OrderBook types common to all [`Bitfinex`] OrderBook streams, as well as raw (L3) OrderBook
+/// types.
+pub mod book;
+
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
 pub mod channel;
@@ -87,14 +95,22 @@ impl Connector for Bitfinex {
         exchange_subs
             .into_iter()
             .map(|ExchangeSub { channel, market }| {
-                WsMessage::Text(
-                    json!({
+                let request = match channel {
+                    BitfinexChannel::ORDER_BOOK_L3 => json!({
                         "event": "subscribe",
                         "channel": channel.as_ref(),
                         "symbol": market.as_ref(),
-                    })
-                    .to_string(),
-                )
+                        "prec": "R0",
+                        "len": BITFINEX_ORDER_BOOK_L3_DEPTH.to_string(),
+                    }),
+                    _ => json!({
+                        "event": "subscribe",
+                        "channel": channel.as_ref(),
+                        "symbol": market.as_ref(),
+                    }),
+                };
+
+                WsMessage::Text(request.to_string())
             })
             .collect()
     }
@@ -107,3 +123,12 @@ where
     type Stream =
         ExchangeWsStream<StatelessTransformer<Self, Instrument::Id, PublicTrades, BitfinexMessage>>;
 }
+
+impl<Instrument> StreamSelector<Instrument, OrderBooksL3> for Bitfinex
+where
+    Instrument: InstrumentData,
+{
+    type Stream = ExchangeWsStream<
+        MultiBookTransformer<Self, Instrument::Id, OrderBooksL3, BitfinexBookL3Updater>,
+    >;
+}
@@ -1,4 +1,7 @@
-use super::subscription::{BitfinexPlatformEvent, BitfinexSubResponse};
+use super::{
+    channel::BitfinexChannel,
+    subscription::{BitfinexChannelId, BitfinexPlatformEvent, BitfinexSubResponse},
+};
 use crate::{
     exchange::{Connector, ExchangeSub},
     instrument::InstrumentData,
@@ -11,7 +14,7 @@ use barter_integration::{
     error::SocketError,
     model::SubscriptionId,
     protocol::{
-        websocket::{WebSocket, WebSocketParser},
+        websocket::{WebSocket, WebSocketParser, WsMessage},
         StreamParser,
     },
     Validator,
@@ -31,6 +34,8 @@ use tracing::debug;
 /// - Therefore the [`SubscriptionId`] format must change during [`BitfinexWebSocketSubValidator::validate`]
 ///   to use the [`BitfinexChannelId`](super::subscription::BitfinexChannelId)
 ///   (see module level "SubscriptionId" documentation notes for more details).
+/// - Initial snapshots are only buffered for OrderBook channels, since they are required to
+///   initialise the local OrderBook. Other initial snapshots (eg/ trades) are discarded.
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
 pub struct BitfinexWebSocketSubValidator;
 
@@ -41,7 +46,7 @@ impl SubscriptionValidator for BitfinexWebSocketSubValidator {
     async fn validate<Exchange, Instrument, Kind>(
         mut map: Map<Instrument::Id>,
         websocket: &mut WebSocket,
-    ) -> Result<Map<Instrument::Id>, SocketError>
+    ) -> Result<(Map<Instrument::Id>, Vec<WsMessage>), SocketError>
     where
         Exchange: Connector + Send,
         Instrument: InstrumentData,
@@ -56,13 +61,17 @@ impl SubscriptionValidator for BitfinexWebSocketSubValidator {
         let mut success_responses = 0usize;
         let mut init_snapshots_received = 0usize;
 
+        // Buffer initial OrderBook snapshots so they can be used to initialise the OrderBook
+        let mut book_channel_ids = Vec::new();
+        let mut buffered = Vec::new();
+
         loop {
             // Break if all Subscriptions were a success
             if success_responses == expected_responses
                 && init_snapshots_received == expected_responses
             {
                 debug!(exchange = %Exchange::ID, "validated exchange WebSocket subscriptions");
-                break Ok(map);
+                break Ok((map, buffered));
             }
 
             tokio::select! {
@@ -101,6 +110,9 @@ impl SubscriptionValidator for BitfinexWebSocketSubValidator {
                                 // Replace SubscriptionId with SubscriptionId(channel_id)
                                 if let Some(subscription) = map.0.remove(&subscription_id) {
                                     success_responses += 1;
+                                    if channel == BitfinexChannel::ORDER_BOOK_L3.as_ref() {
+                                        book_channel_ids.push(*channel_id);
+                                    }
                                     map.0.insert(SubscriptionId(channel_id.0.to_string()), subscription);
 
                                     debug!(
@@ -122,6 +134,12 @@ impl SubscriptionValidator for BitfinexWebSocketSubValidator {
                         Some(Err(SocketError::Deserialise { error, payload })) if success_responses >= 1 => {
                             // Already active Bitfinex subscriptions will send initial snapshots
                             init_snapshots_received += 1;
+                            if de_snapshot_channel_id(&payload)
+                                .is_some_and(|channel_id| book_channel_ids.contains(&channel_id))
+                            {
+                                buffered.push(WsMessage::Text(payload));
+                                continue
+                            }
                             debug!(
                                 exchange = %Exchange::ID,
                                 ?error,
@@ -147,3 +165,11 @@ impl SubscriptionValidator for BitfinexWebSocketSubValidator {
         }
     }
 }
+
+/// Deserialise the [`BitfinexChannelId`] from the first element of a raw
+/// [`Bitfinex`](super::Bitfinex) initial snapshot payload (eg/ `[CHANNEL_ID, [...]]`).
+fn de_snapshot_channel_id(payload: &str) -> Option<BitfinexChannelId> {
+    serde_json::from_str::<(BitfinexChannelId, serde::de::IgnoredAny)>(payload)
+        .map(|(channel_id, _)| channel_id)
+        .ok()
+}
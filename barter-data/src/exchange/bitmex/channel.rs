@@ -1,6 +1,6 @@
 use crate::{
     exchange::bitmex::Bitmex,
-    subscription::{trade::PublicTrades, Subscription},
+    subscription::{liquidation::Liquidations, trade::PublicTrades, Subscription},
     Identifier,
 };
 use serde::Serialize;
@@ -17,6 +17,11 @@ impl BitmexChannel {
     ///
     /// See docs: <https://www.bitmex.com/app/wsAPI>
     pub const TRADES: Self = Self("trade");
+
+    /// [`Bitmex`] real-time liquidation orders channel name.
+    ///
+    /// See docs: <https://www.bitmex.com/app/wsAPI#Subscriptions>
+    pub const LIQUIDATIONS: Self = Self("liquidation");
 }
 
 impl<Instrument> Identifier<BitmexChannel> for Subscription<Bitmex, Instrument, PublicTrades> {
@@ -25,6 +30,12 @@ impl<Instrument> Identifier<BitmexChannel> for Subscription<Bitmex, Instrument,
     }
 }
 
+impl<Instrument> Identifier<BitmexChannel> for Subscription<Bitmex, Instrument, Liquidations> {
+    fn id(&self) -> BitmexChannel {
+        BitmexChannel::LIQUIDATIONS
+    }
+}
+
 impl AsRef<str> for BitmexChannel {
     fn as_ref(&self) -> &str {
         self.0
@@ -0,0 +1,221 @@
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::ExchangeId,
+    subscription::liquidation::Liquidation,
+    Identifier,
+};
+use barter_integration::model::{Exchange, Side, SubscriptionId};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+/// [`Bitmex`](super::Bitmex) real-time liquidations WebSocket message.
+///
+/// Liquidation orders are "insert"ed when they enter the book, and subsequent "update" & "delete"
+/// actions only contain the fields that have changed. Only "insert" actions are translated into
+/// [`Liquidation`]s.
+///
+/// Note that the liquidation payload does not contain a timestamp, so the received time is used.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.bitmex.com/app/wsAPI#Subscriptions>
+/// ```json
+/// {
+///     "table": "liquidation",
+///     "action": "insert",
+///     "data": [
+///         {
+///             "orderID": "6b3e5a08-3d0d-4b6c-8f43-2b8d0e2d1c4a",
+///             "symbol": "XBTUSD",
+///             "side": "Sell",
+///             "price": 24231.5,
+///             "leavesQty": 1500
+///         }
+///     ]
+/// }
+///```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BitmexLiquidation {
+    pub table: String,
+    pub action: String,
+    pub data: Vec<BitmexLiquidationInner>,
+}
+
+/// [`Bitmex`](super::Bitmex) liquidation order.
+///
+/// See [`BitmexLiquidation`] for full raw payload examples.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BitmexLiquidationInner {
+    pub symbol: String,
+    pub side: Option<Side>,
+    pub price: Option<f64>,
+    #[serde(rename = "leavesQty")]
+    pub quantity: Option<f64>,
+}
+
+impl Identifier<Option<SubscriptionId>> for BitmexLiquidation {
+    fn id(&self) -> Option<SubscriptionId> {
+        self.data
+            .first()
+            .map(|liquidation| SubscriptionId(format!("{}|{}", self.table, liquidation.symbol)))
+    }
+}
+
+impl<InstrumentId: Clone> From<(ExchangeId, InstrumentId, BitmexLiquidation)>
+    for MarketIter<InstrumentId, Liquidation>
+{
+    fn from(
+        (exchange_id, instrument, liquidations): (ExchangeId, InstrumentId, BitmexLiquidation),
+    ) -> Self {
+        if liquidations.action != "insert" {
+            return Self(vec![]);
+        }
+
+        let time = Utc::now();
+
+        liquidations
+            .data
+            .into_iter()
+            .filter_map(|liquidation| {
+                let (Some(side), Some(price), Some(quantity)) =
+                    (liquidation.side, liquidation.price, liquidation.quantity)
+                else {
+                    return None;
+                };
+
+                Some(Ok(MarketEvent {
+                    exchange_time: time,
+                    received_time: time,
+                    exchange: Exchange::from(exchange_id),
+                    instrument: instrument.clone(),
+                    kind: Liquidation {
+                        side,
+                        price,
+                        quantity,
+                        time,
+                    },
+                }))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::error::SocketError;
+
+        #[test]
+        fn test_bitmex_liquidation() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<BitmexLiquidation, SocketError>,
+            }
+
+            let tests = vec![
+                // TC0: input BitmexLiquidation insert is deserialised
+                TestCase {
+                    input: r#"
+                    {
+                        "table": "liquidation",
+                        "action": "insert",
+                        "data": [
+                            {
+                                "orderID": "6b3e5a08-3d0d-4b6c-8f43-2b8d0e2d1c4a",
+                                "symbol": "XBTUSD",
+                                "side": "Sell",
+                                "price": 24231.5,
+                                "leavesQty": 1500
+                            }
+                        ]
+                    }
+                    "#,
+                    expected: Ok(BitmexLiquidation {
+                        table: "liquidation".to_string(),
+                        action: "insert".to_string(),
+                        data: vec![BitmexLiquidationInner {
+                            symbol: "XBTUSD".to_string(),
+                            side: Some(Side::Sell),
+                            price: Some(24231.5),
+                            quantity: Some(1500.0),
+                        }],
+                    }),
+                },
+                // TC1: input BitmexLiquidation delete is deserialised
+                TestCase {
+                    input: r#"
+                    {
+                        "table": "liquidation",
+                        "action": "delete",
+                        "data": [
+                            {
+                                "orderID": "6b3e5a08-3d0d-4b6c-8f43-2b8d0e2d1c4a",
+                                "symbol": "XBTUSD"
+                            }
+                        ]
+                    }
+                    "#,
+                    expected: Ok(BitmexLiquidation {
+                        table: "liquidation".to_string(),
+                        action: "delete".to_string(),
+                        data: vec![BitmexLiquidationInner {
+                            symbol: "XBTUSD".to_string(),
+                            side: None,
+                            price: None,
+                            quantity: None,
+                        }],
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<BitmexLiquidation>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_bitmex_liquidation_only_inserts_are_transformed() {
+        let liquidation = |action: &str| BitmexLiquidation {
+            table: "liquidation".to_string(),
+            action: action.to_string(),
+            data: vec![BitmexLiquidationInner {
+                symbol: "XBTUSD".to_string(),
+                side: Some(Side::Buy),
+                price: Some(24231.5),
+                quantity: Some(1500.0),
+            }],
+        };
+
+        let inserted = MarketIter::<&str, Liquidation>::from((
+            ExchangeId::Bitmex,
+            "xbt_usd",
+            liquidation("insert"),
+        ))
+        .0;
+        assert_eq!(inserted.len(), 1);
+        assert_eq!(inserted[0].as_ref().unwrap().kind.quantity, 1500.0);
+
+        let updated = MarketIter::<&str, Liquidation>::from((
+            ExchangeId::Bitmex,
+            "xbt_usd",
+            liquidation("update"),
+        ))
+        .0;
+        assert!(updated.is_empty());
+    }
+}
@@ -1,15 +1,15 @@
 use crate::{
     exchange::{
         bitmex::{
-            channel::BitmexChannel, market::BitmexMarket, subscription::BitmexSubResponse,
-            trade::BitmexTrade,
+            channel::BitmexChannel, liquidation::BitmexLiquidation, market::BitmexMarket,
+            subscription::BitmexSubResponse, trade::BitmexTrade,
         },
         subscription::ExchangeSub,
         Connector, ExchangeId, StreamSelector,
     },
     instrument::InstrumentData,
     subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
-    subscription::{trade::PublicTrades, Map},
+    subscription::{liquidation::Liquidations, trade::PublicTrades, Map},
     transformer::stateless::StatelessTransformer,
     ExchangeWsStream,
 };
@@ -22,6 +22,9 @@ use url::Url;
 /// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
 pub mod channel;
 
+/// Liquidation types for [`Bitmex`](Bitmex)
+pub mod liquidation;
+
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`] specific market used for generating [`Connector::requests`].
 pub mod market;
@@ -84,6 +87,15 @@ where
         ExchangeWsStream<StatelessTransformer<Self, Instrument::Id, PublicTrades, BitmexTrade>>;
 }
 
+impl<Instrument> StreamSelector<Instrument, Liquidations> for Bitmex
+where
+    Instrument: InstrumentData,
+{
+    type Stream = ExchangeWsStream<
+        StatelessTransformer<Self, Instrument::Id, Liquidations, BitmexLiquidation>,
+    >;
+}
+
 impl<'de> serde::Deserialize<'de> for Bitmex {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -0,0 +1,333 @@
+use super::BybitLevel;
+use crate::{
+    error::DataError,
+    exchange::bybit::{message::BybitPayload, subscription::BybitResponse},
+    subscription::book::{OrderBook, OrderBookSide},
+    transformer::book::{InstrumentOrderBook, OrderBookUpdater},
+    Identifier,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    model::{Side, SubscriptionId},
+    protocol::websocket::WsMessage,
+};
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
+
+/// Terse type alias for a [`Bybit`](super::super::Bybit) real-time OrderBook Level2 WebSocket
+/// message.
+pub type BybitOrderBookL2 = BybitPayload<BybitOrderBookL2Data>;
+
+/// [`Bybit`](super::super::Bybit) OrderBook Level2 websocket message supports both
+/// [`BybitOrderBookL2`] and [`BybitResponse`].
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum BybitOrderBookL2Message {
+    Response(BybitResponse),
+    OrderBook(BybitOrderBookL2),
+}
+
+/// [`Bybit`](super::super::Bybit) OrderBook Level2 snapshot or delta data contained in a
+/// [`BybitOrderBookL2`] message. A [`BybitPayload`] `type` of "snapshot" requires the local
+/// [`OrderBook`] to be reset, whereas "delta" levels are upserted into it.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/orderbook>
+/// ```json
+/// {
+///     "topic": "orderbook.50.BTCUSDT",
+///     "type": "snapshot",
+///     "ts": 1672304484978,
+///     "data": {
+///         "s": "BTCUSDT",
+///         "b": [["16493.50", "0.006"], ["16493.00", "0.100"]],
+///         "a": [["16611.00", "0.029"], ["16612.00", "0.213"]],
+///         "u": 18521288,
+///         "seq": 7961638724
+///     },
+///     "cts": 1672304484976
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BybitOrderBookL2Data {
+    #[serde(alias = "b")]
+    pub bids: Vec<BybitLevel>,
+    #[serde(alias = "a")]
+    pub asks: Vec<BybitLevel>,
+    #[serde(alias = "u")]
+    pub update_id: u64,
+}
+
+impl Identifier<Option<SubscriptionId>> for BybitOrderBookL2Message {
+    fn id(&self) -> Option<SubscriptionId> {
+        match self {
+            BybitOrderBookL2Message::OrderBook(book) => Some(book.subscription_id.clone()),
+            BybitOrderBookL2Message::Response(_) => None,
+        }
+    }
+}
+
+/// [`Bybit`](super::super::Bybit) [`OrderBookUpdater`].
+///
+/// Bybit: How To Manage A Local OrderBook Correctly
+///
+/// 1. Subscribe to the orderbook.{depth}.{symbol} topic.
+/// 2. The first message received is a "snapshot" of the OrderBook, which initialises the local
+///    OrderBook.
+/// 3. Subsequent "delta" messages contain the absolute quantity for each changed price level.
+/// 4. If the quantity is 0, remove the price level.
+/// 5. Each "delta" update id (u) should be equal to the previous update id + 1.
+/// 6. If a new "snapshot" is received (eg/ u=1 after a service restart), reset the local
+///    OrderBook.
+///
+/// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/orderbook>
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub struct BybitBookUpdater {
+    pub updates_processed: u64,
+    pub last_update_id: Option<u64>,
+}
+
+impl BybitBookUpdater {
+    /// Bybit: How To Manage A Local OrderBook Correctly: Step 5:
+    /// "Each "delta" update id (u) should be equal to the previous update id + 1"
+    pub fn validate_next_update(&self, update: &BybitOrderBookL2Data) -> Result<(), DataError> {
+        let last_update_id = self.last_update_id.ok_or(DataError::MissingSnapshot)?;
+
+        if update.update_id == last_update_id + 1 {
+            Ok(())
+        } else {
+            Err(DataError::InvalidSequence {
+                prev_last_update_id: last_update_id,
+                first_update_id: update.update_id,
+            })
+        }
+    }
+}
+
+#[async_trait]
+impl OrderBookUpdater for BybitBookUpdater {
+    type OrderBook = OrderBook;
+    type Update = BybitOrderBookL2Message;
+    type Output = OrderBook;
+
+    async fn init<Exchange, Kind, InstrumentId>(
+        _: mpsc::UnboundedSender<WsMessage>,
+        _: SubscriptionId,
+        instrument: InstrumentId,
+    ) -> Result<InstrumentOrderBook<InstrumentId, Self>, DataError>
+    where
+        Exchange: Send,
+        Kind: Send,
+        InstrumentId: Send,
+    {
+        // Initial OrderBook snapshot is the first message received via the WebSocket
+        Ok(InstrumentOrderBook {
+            instrument,
+            updater: Self::default(),
+            book: OrderBook::default(),
+        })
+    }
+
+    fn update(
+        &mut self,
+        book: &mut Self::OrderBook,
+        update: Self::Update,
+    ) -> Result<Option<Self::Output>, DataError> {
+        // Bybit: How To Manage A Local OrderBook Correctly
+        // See Self's Rust Docs for more information on each numbered step
+        let update = match update {
+            BybitOrderBookL2Message::Response(_) => return Ok(None),
+            BybitOrderBookL2Message::OrderBook(update) => update,
+        };
+
+        if update.r#type == "snapshot" {
+            // 2. & 6. Snapshot (re)initialises the local OrderBook
+            book.bids = OrderBookSide::new(Side::Buy, update.data.bids);
+            book.asks = OrderBookSide::new(Side::Sell, update.data.asks);
+        } else {
+            // 5. Each "delta" update id should be equal to the previous update id + 1
+            self.validate_next_update(&update.data)?;
+
+            // 3. & 4. Upsert absolute quantity of each price level, removing 0 quantities
+            book.bids.upsert(update.data.bids);
+            book.asks.upsert(update.data.asks);
+        }
+
+        // Update OrderBook & OrderBookUpdater metadata
+        book.last_update_time = update.time;
+        self.updates_processed += 1;
+        self.last_update_id = Some(update.data.update_id);
+
+        Ok(Some(book.snapshot()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::de::datetime_utc_from_epoch_duration;
+    use std::time::Duration;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_bybit_order_book_l2() {
+            let input = r#"
+            {
+                "topic": "orderbook.50.BTCUSDT",
+                "type": "snapshot",
+                "ts": 1672304484978,
+                "data": {
+                    "s": "BTCUSDT",
+                    "b": [["16493.50", "0.006"], ["16493.00", "0.100"]],
+                    "a": [["16611.00", "0.029"]],
+                    "u": 18521288,
+                    "seq": 7961638724
+                },
+                "cts": 1672304484976
+            }
+            "#;
+
+            assert_eq!(
+                serde_json::from_str::<BybitOrderBookL2>(input).unwrap(),
+                BybitOrderBookL2 {
+                    subscription_id: SubscriptionId::from("orderbook.50|BTCUSDT"),
+                    r#type: "snapshot".to_string(),
+                    time: datetime_utc_from_epoch_duration(Duration::from_millis(1672304484978)),
+                    data: BybitOrderBookL2Data {
+                        bids: vec![
+                            BybitLevel {
+                                price: 16493.50,
+                                amount: 0.006
+                            },
+                            BybitLevel {
+                                price: 16493.00,
+                                amount: 0.100
+                            },
+                        ],
+                        asks: vec![BybitLevel {
+                            price: 16611.00,
+                            amount: 0.029
+                        }],
+                        update_id: 18521288,
+                    },
+                }
+            );
+        }
+    }
+
+    mod bybit_book_updater {
+        use super::*;
+        use crate::subscription::book::Level;
+
+        fn message(r#type: &str, update_id: u64, bids: Vec<BybitLevel>) -> BybitOrderBookL2Message {
+            BybitOrderBookL2Message::OrderBook(BybitOrderBookL2 {
+                subscription_id: SubscriptionId::from("orderbook.50|BTCUSDT"),
+                r#type: r#type.to_string(),
+                time: datetime_utc_from_epoch_duration(Duration::from_millis(update_id)),
+                data: BybitOrderBookL2Data {
+                    bids,
+                    asks: vec![],
+                    update_id,
+                },
+            })
+        }
+
+        #[test]
+        fn test_update() {
+            struct TestCase {
+                updater: BybitBookUpdater,
+                book: OrderBook,
+                input_update: BybitOrderBookL2Message,
+                expected: Result<Option<OrderBook>, DataError>,
+            }
+
+            let level = |price, amount| BybitLevel { price, amount };
+
+            let tests = vec![
+                TestCase {
+                    // TC0: delta received before the initial snapshot is invalid
+                    updater: BybitBookUpdater::default(),
+                    book: OrderBook::default(),
+                    input_update: message("delta", 10, vec![level(50.0, 1.0)]),
+                    expected: Err(DataError::MissingSnapshot),
+                },
+                TestCase {
+                    // TC1: snapshot initialises the OrderBook
+                    updater: BybitBookUpdater::default(),
+                    book: OrderBook::default(),
+                    input_update: message("snapshot", 10, vec![level(50.0, 1.0), level(60.0, 1.0)]),
+                    expected: Ok(Some(OrderBook {
+                        last_update_time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            10,
+                        )),
+                        bids: OrderBookSide::new(
+                            Side::Buy,
+                            vec![Level::new(60, 1), Level::new(50, 1)],
+                        ),
+                        asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
+                    })),
+                },
+                TestCase {
+                    // TC2: valid delta removes & upserts levels
+                    updater: BybitBookUpdater {
+                        updates_processed: 1,
+                        last_update_id: Some(10),
+                    },
+                    book: OrderBook {
+                        last_update_time: Default::default(),
+                        bids: OrderBookSide::new(
+                            Side::Buy,
+                            vec![Level::new(60, 1), Level::new(50, 1)],
+                        ),
+                        asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
+                    },
+                    input_update: message("delta", 11, vec![level(60.0, 0.0), level(55.0, 2.0)]),
+                    expected: Ok(Some(OrderBook {
+                        last_update_time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            11,
+                        )),
+                        bids: OrderBookSide::new(
+                            Side::Buy,
+                            vec![Level::new(55, 2), Level::new(50, 1)],
+                        ),
+                        asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
+                    })),
+                },
+                TestCase {
+                    // TC3: delta w/ update id != last_update_id + 1 is an invalid sequence
+                    updater: BybitBookUpdater {
+                        updates_processed: 1,
+                        last_update_id: Some(10),
+                    },
+                    book: OrderBook::default(),
+                    input_update: message("delta", 12, vec![level(55.0, 2.0)]),
+                    expected: Err(DataError::InvalidSequence {
+                        prev_last_update_id: 10,
+                        first_update_id: 12,
+                    }),
+                },
+            ];
+
+            for (index, mut test) in tests.into_iter().enumerate() {
+                let actual = test.updater.update(&mut test.book, test.input_update);
+
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+}
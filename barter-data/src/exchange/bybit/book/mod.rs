@@ -0,0 +1,50 @@
+use crate::subscription::book::Level;
+use serde::{Deserialize, Serialize};
+
+/// Level 2 OrderBook types.
+pub mod l2;
+
+/// [`Bybit`](super::Bybit) OrderBook level.
+///
+/// #### Raw Payload Examples
+/// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/orderbook>
+/// ```json
+/// ["16493.50", "0.006"]
+/// ```
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BybitLevel {
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub price: f64,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub amount: f64,
+}
+
+impl From<BybitLevel> for Level {
+    fn from(level: BybitLevel) -> Self {
+        Self {
+            price: level.price,
+            amount: level.amount,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_bybit_level() {
+            let input = r#"["16493.50", "0.006"]"#;
+            assert_eq!(
+                serde_json::from_str::<BybitLevel>(input).unwrap(),
+                BybitLevel {
+                    price: 16493.50,
+                    amount: 0.006
+                },
+            )
+        }
+    }
+}
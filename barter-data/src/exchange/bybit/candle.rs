@@ -0,0 +1,242 @@
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::{
+        bybit::{message::BybitPayload, subscription::BybitResponse},
+        ExchangeId,
+    },
+    subscription::candle::Candle,
+    Identifier,
+};
+use barter_integration::model::{Exchange, SubscriptionId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for a [`Bybit`](super::Bybit) real-time kline WebSocket message.
+pub type BybitCandle = BybitPayload<Vec<BybitKline>>;
+
+/// [`Bybit`](super::Bybit) kline websocket message supports both [`BybitCandle`] and
+/// [`BybitResponse`].
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum BybitCandleMessage {
+    Response(BybitResponse),
+    Candle(BybitCandle),
+}
+
+/// ### Raw Payload Examples
+/// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/kline>
+///```json
+/// {
+///     "topic": "kline.5.BTCUSDT",
+///     "type": "snapshot",
+///     "ts": 1672324988882,
+///     "data": [
+///         {
+///             "start": 1672324800000,
+///             "end": 1672325099999,
+///             "interval": "5",
+///             "open": "16649.5",
+///             "close": "16677",
+///             "high": "16677",
+///             "low": "16608",
+///             "volume": "2.081",
+///             "turnover": "34666.4005",
+///             "confirm": true,
+///             "timestamp": 1672324988882
+///         }
+///     ]
+/// }
+/// ```
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BybitKline {
+    #[serde(
+        rename = "end",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub close_time: DateTime<Utc>,
+
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub open: f64,
+
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub high: f64,
+
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub low: f64,
+
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub close: f64,
+
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub volume: f64,
+
+    #[serde(rename = "confirm")]
+    pub closed: bool,
+}
+
+impl Identifier<Option<SubscriptionId>> for BybitCandleMessage {
+    fn id(&self) -> Option<SubscriptionId> {
+        match self {
+            BybitCandleMessage::Candle(candle) => Some(candle.subscription_id.clone()),
+            BybitCandleMessage::Response(_) => None,
+        }
+    }
+}
+
+impl<InstrumentId: Clone> From<(ExchangeId, InstrumentId, BybitCandleMessage)>
+    for MarketIter<InstrumentId, Candle>
+{
+    fn from(
+        (exchange_id, instrument, message): (ExchangeId, InstrumentId, BybitCandleMessage),
+    ) -> Self {
+        let candles = match message {
+            BybitCandleMessage::Response(_) => return Self(vec![]),
+            BybitCandleMessage::Candle(candles) => candles,
+        };
+
+        // Only yield closed candles, in-progress interval updates are ignored
+        candles
+            .data
+            .into_iter()
+            .filter(|kline| kline.closed)
+            .map(|kline| {
+                Ok(MarketEvent {
+                    exchange_time: kline.close_time,
+                    received_time: Utc::now(),
+                    exchange: Exchange::from(exchange_id),
+                    instrument: instrument.clone(),
+                    kind: Candle {
+                        close_time: kline.close_time,
+                        open: kline.open,
+                        high: kline.high,
+                        low: kline.low,
+                        close: kline.close,
+                        volume: kline.volume,
+                        // Bybit does not provide the number of trades in a kline
+                        trade_count: 0,
+                    },
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::{de::datetime_utc_from_epoch_duration, error::SocketError};
+        use serde::de::Error;
+        use std::time::Duration;
+
+        #[test]
+        fn test_bybit_candle() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<BybitCandle, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid closed 5m kline payload
+                    input: r#"
+                    {
+                        "topic": "kline.5.BTCUSDT",
+                        "type": "snapshot",
+                        "ts": 1672324988882,
+                        "data": [
+                            {
+                                "start": 1672324800000,
+                                "end": 1672325099999,
+                                "interval": "5",
+                                "open": "16649.5",
+                                "close": "16677",
+                                "high": "16677",
+                                "low": "16608",
+                                "volume": "2.081",
+                                "turnover": "34666.4005",
+                                "confirm": true,
+                                "timestamp": 1672324988882
+                            }
+                        ]
+                    }
+                    "#,
+                    expected: Ok(BybitCandle {
+                        subscription_id: SubscriptionId::from("kline.5|BTCUSDT"),
+                        r#type: "snapshot".to_string(),
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1672324988882,
+                        )),
+                        data: vec![BybitKline {
+                            close_time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                1672325099999,
+                            )),
+                            open: 16649.5,
+                            high: 16677.0,
+                            low: 16608.0,
+                            close: 16677.0,
+                            volume: 2.081,
+                            closed: true,
+                        }],
+                    }),
+                },
+                TestCase {
+                    // TC1: kline topic missing the interval is invalid
+                    input: r#"
+                    {
+                        "topic": "kline.BTCUSDT",
+                        "type": "snapshot",
+                        "ts": 1672324988882,
+                        "data": []
+                    }
+                    "#,
+                    expected: Err(SocketError::Deserialise {
+                        error: serde_json::Error::custom(""),
+                        payload: "".to_owned(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<BybitCandle>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_bybit_candle_closed_filter() {
+        let kline = |closed| BybitKline {
+            close_time: DateTime::<Utc>::MIN_UTC,
+            open: 1.0,
+            high: 2.0,
+            low: 0.5,
+            close: 1.5,
+            volume: 10.0,
+            closed,
+        };
+
+        let message = BybitCandleMessage::Candle(BybitCandle {
+            subscription_id: SubscriptionId::from("kline.1|BTCUSDT"),
+            r#type: "snapshot".to_string(),
+            time: DateTime::<Utc>::MIN_UTC,
+            data: vec![kline(false), kline(true), kline(false)],
+        });
+
+        let actual = MarketIter::<(), Candle>::from((ExchangeId::BybitSpot, (), message));
+        assert_eq!(actual.0.len(), 1);
+    }
+}
@@ -1,6 +1,16 @@
 use crate::{
-    exchange::bybit::Bybit,
-    subscription::{trade::PublicTrades, Subscription},
+    exchange::bybit::{futures::BybitPerpetualsUsd, Bybit},
+    subscription::{
+        book::OrderBooksL2,
+        candle::{CandleInterval, Candles},
+        funding::FundingRates,
+        liquidation::Liquidations,
+        open_interest::OpenInterests,
+        price::{IndexPrices, MarkPrices},
+        ticker::Tickers,
+        trade::PublicTrades,
+        Subscription,
+    },
     Identifier,
 };
 use serde::Serialize;
@@ -17,6 +27,38 @@ impl BybitChannel {
     ///
     /// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/trade>
     pub const TRADES: Self = Self("publicTrade");
+
+    /// [`Bybit`] real-time OrderBook Level2 (depth 50) channel name.
+    ///
+    /// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/orderbook>
+    pub const ORDER_BOOK_L2: Self = Self("orderbook.50");
+
+    /// [`Bybit`] real-time tickers channel name, which contains the 24 hour statistics, and
+    /// for [`BybitPerpetualsUsd`] the open interest, funding rate, mark price & index price.
+    ///
+    /// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/ticker>
+    pub const TICKERS: Self = Self("tickers");
+
+    /// [`BybitPerpetualsUsd`] real-time liquidations channel name.
+    ///
+    /// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/all-liquidation>
+    pub const LIQUIDATIONS: Self = Self("allLiquidation");
+
+    /// [`Bybit`] kline (candle) channel name for the provided [`CandleInterval`].
+    ///
+    /// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/kline>
+    pub const fn candles(interval: CandleInterval) -> Self {
+        match interval {
+            CandleInterval::M1 => Self("kline.1"),
+            CandleInterval::M5 => Self("kline.5"),
+            CandleInterval::M15 => Self("kline.15"),
+            CandleInterval::M30 => Self("kline.30"),
+            CandleInterval::H1 => Self("kline.60"),
+            CandleInterval::H4 => Self("kline.240"),
+            CandleInterval::D1 => Self("kline.D"),
+            CandleInterval::W1 => Self("kline.W"),
+        }
+    }
 }
 
 impl<Server, Instrument> Identifier<BybitChannel>
@@ -27,6 +69,70 @@ impl<Server, Instrument> Identifier<BybitChannel>
     }
 }
 
+impl<Server, Instrument> Identifier<BybitChannel>
+    for Subscription<Bybit<Server>, Instrument, Candles>
+{
+    fn id(&self) -> BybitChannel {
+        BybitChannel::candles(self.kind.0)
+    }
+}
+
+impl<Server, Instrument> Identifier<BybitChannel>
+    for Subscription<Bybit<Server>, Instrument, OrderBooksL2>
+{
+    fn id(&self) -> BybitChannel {
+        BybitChannel::ORDER_BOOK_L2
+    }
+}
+
+impl<Instrument> Identifier<BybitChannel>
+    for Subscription<BybitPerpetualsUsd, Instrument, FundingRates>
+{
+    fn id(&self) -> BybitChannel {
+        BybitChannel::TICKERS
+    }
+}
+
+impl<Instrument> Identifier<BybitChannel>
+    for Subscription<BybitPerpetualsUsd, Instrument, MarkPrices>
+{
+    fn id(&self) -> BybitChannel {
+        BybitChannel::TICKERS
+    }
+}
+
+impl<Instrument> Identifier<BybitChannel>
+    for Subscription<BybitPerpetualsUsd, Instrument, IndexPrices>
+{
+    fn id(&self) -> BybitChannel {
+        BybitChannel::TICKERS
+    }
+}
+
+impl<Server, Instrument> Identifier<BybitChannel>
+    for Subscription<Bybit<Server>, Instrument, Tickers>
+{
+    fn id(&self) -> BybitChannel {
+        BybitChannel::TICKERS
+    }
+}
+
+impl<Instrument> Identifier<BybitChannel>
+    for Subscription<BybitPerpetualsUsd, Instrument, OpenInterests>
+{
+    fn id(&self) -> BybitChannel {
+        BybitChannel::TICKERS
+    }
+}
+
+impl<Instrument> Identifier<BybitChannel>
+    for Subscription<BybitPerpetualsUsd, Instrument, Liquidations>
+{
+    fn id(&self) -> BybitChannel {
+        BybitChannel::LIQUIDATIONS
+    }
+}
+
 impl AsRef<str> for BybitChannel {
     fn as_ref(&self) -> &str {
         self.0
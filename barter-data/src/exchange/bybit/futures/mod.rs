@@ -1,5 +1,21 @@
-use super::{Bybit, ExchangeServer};
-use crate::exchange::ExchangeId;
+use super::{
+    liquidation::BybitLiquidationMessage,
+    ticker::{BybitTickerMessage, BybitTickerTransformer},
+    Bybit, ExchangeServer,
+};
+use crate::{
+    exchange::{ExchangeId, StreamSelector},
+    instrument::InstrumentData,
+    subscription::{
+        funding::FundingRates,
+        liquidation::Liquidations,
+        open_interest::OpenInterests,
+        price::{IndexPrices, MarkPrices},
+        ticker::Tickers,
+    },
+    transformer::stateless::StatelessTransformer,
+    ExchangeWsStream,
+};
 
 /// [`BybitPerpetualsUsd`] WebSocket server base url.
 ///
@@ -20,3 +36,55 @@ impl ExchangeServer for BybitServerPerpetualsUsd {
         WEBSOCKET_BASE_URL_BYBIT_PERPETUALS_USD
     }
 }
+
+impl<Instrument> StreamSelector<Instrument, FundingRates> for BybitPerpetualsUsd
+where
+    Instrument: InstrumentData,
+{
+    type Stream = ExchangeWsStream<
+        StatelessTransformer<Self, Instrument::Id, FundingRates, BybitTickerMessage>,
+    >;
+}
+
+impl<Instrument> StreamSelector<Instrument, MarkPrices> for BybitPerpetualsUsd
+where
+    Instrument: InstrumentData,
+{
+    type Stream = ExchangeWsStream<
+        StatelessTransformer<Self, Instrument::Id, MarkPrices, BybitTickerMessage>,
+    >;
+}
+
+impl<Instrument> StreamSelector<Instrument, IndexPrices> for BybitPerpetualsUsd
+where
+    Instrument: InstrumentData,
+{
+    type Stream = ExchangeWsStream<
+        StatelessTransformer<Self, Instrument::Id, IndexPrices, BybitTickerMessage>,
+    >;
+}
+
+impl<Instrument> StreamSelector<Instrument, OpenInterests> for BybitPerpetualsUsd
+where
+    Instrument: InstrumentData,
+{
+    type Stream = ExchangeWsStream<
+        StatelessTransformer<Self, Instrument::Id, OpenInterests, BybitTickerMessage>,
+    >;
+}
+
+impl<Instrument> StreamSelector<Instrument, Tickers> for BybitPerpetualsUsd
+where
+    Instrument: InstrumentData,
+{
+    type Stream = ExchangeWsStream<BybitTickerTransformer<Instrument::Id>>;
+}
+
+impl<Instrument> StreamSelector<Instrument, Liquidations> for BybitPerpetualsUsd
+where
+    Instrument: InstrumentData,
+{
+    type Stream = ExchangeWsStream<
+        StatelessTransformer<Self, Instrument::Id, Liquidations, BybitLiquidationMessage>,
+    >;
+}
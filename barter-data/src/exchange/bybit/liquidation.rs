@@ -0,0 +1,193 @@
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::{
+        bybit::{message::BybitPayload, subscription::BybitResponse},
+        ExchangeId,
+    },
+    subscription::liquidation::Liquidation,
+    Identifier,
+};
+use barter_integration::model::{Exchange, Side, SubscriptionId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for a [`Bybit`](super::Bybit) real-time liquidations WebSocket message.
+pub type BybitLiquidation = BybitPayload<Vec<BybitLiquidationInner>>;
+
+/// [`Bybit`](super::Bybit) liquidation websocket message supports both [`BybitLiquidation`] and
+/// [`BybitResponse`].
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum BybitLiquidationMessage {
+    Response(BybitResponse),
+    Liquidation(BybitLiquidation),
+}
+
+/// [`Bybit`](super::Bybit) liquidation.
+///
+/// Note that "S" is the side of the liquidated position, so it is inverted to yield the
+/// [`Side`] of the liquidation order.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/all-liquidation>
+///```json
+/// {
+///     "topic": "allLiquidation.ROSEUSDT",
+///     "type": "snapshot",
+///     "ts": 1739502303204,
+///     "data": [
+///         {
+///             "T": 1739502302929,
+///             "s": "ROSEUSDT",
+///             "S": "Sell",
+///             "v": "20000",
+///             "p": "0.04499"
+///         }
+///     ]
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BybitLiquidationInner {
+    #[serde(
+        alias = "T",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+
+    #[serde(rename = "s")]
+    pub market: String,
+
+    #[serde(rename = "S")]
+    pub position_side: Side,
+
+    #[serde(alias = "v", deserialize_with = "barter_integration::de::de_str")]
+    pub quantity: f64,
+
+    #[serde(alias = "p", deserialize_with = "barter_integration::de::de_str")]
+    pub price: f64,
+}
+
+impl Identifier<Option<SubscriptionId>> for BybitLiquidationMessage {
+    fn id(&self) -> Option<SubscriptionId> {
+        match self {
+            BybitLiquidationMessage::Liquidation(liquidation) => {
+                Some(liquidation.subscription_id.clone())
+            }
+            BybitLiquidationMessage::Response(_) => None,
+        }
+    }
+}
+
+impl<InstrumentId: Clone> From<(ExchangeId, InstrumentId, BybitLiquidationMessage)>
+    for MarketIter<InstrumentId, Liquidation>
+{
+    fn from(
+        (exchange_id, instrument, message): (ExchangeId, InstrumentId, BybitLiquidationMessage),
+    ) -> Self {
+        let BybitLiquidationMessage::Liquidation(liquidations) = message else {
+            return Self(vec![]);
+        };
+
+        liquidations
+            .data
+            .into_iter()
+            .map(|liquidation| {
+                Ok(MarketEvent {
+                    exchange_time: liquidation.time,
+                    received_time: Utc::now(),
+                    exchange: Exchange::from(exchange_id),
+                    instrument: instrument.clone(),
+                    kind: Liquidation {
+                        side: match liquidation.position_side {
+                            Side::Buy => Side::Sell,
+                            Side::Sell => Side::Buy,
+                        },
+                        price: liquidation.price,
+                        quantity: liquidation.quantity,
+                        time: liquidation.time,
+                    },
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::de::datetime_utc_from_epoch_duration;
+        use std::time::Duration;
+
+        #[test]
+        fn test_bybit_liquidation() {
+            let input = r#"
+            {
+                "topic": "allLiquidation.ROSEUSDT",
+                "type": "snapshot",
+                "ts": 1739502303204,
+                "data": [
+                    {
+                        "T": 1739502302929,
+                        "s": "ROSEUSDT",
+                        "S": "Sell",
+                        "v": "20000",
+                        "p": "0.04499"
+                    }
+                ]
+            }
+            "#;
+
+            let actual = serde_json::from_str::<BybitLiquidationMessage>(input).unwrap();
+            let BybitLiquidationMessage::Liquidation(actual) = actual else {
+                panic!("expected BybitLiquidationMessage::Liquidation, got: {actual:?}");
+            };
+
+            assert_eq!(
+                actual,
+                BybitLiquidation {
+                    subscription_id: SubscriptionId::from("allLiquidation|ROSEUSDT"),
+                    r#type: "snapshot".to_string(),
+                    time: datetime_utc_from_epoch_duration(Duration::from_millis(1739502303204)),
+                    data: vec![BybitLiquidationInner {
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1739502302929
+                        )),
+                        market: "ROSEUSDT".to_string(),
+                        position_side: Side::Sell,
+                        quantity: 20000.0,
+                        price: 0.04499,
+                    }],
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn test_bybit_liquidation_side_is_inverted() {
+        let message = BybitLiquidationMessage::Liquidation(BybitLiquidation {
+            subscription_id: SubscriptionId::from("allLiquidation|BTCUSDT"),
+            r#type: "snapshot".to_string(),
+            time: Utc::now(),
+            data: vec![BybitLiquidationInner {
+                time: Utc::now(),
+                market: "BTCUSDT".to_string(),
+                position_side: Side::Buy,
+                quantity: 1.0,
+                price: 20000.0,
+            }],
+        });
+
+        let events = MarketIter::<&str, Liquidation>::from((
+            ExchangeId::BybitPerpetualsUsd,
+            "btc_usdt",
+            message,
+        ))
+        .0;
+
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].as_ref().unwrap().kind.side, Side::Sell);
+    }
+}
@@ -60,10 +60,11 @@ pub struct BybitPayload<T> {
     pub data: T,
 }
 
-/// Deserialize a [`BybitPayload`] "s" (eg/ "publicTrade.BTCUSDT") as the associated
+/// Deserialize a [`BybitPayload`] "topic" (eg/ "publicTrade.BTCUSDT") as the associated
 /// [`SubscriptionId`].
 ///
-/// eg/ "publicTrade|BTCUSDT"
+/// eg/ "publicTrade|BTCUSDT", "tickers|BTCUSDT", "allLiquidation|BTCUSDT", or "kline.5|BTCUSDT" for a "kline.5.BTCUSDT" topic, or
+/// "orderbook.50|BTCUSDT" for an "orderbook.50.BTCUSDT" topic
 pub fn de_message_subscription_id<'de, D>(deserializer: D) -> Result<SubscriptionId, D::Error>
 where
     D: serde::de::Deserializer<'de>,
@@ -71,11 +72,22 @@ where
     let input = <&str as serde::Deserialize>::deserialize(deserializer)?;
     let mut tokens = input.split('.');
 
-    match (tokens.next(), tokens.next(), tokens.next()) {
-        (Some("publicTrade"), Some(market), None) => Ok(SubscriptionId::from(format!(
+    match (tokens.next(), tokens.next(), tokens.next(), tokens.next()) {
+        (Some("publicTrade"), Some(market), None, None) => Ok(SubscriptionId::from(format!(
             "{}|{market}",
             BybitChannel::TRADES.0
         ))),
+        (Some("tickers"), Some(market), None, None) => Ok(SubscriptionId::from(format!(
+            "{}|{market}",
+            BybitChannel::TICKERS.0
+        ))),
+        (Some("allLiquidation"), Some(market), None, None) => Ok(SubscriptionId::from(format!(
+            "{}|{market}",
+            BybitChannel::LIQUIDATIONS.0
+        ))),
+        (Some(channel @ ("kline" | "orderbook")), Some(param), Some(market), None) => {
+            Ok(SubscriptionId::from(format!("{channel}.{param}|{market}")))
+        }
         _ => Err(Error::invalid_value(
             Unexpected::Str(input),
             &"invalid message type expected pattern: <type>.<symbol>",
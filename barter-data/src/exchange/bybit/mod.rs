@@ -1,16 +1,16 @@
 use crate::{
     exchange::{
         bybit::{
-            channel::BybitChannel, market::BybitMarket, message::BybitMessage,
-            subscription::BybitResponse,
+            book::l2::BybitBookUpdater, candle::BybitCandleMessage, channel::BybitChannel,
+            market::BybitMarket, message::BybitMessage, subscription::BybitResponse,
         },
         subscription::ExchangeSub,
         Connector, ExchangeId, ExchangeServer, PingInterval, StreamSelector,
     },
     instrument::InstrumentData,
     subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
-    subscription::{trade::PublicTrades, Map},
-    transformer::stateless::StatelessTransformer,
+    subscription::{book::OrderBooksL2, candle::Candles, trade::PublicTrades, Map},
+    transformer::{book::MultiBookTransformer, stateless::StatelessTransformer},
     ExchangeWsStream,
 };
 use barter_integration::{error::SocketError, protocol::websocket::WsMessage};
@@ -19,6 +19,14 @@ use std::{fmt::Debug, marker::PhantomData, time::Duration};
 use tokio::time;
 use url::Url;
 
+/// OrderBook types common to both [`BybitSpot`](spot::BybitSpot) and
+/// [`BybitFuturesUsd`](futures::BybitPerpetualsUsd).
+pub mod book;
+
+/// Candle types common to both [`BybitSpot`](spot::BybitSpot) and
+/// [`BybitFuturesUsd`](futures::BybitPerpetualsUsd).
+pub mod candle;
+
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
 pub mod channel;
@@ -27,6 +35,9 @@ pub mod channel;
 /// [`BybitFuturesUsd`](futures::BybitPerpetualsUsd).
 pub mod futures;
 
+/// Liquidation types for [`BybitFuturesUsd`](futures::BybitPerpetualsUsd).
+pub mod liquidation;
+
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`] specific market used for generating [`Connector::requests`].
 pub mod market;
@@ -44,6 +55,11 @@ pub mod spot;
 /// and [`BybitFuturesUsd`](futures::BybitPerpetualsUsd).
 pub mod subscription;
 
+/// Ticker types common to both [`BybitSpot`](spot::BybitSpot) and
+/// [`BybitFuturesUsd`](futures::BybitPerpetualsUsd), which contain the 24 hour statistics, open
+/// interest, funding rate, mark price & index price.
+pub mod ticker;
+
 /// Public trade types common to both [`BybitSpot`](spot::BybitSpot) and
 /// [`BybitFuturesUsd`](futures::BybitPerpetualsUsd).
 pub mod trade;
@@ -116,6 +132,25 @@ where
         ExchangeWsStream<StatelessTransformer<Self, Instrument::Id, PublicTrades, BybitMessage>>;
 }
 
+impl<Instrument, Server> StreamSelector<Instrument, Candles> for Bybit<Server>
+where
+    Instrument: InstrumentData,
+    Server: ExchangeServer + Debug + Send + Sync,
+{
+    type Stream =
+        ExchangeWsStream<StatelessTransformer<Self, Instrument::Id, Candles, BybitCandleMessage>>;
+}
+
+impl<Instrument, Server> StreamSelector<Instrument, OrderBooksL2> for Bybit<Server>
+where
+    Instrument: InstrumentData,
+    Server: ExchangeServer + Debug + Send + Sync,
+{
+    type Stream = ExchangeWsStream<
+        MultiBookTransformer<Self, Instrument::Id, OrderBooksL2, BybitBookUpdater>,
+    >;
+}
+
 impl<'de, Server> serde::Deserialize<'de> for Bybit<Server>
 where
     Server: ExchangeServer,
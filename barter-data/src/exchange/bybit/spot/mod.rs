@@ -1,5 +1,11 @@
-use super::{Bybit, ExchangeServer};
-use crate::exchange::ExchangeId;
+use super::{ticker::BybitTickerMessage, Bybit, ExchangeServer};
+use crate::{
+    exchange::{ExchangeId, StreamSelector},
+    instrument::InstrumentData,
+    subscription::ticker::Tickers,
+    transformer::stateless::StatelessTransformer,
+    ExchangeWsStream,
+};
 
 /// [`BybitSpot`] WebSocket server base url.
 ///
@@ -20,3 +26,11 @@ impl ExchangeServer for BybitServerSpot {
         WEBSOCKET_BASE_URL_BYBIT_SPOT
     }
 }
+
+impl<Instrument> StreamSelector<Instrument, Tickers> for BybitSpot
+where
+    Instrument: InstrumentData,
+{
+    type Stream =
+        ExchangeWsStream<StatelessTransformer<Self, Instrument::Id, Tickers, BybitTickerMessage>>;
+}
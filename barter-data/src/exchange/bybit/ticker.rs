@@ -0,0 +1,628 @@
+use crate::{
+    error::DataError,
+    event::{MarketEvent, MarketIter},
+    exchange::{
+        bybit::{futures::BybitPerpetualsUsd, message::BybitPayload, subscription::BybitResponse},
+        Connector, ExchangeId,
+    },
+    subscription::{
+        funding::FundingRate,
+        open_interest::OpenInterest,
+        price::{IndexPrice, MarkPrice},
+        ticker::{Ticker, Tickers},
+        Map,
+    },
+    transformer::ExchangeTransformer,
+    Identifier,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    de::datetime_utc_from_epoch_duration,
+    model::{Exchange, SubscriptionId},
+    protocol::websocket::WsMessage,
+    Transformer,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, time::Duration};
+use tokio::sync::mpsc;
+
+/// Terse type alias for a [`Bybit`](super::Bybit) real-time ticker WebSocket message.
+pub type BybitTicker = BybitPayload<BybitTickerData>;
+
+/// [`Bybit`](super::Bybit) ticker websocket message supports both [`BybitTicker`] and
+/// [`BybitResponse`].
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+#[allow(clippy::large_enum_variant)]
+pub enum BybitTickerMessage {
+    Response(BybitResponse),
+    Ticker(BybitTicker),
+}
+
+/// [`Bybit`](super::Bybit) ticker data.
+///
+/// Note that perpetual tickers send an initial "snapshot" containing every field, whereas
+/// subsequent "delta" messages only contain the fields that have changed. Spot tickers are
+/// always a "snapshot".
+///
+/// ### Raw Payload Examples
+/// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/ticker>
+/// #### Perpetual
+///```json
+/// {
+///     "topic": "tickers.BTCUSDT",
+///     "type": "snapshot",
+///     "data": {
+///         "symbol": "BTCUSDT",
+///         "tickDirection": "PlusTick",
+///         "price24hPcnt": "0.017103",
+///         "lastPrice": "17216.00",
+///         "prevPrice24h": "16926.50",
+///         "highPrice24h": "17281.50",
+///         "lowPrice24h": "16915.00",
+///         "markPrice": "17217.33",
+///         "indexPrice": "17227.36",
+///         "openInterest": "68744.761",
+///         "openInterestValue": "1183601235.91",
+///         "turnover24h": "1570383121.943499",
+///         "volume24h": "91705.276",
+///         "nextFundingTime": "1673280000000",
+///         "fundingRate": "-0.000212",
+///         "bid1Price": "17215.50",
+///         "ask1Price": "17216.00"
+///     },
+///     "cs": 24987956059,
+///     "ts": 1673272861686
+/// }
+/// ```
+///
+/// #### Spot
+///```json
+/// {
+///     "topic": "tickers.BTCUSDT",
+///     "ts": 1673853746003,
+///     "type": "snapshot",
+///     "cs": 2588407389,
+///     "data": {
+///         "symbol": "BTCUSDT",
+///         "lastPrice": "21109.77",
+///         "highPrice24h": "21426.99",
+///         "lowPrice24h": "20575",
+///         "prevPrice24h": "20704.93",
+///         "volume24h": "6780.866843",
+///         "turnover24h": "141946527.22907118",
+///         "price24hPcnt": "0.0196",
+///         "usdIndexPrice": "21120.2400136"
+///     }
+/// }
+/// ```
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitTickerData {
+    #[serde(default, deserialize_with = "de_option_str_f64")]
+    pub funding_rate: Option<f64>,
+    #[serde(
+        default,
+        deserialize_with = "de_option_str_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub next_funding_time: Option<DateTime<Utc>>,
+    #[serde(default, deserialize_with = "de_option_str_f64")]
+    pub mark_price: Option<f64>,
+    #[serde(default, deserialize_with = "de_option_str_f64")]
+    pub index_price: Option<f64>,
+    #[serde(default, deserialize_with = "de_option_str_f64")]
+    pub last_price: Option<f64>,
+    #[serde(default, deserialize_with = "de_option_str_f64")]
+    pub high_price_24h: Option<f64>,
+    #[serde(default, deserialize_with = "de_option_str_f64")]
+    pub low_price_24h: Option<f64>,
+    #[serde(default, deserialize_with = "de_option_str_f64")]
+    pub prev_price_24h: Option<f64>,
+    #[serde(default, deserialize_with = "de_option_str_f64")]
+    pub price_24h_pcnt: Option<f64>,
+    #[serde(default, deserialize_with = "de_option_str_f64")]
+    pub volume_24h: Option<f64>,
+    #[serde(default, deserialize_with = "de_option_str_f64")]
+    pub open_interest: Option<f64>,
+    #[serde(default, deserialize_with = "de_option_str_f64")]
+    pub open_interest_value: Option<f64>,
+}
+
+impl BybitTickerData {
+    /// Applies a "delta" [`BybitTickerData`] update, overwriting only the fields it contains.
+    pub fn apply_delta(&mut self, delta: BybitTickerData) {
+        self.funding_rate = delta.funding_rate.or(self.funding_rate);
+        self.next_funding_time = delta.next_funding_time.or(self.next_funding_time);
+        self.mark_price = delta.mark_price.or(self.mark_price);
+        self.index_price = delta.index_price.or(self.index_price);
+        self.last_price = delta.last_price.or(self.last_price);
+        self.high_price_24h = delta.high_price_24h.or(self.high_price_24h);
+        self.low_price_24h = delta.low_price_24h.or(self.low_price_24h);
+        self.prev_price_24h = delta.prev_price_24h.or(self.prev_price_24h);
+        self.price_24h_pcnt = delta.price_24h_pcnt.or(self.price_24h_pcnt);
+        self.volume_24h = delta.volume_24h.or(self.volume_24h);
+        self.open_interest = delta.open_interest.or(self.open_interest);
+        self.open_interest_value = delta.open_interest_value.or(self.open_interest_value);
+    }
+}
+
+impl Identifier<Option<SubscriptionId>> for BybitTickerMessage {
+    fn id(&self) -> Option<SubscriptionId> {
+        match self {
+            BybitTickerMessage::Ticker(ticker) => Some(ticker.subscription_id.clone()),
+            BybitTickerMessage::Response(_) => None,
+        }
+    }
+}
+
+impl<InstrumentId> From<(ExchangeId, InstrumentId, BybitTickerMessage)>
+    for MarketIter<InstrumentId, FundingRate>
+{
+    fn from(
+        (exchange_id, instrument, message): (ExchangeId, InstrumentId, BybitTickerMessage),
+    ) -> Self {
+        // Delta tickers only contain a funding rate if it has changed
+        let (time, data) = match message {
+            BybitTickerMessage::Ticker(ticker) => (ticker.time, ticker.data),
+            BybitTickerMessage::Response(_) => return Self(vec![]),
+        };
+        let Some(rate) = data.funding_rate else {
+            return Self(vec![]);
+        };
+
+        Self(vec![Ok(MarketEvent {
+            exchange_time: time,
+            received_time: Utc::now(),
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: FundingRate {
+                rate,
+                time,
+                next_funding_time: data.next_funding_time,
+            },
+        })])
+    }
+}
+
+impl<InstrumentId> From<(ExchangeId, InstrumentId, BybitTickerMessage)>
+    for MarketIter<InstrumentId, MarkPrice>
+{
+    fn from(
+        (exchange_id, instrument, message): (ExchangeId, InstrumentId, BybitTickerMessage),
+    ) -> Self {
+        // Delta tickers only contain a mark price if it has changed
+        let (time, data) = match message {
+            BybitTickerMessage::Ticker(ticker) => (ticker.time, ticker.data),
+            BybitTickerMessage::Response(_) => return Self(vec![]),
+        };
+        let Some(price) = data.mark_price else {
+            return Self(vec![]);
+        };
+
+        Self(vec![Ok(MarketEvent {
+            exchange_time: time,
+            received_time: Utc::now(),
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: MarkPrice { price, time },
+        })])
+    }
+}
+
+impl<InstrumentId> From<(ExchangeId, InstrumentId, BybitTickerMessage)>
+    for MarketIter<InstrumentId, IndexPrice>
+{
+    fn from(
+        (exchange_id, instrument, message): (ExchangeId, InstrumentId, BybitTickerMessage),
+    ) -> Self {
+        // Delta tickers only contain an index price if it has changed
+        let (time, data) = match message {
+            BybitTickerMessage::Ticker(ticker) => (ticker.time, ticker.data),
+            BybitTickerMessage::Response(_) => return Self(vec![]),
+        };
+        let Some(price) = data.index_price else {
+            return Self(vec![]);
+        };
+
+        Self(vec![Ok(MarketEvent {
+            exchange_time: time,
+            received_time: Utc::now(),
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: IndexPrice { price, time },
+        })])
+    }
+}
+
+impl<InstrumentId> From<(ExchangeId, InstrumentId, BybitTickerMessage)>
+    for MarketIter<InstrumentId, Ticker>
+{
+    fn from(
+        (exchange_id, instrument, message): (ExchangeId, InstrumentId, BybitTickerMessage),
+    ) -> Self {
+        // Only snapshot tickers (eg/ every spot ticker) contain all the 24 hour statistics
+        let (time, data) = match message {
+            BybitTickerMessage::Ticker(ticker) => (ticker.time, ticker.data),
+            BybitTickerMessage::Response(_) => return Self(vec![]),
+        };
+        let (
+            Some(last_price),
+            Some(high_24h),
+            Some(low_24h),
+            Some(prev_price_24h),
+            Some(price_24h_pcnt),
+            Some(volume_24h),
+        ) = (
+            data.last_price,
+            data.high_price_24h,
+            data.low_price_24h,
+            data.prev_price_24h,
+            data.price_24h_pcnt,
+            data.volume_24h,
+        )
+        else {
+            return Self(vec![]);
+        };
+
+        Self(vec![Ok(MarketEvent {
+            exchange_time: time,
+            received_time: Utc::now(),
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: Ticker {
+                last_price,
+                high_24h,
+                low_24h,
+                volume_24h,
+                price_change_24h: last_price - prev_price_24h,
+                // Bybit "price24hPcnt" is a fraction (eg/ 0.0196 for 1.96%)
+                price_change_percent_24h: price_24h_pcnt * 100.0,
+                time,
+            },
+        })])
+    }
+}
+
+impl<InstrumentId> From<(ExchangeId, InstrumentId, BybitTickerMessage)>
+    for MarketIter<InstrumentId, OpenInterest>
+{
+    fn from(
+        (exchange_id, instrument, message): (ExchangeId, InstrumentId, BybitTickerMessage),
+    ) -> Self {
+        // Delta tickers only contain the open interest if it has changed
+        let (time, data) = match message {
+            BybitTickerMessage::Ticker(ticker) => (ticker.time, ticker.data),
+            BybitTickerMessage::Response(_) => return Self(vec![]),
+        };
+        let Some(quantity) = data.open_interest else {
+            return Self(vec![]);
+        };
+
+        Self(vec![Ok(MarketEvent {
+            exchange_time: time,
+            received_time: Utc::now(),
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: OpenInterest {
+                quantity,
+                notional: data.open_interest_value,
+                time,
+            },
+        })])
+    }
+}
+
+/// [`BybitPerpetualsUsd`] [`Tickers`] [`ExchangeTransformer`].
+///
+/// Perpetual tickers send an initial "snapshot" followed by "delta" messages that only contain
+/// the fields that have changed. This transformer caches the latest [`BybitTickerData`] for each
+/// [`SubscriptionId`], applies each delta to it, and yields a [`Ticker`] from the merged state.
+#[derive(Clone, PartialEq, Debug)]
+pub struct BybitTickerTransformer<InstrumentId> {
+    instrument_map: Map<InstrumentId>,
+    tickers: HashMap<SubscriptionId, BybitTickerData>,
+}
+
+#[async_trait]
+impl<InstrumentId> ExchangeTransformer<BybitPerpetualsUsd, InstrumentId, Tickers>
+    for BybitTickerTransformer<InstrumentId>
+where
+    InstrumentId: Clone + Send,
+{
+    async fn new(
+        _: mpsc::UnboundedSender<WsMessage>,
+        instrument_map: Map<InstrumentId>,
+    ) -> Result<Self, DataError> {
+        Ok(Self {
+            instrument_map,
+            tickers: HashMap::new(),
+        })
+    }
+}
+
+impl<InstrumentId> Transformer for BybitTickerTransformer<InstrumentId>
+where
+    InstrumentId: Clone,
+{
+    type Error = DataError;
+    type Input = BybitTickerMessage;
+    type Output = MarketEvent<InstrumentId, Ticker>;
+    type OutputIter = Vec<Result<Self::Output, Self::Error>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        let mut ticker = match input {
+            BybitTickerMessage::Ticker(ticker) => ticker,
+            BybitTickerMessage::Response(_) => return vec![],
+        };
+
+        // Find Instrument associated with Input
+        let instrument = match self.instrument_map.find(&ticker.subscription_id) {
+            Ok(instrument) => instrument.clone(),
+            Err(unidentifiable) => return vec![Err(DataError::Socket(unidentifiable))],
+        };
+
+        // Replace cached state with a snapshot, or merge a delta into the cached state
+        let cached = self
+            .tickers
+            .entry(ticker.subscription_id.clone())
+            .or_default();
+        if ticker.r#type == "snapshot" {
+            *cached = ticker.data;
+        } else {
+            cached.apply_delta(ticker.data);
+        }
+        ticker.data = *cached;
+
+        MarketIter::<InstrumentId, Ticker>::from((
+            BybitPerpetualsUsd::ID,
+            instrument,
+            BybitTickerMessage::Ticker(ticker),
+        ))
+        .0
+    }
+}
+
+/// Deserialize an optional stringified `f64` (eg/ "markPrice": "17217.33").
+fn de_option_str_f64<'de, D>(deserializer: D) -> Result<Option<f64>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    <Option<String> as Deserialize>::deserialize(deserializer)?
+        .map(|value| value.parse::<f64>().map_err(serde::de::Error::custom))
+        .transpose()
+}
+
+/// Deserialize an optional stringified `u64` milliseconds value as `DateTime<Utc>`
+/// (eg/ "nextFundingTime": "1673280000000").
+fn de_option_str_u64_epoch_ms_as_datetime_utc<'de, D>(
+    deserializer: D,
+) -> Result<Option<DateTime<Utc>>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    <Option<String> as Deserialize>::deserialize(deserializer)?
+        .map(|value| {
+            value
+                .parse::<u64>()
+                .map(|epoch_ms| datetime_utc_from_epoch_duration(Duration::from_millis(epoch_ms)))
+                .map_err(serde::de::Error::custom)
+        })
+        .transpose()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_bybit_ticker() {
+            struct TestCase {
+                input: &'static str,
+                expected: BybitTicker,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: snapshot ticker contains every field
+                    input: r#"
+                    {
+                        "topic": "tickers.BTCUSDT",
+                        "type": "snapshot",
+                        "data": {
+                            "symbol": "BTCUSDT",
+                            "tickDirection": "PlusTick",
+                            "lastPrice": "17216.00",
+                            "markPrice": "17217.33",
+                            "indexPrice": "17227.36",
+                            "nextFundingTime": "1673280000000",
+                            "fundingRate": "-0.000212"
+                        },
+                        "cs": 24987956059,
+                        "ts": 1673272861686
+                    }
+                    "#,
+                    expected: BybitTicker {
+                        subscription_id: SubscriptionId::from("tickers|BTCUSDT"),
+                        r#type: "snapshot".to_string(),
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1673272861686,
+                        )),
+                        data: BybitTickerData {
+                            funding_rate: Some(-0.000212),
+                            next_funding_time: Some(datetime_utc_from_epoch_duration(
+                                Duration::from_millis(1673280000000),
+                            )),
+                            mark_price: Some(17217.33),
+                            index_price: Some(17227.36),
+                            last_price: Some(17216.0),
+                            ..BybitTickerData::default()
+                        },
+                    },
+                },
+                TestCase {
+                    // TC1: delta ticker only contains changed fields
+                    input: r#"
+                    {
+                        "topic": "tickers.BTCUSDT",
+                        "type": "delta",
+                        "data": {
+                            "symbol": "BTCUSDT",
+                            "markPrice": "17218.00"
+                        },
+                        "cs": 24987956060,
+                        "ts": 1673272861786
+                    }
+                    "#,
+                    expected: BybitTicker {
+                        subscription_id: SubscriptionId::from("tickers|BTCUSDT"),
+                        r#type: "delta".to_string(),
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1673272861786,
+                        )),
+                        data: BybitTickerData {
+                            mark_price: Some(17218.0),
+                            ..BybitTickerData::default()
+                        },
+                    },
+                },
+                TestCase {
+                    // TC2: spot ticker contains the 24 hour statistics
+                    input: r#"
+                    {
+                        "topic": "tickers.BTCUSDT",
+                        "ts": 1673853746003,
+                        "type": "snapshot",
+                        "cs": 2588407389,
+                        "data": {
+                            "symbol": "BTCUSDT",
+                            "lastPrice": "21109.77",
+                            "highPrice24h": "21426.99",
+                            "lowPrice24h": "20575",
+                            "prevPrice24h": "20704.93",
+                            "volume24h": "6780.866843",
+                            "turnover24h": "141946527.22907118",
+                            "price24hPcnt": "0.0196",
+                            "usdIndexPrice": "21120.2400136"
+                        }
+                    }
+                    "#,
+                    expected: BybitTicker {
+                        subscription_id: SubscriptionId::from("tickers|BTCUSDT"),
+                        r#type: "snapshot".to_string(),
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1673853746003,
+                        )),
+                        data: BybitTickerData {
+                            last_price: Some(21109.77),
+                            high_price_24h: Some(21426.99),
+                            low_price_24h: Some(20575.0),
+                            prev_price_24h: Some(20704.93),
+                            price_24h_pcnt: Some(0.0196),
+                            volume_24h: Some(6780.866843),
+                            ..BybitTickerData::default()
+                        },
+                    },
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<BybitTicker>(test.input).unwrap();
+                assert_eq!(actual, test.expected, "TC{} failed", index);
+            }
+        }
+    }
+
+    #[test]
+    fn test_bybit_ticker_transformer() {
+        struct TestCase {
+            input: BybitTickerMessage,
+            expected_volume_24h: Option<f64>,
+        }
+
+        let ticker = |r#type: &str, data: BybitTickerData| {
+            BybitTickerMessage::Ticker(BybitTicker {
+                subscription_id: SubscriptionId::from("tickers|BTCUSDT"),
+                r#type: r#type.to_string(),
+                time: datetime_utc_from_epoch_duration(Duration::from_millis(1673272861686)),
+                data,
+            })
+        };
+
+        let snapshot = BybitTickerData {
+            last_price: Some(17216.0),
+            high_price_24h: Some(17281.5),
+            low_price_24h: Some(16915.0),
+            prev_price_24h: Some(16926.5),
+            price_24h_pcnt: Some(0.017103),
+            volume_24h: Some(91705.276),
+            ..BybitTickerData::default()
+        };
+
+        let mut transformer = BybitTickerTransformer {
+            instrument_map: Map(HashMap::from([(
+                SubscriptionId::from("tickers|BTCUSDT"),
+                "instrument",
+            )])),
+            tickers: HashMap::new(),
+        };
+
+        let tests = vec![
+            TestCase {
+                // TC0: delta before a snapshot does not contain every 24 hour statistic
+                input: ticker(
+                    "delta",
+                    BybitTickerData {
+                        volume_24h: Some(1.0),
+                        ..BybitTickerData::default()
+                    },
+                ),
+                expected_volume_24h: None,
+            },
+            TestCase {
+                // TC1: snapshot yields a Ticker
+                input: ticker("snapshot", snapshot),
+                expected_volume_24h: Some(91705.276),
+            },
+            TestCase {
+                // TC2: delta is merged into the cached snapshot
+                input: ticker(
+                    "delta",
+                    BybitTickerData {
+                        volume_24h: Some(91710.0),
+                        ..BybitTickerData::default()
+                    },
+                ),
+                expected_volume_24h: Some(91710.0),
+            },
+            TestCase {
+                // TC3: delta without 24 hour statistics yields the cached Ticker
+                input: ticker(
+                    "delta",
+                    BybitTickerData {
+                        mark_price: Some(17218.0),
+                        ..BybitTickerData::default()
+                    },
+                ),
+                expected_volume_24h: Some(91710.0),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = transformer
+                .transform(test.input)
+                .into_iter()
+                .map(|event| event.unwrap().kind.volume_24h)
+                .collect::<Vec<_>>();
+            assert_eq!(
+                actual,
+                test.expected_volume_24h.into_iter().collect::<Vec<_>>(),
+                "TC{} failed",
+                index
+            );
+        }
+    }
+}
@@ -0,0 +1,360 @@
+use super::{super::channel::CoinbaseChannel, CoinbaseLevel};
+use crate::{
+    error::DataError,
+    exchange::ExchangeSub,
+    subscription::book::{Level, OrderBook, OrderBookSide},
+    transformer::book::{InstrumentOrderBook, OrderBookUpdater},
+    Identifier,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    model::{Side, SubscriptionId},
+    protocol::websocket::WsMessage,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
+
+/// [`Coinbase`](super::super::Coinbase) real-time OrderBook Level2 WebSocket message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-channels#level2-batch-channel>
+/// #### Snapshot
+/// ```json
+/// {
+///     "type": "snapshot",
+///     "product_id": "BTC-USD",
+///     "bids": [["10101.10", "0.45054140"]],
+///     "asks": [["10102.55", "0.57753524"]]
+/// }
+/// ```
+///
+/// #### Update
+/// ```json
+/// {
+///     "type": "l2update",
+///     "product_id": "BTC-USD",
+///     "time": "2019-08-14T20:42:27.265Z",
+///     "changes": [["buy", "10101.80000000", "0.162567"]]
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum CoinbaseOrderBookL2 {
+    Snapshot {
+        #[serde(alias = "product_id", deserialize_with = "de_ob_l2_subscription_id")]
+        subscription_id: SubscriptionId,
+        bids: Vec<CoinbaseLevel>,
+        asks: Vec<CoinbaseLevel>,
+    },
+    #[serde(rename = "l2update")]
+    Update {
+        #[serde(alias = "product_id", deserialize_with = "de_ob_l2_subscription_id")]
+        subscription_id: SubscriptionId,
+        time: DateTime<Utc>,
+        changes: Vec<CoinbaseLevelChange>,
+    },
+}
+
+/// [`Coinbase`](super::super::Coinbase) OrderBook Level2 change contained in a
+/// [`CoinbaseOrderBookL2::Update`].
+///
+/// See [`CoinbaseOrderBookL2`] for full raw payload examples.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct CoinbaseLevelChange {
+    pub side: Side,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub price: f64,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub amount: f64,
+}
+
+impl From<CoinbaseLevelChange> for Level {
+    fn from(change: CoinbaseLevelChange) -> Self {
+        Self {
+            price: change.price,
+            amount: change.amount,
+        }
+    }
+}
+
+impl Identifier<Option<SubscriptionId>> for CoinbaseOrderBookL2 {
+    fn id(&self) -> Option<SubscriptionId> {
+        match self {
+            CoinbaseOrderBookL2::Snapshot {
+                subscription_id, ..
+            }
+            | CoinbaseOrderBookL2::Update {
+                subscription_id, ..
+            } => Some(subscription_id.clone()),
+        }
+    }
+}
+
+/// Deserialize a [`CoinbaseOrderBookL2`] "product_id" (eg/ "BTC-USD") as the associated
+/// [`SubscriptionId`] (eg/ SubscriptionId("level2_batch|BTC-USD").
+pub fn de_ob_l2_subscription_id<'de, D>(deserializer: D) -> Result<SubscriptionId, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    <&str as Deserialize>::deserialize(deserializer)
+        .map(|product_id| ExchangeSub::from((CoinbaseChannel::ORDER_BOOK_L2, product_id)).id())
+}
+
+/// [`Coinbase`](super::super::Coinbase) [`OrderBookUpdater`].
+///
+/// Coinbase: How To Manage A Local OrderBook Correctly
+///
+/// 1. Subscribe to the "level2_batch" channel.
+/// 2. The first message received is a "snapshot" of the OrderBook, which initialises the local
+///    OrderBook.
+/// 3. Subsequent "l2update" messages contain the absolute size for each changed price level.
+/// 4. If the size is 0, remove the price level.
+///
+/// Note that the "level2_batch" channel does not provide sequence numbers, so only the ordering
+/// of the initial snapshot can be validated.
+///
+/// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-channels#level2-batch-channel>
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub struct CoinbaseBookUpdater {
+    pub updates_processed: u64,
+}
+
+#[async_trait]
+impl OrderBookUpdater for CoinbaseBookUpdater {
+    type OrderBook = OrderBook;
+    type Update = CoinbaseOrderBookL2;
+    type Output = OrderBook;
+
+    async fn init<Exchange, Kind, InstrumentId>(
+        _: mpsc::UnboundedSender<WsMessage>,
+        _: SubscriptionId,
+        instrument: InstrumentId,
+    ) -> Result<InstrumentOrderBook<InstrumentId, Self>, DataError>
+    where
+        Exchange: Send,
+        Kind: Send,
+        InstrumentId: Send,
+    {
+        // Initial OrderBook snapshot is the first message received via the WebSocket
+        Ok(InstrumentOrderBook {
+            instrument,
+            updater: Self::default(),
+            book: OrderBook::default(),
+        })
+    }
+
+    fn update(
+        &mut self,
+        book: &mut Self::OrderBook,
+        update: Self::Update,
+    ) -> Result<Option<Self::Output>, DataError> {
+        // Coinbase: How To Manage A Local OrderBook Correctly
+        // See Self's Rust Docs for more information on each numbered step
+        match update {
+            CoinbaseOrderBookL2::Snapshot { bids, asks, .. } => {
+                // 2. Snapshot initialises the local OrderBook
+                book.last_update_time = Utc::now();
+                book.bids = OrderBookSide::new(Side::Buy, bids);
+                book.asks = OrderBookSide::new(Side::Sell, asks);
+            }
+            CoinbaseOrderBookL2::Update { .. } if self.updates_processed == 0 => {
+                return Err(DataError::MissingSnapshot);
+            }
+            CoinbaseOrderBookL2::Update { time, changes, .. } => {
+                // 3. & 4. Upsert absolute size of each price level, removing 0 sizes
+                book.last_update_time = time;
+                for change in changes {
+                    match change.side {
+                        Side::Buy => book.bids.upsert_single(change),
+                        Side::Sell => book.asks.upsert_single(change),
+                    }
+                }
+            }
+        }
+
+        // Update OrderBookUpdater metadata
+        self.updates_processed += 1;
+
+        Ok(Some(book.snapshot()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::error::SocketError;
+        use serde::de::Error;
+        use std::str::FromStr;
+
+        #[test]
+        fn test_coinbase_order_book_l2() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<CoinbaseOrderBookL2, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid snapshot
+                    input: r#"
+                    {
+                        "type": "snapshot",
+                        "product_id": "BTC-USD",
+                        "bids": [["10101.10", "0.45054140"]],
+                        "asks": [["10102.55", "0.57753524"]]
+                    }
+                    "#,
+                    expected: Ok(CoinbaseOrderBookL2::Snapshot {
+                        subscription_id: SubscriptionId::from("level2_batch|BTC-USD"),
+                        bids: vec![CoinbaseLevel {
+                            price: 10101.10,
+                            amount: 0.45054140,
+                        }],
+                        asks: vec![CoinbaseLevel {
+                            price: 10102.55,
+                            amount: 0.57753524,
+                        }],
+                    }),
+                },
+                TestCase {
+                    // TC1: valid l2update
+                    input: r#"
+                    {
+                        "type": "l2update",
+                        "product_id": "BTC-USD",
+                        "time": "2019-08-14T20:42:27.265Z",
+                        "changes": [["buy", "10101.80000000", "0.162567"]]
+                    }
+                    "#,
+                    expected: Ok(CoinbaseOrderBookL2::Update {
+                        subscription_id: SubscriptionId::from("level2_batch|BTC-USD"),
+                        time: DateTime::from_str("2019-08-14T20:42:27.265Z").unwrap(),
+                        changes: vec![CoinbaseLevelChange {
+                            side: Side::Buy,
+                            price: 10101.8,
+                            amount: 0.162567,
+                        }],
+                    }),
+                },
+                TestCase {
+                    // TC2: invalid l2update w/ unknown side
+                    input: r#"
+                    {
+                        "type": "l2update",
+                        "product_id": "BTC-USD",
+                        "time": "2019-08-14T20:42:27.265Z",
+                        "changes": [["unknown", "10101.80000000", "0.162567"]]
+                    }
+                    "#,
+                    expected: Err(SocketError::Deserialise {
+                        error: serde_json::Error::custom(""),
+                        payload: "".to_owned(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<CoinbaseOrderBookL2>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+
+    mod coinbase_book_updater {
+        use super::*;
+
+        #[test]
+        fn test_update() {
+            struct TestCase {
+                updater: CoinbaseBookUpdater,
+                book: OrderBook,
+                input_update: CoinbaseOrderBookL2,
+                expected: Result<Option<OrderBook>, DataError>,
+            }
+
+            let time = Utc::now();
+
+            let change = |side, price, amount| CoinbaseLevelChange {
+                side,
+                price,
+                amount,
+            };
+
+            let tests = vec![
+                TestCase {
+                    // TC0: update received before the initial snapshot is invalid
+                    updater: CoinbaseBookUpdater::default(),
+                    book: OrderBook::default(),
+                    input_update: CoinbaseOrderBookL2::Update {
+                        subscription_id: SubscriptionId::from("level2_batch|BTC-USD"),
+                        time,
+                        changes: vec![change(Side::Buy, 100.0, 1.0)],
+                    },
+                    expected: Err(DataError::MissingSnapshot),
+                },
+                TestCase {
+                    // TC1: valid update removes & upserts levels on the associated Side
+                    updater: CoinbaseBookUpdater {
+                        updates_processed: 1,
+                    },
+                    book: OrderBook {
+                        last_update_time: Default::default(),
+                        bids: OrderBookSide::new(Side::Buy, vec![Level::new(90, 1)]),
+                        asks: OrderBookSide::new(Side::Sell, vec![Level::new(110, 1)]),
+                    },
+                    input_update: CoinbaseOrderBookL2::Update {
+                        subscription_id: SubscriptionId::from("level2_batch|BTC-USD"),
+                        time,
+                        changes: vec![
+                            change(Side::Buy, 95.0, 2.0),
+                            change(Side::Sell, 110.0, 0.0),
+                            change(Side::Sell, 105.0, 3.0),
+                        ],
+                    },
+                    expected: Ok(Some(OrderBook {
+                        last_update_time: time,
+                        bids: OrderBookSide::new(
+                            Side::Buy,
+                            vec![Level::new(95, 2), Level::new(90, 1)],
+                        ),
+                        asks: OrderBookSide::new(Side::Sell, vec![Level::new(105, 3)]),
+                    })),
+                },
+            ];
+
+            for (index, mut test) in tests.into_iter().enumerate() {
+                let actual = test.updater.update(&mut test.book, test.input_update);
+
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,760 @@
+use super::super::channel::CoinbaseChannel;
+use crate::{
+    error::DataError,
+    exchange::ExchangeSub,
+    subscription::book::{
+        OrderBookL3, OrderBookL3Event, OrderBookL3Side, OrderBookL3Update, OrderL3,
+    },
+    transformer::book::{InstrumentOrderBook, OrderBookUpdater},
+    Identifier,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    error::SocketError,
+    model::{Side, SubscriptionId},
+    protocol::websocket::WsMessage,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
+
+/// [`Coinbase`](super::super::Coinbase) HTTP OrderBook L3 snapshot url.
+///
+/// See docs: <https://docs.cloud.coinbase.com/exchange/reference/exchangerestapi_getproductbook>
+pub const HTTP_BOOK_L3_SNAPSHOT_URL_COINBASE: &str = "https://api.exchange.coinbase.com/products";
+
+/// [`Coinbase`](super::super::Coinbase) HTTP `User-Agent` header value, which is required by the
+/// Coinbase REST API.
+pub const HTTP_USER_AGENT_COINBASE: &str = "barter-data";
+
+/// [`Coinbase`](super::super::Coinbase) HTTP OrderBook L3 snapshot.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.cloud.coinbase.com/exchange/reference/exchangerestapi_getproductbook>
+/// ```json
+/// {
+///     "sequence": 3,
+///     "bids": [["295.96", "0.05088265", "3b0f1225-7f84-490b-a29f-0faef9de823a"]],
+///     "asks": [["295.97", "5.72036512", "da863862-25f4-4868-ac41-005d11ab0a5f"]]
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct CoinbaseOrderBookL3Snapshot {
+    pub sequence: u64,
+    pub bids: Vec<CoinbaseOrderL3>,
+    pub asks: Vec<CoinbaseOrderL3>,
+}
+
+impl From<CoinbaseOrderBookL3Snapshot> for OrderBookL3 {
+    fn from(snapshot: CoinbaseOrderBookL3Snapshot) -> Self {
+        Self {
+            last_update_time: Utc::now(),
+            bids: OrderBookL3Side::new(Side::Buy, snapshot.bids),
+            asks: OrderBookL3Side::new(Side::Sell, snapshot.asks),
+        }
+    }
+}
+
+/// [`Coinbase`](super::super::Coinbase) OrderBook L3 resting order contained in a
+/// [`CoinbaseOrderBookL3Snapshot`].
+///
+/// #### Raw Payload Examples
+/// ```json
+/// ["295.96", "0.05088265", "3b0f1225-7f84-490b-a29f-0faef9de823a"]
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct CoinbaseOrderL3 {
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub price: f64,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub amount: f64,
+    pub id: String,
+}
+
+impl From<CoinbaseOrderL3> for OrderL3 {
+    fn from(order: CoinbaseOrderL3) -> Self {
+        Self {
+            id: order.id,
+            price: order.price,
+            amount: order.amount,
+        }
+    }
+}
+
+/// [`Coinbase`](super::super::Coinbase) real-time OrderBook Level3 WebSocket message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-channels#full-channel>
+/// #### Open
+/// ```json
+/// {
+///     "type": "open",
+///     "time": "2014-11-07T08:19:27.028459Z",
+///     "product_id": "BTC-USD",
+///     "sequence": 10,
+///     "order_id": "d50ec984-77a8-460a-b958-66f114b0de9b",
+///     "price": "200.2",
+///     "remaining_size": "1.00",
+///     "side": "sell"
+/// }
+/// ```
+///
+/// #### Match
+/// ```json
+/// {
+///     "type": "match",
+///     "trade_id": 10,
+///     "sequence": 50,
+///     "maker_order_id": "ac928c66-ca53-498f-9c13-a110027a60e8",
+///     "taker_order_id": "132fb6ae-456b-4654-b4e0-d681ac05cea1",
+///     "time": "2014-11-07T08:19:27.028459Z",
+///     "product_id": "BTC-USD",
+///     "size": "5.23512",
+///     "price": "400.23",
+///     "side": "sell"
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct CoinbaseOrderBookL3 {
+    #[serde(alias = "product_id", deserialize_with = "de_ob_l3_subscription_id")]
+    pub subscription_id: SubscriptionId,
+    pub sequence: u64,
+    pub time: DateTime<Utc>,
+    #[serde(flatten)]
+    pub kind: CoinbaseOrderBookL3Kind,
+}
+
+/// [`Coinbase`](super::super::Coinbase) OrderBook Level3 message variants.
+///
+/// Note that "received" & "activate" messages do not affect the OrderBook, since the order is
+/// not yet resting.
+///
+/// See [`CoinbaseOrderBookL3`] for full raw payload examples.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum CoinbaseOrderBookL3Kind {
+    Received,
+    Open {
+        order_id: String,
+        side: Side,
+        #[serde(deserialize_with = "barter_integration::de::de_str")]
+        price: f64,
+        #[serde(deserialize_with = "barter_integration::de::de_str")]
+        remaining_size: f64,
+    },
+    Done {
+        order_id: String,
+        side: Side,
+    },
+    Match {
+        maker_order_id: String,
+        side: Side,
+        #[serde(deserialize_with = "barter_integration::de::de_str")]
+        size: f64,
+    },
+    Change {
+        order_id: String,
+        side: Side,
+        #[serde(deserialize_with = "barter_integration::de::de_str")]
+        new_size: f64,
+        #[serde(default, deserialize_with = "de_option_str_f64")]
+        new_price: Option<f64>,
+    },
+    Activate,
+}
+
+impl Identifier<Option<SubscriptionId>> for CoinbaseOrderBookL3 {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(self.subscription_id.clone())
+    }
+}
+
+/// Deserialize a [`CoinbaseOrderBookL3`] "product_id" (eg/ "BTC-USD") as the associated
+/// [`SubscriptionId`] (eg/ SubscriptionId("full|BTC-USD").
+pub fn de_ob_l3_subscription_id<'de, D>(deserializer: D) -> Result<SubscriptionId, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    <String as Deserialize>::deserialize(deserializer).map(|product_id| {
+        ExchangeSub::from((CoinbaseChannel::ORDER_BOOK_L3, product_id.as_str())).id()
+    })
+}
+
+/// Deserialize an optional stringified `f64` (eg/ "new_price": "400.23").
+fn de_option_str_f64<'de, D>(deserializer: D) -> Result<Option<f64>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    <Option<String> as Deserialize>::deserialize(deserializer)?
+        .map(|value| value.parse::<f64>().map_err(serde::de::Error::custom))
+        .transpose()
+}
+
+/// Extract the product (eg/ "BTC-USD") from a [`Coinbase`](super::super::Coinbase) OrderBook L3
+/// [`SubscriptionId`] (eg/ SubscriptionId("full|BTC-USD")).
+pub fn ob_l3_subscription_id_product(subscription_id: &SubscriptionId) -> Result<&str, DataError> {
+    subscription_id
+        .as_ref()
+        .split_once('|')
+        .map(|(_, product)| product)
+        .ok_or_else(|| {
+            DataError::Socket(SocketError::Unidentifiable(SubscriptionId::from(
+                subscription_id.as_ref(),
+            )))
+        })
+}
+
+/// [`Coinbase`](super::super::Coinbase) [`OrderBookUpdater`] for Level3 OrderBooks.
+///
+/// Coinbase: How To Manage A Local L3 OrderBook Correctly
+///
+/// 1. Subscribe to the "full" channel and buffer the messages received.
+/// 2. Fetch an OrderBook L3 snapshot via HTTP.
+/// 3. Drop any message where the sequence is <= the snapshot sequence.
+/// 4. Each subsequent message sequence should be equal to the previous sequence + 1.
+/// 5. "open" messages add a resting order to the OrderBook.
+/// 6. "done" messages remove the order from the OrderBook.
+/// 7. "match" messages reduce the remaining size of the maker order.
+/// 8. "change" messages replace the remaining size (and price) of a resting order.
+///
+/// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-channels#full-channel>
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub struct CoinbaseBookL3Updater {
+    pub updates_processed: u64,
+    pub last_sequence: u64,
+}
+
+impl CoinbaseBookL3Updater {
+    /// Construct a new Coinbase OrderBook L3 [`CoinbaseBookL3Updater`] using the provided
+    /// snapshot sequence.
+    pub fn new(last_sequence: u64) -> Self {
+        Self {
+            updates_processed: 0,
+            last_sequence,
+        }
+    }
+
+    /// Coinbase: How To Manage A Local L3 OrderBook Correctly: Step 4:
+    /// "Each subsequent message sequence should be equal to the previous sequence + 1"
+    pub fn validate_next_update(&self, update: &CoinbaseOrderBookL3) -> Result<(), DataError> {
+        if update.sequence == self.last_sequence + 1 {
+            Ok(())
+        } else {
+            Err(DataError::InvalidSequence {
+                prev_last_update_id: self.last_sequence,
+                first_update_id: update.sequence,
+            })
+        }
+    }
+}
+
+#[async_trait]
+impl OrderBookUpdater for CoinbaseBookL3Updater {
+    type OrderBook = OrderBookL3;
+    type Update = CoinbaseOrderBookL3;
+    type Output = (DateTime<Utc>, OrderBookL3Update);
+
+    async fn init<Exchange, Kind, InstrumentId>(
+        _: mpsc::UnboundedSender<WsMessage>,
+        subscription_id: SubscriptionId,
+        instrument: InstrumentId,
+    ) -> Result<InstrumentOrderBook<InstrumentId, Self>, DataError>
+    where
+        Exchange: Send,
+        Kind: Send,
+        InstrumentId: Send,
+    {
+        // Construct initial OrderBook snapshot GET url
+        let snapshot_url = format!(
+            "{}/{}/book?level=3",
+            HTTP_BOOK_L3_SNAPSHOT_URL_COINBASE,
+            ob_l3_subscription_id_product(&subscription_id)?,
+        );
+
+        // Fetch initial OrderBook snapshot via HTTP
+        let snapshot = reqwest::Client::new()
+            .get(snapshot_url)
+            .header(reqwest::header::USER_AGENT, HTTP_USER_AGENT_COINBASE)
+            .send()
+            .await
+            .map_err(SocketError::Http)?
+            .json::<CoinbaseOrderBookL3Snapshot>()
+            .await
+            .map_err(SocketError::Http)?;
+
+        Ok(InstrumentOrderBook {
+            instrument,
+            updater: Self::new(snapshot.sequence),
+            book: OrderBookL3::from(snapshot),
+        })
+    }
+
+    fn update(
+        &mut self,
+        book: &mut Self::OrderBook,
+        update: Self::Update,
+    ) -> Result<Option<Self::Output>, DataError> {
+        // Coinbase: How To Manage A Local L3 OrderBook Correctly
+        // See Self's Rust Docs for more information on each numbered step
+
+        // 3. Drop any message where the sequence is <= the snapshot sequence
+        if update.sequence <= self.last_sequence {
+            return Ok(None);
+        }
+
+        // 4. Each subsequent message sequence should be equal to the previous sequence + 1
+        self.validate_next_update(&update)?;
+        self.last_sequence = update.sequence;
+
+        let event = match update.kind {
+            // 5. "open" messages add a resting order to the OrderBook
+            CoinbaseOrderBookL3Kind::Open {
+                order_id,
+                side,
+                price,
+                remaining_size,
+            } => OrderBookL3Event::Add {
+                side,
+                order: OrderL3::new(order_id, price, remaining_size),
+            },
+
+            // 6. "done" messages remove the order from the OrderBook
+            CoinbaseOrderBookL3Kind::Done { order_id, side } => {
+                OrderBookL3Event::Delete { side, id: order_id }
+            }
+
+            // 7. "match" messages reduce the remaining size of the maker order
+            CoinbaseOrderBookL3Kind::Match {
+                maker_order_id,
+                side,
+                size,
+            } => match book_side(book, side).order(&maker_order_id) {
+                Some(maker) => OrderBookL3Event::Modify {
+                    side,
+                    order: OrderL3::new(
+                        maker_order_id,
+                        maker.price,
+                        (maker.amount - size).max(0.0),
+                    ),
+                },
+                None => return Ok(None),
+            },
+
+            // 8. "change" messages replace the remaining size (and price) of a resting order
+            CoinbaseOrderBookL3Kind::Change {
+                order_id,
+                side,
+                new_size,
+                new_price,
+            } => match book_side(book, side).order(&order_id) {
+                Some(order) => OrderBookL3Event::Modify {
+                    side,
+                    order: OrderL3::new(order_id, new_price.unwrap_or(order.price), new_size),
+                },
+                None => return Ok(None),
+            },
+
+            // Orders that are not (yet) resting do not affect the OrderBook
+            CoinbaseOrderBookL3Kind::Received | CoinbaseOrderBookL3Kind::Activate => {
+                return Ok(None)
+            }
+        };
+
+        // Update OrderBook & OrderBookUpdater metadata
+        book.apply(event.clone());
+        book.last_update_time = update.time;
+        self.updates_processed += 1;
+
+        // Yield the full OrderBookL3 once, and only the applied OrderBookL3Event thereafter
+        let output = if self.updates_processed == 1 {
+            OrderBookL3Update::Snapshot(book.clone())
+        } else {
+            OrderBookL3Update::Event(event)
+        };
+
+        Ok(Some((update.time, output)))
+    }
+}
+
+/// Return the [`OrderBookL3Side`] of the [`OrderBookL3`] associated with the provided [`Side`].
+fn book_side(book: &OrderBookL3, side: Side) -> &OrderBookL3Side {
+    match side {
+        Side::Buy => &book.bids,
+        Side::Sell => &book.asks,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use serde::de::Error;
+        use std::str::FromStr;
+
+        #[test]
+        fn test_coinbase_order_book_l3_snapshot() {
+            let input = r#"
+            {
+                "sequence": 3,
+                "bids": [["295.96", "0.05088265", "3b0f1225-7f84-490b-a29f-0faef9de823a"]],
+                "asks": [["295.97", "5.72036512", "da863862-25f4-4868-ac41-005d11ab0a5f"]]
+            }
+            "#;
+
+            assert_eq!(
+                serde_json::from_str::<CoinbaseOrderBookL3Snapshot>(input).unwrap(),
+                CoinbaseOrderBookL3Snapshot {
+                    sequence: 3,
+                    bids: vec![CoinbaseOrderL3 {
+                        price: 295.96,
+                        amount: 0.05088265,
+                        id: "3b0f1225-7f84-490b-a29f-0faef9de823a".to_string(),
+                    }],
+                    asks: vec![CoinbaseOrderL3 {
+                        price: 295.97,
+                        amount: 5.72036512,
+                        id: "da863862-25f4-4868-ac41-005d11ab0a5f".to_string(),
+                    }],
+                }
+            );
+        }
+
+        #[test]
+        fn test_coinbase_order_book_l3() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<CoinbaseOrderBookL3, SocketError>,
+            }
+
+            let time = DateTime::from_str("2014-11-07T08:19:27.028459Z").unwrap();
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid open
+                    input: r#"
+                    {
+                        "type": "open",
+                        "time": "2014-11-07T08:19:27.028459Z",
+                        "product_id": "BTC-USD",
+                        "sequence": 10,
+                        "order_id": "d50ec984-77a8-460a-b958-66f114b0de9b",
+                        "price": "200.2",
+                        "remaining_size": "1.00",
+                        "side": "sell"
+                    }
+                    "#,
+                    expected: Ok(CoinbaseOrderBookL3 {
+                        subscription_id: SubscriptionId::from("full|BTC-USD"),
+                        sequence: 10,
+                        time,
+                        kind: CoinbaseOrderBookL3Kind::Open {
+                            order_id: "d50ec984-77a8-460a-b958-66f114b0de9b".to_string(),
+                            side: Side::Sell,
+                            price: 200.2,
+                            remaining_size: 1.0,
+                        },
+                    }),
+                },
+                TestCase {
+                    // TC1: valid match
+                    input: r#"
+                    {
+                        "type": "match",
+                        "trade_id": 10,
+                        "sequence": 50,
+                        "maker_order_id": "ac928c66-ca53-498f-9c13-a110027a60e8",
+                        "taker_order_id": "132fb6ae-456b-4654-b4e0-d681ac05cea1",
+                        "time": "2014-11-07T08:19:27.028459Z",
+                        "product_id": "BTC-USD",
+                        "size": "5.23512",
+                        "price": "400.23",
+                        "side": "sell"
+                    }
+                    "#,
+                    expected: Ok(CoinbaseOrderBookL3 {
+                        subscription_id: SubscriptionId::from("full|BTC-USD"),
+                        sequence: 50,
+                        time,
+                        kind: CoinbaseOrderBookL3Kind::Match {
+                            maker_order_id: "ac928c66-ca53-498f-9c13-a110027a60e8".to_string(),
+                            side: Side::Sell,
+                            size: 5.23512,
+                        },
+                    }),
+                },
+                TestCase {
+                    // TC2: valid change w/ null price
+                    input: r#"
+                    {
+                        "type": "change",
+                        "reason": "STP",
+                        "time": "2014-11-07T08:19:27.028459Z",
+                        "sequence": 80,
+                        "order_id": "ac928c66-ca53-498f-9c13-a110027a60e8",
+                        "side": "sell",
+                        "product_id": "BTC-USD",
+                        "old_size": "12.234412",
+                        "new_size": "5.23512",
+                        "price": null
+                    }
+                    "#,
+                    expected: Ok(CoinbaseOrderBookL3 {
+                        subscription_id: SubscriptionId::from("full|BTC-USD"),
+                        sequence: 80,
+                        time,
+                        kind: CoinbaseOrderBookL3Kind::Change {
+                            order_id: "ac928c66-ca53-498f-9c13-a110027a60e8".to_string(),
+                            side: Side::Sell,
+                            new_size: 5.23512,
+                            new_price: None,
+                        },
+                    }),
+                },
+                TestCase {
+                    // TC3: valid received
+                    input: r#"
+                    {
+                        "type": "received",
+                        "time": "2014-11-07T08:19:27.028459Z",
+                        "product_id": "BTC-USD",
+                        "sequence": 10,
+                        "order_id": "d50ec984-77a8-460a-b958-66f114b0de9b",
+                        "size": "1.34",
+                        "price": "502.1",
+                        "side": "buy",
+                        "order_type": "limit"
+                    }
+                    "#,
+                    expected: Ok(CoinbaseOrderBookL3 {
+                        subscription_id: SubscriptionId::from("full|BTC-USD"),
+                        sequence: 10,
+                        time,
+                        kind: CoinbaseOrderBookL3Kind::Received,
+                    }),
+                },
+                TestCase {
+                    // TC4: invalid unknown type
+                    input: r#"
+                    {
+                        "type": "unknown",
+                        "time": "2014-11-07T08:19:27.028459Z",
+                        "product_id": "BTC-USD",
+                        "sequence": 10
+                    }
+                    "#,
+                    expected: Err(SocketError::Deserialise {
+                        error: serde_json::Error::custom(""),
+                        payload: "".to_owned(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<CoinbaseOrderBookL3>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+
+    mod coinbase_book_l3_updater {
+        use super::*;
+
+        fn message(sequence: u64, kind: CoinbaseOrderBookL3Kind) -> CoinbaseOrderBookL3 {
+            CoinbaseOrderBookL3 {
+                subscription_id: SubscriptionId::from("full|BTC-USD"),
+                sequence,
+                time: Default::default(),
+                kind,
+            }
+        }
+
+        #[test]
+        fn test_update() {
+            struct TestCase {
+                updater: CoinbaseBookL3Updater,
+                input_update: CoinbaseOrderBookL3,
+                expected: Result<Option<(Vec<OrderL3>, Vec<OrderL3>)>, DataError>,
+            }
+
+            let book = || OrderBookL3 {
+                last_update_time: Default::default(),
+                bids: OrderBookL3Side::new(
+                    Side::Buy,
+                    vec![OrderL3::new("a", 100.0, 1.0), OrderL3::new("b", 100.0, 2.0)],
+                ),
+                asks: OrderBookL3Side::new(Side::Sell, vec![OrderL3::new("c", 110.0, 1.0)]),
+            };
+
+            let tests = vec![
+                TestCase {
+                    // TC0: message w/ sequence <= snapshot sequence is dropped
+                    updater: CoinbaseBookL3Updater::new(10),
+                    input_update: message(
+                        10,
+                        CoinbaseOrderBookL3Kind::Done {
+                            order_id: "a".to_string(),
+                            side: Side::Buy,
+                        },
+                    ),
+                    expected: Ok(None),
+                },
+                TestCase {
+                    // TC1: message w/ sequence gap is an invalid sequence
+                    updater: CoinbaseBookL3Updater::new(10),
+                    input_update: message(12, CoinbaseOrderBookL3Kind::Received),
+                    expected: Err(DataError::InvalidSequence {
+                        prev_last_update_id: 10,
+                        first_update_id: 12,
+                    }),
+                },
+                TestCase {
+                    // TC2: received message does not affect the OrderBook
+                    updater: CoinbaseBookL3Updater::new(10),
+                    input_update: message(11, CoinbaseOrderBookL3Kind::Received),
+                    expected: Ok(None),
+                },
+                TestCase {
+                    // TC3: open message adds order to the back of the price level queue
+                    updater: CoinbaseBookL3Updater::new(10),
+                    input_update: message(
+                        11,
+                        CoinbaseOrderBookL3Kind::Open {
+                            order_id: "d".to_string(),
+                            side: Side::Buy,
+                            price: 100.0,
+                            remaining_size: 3.0,
+                        },
+                    ),
+                    expected: Ok(Some((
+                        vec![
+                            OrderL3::new("a", 100.0, 1.0),
+                            OrderL3::new("b", 100.0, 2.0),
+                            OrderL3::new("d", 100.0, 3.0),
+                        ],
+                        vec![OrderL3::new("c", 110.0, 1.0)],
+                    ))),
+                },
+                TestCase {
+                    // TC4: match message reduces the maker order remaining size
+                    updater: CoinbaseBookL3Updater::new(10),
+                    input_update: message(
+                        11,
+                        CoinbaseOrderBookL3Kind::Match {
+                            maker_order_id: "b".to_string(),
+                            side: Side::Buy,
+                            size: 0.5,
+                        },
+                    ),
+                    expected: Ok(Some((
+                        vec![OrderL3::new("a", 100.0, 1.0), OrderL3::new("b", 100.0, 1.5)],
+                        vec![OrderL3::new("c", 110.0, 1.0)],
+                    ))),
+                },
+                TestCase {
+                    // TC5: change message w/ new price re-inserts the order
+                    updater: CoinbaseBookL3Updater::new(10),
+                    input_update: message(
+                        11,
+                        CoinbaseOrderBookL3Kind::Change {
+                            order_id: "a".to_string(),
+                            side: Side::Buy,
+                            new_size: 1.0,
+                            new_price: Some(101.0),
+                        },
+                    ),
+                    expected: Ok(Some((
+                        vec![OrderL3::new("a", 101.0, 1.0), OrderL3::new("b", 100.0, 2.0)],
+                        vec![OrderL3::new("c", 110.0, 1.0)],
+                    ))),
+                },
+                TestCase {
+                    // TC6: done message removes the order
+                    updater: CoinbaseBookL3Updater::new(10),
+                    input_update: message(
+                        11,
+                        CoinbaseOrderBookL3Kind::Done {
+                            order_id: "c".to_string(),
+                            side: Side::Sell,
+                        },
+                    ),
+                    expected: Ok(Some((
+                        vec![OrderL3::new("a", 100.0, 1.0), OrderL3::new("b", 100.0, 2.0)],
+                        vec![],
+                    ))),
+                },
+            ];
+
+            for (index, mut test) in tests.into_iter().enumerate() {
+                let mut book = book();
+                let actual = test
+                    .updater
+                    .update(&mut book, test.input_update)
+                    .map(|output| {
+                        output.map(|_| (book.bids.orders().to_vec(), book.asks.orders().to_vec()))
+                    });
+
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn test_update_yields_snapshot_then_events() {
+            let mut updater = CoinbaseBookL3Updater::new(10);
+            let mut book = OrderBookL3::default();
+
+            let open = |order_id: &str, price| CoinbaseOrderBookL3Kind::Open {
+                order_id: order_id.to_string(),
+                side: Side::Sell,
+                price,
+                remaining_size: 1.0,
+            };
+
+            // First applied update yields the full OrderBookL3
+            let (_, output) = updater
+                .update(&mut book, message(11, open("a", 110.0)))
+                .unwrap()
+                .unwrap();
+            assert_eq!(output, OrderBookL3Update::Snapshot(book.clone()));
+
+            // Subsequent updates only yield the applied OrderBookL3Event
+            let (_, output) = updater
+                .update(&mut book, message(12, open("b", 111.0)))
+                .unwrap()
+                .unwrap();
+            assert_eq!(
+                output,
+                OrderBookL3Update::Event(OrderBookL3Event::Add {
+                    side: Side::Sell,
+                    order: OrderL3::new("b", 111.0, 1.0),
+                })
+            );
+            assert_eq!(book.asks.orders().len(), 2);
+        }
+    }
+}
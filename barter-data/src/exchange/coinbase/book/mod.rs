@@ -0,0 +1,53 @@
+use crate::subscription::book::Level;
+use serde::{Deserialize, Serialize};
+
+/// Level 2 OrderBook types.
+pub mod l2;
+
+/// Level 3 OrderBook types.
+pub mod l3;
+
+/// [`Coinbase`](super::Coinbase) OrderBook level.
+///
+/// #### Raw Payload Examples
+/// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-channels#level2-batch-channel>
+/// ```json
+/// ["10101.10", "0.45054140"]
+/// ```
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct CoinbaseLevel {
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub price: f64,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub amount: f64,
+}
+
+impl From<CoinbaseLevel> for Level {
+    fn from(level: CoinbaseLevel) -> Self {
+        Self {
+            price: level.price,
+            amount: level.amount,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_coinbase_level() {
+            let input = r#"["10101.10", "0.45054140"]"#;
+            assert_eq!(
+                serde_json::from_str::<CoinbaseLevel>(input).unwrap(),
+                CoinbaseLevel {
+                    price: 10101.10,
+                    amount: 0.45054140
+                },
+            )
+        }
+    }
+}
@@ -1,6 +1,10 @@
 use super::Coinbase;
 use crate::{
-    subscription::{trade::PublicTrades, Subscription},
+    subscription::{
+        book::{OrderBooksL2, OrderBooksL3},
+        trade::PublicTrades,
+        Subscription,
+    },
     Identifier,
 };
 use serde::Serialize;
@@ -17,6 +21,17 @@ impl CoinbaseChannel {
     ///
     /// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-channels#match>
     pub const TRADES: Self = Self("matches");
+
+    /// [`Coinbase`] real-time OrderBook Level2 channel, which batches updates every 50ms.
+    ///
+    /// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-channels#level2-batch-channel>
+    pub const ORDER_BOOK_L2: Self = Self("level2_batch");
+
+    /// [`Coinbase`] real-time OrderBook Level3 channel, which contains every order lifecycle
+    /// message.
+    ///
+    /// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-channels#full-channel>
+    pub const ORDER_BOOK_L3: Self = Self("full");
 }
 
 impl<Instrument> Identifier<CoinbaseChannel> for Subscription<Coinbase, Instrument, PublicTrades> {
@@ -25,6 +40,18 @@ impl<Instrument> Identifier<CoinbaseChannel> for Subscription<Coinbase, Instrume
     }
 }
 
+impl<Instrument> Identifier<CoinbaseChannel> for Subscription<Coinbase, Instrument, OrderBooksL2> {
+    fn id(&self) -> CoinbaseChannel {
+        CoinbaseChannel::ORDER_BOOK_L2
+    }
+}
+
+impl<Instrument> Identifier<CoinbaseChannel> for Subscription<Coinbase, Instrument, OrderBooksL3> {
+    fn id(&self) -> CoinbaseChannel {
+        CoinbaseChannel::ORDER_BOOK_L3
+    }
+}
+
 impl AsRef<str> for CoinbaseChannel {
     fn as_ref(&self) -> &str {
         self.0
@@ -1,20 +1,31 @@
 use self::{
-    channel::CoinbaseChannel, market::CoinbaseMarket, subscription::CoinbaseSubResponse,
+    book::{l2::CoinbaseBookUpdater, l3::CoinbaseBookL3Updater},
+    channel::CoinbaseChannel,
+    market::CoinbaseMarket,
+    subscription::CoinbaseSubResponse,
     trade::CoinbaseTrade,
 };
 use crate::{
     exchange::{Connector, ExchangeId, ExchangeSub, StreamSelector},
     instrument::InstrumentData,
     subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
-    subscription::trade::PublicTrades,
-    transformer::stateless::StatelessTransformer,
+    subscription::{
+        book::{OrderBooksL2, OrderBooksL3},
+        trade::PublicTrades,
+        Map,
+    },
+    transformer::{book::MultiBookTransformer, stateless::StatelessTransformer},
     ExchangeWsStream,
 };
 use barter_integration::{error::SocketError, protocol::websocket::WsMessage};
 use barter_macro::{DeExchange, SerExchange};
+use itertools::Itertools;
 use serde_json::json;
 use url::Url;
 
+/// OrderBook types for [`Coinbase`].
+pub mod book;
+
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
 pub mod channel;
@@ -56,19 +67,25 @@ impl Connector for Coinbase {
     }
 
     fn requests(exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
-        exchange_subs
-            .into_iter()
-            .map(|ExchangeSub { channel, market }| {
-                WsMessage::Text(
-                    json!({
-                        "type": "subscribe",
-                        "product_ids": [market.as_ref()],
-                        "channels": [channel.as_ref()],
-                    })
-                    .to_string(),
-                )
+        // Send a single request so the exchange sends a single subscription response, ensuring
+        // initial OrderBook snapshots are not received before all subscriptions are validated
+        let (channels, product_ids): (Vec<_>, Vec<_>) = exchange_subs
+            .iter()
+            .map(|ExchangeSub { channel, market }| (channel.as_ref(), market.as_ref()))
+            .unzip();
+
+        vec![WsMessage::Text(
+            json!({
+                "type": "subscribe",
+                "product_ids": product_ids,
+                "channels": channels.into_iter().unique().collect::<Vec<_>>(),
             })
-            .collect()
+            .to_string(),
+        )]
+    }
+
+    fn expected_responses<InstrumentId>(_: &Map<InstrumentId>) -> usize {
+        1
     }
 }
 
@@ -79,3 +96,21 @@ where
     type Stream =
         ExchangeWsStream<StatelessTransformer<Self, Instrument::Id, PublicTrades, CoinbaseTrade>>;
 }
+
+impl<Instrument> StreamSelector<Instrument, OrderBooksL2> for Coinbase
+where
+    Instrument: InstrumentData,
+{
+    type Stream = ExchangeWsStream<
+        MultiBookTransformer<Self, Instrument::Id, OrderBooksL2, CoinbaseBookUpdater>,
+    >;
+}
+
+impl<Instrument> StreamSelector<Instrument, OrderBooksL3> for Coinbase
+where
+    Instrument: InstrumentData,
+{
+    type Stream = ExchangeWsStream<
+        MultiBookTransformer<Self, Instrument::Id, OrderBooksL3, CoinbaseBookL3Updater>,
+    >;
+}
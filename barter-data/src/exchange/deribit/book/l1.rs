@@ -0,0 +1,114 @@
+use super::super::message::DeribitMessage;
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::ExchangeId,
+    subscription::book::{Level, OrderBookL1},
+};
+use barter_integration::model::Exchange;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for a [`Deribit`](super::super::Deribit) real-time OrderBook Level1
+/// (top of book) WebSocket message.
+pub type DeribitOrderBookL1 = DeribitMessage<DeribitQuote>;
+
+/// [`Deribit`](super::super::Deribit) real-time best bid and ask.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.deribit.com/#quote-instrument_name>
+/// ```json
+/// {
+///   "timestamp": 1550658624149,
+///   "instrument_name": "BTC-PERPETUAL",
+///   "best_bid_price": 3914.97,
+///   "best_bid_amount": 40.0,
+///   "best_ask_price": 3996.61,
+///   "best_ask_amount": 50.0
+/// }
+/// ```
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct DeribitQuote {
+    #[serde(
+        rename = "timestamp",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    pub best_bid_price: f64,
+    pub best_bid_amount: f64,
+    pub best_ask_price: f64,
+    pub best_ask_amount: f64,
+}
+
+impl<InstrumentId> From<(ExchangeId, InstrumentId, DeribitOrderBookL1)>
+    for MarketIter<InstrumentId, OrderBookL1>
+{
+    fn from(
+        (exchange_id, instrument, book): (ExchangeId, InstrumentId, DeribitOrderBookL1),
+    ) -> Self {
+        match book {
+            DeribitMessage::Data { params } => Self(vec![Ok(MarketEvent {
+                exchange_time: params.data.time,
+                received_time: Utc::now(),
+                exchange: Exchange::from(exchange_id),
+                instrument,
+                kind: OrderBookL1 {
+                    last_update_time: params.data.time,
+                    best_bid: Level::new(params.data.best_bid_price, params.data.best_bid_amount),
+                    best_ask: Level::new(params.data.best_ask_price, params.data.best_ask_amount),
+                },
+            })]),
+            DeribitMessage::Heartbeat { .. } | DeribitMessage::Response { .. } => Self(vec![]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use crate::exchange::deribit::message::DeribitParams;
+        use barter_integration::{de::datetime_utc_from_epoch_duration, model::SubscriptionId};
+        use std::time::Duration;
+
+        #[test]
+        fn test_deribit_order_book_l1() {
+            let input = r#"
+            {
+                "jsonrpc": "2.0",
+                "method": "subscription",
+                "params": {
+                    "channel": "quote.BTC-PERPETUAL",
+                    "data": {
+                        "timestamp": 1550658624149,
+                        "instrument_name": "BTC-PERPETUAL",
+                        "best_bid_price": 3914.97,
+                        "best_bid_amount": 40.0,
+                        "best_ask_price": 3996.61,
+                        "best_ask_amount": 50.0
+                    }
+                }
+            }
+            "#;
+
+            assert_eq!(
+                serde_json::from_str::<DeribitOrderBookL1>(input).unwrap(),
+                DeribitMessage::Data {
+                    params: DeribitParams {
+                        subscription_id: SubscriptionId::from("quote|BTC-PERPETUAL"),
+                        data: DeribitQuote {
+                            time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                1550658624149
+                            )),
+                            best_bid_price: 3914.97,
+                            best_bid_amount: 40.0,
+                            best_ask_price: 3996.61,
+                            best_ask_amount: 50.0,
+                        },
+                    },
+                }
+            );
+        }
+    }
+}
@@ -0,0 +1,373 @@
+use super::{super::message::DeribitMessage, DeribitLevel};
+use crate::{
+    error::DataError,
+    subscription::book::{OrderBook, OrderBookSide},
+    transformer::book::{InstrumentOrderBook, OrderBookUpdater},
+};
+use async_trait::async_trait;
+use barter_integration::{
+    model::{Side, SubscriptionId},
+    protocol::websocket::WsMessage,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
+
+/// Terse type alias for a [`Deribit`](super::super::Deribit) real-time OrderBook Level2
+/// WebSocket message.
+pub type DeribitOrderBookL2 = DeribitMessage<DeribitOrderBookL2Data>;
+
+/// [`Deribit`](super::super::Deribit) OrderBook Level2 snapshot or change data.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.deribit.com/#book-instrument_name-interval>
+/// #### Snapshot
+/// ```json
+/// {
+///   "type": "snapshot",
+///   "timestamp": 1554373962454,
+///   "instrument_name": "BTC-PERPETUAL",
+///   "change_id": 297217,
+///   "bids": [["new", 5042.34, 30.0], ["new", 5041.94, 20.0]],
+///   "asks": [["new", 5042.64, 40.0], ["new", 5043.3, 40.0]]
+/// }
+/// ```
+///
+/// #### Change
+/// ```json
+/// {
+///   "type": "change",
+///   "timestamp": 1554373911330,
+///   "prev_change_id": 297217,
+///   "instrument_name": "BTC-PERPETUAL",
+///   "change_id": 297218,
+///   "bids": [["delete", 5041.94, 0]],
+///   "asks": []
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct DeribitOrderBookL2Data {
+    #[serde(rename = "type")]
+    pub kind: DeribitOrderBookL2Kind,
+    #[serde(
+        rename = "timestamp",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    #[serde(default)]
+    pub prev_change_id: Option<u64>,
+    pub change_id: u64,
+    pub bids: Vec<DeribitLevel>,
+    pub asks: Vec<DeribitLevel>,
+}
+
+/// [`Deribit`](super::super::Deribit) OrderBook Level2 data kind, communicating if the
+/// [`DeribitOrderBookL2Data`] is a full snapshot, or an incremental change.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DeribitOrderBookL2Kind {
+    Snapshot,
+    Change,
+}
+
+/// [`Deribit`](super::super::Deribit) [`OrderBookUpdater`].
+///
+/// Deribit: How To Manage A Local OrderBook Correctly
+///
+/// 1. Subscribe to the "book.{instrument_name}.100ms" channel.
+/// 2. The first notification received has type "snapshot", which initialises the local OrderBook.
+/// 3. Subsequent notifications with type "change" contain "new", "change" & "delete" actions for
+///    each changed price level.
+/// 4. A "delete" action has an amount of 0, so remove the price level.
+/// 5. Each "change" prev_change_id should be equal to the previous notification change_id,
+///    otherwise re-subscribe to receive a new snapshot.
+///
+/// See docs: <https://docs.deribit.com/#book-instrument_name-interval>
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub struct DeribitBookUpdater {
+    pub updates_processed: u64,
+    pub last_change_id: Option<u64>,
+}
+
+impl DeribitBookUpdater {
+    /// Deribit: How To Manage A Local OrderBook Correctly: Step 5:
+    /// "Each "change" prev_change_id should be equal to the previous notification change_id"
+    pub fn validate_next_update(&self, update: &DeribitOrderBookL2Data) -> Result<(), DataError> {
+        let last_change_id = self.last_change_id.ok_or(DataError::MissingSnapshot)?;
+
+        match update.prev_change_id {
+            Some(prev_change_id) if prev_change_id == last_change_id => Ok(()),
+            prev_change_id => Err(DataError::InvalidSequence {
+                prev_last_update_id: last_change_id,
+                first_update_id: prev_change_id.unwrap_or_default(),
+            }),
+        }
+    }
+}
+
+#[async_trait]
+impl OrderBookUpdater for DeribitBookUpdater {
+    type OrderBook = OrderBook;
+    type Update = DeribitOrderBookL2;
+    type Output = OrderBook;
+
+    async fn init<Exchange, Kind, InstrumentId>(
+        _: mpsc::UnboundedSender<WsMessage>,
+        _: SubscriptionId,
+        instrument: InstrumentId,
+    ) -> Result<InstrumentOrderBook<InstrumentId, Self>, DataError>
+    where
+        Exchange: Send,
+        Kind: Send,
+        InstrumentId: Send,
+    {
+        // Initial OrderBook snapshot is the first message received via the WebSocket
+        Ok(InstrumentOrderBook {
+            instrument,
+            updater: Self::default(),
+            book: OrderBook::default(),
+        })
+    }
+
+    fn update(
+        &mut self,
+        book: &mut Self::OrderBook,
+        update: Self::Update,
+    ) -> Result<Option<Self::Output>, DataError> {
+        // Heartbeats & JSON-RPC responses do not contain OrderBook data
+        let data = match update {
+            DeribitMessage::Data { params } => params.data,
+            DeribitMessage::Heartbeat { .. } | DeribitMessage::Response { .. } => return Ok(None),
+        };
+
+        // Deribit: How To Manage A Local OrderBook Correctly
+        // See Self's Rust Docs for more information on each numbered step
+        match data.kind {
+            DeribitOrderBookL2Kind::Snapshot => {
+                // 2. Snapshot initialises the local OrderBook
+                book.bids = OrderBookSide::new(Side::Buy, data.bids);
+                book.asks = OrderBookSide::new(Side::Sell, data.asks);
+            }
+            DeribitOrderBookL2Kind::Change => {
+                // 5. Each "change" prev_change_id should be equal to the previous change_id
+                self.validate_next_update(&data)?;
+
+                // 3. & 4. Upsert absolute amount of each price level, removing 0 amounts
+                book.bids.upsert(data.bids);
+                book.asks.upsert(data.asks);
+            }
+        }
+
+        // Update OrderBook & OrderBookUpdater metadata
+        book.last_update_time = data.time;
+        self.updates_processed += 1;
+        self.last_change_id = Some(data.change_id);
+
+        Ok(Some(book.snapshot()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::deribit::message::DeribitParams;
+    use barter_integration::de::datetime_utc_from_epoch_duration;
+    use std::time::Duration;
+
+    fn message(
+        kind: DeribitOrderBookL2Kind,
+        prev_change_id: Option<u64>,
+        change_id: u64,
+        asks: Vec<DeribitLevel>,
+    ) -> DeribitOrderBookL2 {
+        DeribitMessage::Data {
+            params: DeribitParams {
+                subscription_id: SubscriptionId::from("book|BTC-PERPETUAL"),
+                data: DeribitOrderBookL2Data {
+                    kind,
+                    time: datetime_utc_from_epoch_duration(Duration::from_millis(change_id)),
+                    prev_change_id,
+                    change_id,
+                    bids: vec![],
+                    asks,
+                },
+            },
+        }
+    }
+
+    mod de {
+        use super::*;
+        use crate::exchange::deribit::book::DeribitLevelAction;
+
+        #[test]
+        fn test_deribit_order_book_l2() {
+            let input = r#"
+            {
+                "jsonrpc": "2.0",
+                "method": "subscription",
+                "params": {
+                    "channel": "book.BTC-PERPETUAL.100ms",
+                    "data": {
+                        "type": "change",
+                        "timestamp": 297218,
+                        "prev_change_id": 297217,
+                        "instrument_name": "BTC-PERPETUAL",
+                        "change_id": 297218,
+                        "bids": [],
+                        "asks": [["delete", 5042.64, 0], ["new", 5043.3, 40.0]]
+                    }
+                }
+            }
+            "#;
+
+            assert_eq!(
+                serde_json::from_str::<DeribitOrderBookL2>(input).unwrap(),
+                message(
+                    DeribitOrderBookL2Kind::Change,
+                    Some(297217),
+                    297218,
+                    vec![
+                        DeribitLevel {
+                            action: DeribitLevelAction::Delete,
+                            price: 5042.64,
+                            amount: 0.0
+                        },
+                        DeribitLevel {
+                            action: DeribitLevelAction::New,
+                            price: 5043.3,
+                            amount: 40.0
+                        },
+                    ],
+                )
+            );
+        }
+    }
+
+    mod deribit_book_updater {
+        use super::*;
+        use crate::{exchange::deribit::book::DeribitLevelAction, subscription::book::Level};
+
+        #[test]
+        fn test_update() {
+            struct TestCase {
+                updater: DeribitBookUpdater,
+                book: OrderBook,
+                input_update: DeribitOrderBookL2,
+                expected: Result<Option<OrderBook>, DataError>,
+            }
+
+            let level = |action, price, amount| DeribitLevel {
+                action,
+                price,
+                amount,
+            };
+
+            let tests = vec![
+                TestCase {
+                    // TC0: change received before the initial snapshot is invalid
+                    updater: DeribitBookUpdater::default(),
+                    book: OrderBook::default(),
+                    input_update: message(DeribitOrderBookL2Kind::Change, Some(9), 10, vec![]),
+                    expected: Err(DataError::MissingSnapshot),
+                },
+                TestCase {
+                    // TC1: snapshot initialises the OrderBook
+                    updater: DeribitBookUpdater::default(),
+                    book: OrderBook::default(),
+                    input_update: message(
+                        DeribitOrderBookL2Kind::Snapshot,
+                        None,
+                        10,
+                        vec![
+                            level(DeribitLevelAction::New, 110.0, 1.0),
+                            level(DeribitLevelAction::New, 100.0, 1.0),
+                        ],
+                    ),
+                    expected: Ok(Some(OrderBook {
+                        last_update_time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            10,
+                        )),
+                        bids: OrderBookSide::new(Side::Buy, Vec::<Level>::new()),
+                        asks: OrderBookSide::new(
+                            Side::Sell,
+                            vec![Level::new(100, 1), Level::new(110, 1)],
+                        ),
+                    })),
+                },
+                TestCase {
+                    // TC2: valid change removes & upserts levels
+                    updater: DeribitBookUpdater {
+                        updates_processed: 1,
+                        last_change_id: Some(10),
+                    },
+                    book: OrderBook {
+                        last_update_time: Default::default(),
+                        bids: OrderBookSide::new(Side::Buy, Vec::<Level>::new()),
+                        asks: OrderBookSide::new(
+                            Side::Sell,
+                            vec![Level::new(100, 1), Level::new(110, 1)],
+                        ),
+                    },
+                    input_update: message(
+                        DeribitOrderBookL2Kind::Change,
+                        Some(10),
+                        15,
+                        vec![
+                            level(DeribitLevelAction::Delete, 100.0, 0.0),
+                            level(DeribitLevelAction::New, 105.0, 3.0),
+                        ],
+                    ),
+                    expected: Ok(Some(OrderBook {
+                        last_update_time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            15,
+                        )),
+                        bids: OrderBookSide::new(Side::Buy, Vec::<Level>::new()),
+                        asks: OrderBookSide::new(
+                            Side::Sell,
+                            vec![Level::new(105, 3), Level::new(110, 1)],
+                        ),
+                    })),
+                },
+                TestCase {
+                    // TC3: change w/ prev_change_id != last change_id is an invalid sequence
+                    updater: DeribitBookUpdater {
+                        updates_processed: 1,
+                        last_change_id: Some(10),
+                    },
+                    book: OrderBook::default(),
+                    input_update: message(DeribitOrderBookL2Kind::Change, Some(12), 15, vec![]),
+                    expected: Err(DataError::InvalidSequence {
+                        prev_last_update_id: 10,
+                        first_update_id: 12,
+                    }),
+                },
+                TestCase {
+                    // TC4: heartbeat does not generate an OrderBook snapshot
+                    updater: DeribitBookUpdater::default(),
+                    book: OrderBook::default(),
+                    input_update: DeribitMessage::Response { id: 3 },
+                    expected: Ok(None),
+                },
+            ];
+
+            for (index, mut test) in tests.into_iter().enumerate() {
+                let actual = test.updater.update(&mut test.book, test.input_update);
+
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+}
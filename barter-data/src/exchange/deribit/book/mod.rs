@@ -0,0 +1,65 @@
+use crate::subscription::book::Level;
+use serde::{Deserialize, Serialize};
+
+/// Level 1 OrderBook types (top of book).
+pub mod l1;
+
+/// Level 2 OrderBook types.
+pub mod l2;
+
+/// [`Deribit`](super::Deribit) OrderBook level change.
+///
+/// Note that a "delete" action always has an amount of 0, so the change can be upserted like an
+/// absolute price level quantity.
+///
+/// #### Raw Payload Examples
+/// See docs: <https://docs.deribit.com/#book-instrument_name-interval>
+/// ```json
+/// ["new", 5042.34, 30.0]
+/// ```
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct DeribitLevel {
+    pub action: DeribitLevelAction,
+    pub price: f64,
+    pub amount: f64,
+}
+
+/// [`Deribit`](super::Deribit) OrderBook level change action.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DeribitLevelAction {
+    New,
+    Change,
+    Delete,
+}
+
+impl From<DeribitLevel> for Level {
+    fn from(level: DeribitLevel) -> Self {
+        Self {
+            price: level.price,
+            amount: level.amount,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_deribit_level() {
+            let input = r#"["delete", 5041.94, 0]"#;
+            assert_eq!(
+                serde_json::from_str::<DeribitLevel>(input).unwrap(),
+                DeribitLevel {
+                    action: DeribitLevelAction::Delete,
+                    price: 5041.94,
+                    amount: 0.0,
+                },
+            )
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use super::Deribit;
+use crate::{
+    subscription::{
+        book::{OrderBooksL1, OrderBooksL2},
+        trade::PublicTrades,
+        Subscription,
+    },
+    Identifier,
+};
+use serde::Serialize;
+
+/// Type that defines how to translate a Barter [`Subscription`] into a
+/// [`Deribit`] channel to be subscribed to.
+///
+/// Note that the full [`Deribit`] channel name also contains the market and, for some channels,
+/// a notification interval (eg/ "trades.BTC-PERPETUAL.100ms").
+///
+/// See docs: <https://docs.deribit.com/#subscriptions>
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Serialize)]
+pub struct DeribitChannel(pub &'static str);
+
+impl DeribitChannel {
+    /// [`Deribit`] real-time trades channel.
+    ///
+    /// See docs: <https://docs.deribit.com/#trades-instrument_name-interval>
+    pub const TRADES: Self = Self("trades");
+
+    /// [`Deribit`] real-time OrderBook Level1 (top of book) channel.
+    ///
+    /// See docs: <https://docs.deribit.com/#quote-instrument_name>
+    pub const ORDER_BOOK_L1: Self = Self("quote");
+
+    /// [`Deribit`] real-time OrderBook Level2 channel.
+    ///
+    /// See docs: <https://docs.deribit.com/#book-instrument_name-interval>
+    pub const ORDER_BOOK_L2: Self = Self("book");
+
+    /// Notification interval suffix of the full [`Deribit`] channel name, if the channel
+    /// requires one.
+    ///
+    /// Note that the "raw" interval requires an authorised connection, so "100ms" is used.
+    pub fn interval(&self) -> Option<&'static str> {
+        match *self {
+            Self::ORDER_BOOK_L1 => None,
+            _ => Some("100ms"),
+        }
+    }
+}
+
+impl<Instrument> Identifier<DeribitChannel> for Subscription<Deribit, Instrument, PublicTrades> {
+    fn id(&self) -> DeribitChannel {
+        DeribitChannel::TRADES
+    }
+}
+
+impl<Instrument> Identifier<DeribitChannel> for Subscription<Deribit, Instrument, OrderBooksL1> {
+    fn id(&self) -> DeribitChannel {
+        DeribitChannel::ORDER_BOOK_L1
+    }
+}
+
+impl<Instrument> Identifier<DeribitChannel> for Subscription<Deribit, Instrument, OrderBooksL2> {
+    fn id(&self) -> DeribitChannel {
+        DeribitChannel::ORDER_BOOK_L2
+    }
+}
+
+impl AsRef<str> for DeribitChannel {
+    fn as_ref(&self) -> &str {
+        self.0
+    }
+}
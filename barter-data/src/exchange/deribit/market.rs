@@ -0,0 +1,176 @@
+use super::Deribit;
+use crate::{
+    instrument::{KeyedInstrument, MarketInstrumentData},
+    subscription::Subscription,
+    Identifier,
+};
+use barter_integration::model::instrument::{
+    kind::{InstrumentKind, OptionKind},
+    symbol::Symbol,
+    Instrument,
+};
+use chrono::{
+    format::{DelayedFormat, StrftimeItems},
+    DateTime, Utc,
+};
+use serde::{Deserialize, Serialize};
+
+/// Type that defines how to translate a Barter [`Subscription`] into a
+/// [`Deribit`] market that can be subscribed to.
+///
+/// See docs: <https://docs.deribit.com/#public-get_instruments>
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct DeribitMarket(pub String);
+
+impl<Kind> Identifier<DeribitMarket> for Subscription<Deribit, Instrument, Kind> {
+    fn id(&self) -> DeribitMarket {
+        deribit_market(&self.instrument)
+    }
+}
+
+impl<Kind> Identifier<DeribitMarket> for Subscription<Deribit, KeyedInstrument, Kind> {
+    fn id(&self) -> DeribitMarket {
+        deribit_market(&self.instrument.data)
+    }
+}
+
+impl<Kind> Identifier<DeribitMarket> for Subscription<Deribit, MarketInstrumentData, Kind> {
+    fn id(&self) -> DeribitMarket {
+        DeribitMarket(self.instrument.name_exchange.clone())
+    }
+}
+
+impl AsRef<str> for DeribitMarket {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+fn deribit_market(instrument: &Instrument) -> DeribitMarket {
+    use InstrumentKind::*;
+    let Instrument { base, quote, kind } = instrument;
+
+    DeribitMarket(match kind {
+        Spot => format!("{base}_{quote}").to_uppercase(),
+        Future(future) => format!(
+            "{}-{}",
+            format_currency(base, quote),
+            format_expiry(future.expiry).to_string().to_uppercase()
+        ),
+        Perpetual => format!("{}-PERPETUAL", format_currency(base, quote)),
+        Option(option) => format!(
+            "{}-{}-{}-{}",
+            format_currency(base, quote),
+            format_expiry(option.expiry).to_string().to_uppercase(),
+            format_strike(&option.strike.to_string()),
+            match option.kind {
+                OptionKind::Call => "C",
+                OptionKind::Put => "P",
+            },
+        ),
+    })
+}
+
+/// Format the currency prefix of a [`Deribit`] derivative instrument name.
+///
+/// Inverse contracts are settled in the base currency and are named after it (eg/ "BTC"), whereas
+/// linear contracts are named after the base & quote currency (eg/ "BTC_USDC").
+///
+/// See docs: <https://docs.deribit.com/#naming>
+fn format_currency(base: &Symbol, quote: &Symbol) -> String {
+    match quote.as_ref() {
+        "usd" => base.as_ref().to_uppercase(),
+        _ => format!("{base}_{quote}").to_uppercase(),
+    }
+}
+
+/// Format the expiry DateTime<Utc> to be Deribit API compatible.
+///
+/// eg/ "5APR24" (5th of April 2024)
+///
+/// See docs: <https://docs.deribit.com/#naming>
+fn format_expiry<'a>(expiry: DateTime<Utc>) -> DelayedFormat<StrftimeItems<'a>> {
+    expiry.date_naive().format("%-d%b%y")
+}
+
+/// Format the strike to be Deribit API compatible, where trailing fractional zeros are removed
+/// and any decimal point is replaced with a "d".
+///
+/// eg/ "0d625" (strike of 0.6250)
+///
+/// See docs: <https://docs.deribit.com/#naming>
+fn format_strike(strike: &str) -> String {
+    match strike.contains('.') {
+        true => strike
+            .trim_end_matches('0')
+            .trim_end_matches('.')
+            .replace('.', "d"),
+        false => strike.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::model::instrument::kind::{
+        FutureContract, OptionContract, OptionExercise,
+    };
+    use chrono::TimeZone;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_deribit_market() {
+        struct TestCase {
+            input: Instrument,
+            expected: DeribitMarket,
+        }
+
+        let expiry = Utc.with_ymd_and_hms(2024, 4, 5, 8, 0, 0).unwrap();
+
+        let option = |strike, kind| {
+            InstrumentKind::Option(OptionContract {
+                kind,
+                exercise: OptionExercise::European,
+                expiry,
+                strike,
+            })
+        };
+
+        let tests = vec![
+            TestCase {
+                // TC0: inverse perpetual
+                input: Instrument::from(("btc", "usd", InstrumentKind::Perpetual)),
+                expected: DeribitMarket("BTC-PERPETUAL".to_string()),
+            },
+            TestCase {
+                // TC1: linear perpetual
+                input: Instrument::from(("btc", "usdc", InstrumentKind::Perpetual)),
+                expected: DeribitMarket("BTC_USDC-PERPETUAL".to_string()),
+            },
+            TestCase {
+                // TC2: inverse future
+                input: Instrument::from((
+                    "eth",
+                    "usd",
+                    InstrumentKind::Future(FutureContract { expiry }),
+                )),
+                expected: DeribitMarket("ETH-5APR24".to_string()),
+            },
+            TestCase {
+                // TC3: inverse call option
+                input: Instrument::from(("btc", "usd", option(dec!(60000), OptionKind::Call))),
+                expected: DeribitMarket("BTC-5APR24-60000-C".to_string()),
+            },
+            TestCase {
+                // TC4: linear put option w/ decimal strike
+                input: Instrument::from(("xrp", "usdc", option(dec!(0.6250), OptionKind::Put))),
+                expected: DeribitMarket("XRP_USDC-5APR24-0d625-P".to_string()),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = deribit_market(&test.input);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
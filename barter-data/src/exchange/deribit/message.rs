@@ -0,0 +1,222 @@
+use crate::{exchange::ExchangeSub, Identifier};
+use barter_integration::model::SubscriptionId;
+use serde::{Deserialize, Serialize};
+
+/// [`Deribit`](super::Deribit) message variants that can be received over
+/// [`WebSocket`](barter_integration::protocol::websocket::WebSocket).
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.deribit.com/#subscriptions>
+/// #### Subscription Data
+/// ```json
+/// {
+///   "jsonrpc": "2.0",
+///   "method": "subscription",
+///   "params": {
+///     "channel": "trades.BTC-PERPETUAL.100ms",
+///     "data": [
+///       {
+///         "trade_seq": 30289432,
+///         "trade_id": "48079254",
+///         "timestamp": 1590484156350,
+///         "price": 8950.0,
+///         "instrument_name": "BTC-PERPETUAL",
+///         "direction": "sell",
+///         "amount": 10.0
+///       }
+///     ]
+///   }
+/// }
+/// ```
+///
+/// #### Heartbeat
+/// See docs: <https://docs.deribit.com/#public-set_heartbeat>
+/// ```json
+/// {
+///   "jsonrpc": "2.0",
+///   "method": "heartbeat",
+///   "params": {"type": "test_request"}
+/// }
+/// ```
+///
+/// #### Response (eg/ to a "public/test" request)
+/// ```json
+/// {
+///   "jsonrpc": "2.0",
+///   "id": 3,
+///   "result": {"version": "1.2.26"}
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum DeribitMessage<T> {
+    Data { params: DeribitParams<T> },
+    Heartbeat { params: DeribitHeartbeat },
+    Response { id: u64 },
+}
+
+impl<T> Identifier<Option<SubscriptionId>> for DeribitMessage<T> {
+    fn id(&self) -> Option<SubscriptionId> {
+        match self {
+            Self::Data { params } => Some(params.subscription_id.clone()),
+            Self::Heartbeat { .. } | Self::Response { .. } => None,
+        }
+    }
+}
+
+/// [`Deribit`](super::Deribit) subscription notification parameters, containing the market data
+/// and the associated [`SubscriptionId`].
+///
+/// See [`DeribitMessage`] for full raw payload examples.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct DeribitParams<T> {
+    #[serde(
+        rename = "channel",
+        deserialize_with = "de_deribit_channel_as_subscription_id"
+    )]
+    pub subscription_id: SubscriptionId,
+    pub data: T,
+}
+
+/// [`Deribit`](super::Deribit) heartbeat notification parameters.
+///
+/// A [`DeribitHeartbeatKind::TestRequest`] is answered by the "public/test" requests sent via the
+/// [`Connector::ping_interval`](crate::exchange::Connector::ping_interval).
+///
+/// See [`DeribitMessage`] for full raw payload examples.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct DeribitHeartbeat {
+    #[serde(rename = "type")]
+    pub kind: DeribitHeartbeatKind,
+}
+
+/// [`Deribit`](super::Deribit) heartbeat notification kind.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeribitHeartbeatKind {
+    Heartbeat,
+    TestRequest,
+}
+
+/// Deserialize a [`DeribitParams`] "channel" (eg/ "trades.BTC-PERPETUAL.100ms") as the associated
+/// [`SubscriptionId`] (eg/ SubscriptionId("trades|BTC-PERPETUAL")).
+pub fn de_deribit_channel_as_subscription_id<'de, D>(
+    deserializer: D,
+) -> Result<SubscriptionId, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let channel = <String as Deserialize>::deserialize(deserializer)?;
+
+    let mut parts = channel.split('.');
+    match (parts.next(), parts.next()) {
+        (Some(channel), Some(market)) => Ok(ExchangeSub::from((channel, market)).id()),
+        _ => Err(serde::de::Error::invalid_value(
+            serde::de::Unexpected::Str(&channel),
+            &"channel in the format: {channel}.{market}(.{interval})",
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::error::SocketError;
+
+        #[test]
+        fn test_deribit_message() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<DeribitMessage<Vec<u64>>, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid subscription data
+                    input: r#"
+                    {
+                        "jsonrpc": "2.0",
+                        "method": "subscription",
+                        "params": {"channel": "trades.BTC-PERPETUAL.100ms", "data": [1, 2]}
+                    }
+                    "#,
+                    expected: Ok(DeribitMessage::Data {
+                        params: DeribitParams {
+                            subscription_id: SubscriptionId::from("trades|BTC-PERPETUAL"),
+                            data: vec![1, 2],
+                        },
+                    }),
+                },
+                TestCase {
+                    // TC1: valid subscription data w/ channel without an interval
+                    input: r#"
+                    {
+                        "jsonrpc": "2.0",
+                        "method": "subscription",
+                        "params": {"channel": "quote.BTC-5APR24-60000-C", "data": []}
+                    }
+                    "#,
+                    expected: Ok(DeribitMessage::Data {
+                        params: DeribitParams {
+                            subscription_id: SubscriptionId::from("quote|BTC-5APR24-60000-C"),
+                            data: vec![],
+                        },
+                    }),
+                },
+                TestCase {
+                    // TC2: valid heartbeat test_request
+                    input: r#"
+                    {
+                        "jsonrpc": "2.0",
+                        "method": "heartbeat",
+                        "params": {"type": "test_request"}
+                    }
+                    "#,
+                    expected: Ok(DeribitMessage::Heartbeat {
+                        params: DeribitHeartbeat {
+                            kind: DeribitHeartbeatKind::TestRequest,
+                        },
+                    }),
+                },
+                TestCase {
+                    // TC3: valid public/test response
+                    input: r#"{"jsonrpc": "2.0", "id": 3, "result": {"version": "1.2.26"}}"#,
+                    expected: Ok(DeribitMessage::Response { id: 3 }),
+                },
+                TestCase {
+                    // TC4: invalid subscription data w/ unexpected channel format
+                    input: r#"
+                    {
+                        "jsonrpc": "2.0",
+                        "method": "subscription",
+                        "params": {"channel": "trades", "data": [1, 2]}
+                    }
+                    "#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "",
+                        item: "".to_string(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<DeribitMessage<Vec<u64>>>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,179 @@
+//!
+//! ### Notes
+//! #### JSON-RPC
+//! - Deribit uses JSON-RPC over WebSocket, so every request has a "method" & "params", and market
+//!   data is received as "subscription" notifications containing the full "channel" name
+//!   (eg/ "trades.BTC-PERPETUAL.100ms").
+//!
+//! #### Heartbeats
+//! - On connection, a heartbeat is enabled via "public/set_heartbeat". The server then sends
+//!   "heartbeat" notifications, and "test_request" notifications that must be answered with a
+//!   "public/test" request to keep the connection alive.
+//! - [`Deribit`] answers these by sending a "public/test" request via [`Connector::ping_interval`]
+//!   more frequently than the heartbeat interval.
+
+use self::{
+    book::{l1::DeribitOrderBookL1, l2::DeribitBookUpdater},
+    channel::DeribitChannel,
+    market::DeribitMarket,
+    subscription::DeribitSubResponse,
+    trade::DeribitTrades,
+};
+use crate::{
+    exchange::{Connector, ExchangeId, ExchangeSub, PingInterval, StreamSelector},
+    instrument::InstrumentData,
+    subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
+    subscription::{
+        book::{OrderBooksL1, OrderBooksL2},
+        trade::PublicTrades,
+        Map,
+    },
+    transformer::{book::MultiBookTransformer, stateless::StatelessTransformer},
+    ExchangeWsStream,
+};
+use barter_integration::{error::SocketError, protocol::websocket::WsMessage};
+use barter_macro::{DeExchange, SerExchange};
+use serde_json::json;
+use std::time::Duration;
+use url::Url;
+
+/// OrderBook types for [`Deribit`].
+pub mod book;
+
+/// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
+/// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
+pub mod channel;
+
+/// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
+/// into an exchange [`Connector`] specific market used for generating [`Connector::requests`].
+pub mod market;
+
+/// [`DeribitMessage`](message::DeribitMessage) type for [`Deribit`].
+pub mod message;
+
+/// [`Subscription`](crate::subscription::Subscription) response type and response
+/// [`Validator`](barter_integration::Validator) for [`Deribit`].
+pub mod subscription;
+
+/// Public trade types for [`Deribit`].
+pub mod trade;
+
+/// [`Deribit`] server base url.
+///
+/// See docs: <https://docs.deribit.com/#json-rpc>
+pub const BASE_URL_DERIBIT: &str = "wss://www.deribit.com/ws/api/v2";
+
+/// [`Deribit`] server heartbeat interval requested via "public/set_heartbeat".
+///
+/// See docs: <https://docs.deribit.com/#public-set_heartbeat>
+pub const HEARTBEAT_INTERVAL_DERIBIT: Duration = Duration::from_secs(30);
+
+/// [`Deribit`] server [`PingInterval`] duration, which must be shorter than the
+/// [`HEARTBEAT_INTERVAL_DERIBIT`] so every "test_request" is answered.
+///
+/// See docs: <https://docs.deribit.com/#public-test>
+pub const PING_INTERVAL_DERIBIT: Duration = Duration::from_secs(10);
+
+/// [`Deribit`] JSON-RPC request id used for "public/set_heartbeat" requests.
+pub const REQUEST_ID_SET_HEARTBEAT_DERIBIT: u64 = 1;
+
+/// [`Deribit`] JSON-RPC request id used for "public/subscribe" requests.
+pub const REQUEST_ID_SUBSCRIBE_DERIBIT: u64 = 2;
+
+/// [`Deribit`] JSON-RPC request id used for "public/test" requests.
+pub const REQUEST_ID_TEST_DERIBIT: u64 = 3;
+
+/// [`Deribit`] exchange.
+///
+/// See docs: <https://docs.deribit.com/>
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, DeExchange, SerExchange,
+)]
+pub struct Deribit;
+
+impl Connector for Deribit {
+    const ID: ExchangeId = ExchangeId::Deribit;
+    type Channel = DeribitChannel;
+    type Market = DeribitMarket;
+    type Subscriber = WebSocketSubscriber;
+    type SubValidator = WebSocketSubValidator;
+    type SubResponse = DeribitSubResponse;
+
+    fn url() -> Result<Url, SocketError> {
+        Url::parse(BASE_URL_DERIBIT).map_err(SocketError::UrlParse)
+    }
+
+    fn ping_interval() -> Option<PingInterval> {
+        Some(PingInterval {
+            interval: tokio::time::interval(PING_INTERVAL_DERIBIT),
+            ping: || {
+                WsMessage::Text(
+                    json!({
+                        "jsonrpc": "2.0",
+                        "id": REQUEST_ID_TEST_DERIBIT,
+                        "method": "public/test",
+                        "params": {},
+                    })
+                    .to_string(),
+                )
+            },
+        })
+    }
+
+    fn requests(exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
+        vec![
+            WsMessage::Text(
+                json!({
+                    "jsonrpc": "2.0",
+                    "id": REQUEST_ID_SET_HEARTBEAT_DERIBIT,
+                    "method": "public/set_heartbeat",
+                    "params": {
+                        "interval": HEARTBEAT_INTERVAL_DERIBIT.as_secs(),
+                    },
+                })
+                .to_string(),
+            ),
+            WsMessage::Text(
+                json!({
+                    "jsonrpc": "2.0",
+                    "id": REQUEST_ID_SUBSCRIBE_DERIBIT,
+                    "method": "public/subscribe",
+                    "params": {
+                        "channels": &exchange_subs,
+                    },
+                })
+                .to_string(),
+            ),
+        ]
+    }
+
+    fn expected_responses<InstrumentId>(_: &Map<InstrumentId>) -> usize {
+        1
+    }
+}
+
+impl<Instrument> StreamSelector<Instrument, PublicTrades> for Deribit
+where
+    Instrument: InstrumentData,
+{
+    type Stream =
+        ExchangeWsStream<StatelessTransformer<Self, Instrument::Id, PublicTrades, DeribitTrades>>;
+}
+
+impl<Instrument> StreamSelector<Instrument, OrderBooksL1> for Deribit
+where
+    Instrument: InstrumentData,
+{
+    type Stream = ExchangeWsStream<
+        StatelessTransformer<Self, Instrument::Id, OrderBooksL1, DeribitOrderBookL1>,
+    >;
+}
+
+impl<Instrument> StreamSelector<Instrument, OrderBooksL2> for Deribit
+where
+    Instrument: InstrumentData,
+{
+    type Stream = ExchangeWsStream<
+        MultiBookTransformer<Self, Instrument::Id, OrderBooksL2, DeribitBookUpdater>,
+    >;
+}
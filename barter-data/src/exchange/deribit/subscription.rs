@@ -0,0 +1,221 @@
+use super::{channel::DeribitChannel, market::DeribitMarket};
+use crate::exchange::subscription::ExchangeSub;
+use barter_integration::{error::SocketError, Validator};
+use serde::{Deserialize, Serialize, Serializer};
+
+// Implement custom Serialize to assist aesthetics of <Deribit as Connector>::requests() function.
+impl Serialize for ExchangeSub<DeribitChannel, DeribitMarket> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let channel = match self.channel.interval() {
+            Some(interval) => format!(
+                "{}.{}.{interval}",
+                self.channel.as_ref(),
+                self.market.as_ref()
+            ),
+            None => format!("{}.{}", self.channel.as_ref(), self.market.as_ref()),
+        };
+
+        serializer.serialize_str(&channel)
+    }
+}
+
+/// [`Deribit`](super::Deribit) WebSocket subscription response.
+///
+/// ### Raw Payload Examples
+/// #### Subscription Trades Ok Response
+/// ```json
+/// {
+///   "jsonrpc": "2.0",
+///   "id": 2,
+///   "result": ["trades.BTC-PERPETUAL.100ms"],
+///   "usIn": 1712345678123456,
+///   "usOut": 1712345678123789,
+///   "usDiff": 333,
+///   "testnet": false
+/// }
+/// ```
+///
+/// #### Subscription Error Response
+/// ```json
+/// {
+///   "jsonrpc": "2.0",
+///   "id": 2,
+///   "error": {
+///     "message": "Invalid params",
+///     "code": -32602
+///   }
+/// }
+/// ```
+///
+/// See docs: <https://docs.deribit.com/#public-subscribe>
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum DeribitSubResponse {
+    Subscribed {
+        id: u64,
+        #[serde(rename = "result")]
+        channels: Vec<String>,
+    },
+    Error {
+        id: u64,
+        error: DeribitError,
+    },
+}
+
+/// [`Deribit`](super::Deribit) JSON-RPC error.
+///
+/// See [`DeribitSubResponse`] for full raw payload examples.
+///
+/// See docs: <https://docs.deribit.com/#json-rpc>
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct DeribitError {
+    pub code: i64,
+    pub message: String,
+}
+
+impl Validator for DeribitSubResponse {
+    fn validate(self) -> Result<Self, SocketError>
+    where
+        Self: Sized,
+    {
+        match self {
+            // Deribit silently ignores channels it does not recognise
+            Self::Subscribed { ref channels, .. } if channels.is_empty() => Err(
+                SocketError::Subscribe("received subscription response with no channels".into()),
+            ),
+            Self::Subscribed { .. } => Ok(self),
+            Self::Error { error, .. } => Err(SocketError::Subscribe(format!(
+                "received failure subscription response code: {} with message: {}",
+                error.code, error.message,
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_deribit_subscription_response() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<DeribitSubResponse, SocketError>,
+            }
+
+            let cases = vec![
+                TestCase {
+                    // TC0: input response is subscription success
+                    input: r#"
+                    {
+                        "jsonrpc": "2.0",
+                        "id": 2,
+                        "result": ["trades.BTC-PERPETUAL.100ms", "quote.BTC-PERPETUAL"],
+                        "usIn": 1712345678123456,
+                        "usOut": 1712345678123789,
+                        "usDiff": 333,
+                        "testnet": false
+                    }
+                    "#,
+                    expected: Ok(DeribitSubResponse::Subscribed {
+                        id: 2,
+                        channels: vec![
+                            "trades.BTC-PERPETUAL.100ms".to_string(),
+                            "quote.BTC-PERPETUAL".to_string(),
+                        ],
+                    }),
+                },
+                TestCase {
+                    // TC1: input response is subscription error
+                    input: r#"
+                    {
+                        "jsonrpc": "2.0",
+                        "id": 2,
+                        "error": {"message": "Invalid params", "code": -32602}
+                    }
+                    "#,
+                    expected: Ok(DeribitSubResponse::Error {
+                        id: 2,
+                        error: DeribitError {
+                            code: -32602,
+                            message: "Invalid params".to_string(),
+                        },
+                    }),
+                },
+                TestCase {
+                    // TC2: input response is set_heartbeat success, which is not a SubResponse
+                    input: r#"{"jsonrpc": "2.0", "id": 1, "result": "ok"}"#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "",
+                        item: "".to_string(),
+                    }),
+                },
+            ];
+
+            for (index, test) in cases.into_iter().enumerate() {
+                let actual = serde_json::from_str::<DeribitSubResponse>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_validate_deribit_sub_response() {
+        struct TestCase {
+            input_response: DeribitSubResponse,
+            is_valid: bool,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: input response is subscription success
+                input_response: DeribitSubResponse::Subscribed {
+                    id: 2,
+                    channels: vec!["trades.BTC-PERPETUAL.100ms".to_string()],
+                },
+                is_valid: true,
+            },
+            TestCase {
+                // TC1: input response is subscription success w/ no recognised channels
+                input_response: DeribitSubResponse::Subscribed {
+                    id: 2,
+                    channels: vec![],
+                },
+                is_valid: false,
+            },
+            TestCase {
+                // TC2: input response is subscription error
+                input_response: DeribitSubResponse::Error {
+                    id: 2,
+                    error: DeribitError {
+                        code: -32602,
+                        message: "Invalid params".to_string(),
+                    },
+                },
+                is_valid: false,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = test.input_response.validate().is_ok();
+            assert_eq!(actual, test.is_valid, "TestCase {} failed", index);
+        }
+    }
+}
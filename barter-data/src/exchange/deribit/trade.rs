@@ -0,0 +1,113 @@
+use super::message::DeribitMessage;
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::ExchangeId,
+    subscription::trade::PublicTrade,
+};
+use barter_integration::model::{Exchange, Side};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for a [`Deribit`](super::Deribit) real-time trades WebSocket message.
+pub type DeribitTrades = DeribitMessage<Vec<DeribitTrade>>;
+
+/// [`Deribit`](super::Deribit) real-time trade WebSocket message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.deribit.com/#trades-instrument_name-interval>
+/// ```json
+/// {
+///   "trade_seq": 30289432,
+///   "trade_id": "48079254",
+///   "timestamp": 1590484156350,
+///   "tick_direction": 0,
+///   "price": 8950.0,
+///   "mark_price": 8948.9,
+///   "instrument_name": "BTC-PERPETUAL",
+///   "index_price": 8955.88,
+///   "direction": "sell",
+///   "amount": 10.0
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct DeribitTrade {
+    #[serde(rename = "trade_id")]
+    pub id: String,
+    pub price: f64,
+    pub amount: f64,
+    #[serde(rename = "direction")]
+    pub side: Side,
+    #[serde(
+        rename = "timestamp",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+}
+
+impl<InstrumentId: Clone> From<(ExchangeId, InstrumentId, DeribitTrades)>
+    for MarketIter<InstrumentId, PublicTrade>
+{
+    fn from((exchange_id, instrument, trades): (ExchangeId, InstrumentId, DeribitTrades)) -> Self {
+        match trades {
+            DeribitMessage::Data { params } => params
+                .data
+                .into_iter()
+                .map(|trade| {
+                    Ok(MarketEvent {
+                        exchange_time: trade.time,
+                        received_time: Utc::now(),
+                        exchange: Exchange::from(exchange_id),
+                        instrument: instrument.clone(),
+                        kind: PublicTrade {
+                            id: trade.id,
+                            price: trade.price,
+                            amount: trade.amount,
+                            side: trade.side,
+                        },
+                    })
+                })
+                .collect(),
+            DeribitMessage::Heartbeat { .. } | DeribitMessage::Response { .. } => Self(vec![]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::de::datetime_utc_from_epoch_duration;
+        use std::time::Duration;
+
+        #[test]
+        fn test_deribit_trade() {
+            let input = r#"
+            {
+                "trade_seq": 30289432,
+                "trade_id": "48079254",
+                "timestamp": 1590484156350,
+                "tick_direction": 0,
+                "price": 8950.0,
+                "mark_price": 8948.9,
+                "instrument_name": "BTC-PERPETUAL",
+                "index_price": 8955.88,
+                "direction": "sell",
+                "amount": 10.0
+            }
+            "#;
+
+            assert_eq!(
+                serde_json::from_str::<DeribitTrade>(input).unwrap(),
+                DeribitTrade {
+                    id: "48079254".to_string(),
+                    price: 8950.0,
+                    amount: 10.0,
+                    side: Side::Sell,
+                    time: datetime_utc_from_epoch_duration(Duration::from_millis(1590484156350)),
+                }
+            );
+        }
+    }
+}
@@ -1,6 +1,13 @@
+use super::Gateio;
 use crate::{
     instrument::InstrumentData,
-    subscription::{trade::PublicTrades, Subscription},
+    subscription::{
+        funding::FundingRates,
+        liquidation::Liquidations,
+        price::{IndexPrices, MarkPrices},
+        trade::PublicTrades,
+        Subscription,
+    },
     Identifier,
 };
 use barter_integration::model::instrument::kind::InstrumentKind;
@@ -29,6 +36,17 @@ impl GateioChannel {
     ///
     /// See docs: <https://www.gate.io/docs/developers/options/ws/en/#public-contract-trades-channel>
     pub const OPTION_TRADES: Self = Self("options.trades");
+
+    /// Gateio [`InstrumentKind::Perpetual`] real-time tickers channel, which contains the
+    /// funding rate, mark price & index price.
+    ///
+    /// See docs: <https://www.gate.io/docs/developers/futures/ws/en/#tickers-api>
+    pub const PERPETUAL_TICKERS: Self = Self("futures.tickers");
+
+    /// Gateio [`InstrumentKind::Perpetual`] real-time public liquidations channel.
+    ///
+    /// See docs: <https://www.gate.io/docs/developers/futures/ws/en/#public-liquidates-notification>
+    pub const PERPETUAL_LIQUIDATIONS: Self = Self("futures.public_liquidates");
 }
 
 impl<GateioExchange, Instrument> Identifier<GateioChannel>
@@ -45,6 +63,38 @@ where
     }
 }
 
+impl<Server, Instrument> Identifier<GateioChannel>
+    for Subscription<Gateio<Server>, Instrument, FundingRates>
+{
+    fn id(&self) -> GateioChannel {
+        GateioChannel::PERPETUAL_TICKERS
+    }
+}
+
+impl<Server, Instrument> Identifier<GateioChannel>
+    for Subscription<Gateio<Server>, Instrument, MarkPrices>
+{
+    fn id(&self) -> GateioChannel {
+        GateioChannel::PERPETUAL_TICKERS
+    }
+}
+
+impl<Server, Instrument> Identifier<GateioChannel>
+    for Subscription<Gateio<Server>, Instrument, IndexPrices>
+{
+    fn id(&self) -> GateioChannel {
+        GateioChannel::PERPETUAL_TICKERS
+    }
+}
+
+impl<Server, Instrument> Identifier<GateioChannel>
+    for Subscription<Gateio<Server>, Instrument, Liquidations>
+{
+    fn id(&self) -> GateioChannel {
+        GateioChannel::PERPETUAL_LIQUIDATIONS
+    }
+}
+
 impl AsRef<str> for GateioChannel {
     fn as_ref(&self) -> &str {
         self.0
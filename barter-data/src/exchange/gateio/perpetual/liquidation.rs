@@ -0,0 +1,146 @@
+use super::super::message::GateioMessage;
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::{ExchangeId, ExchangeSub},
+    subscription::liquidation::Liquidation,
+    Identifier,
+};
+use barter_integration::model::{Exchange, Side, SubscriptionId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for a [`GateioPerpetualsUsd`](super::GateioPerpetualsUsd) and
+/// [`GateioPerpetualsBtc`](super::GateioPerpetualsBtc) real-time public liquidations WebSocket
+/// message.
+pub type GateioFuturesLiquidations = GateioMessage<Vec<GateioFuturesLiquidation>>;
+
+/// [`GateioPerpetualsUsd`](super::GateioPerpetualsUsd) and
+/// [`GateioPerpetualsBtc`](super::GateioPerpetualsBtc) real-time public liquidation.
+///
+/// Note that a negative "size" is a liquidation sell order, and a positive "size" is a
+/// liquidation buy order.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.gate.io/docs/developers/futures/ws/en/#public-liquidates-notification>
+/// ```json
+/// {
+///   "channel": "futures.public_liquidates",
+///   "event": "update",
+///   "time": 1541505434,
+///   "time_ms": 1541505434123,
+///   "result": [
+///     {
+///       "price": 215.1,
+///       "size": -124,
+///       "time_ms": 1541486601123,
+///       "contract": "BTC_USD"
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct GateioFuturesLiquidation {
+    #[serde(rename = "contract")]
+    pub market: String,
+    #[serde(
+        rename = "time_ms",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    pub price: f64,
+    pub size: f64,
+}
+
+impl Identifier<Option<SubscriptionId>> for GateioFuturesLiquidations {
+    fn id(&self) -> Option<SubscriptionId> {
+        self.data
+            .first()
+            .map(|liquidation| ExchangeSub::from((&self.channel, &liquidation.market)).id())
+    }
+}
+
+impl<InstrumentId: Clone> From<(ExchangeId, InstrumentId, GateioFuturesLiquidations)>
+    for MarketIter<InstrumentId, Liquidation>
+{
+    fn from(
+        (exchange_id, instrument, liquidations): (
+            ExchangeId,
+            InstrumentId,
+            GateioFuturesLiquidations,
+        ),
+    ) -> Self {
+        liquidations
+            .data
+            .into_iter()
+            .map(|liquidation| {
+                Ok(MarketEvent {
+                    exchange_time: liquidation.time,
+                    received_time: Utc::now(),
+                    exchange: Exchange::from(exchange_id),
+                    instrument: instrument.clone(),
+                    kind: Liquidation {
+                        side: if liquidation.size.is_sign_positive() {
+                            Side::Buy
+                        } else {
+                            Side::Sell
+                        },
+                        price: liquidation.price,
+                        quantity: liquidation.size.abs(),
+                        time: liquidation.time,
+                    },
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::de::datetime_utc_from_epoch_duration;
+        use std::time::Duration;
+
+        #[test]
+        fn test_gateio_message_futures_liquidations() {
+            let input = r#"
+            {
+              "channel": "futures.public_liquidates",
+              "event": "update",
+              "time": 1541505434,
+              "time_ms": 1541505434123,
+              "result": [
+                {
+                  "price": 215.1,
+                  "size": -124,
+                  "time_ms": 1541486601123,
+                  "contract": "BTC_USD"
+                }
+              ]
+            }"#;
+
+            let actual = serde_json::from_str::<GateioFuturesLiquidations>(input).unwrap();
+            assert_eq!(
+                actual,
+                GateioFuturesLiquidations {
+                    channel: "futures.public_liquidates".to_string(),
+                    error: None,
+                    data: vec![GateioFuturesLiquidation {
+                        market: "BTC_USD".to_string(),
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1541486601123
+                        )),
+                        price: 215.1,
+                        size: -124.0,
+                    }],
+                }
+            );
+            assert_eq!(
+                actual.id(),
+                Some(SubscriptionId::from("futures.public_liquidates|BTC_USD"))
+            );
+        }
+    }
+}
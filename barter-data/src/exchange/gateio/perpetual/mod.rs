@@ -1,13 +1,27 @@
-use self::trade::GateioFuturesTrades;
+use self::{
+    liquidation::GateioFuturesLiquidations, ticker::GateioFuturesTickers,
+    trade::GateioFuturesTrades,
+};
 use super::Gateio;
 use crate::{
     exchange::{ExchangeId, ExchangeServer, StreamSelector},
     instrument::InstrumentData,
-    subscription::trade::PublicTrades,
+    subscription::{
+        funding::FundingRates,
+        liquidation::Liquidations,
+        price::{IndexPrices, MarkPrices},
+        trade::PublicTrades,
+    },
     transformer::stateless::StatelessTransformer,
     ExchangeWsStream,
 };
 
+/// Public liquidation types.
+pub mod liquidation;
+
+/// Tickers types, which contain the funding rate, mark price & index price.
+pub mod ticker;
+
 /// Public trades types.
 pub mod trade;
 
@@ -68,3 +82,75 @@ where
         StatelessTransformer<Self, Instrument::Id, PublicTrades, GateioFuturesTrades>,
     >;
 }
+
+impl<Instrument> StreamSelector<Instrument, FundingRates> for GateioPerpetualsUsd
+where
+    Instrument: InstrumentData,
+{
+    type Stream = ExchangeWsStream<
+        StatelessTransformer<Self, Instrument::Id, FundingRates, GateioFuturesTickers>,
+    >;
+}
+
+impl<Instrument> StreamSelector<Instrument, MarkPrices> for GateioPerpetualsUsd
+where
+    Instrument: InstrumentData,
+{
+    type Stream = ExchangeWsStream<
+        StatelessTransformer<Self, Instrument::Id, MarkPrices, GateioFuturesTickers>,
+    >;
+}
+
+impl<Instrument> StreamSelector<Instrument, IndexPrices> for GateioPerpetualsUsd
+where
+    Instrument: InstrumentData,
+{
+    type Stream = ExchangeWsStream<
+        StatelessTransformer<Self, Instrument::Id, IndexPrices, GateioFuturesTickers>,
+    >;
+}
+
+impl<Instrument> StreamSelector<Instrument, FundingRates> for GateioPerpetualsBtc
+where
+    Instrument: InstrumentData,
+{
+    type Stream = ExchangeWsStream<
+        StatelessTransformer<Self, Instrument::Id, FundingRates, GateioFuturesTickers>,
+    >;
+}
+
+impl<Instrument> StreamSelector<Instrument, MarkPrices> for GateioPerpetualsBtc
+where
+    Instrument: InstrumentData,
+{
+    type Stream = ExchangeWsStream<
+        StatelessTransformer<Self, Instrument::Id, MarkPrices, GateioFuturesTickers>,
+    >;
+}
+
+impl<Instrument> StreamSelector<Instrument, IndexPrices> for GateioPerpetualsBtc
+where
+    Instrument: InstrumentData,
+{
+    type Stream = ExchangeWsStream<
+        StatelessTransformer<Self, Instrument::Id, IndexPrices, GateioFuturesTickers>,
+    >;
+}
+
+impl<Instrument> StreamSelector<Instrument, Liquidations> for GateioPerpetualsUsd
+where
+    Instrument: InstrumentData,
+{
+    type Stream = ExchangeWsStream<
+        StatelessTransformer<Self, Instrument::Id, Liquidations, GateioFuturesLiquidations>,
+    >;
+}
+
+impl<Instrument> StreamSelector<Instrument, Liquidations> for GateioPerpetualsBtc
+where
+    Instrument: InstrumentData,
+{
+    type Stream = ExchangeWsStream<
+        StatelessTransformer<Self, Instrument::Id, Liquidations, GateioFuturesLiquidations>,
+    >;
+}
@@ -0,0 +1,211 @@
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::{ExchangeId, ExchangeSub},
+    subscription::{
+        funding::FundingRate,
+        price::{IndexPrice, MarkPrice},
+    },
+    Identifier,
+};
+use barter_integration::model::{Exchange, SubscriptionId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// [`GateioPerpetualsUsd`](super::GateioPerpetualsUsd) and
+/// [`GateioPerpetualsBtc`](super::GateioPerpetualsBtc) real-time tickers WebSocket message.
+///
+/// Note that the ticker data does not contain a timestamp, so the message "time_ms" is used.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.gate.io/docs/developers/futures/ws/en/#tickers-api>
+/// ```json
+/// {
+///   "time": 1541659086,
+///   "time_ms": 1541659086123,
+///   "channel": "futures.tickers",
+///   "event": "update",
+///   "result": [
+///     {
+///       "contract": "BTC_USD",
+///       "last": "118.4",
+///       "change_percentage": "0.77",
+///       "funding_rate": "-0.000114",
+///       "funding_rate_indicative": "0.01875",
+///       "mark_price": "118.35",
+///       "index_price": "118.36",
+///       "total_size": "73648",
+///       "volume_24h": "745487577",
+///       "low_24h": "99.2",
+///       "high_24h": "132.5"
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct GateioFuturesTickers {
+    pub channel: String,
+    #[serde(
+        rename = "time_ms",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    #[serde(rename = "result")]
+    pub data: Vec<GateioFuturesTicker>,
+}
+
+/// [`GateioPerpetualsUsd`](super::GateioPerpetualsUsd) and
+/// [`GateioPerpetualsBtc`](super::GateioPerpetualsBtc) real-time ticker.
+///
+/// See [`GateioFuturesTickers`] for full raw payload examples.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct GateioFuturesTicker {
+    #[serde(rename = "contract")]
+    pub market: String,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub funding_rate: f64,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub mark_price: f64,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub index_price: f64,
+}
+
+impl Identifier<Option<SubscriptionId>> for GateioFuturesTickers {
+    fn id(&self) -> Option<SubscriptionId> {
+        self.data
+            .first()
+            .map(|ticker| ExchangeSub::from((&self.channel, &ticker.market)).id())
+    }
+}
+
+impl<InstrumentId: Clone> From<(ExchangeId, InstrumentId, GateioFuturesTickers)>
+    for MarketIter<InstrumentId, FundingRate>
+{
+    fn from(
+        (exchange_id, instrument, tickers): (ExchangeId, InstrumentId, GateioFuturesTickers),
+    ) -> Self {
+        tickers
+            .data
+            .into_iter()
+            .map(|ticker| {
+                Ok(MarketEvent {
+                    exchange_time: tickers.time,
+                    received_time: Utc::now(),
+                    exchange: Exchange::from(exchange_id),
+                    instrument: instrument.clone(),
+                    kind: FundingRate {
+                        rate: ticker.funding_rate,
+                        time: tickers.time,
+                        // Gateio tickers do not contain the next funding time
+                        next_funding_time: None,
+                    },
+                })
+            })
+            .collect()
+    }
+}
+
+impl<InstrumentId: Clone> From<(ExchangeId, InstrumentId, GateioFuturesTickers)>
+    for MarketIter<InstrumentId, MarkPrice>
+{
+    fn from(
+        (exchange_id, instrument, tickers): (ExchangeId, InstrumentId, GateioFuturesTickers),
+    ) -> Self {
+        tickers
+            .data
+            .into_iter()
+            .map(|ticker| {
+                Ok(MarketEvent {
+                    exchange_time: tickers.time,
+                    received_time: Utc::now(),
+                    exchange: Exchange::from(exchange_id),
+                    instrument: instrument.clone(),
+                    kind: MarkPrice {
+                        price: ticker.mark_price,
+                        time: tickers.time,
+                    },
+                })
+            })
+            .collect()
+    }
+}
+
+impl<InstrumentId: Clone> From<(ExchangeId, InstrumentId, GateioFuturesTickers)>
+    for MarketIter<InstrumentId, IndexPrice>
+{
+    fn from(
+        (exchange_id, instrument, tickers): (ExchangeId, InstrumentId, GateioFuturesTickers),
+    ) -> Self {
+        tickers
+            .data
+            .into_iter()
+            .map(|ticker| {
+                Ok(MarketEvent {
+                    exchange_time: tickers.time,
+                    received_time: Utc::now(),
+                    exchange: Exchange::from(exchange_id),
+                    instrument: instrument.clone(),
+                    kind: IndexPrice {
+                        price: ticker.index_price,
+                        time: tickers.time,
+                    },
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::de::datetime_utc_from_epoch_duration;
+        use std::time::Duration;
+
+        #[test]
+        fn test_gateio_message_futures_tickers() {
+            let input = r#"
+            {
+              "time": 1541659086,
+              "time_ms": 1541659086123,
+              "channel": "futures.tickers",
+              "event": "update",
+              "result": [
+                {
+                  "contract": "BTC_USD",
+                  "last": "118.4",
+                  "change_percentage": "0.77",
+                  "funding_rate": "-0.000114",
+                  "funding_rate_indicative": "0.01875",
+                  "mark_price": "118.35",
+                  "index_price": "118.36",
+                  "total_size": "73648",
+                  "volume_24h": "745487577",
+                  "low_24h": "99.2",
+                  "high_24h": "132.5"
+                }
+              ]
+            }"#;
+
+            let actual = serde_json::from_str::<GateioFuturesTickers>(input).unwrap();
+            assert_eq!(
+                actual,
+                GateioFuturesTickers {
+                    channel: "futures.tickers".to_string(),
+                    time: datetime_utc_from_epoch_duration(Duration::from_millis(1541659086123)),
+                    data: vec![GateioFuturesTicker {
+                        market: "BTC_USD".to_string(),
+                        funding_rate: -0.000114,
+                        mark_price: 118.35,
+                        index_price: 118.36,
+                    }],
+                }
+            );
+            assert_eq!(
+                actual.id(),
+                Some(SubscriptionId::from("futures.tickers|BTC_USD"))
+            );
+        }
+    }
+}
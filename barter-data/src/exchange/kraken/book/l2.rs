@@ -0,0 +1,561 @@
+use super::{super::KrakenMessage, KrakenLevel};
+use crate::{
+    error::DataError,
+    exchange::subscription::ExchangeSub,
+    subscription::book::{OrderBook, OrderBookSide},
+    transformer::book::{InstrumentOrderBook, OrderBookUpdater},
+    Identifier,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    de::extract_next,
+    model::{Side, SubscriptionId},
+    protocol::websocket::WsMessage,
+};
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
+
+/// [`Kraken`](super::super::Kraken) OrderBook Level2 depth subscribed to via the
+/// [`KrakenChannel::ORDER_BOOK_L2`](super::super::channel::KrakenChannel::ORDER_BOOK_L2) channel.
+pub const KRAKEN_ORDER_BOOK_L2_DEPTH: usize = 100;
+
+/// Number of [`Kraken`](super::super::Kraken) OrderBook levels on each side used to generate
+/// the OrderBook checksum.
+///
+/// See docs: <https://docs.kraken.com/websockets/#book-checksum>
+pub const KRAKEN_ORDER_BOOK_L2_CHECKSUM_DEPTH: usize = 10;
+
+/// Terse type alias for an [`Kraken`](super::super::Kraken) real-time OrderBook Level2
+/// WebSocket message.
+pub type KrakenOrderBookL2 = KrakenMessage<KrakenOrderBookL2Inner>;
+
+/// [`Kraken`](super::super::Kraken) real-time OrderBook Level2 snapshot or update, and the
+/// associated [`SubscriptionId`].
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.kraken.com/websockets/#message-book>
+/// #### Snapshot
+/// ```json
+/// [
+///     0,
+///     {
+///         "as": [["5541.30000", "2.50700000", "1534614248.123678"]],
+///         "bs": [["5541.20000", "1.52900000", "1534614248.765567"]]
+///     },
+///     "book-100",
+///     "XBT/USD"
+/// ]
+/// ```
+///
+/// #### Update
+/// ```json
+/// [
+///     1234,
+///     {"a": [["5541.30000", "2.50700000", "1534614248.456738"]]},
+///     {"b": [["5541.30000", "0.00000000", "1534614335.345903"]], "c": "974942666"},
+///     "book-100",
+///     "XBT/USD"
+/// ]
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Serialize)]
+pub struct KrakenOrderBookL2Inner {
+    pub subscription_id: SubscriptionId,
+    pub snapshot: bool,
+    pub bids: Vec<KrakenLevel>,
+    pub asks: Vec<KrakenLevel>,
+    pub checksum: Option<u32>,
+}
+
+impl Identifier<Option<SubscriptionId>> for KrakenOrderBookL2Inner {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(self.subscription_id.clone())
+    }
+}
+
+/// [`Kraken`](super::super::Kraken) OrderBook Level2 snapshot or update data object. An update
+/// containing both asks & bids is split across two objects.
+///
+/// See [`KrakenOrderBookL2Inner`] for full raw payload examples.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Default, Deserialize)]
+struct KrakenOrderBookL2Data {
+    #[serde(rename = "as", default)]
+    snapshot_asks: Option<Vec<KrakenLevel>>,
+    #[serde(rename = "bs", default)]
+    snapshot_bids: Option<Vec<KrakenLevel>>,
+    #[serde(rename = "a", default)]
+    asks: Vec<KrakenLevel>,
+    #[serde(rename = "b", default)]
+    bids: Vec<KrakenLevel>,
+    #[serde(rename = "c", default)]
+    checksum: Option<String>,
+}
+
+/// [`KrakenOrderBookL2Inner`] sequence element following the channelID, which is either a
+/// [`KrakenOrderBookL2Data`] object or the channelName.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum KrakenOrderBookL2Element {
+    Data(KrakenOrderBookL2Data),
+    Channel(String),
+}
+
+impl<'de> Deserialize<'de> for KrakenOrderBookL2Inner {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        struct SeqVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for SeqVisitor {
+            type Value = KrakenOrderBookL2Inner;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("KrakenOrderBookL2Inner struct from the Kraken WebSocket API")
+            }
+
+            fn visit_seq<SeqAccessor>(
+                self,
+                mut seq: SeqAccessor,
+            ) -> Result<Self::Value, SeqAccessor::Error>
+            where
+                SeqAccessor: serde::de::SeqAccess<'de>,
+            {
+                // KrakenOrderBookL2Inner Sequence Format:
+                // [channelID, {as, bs} | {a} | {b, c} | {a}, {b, c}, channelName, pair]
+                // <https://docs.kraken.com/websockets/#message-book>
+
+                // Extract deprecated channelID & ignore
+                let _: serde::de::IgnoredAny = extract_next(&mut seq, "channelID")?;
+
+                // Extract OrderBook data object(s) until the channelName (eg/ "book-100")
+                let mut inner = KrakenOrderBookL2Inner {
+                    subscription_id: SubscriptionId::from(""),
+                    snapshot: false,
+                    bids: vec![],
+                    asks: vec![],
+                    checksum: None,
+                };
+                let channel = loop {
+                    match extract_next(&mut seq, "data")? {
+                        KrakenOrderBookL2Element::Channel(channel) => break channel,
+                        KrakenOrderBookL2Element::Data(data) => {
+                            if data.snapshot_asks.is_some() || data.snapshot_bids.is_some() {
+                                inner.snapshot = true;
+                                inner.asks = data.snapshot_asks.unwrap_or_default();
+                                inner.bids = data.snapshot_bids.unwrap_or_default();
+                            }
+                            inner.asks.extend(data.asks);
+                            inner.bids.extend(data.bids);
+
+                            if let Some(checksum) = data.checksum {
+                                inner.checksum =
+                                    Some(checksum.parse().map_err(serde::de::Error::custom)?);
+                            }
+                        }
+                    }
+                };
+
+                // Extract pair (eg/ "XBT/USD") & map to SubscriptionId (ie/ "book-100|{pair}")
+                inner.subscription_id = extract_next::<SeqAccessor, String>(&mut seq, "pair")
+                    .map(|market| ExchangeSub::from((channel, market)).id())?;
+
+                // Ignore any additional elements or SerDe will fail
+                //  '--> Exchange may add fields without warning
+                while seq.next_element::<serde::de::IgnoredAny>()?.is_some() {}
+
+                Ok(inner)
+            }
+        }
+
+        // Use Visitor implementation to deserialise the KrakenOrderBookL2Inner
+        deserializer.deserialize_seq(SeqVisitor)
+    }
+}
+
+/// [`Kraken`](super::super::Kraken) [`OrderBookUpdater`].
+///
+/// Kraken: How To Manage A Local OrderBook Correctly
+///
+/// 1. Subscribe to the "book" channel with the desired depth.
+/// 2. The first message received is a snapshot of the OrderBook, which initialises the local
+///    OrderBook.
+/// 3. Subsequent updates contain the absolute volume for each changed price level, and must be
+///    applied in the order they are received.
+/// 4. If the volume is 0, remove the price level.
+/// 5. Levels that fall outside the subscribed depth are not explicitly removed, so the local
+///    OrderBook must be truncated to the subscribed depth after applying each update.
+/// 6. Each update contains a CRC32 checksum of the top 10 asks & bids, which must match the
+///    checksum of the local OrderBook, otherwise re-subscribe to receive a new snapshot.
+///
+/// See docs: <https://docs.kraken.com/websockets/#book-checksum>
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct KrakenBookUpdater {
+    pub depth: usize,
+    pub updates_processed: u64,
+    pub price_precision: usize,
+    pub amount_precision: usize,
+}
+
+impl KrakenBookUpdater {
+    /// Construct a new Kraken [`OrderBookUpdater`] that maintains an OrderBook truncated to the
+    /// provided depth.
+    pub fn new(depth: usize) -> Self {
+        Self {
+            depth,
+            updates_processed: 0,
+            price_precision: 0,
+            amount_precision: 0,
+        }
+    }
+
+    /// Kraken: How To Manage A Local OrderBook Correctly: Step 6:
+    /// "Each update contains a CRC32 checksum of the top 10 asks & bids, which must match the
+    ///  checksum of the local OrderBook"
+    ///
+    /// Note that the provided [`OrderBook`] must already be sorted.
+    pub fn validate_checksum(&self, book: &OrderBook, expected: u32) -> Result<(), DataError> {
+        let actual = self.checksum(book);
+        if actual == expected {
+            Ok(())
+        } else {
+            Err(DataError::InvalidChecksum { expected, actual })
+        }
+    }
+
+    /// Generate the CRC32 checksum of the top 10 asks followed by the top 10 bids, where each
+    /// [`Level`](crate::subscription::book::Level) contributes its price & amount formatted with
+    /// the decimal point and leading zeros removed.
+    ///
+    /// See docs: <https://docs.kraken.com/websockets/#book-checksum>
+    pub fn checksum(&self, book: &OrderBook) -> u32 {
+        let input = book
+            .asks
+            .levels()
+            .iter()
+            .take(KRAKEN_ORDER_BOOK_L2_CHECKSUM_DEPTH)
+            .chain(
+                book.bids
+                    .levels()
+                    .iter()
+                    .take(KRAKEN_ORDER_BOOK_L2_CHECKSUM_DEPTH),
+            )
+            .fold(String::new(), |mut input, level| {
+                input.push_str(&checksum_value(level.price, self.price_precision));
+                input.push_str(&checksum_value(level.amount, self.amount_precision));
+                input
+            });
+
+        crc32fast::hash(input.as_bytes())
+    }
+}
+
+/// Format a [`Kraken`](super::super::Kraken) price or volume for checksum generation (eg/
+/// 0.05005 with precision 5 => "5005").
+fn checksum_value(value: f64, precision: usize) -> String {
+    format!("{value:.precision$}")
+        .replace('.', "")
+        .trim_start_matches('0')
+        .to_string()
+}
+
+#[async_trait]
+impl OrderBookUpdater for KrakenBookUpdater {
+    type OrderBook = OrderBook;
+    type Update = KrakenOrderBookL2;
+    type Output = OrderBook;
+
+    async fn init<Exchange, Kind, InstrumentId>(
+        _: mpsc::UnboundedSender<WsMessage>,
+        _: SubscriptionId,
+        instrument: InstrumentId,
+    ) -> Result<InstrumentOrderBook<InstrumentId, Self>, DataError>
+    where
+        Exchange: Send,
+        Kind: Send,
+        InstrumentId: Send,
+    {
+        // Initial OrderBook snapshot is the first message received via the WebSocket
+        Ok(InstrumentOrderBook {
+            instrument,
+            updater: Self::new(KRAKEN_ORDER_BOOK_L2_DEPTH),
+            book: OrderBook::default(),
+        })
+    }
+
+    fn update(
+        &mut self,
+        book: &mut Self::OrderBook,
+        update: Self::Update,
+    ) -> Result<Option<Self::Output>, DataError> {
+        // Kraken: How To Manage A Local OrderBook Correctly
+        // See Self's Rust Docs for more information on each numbered step
+        let update = match update {
+            KrakenOrderBookL2::Data(update) => update,
+            KrakenOrderBookL2::Event(_) => return Ok(None),
+        };
+
+        // Determine the most recent Level update time, since Kraken provides no message time
+        let last_update_time = update
+            .asks
+            .iter()
+            .chain(update.bids.iter())
+            .map(|level| level.time)
+            .max();
+
+        if update.snapshot {
+            // 2. Snapshot initialises the local OrderBook & the checksum decimal precision
+            if let Some(level) = update.asks.first().or(update.bids.first()) {
+                self.price_precision = level.price_precision;
+                self.amount_precision = level.amount_precision;
+            }
+            book.bids = OrderBookSide::new(Side::Buy, update.bids);
+            book.asks = OrderBookSide::new(Side::Sell, update.asks);
+        } else if self.updates_processed == 0 {
+            return Err(DataError::MissingSnapshot);
+        } else {
+            // 3. & 4. Upsert absolute volume of each price level, removing 0 volumes
+            book.bids.upsert(update.bids);
+            book.asks.upsert(update.asks);
+        }
+
+        // 5. Truncate (& sort) the local OrderBook to the subscribed depth
+        book.bids.truncate(self.depth);
+        book.asks.truncate(self.depth);
+
+        // 6. Validate the local OrderBook checksum
+        if let Some(expected) = update.checksum {
+            self.validate_checksum(book, expected)?;
+        }
+
+        // Update OrderBook & OrderBookUpdater metadata
+        if let Some(last_update_time) = last_update_time {
+            book.last_update_time = last_update_time;
+        }
+        self.updates_processed += 1;
+
+        Ok(Some(book.snapshot()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscription::book::Level;
+    use barter_integration::de::datetime_utc_from_epoch_duration;
+    use std::time::Duration;
+
+    fn level(price: f64, amount: f64, time: u64) -> KrakenLevel {
+        KrakenLevel {
+            price,
+            amount,
+            time: datetime_utc_from_epoch_duration(Duration::from_secs(time)),
+            price_precision: 5,
+            amount_precision: 8,
+        }
+    }
+
+    mod de {
+        use super::*;
+        use barter_integration::error::SocketError;
+        use serde::de::Error;
+
+        #[test]
+        fn test_kraken_message_order_book_l2() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<KrakenOrderBookL2, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid snapshot
+                    input: r#"
+                    [
+                        0,
+                        {
+                            "as": [["5541.30000", "2.50700000", "1534614248"]],
+                            "bs": [["5541.20000", "1.52900000", "1534614249"]]
+                        },
+                        "book-100",
+                        "XBT/USD"
+                    ]
+                    "#,
+                    expected: Ok(KrakenOrderBookL2::Data(KrakenOrderBookL2Inner {
+                        subscription_id: SubscriptionId::from("book-100|XBT/USD"),
+                        snapshot: true,
+                        bids: vec![level(5541.2, 1.529, 1534614249)],
+                        asks: vec![level(5541.3, 2.507, 1534614248)],
+                        checksum: None,
+                    })),
+                },
+                TestCase {
+                    // TC1: valid update w/ asks & bids split across two objects
+                    input: r#"
+                    [
+                        1234,
+                        {"a": [["5541.30000", "2.50700000", "1534614248", "r"]]},
+                        {"b": [["5541.20000", "0.00000000", "1534614335"]], "c": "974942666"},
+                        "book-100",
+                        "XBT/USD"
+                    ]
+                    "#,
+                    expected: Ok(KrakenOrderBookL2::Data(KrakenOrderBookL2Inner {
+                        subscription_id: SubscriptionId::from("book-100|XBT/USD"),
+                        snapshot: false,
+                        bids: vec![level(5541.2, 0.0, 1534614335)],
+                        asks: vec![level(5541.3, 2.507, 1534614248)],
+                        checksum: Some(974942666),
+                    })),
+                },
+                TestCase {
+                    // TC2: invalid update w/ non-numeric checksum
+                    input: r#"
+                    [
+                        1234,
+                        {"b": [["5541.20000", "0.00000000", "1534614335"]], "c": "invalid"},
+                        "book-100",
+                        "XBT/USD"
+                    ]
+                    "#,
+                    expected: Err(SocketError::Deserialise {
+                        error: serde_json::Error::custom(""),
+                        payload: "".to_owned(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<KrakenOrderBookL2>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+
+    mod kraken_book_updater {
+        use super::*;
+
+        #[test]
+        fn test_checksum_value() {
+            assert_eq!(checksum_value(0.05005, 5), "5005");
+            assert_eq!(checksum_value(0.000005, 8), "500");
+            assert_eq!(checksum_value(5541.3, 5), "554130000");
+        }
+
+        #[test]
+        fn test_update() {
+            struct TestCase {
+                updater: KrakenBookUpdater,
+                book: OrderBook,
+                input_update: KrakenOrderBookL2,
+                expected: Result<Option<OrderBook>, DataError>,
+            }
+
+            let update = |snapshot, bids, asks, checksum| {
+                KrakenOrderBookL2::Data(KrakenOrderBookL2Inner {
+                    subscription_id: SubscriptionId::from("book-100|XBT/USD"),
+                    snapshot,
+                    bids,
+                    asks,
+                    checksum,
+                })
+            };
+
+            let updater = KrakenBookUpdater {
+                depth: 2,
+                updates_processed: 1,
+                price_precision: 5,
+                amount_precision: 8,
+            };
+
+            let book = || OrderBook {
+                last_update_time: datetime_utc_from_epoch_duration(Duration::from_secs(1)),
+                bids: OrderBookSide::new(Side::Buy, vec![Level::new(90, 1), Level::new(80, 1)]),
+                asks: OrderBookSide::new(Side::Sell, vec![Level::new(100, 1)]),
+            };
+
+            let expected_book = OrderBook {
+                last_update_time: datetime_utc_from_epoch_duration(Duration::from_secs(2)),
+                bids: OrderBookSide::new(Side::Buy, vec![Level::new(95, 1), Level::new(90, 1)]),
+                asks: OrderBookSide::new(Side::Sell, vec![Level::new(100, 1)]),
+            };
+            let expected_checksum = updater.checksum(&expected_book);
+
+            let tests = vec![
+                TestCase {
+                    // TC0: update received before the initial snapshot is invalid
+                    updater: KrakenBookUpdater::new(100),
+                    book: OrderBook::default(),
+                    input_update: update(false, vec![level(95.0, 1.0, 2)], vec![], None),
+                    expected: Err(DataError::MissingSnapshot),
+                },
+                TestCase {
+                    // TC1: snapshot initialises the OrderBook
+                    updater: KrakenBookUpdater::new(100),
+                    book: OrderBook::default(),
+                    input_update: update(
+                        true,
+                        vec![level(80.0, 1.0, 1), level(90.0, 1.0, 1)],
+                        vec![level(100.0, 1.0, 1)],
+                        None,
+                    ),
+                    expected: Ok(Some(book())),
+                },
+                TestCase {
+                    // TC2: valid update w/ matching checksum truncates levels beyond depth
+                    updater,
+                    book: book(),
+                    input_update: update(
+                        false,
+                        vec![level(95.0, 1.0, 2)],
+                        vec![],
+                        Some(expected_checksum),
+                    ),
+                    expected: Ok(Some(expected_book)),
+                },
+                TestCase {
+                    // TC3: update w/ mismatched checksum is invalid
+                    updater,
+                    book: book(),
+                    input_update: update(
+                        false,
+                        vec![level(95.0, 1.0, 2)],
+                        vec![],
+                        Some(expected_checksum.wrapping_add(1)),
+                    ),
+                    expected: Err(DataError::InvalidChecksum {
+                        expected: expected_checksum.wrapping_add(1),
+                        actual: expected_checksum,
+                    }),
+                },
+            ];
+
+            for (index, mut test) in tests.into_iter().enumerate() {
+                let actual = test.updater.update(&mut test.book, test.input_update);
+
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+}
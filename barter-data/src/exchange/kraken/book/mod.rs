@@ -1,2 +1,136 @@
+use crate::subscription::book::Level;
+use barter_integration::de::{datetime_utc_from_epoch_duration, extract_next};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
 /// Level 1 OrderBook types (top of book).
 pub mod l1;
+
+/// Level 2 OrderBook types.
+pub mod l2;
+
+/// [`Kraken`](super::Kraken) OrderBook level.
+///
+/// The decimal precision of the raw price & volume Strings is retained since it is required to
+/// generate the [`Kraken`](super::Kraken) OrderBook checksum.
+///
+/// #### Raw Payload Examples
+/// See docs: <https://docs.kraken.com/websockets/#message-book>
+/// ```json
+/// ["5541.30000", "2.50700000", "1534614248.123678"]
+/// ```
+///
+/// #### Republished Update
+/// ```json
+/// ["5541.30000", "2.50700000", "1534614248.123678", "r"]
+/// ```
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Serialize)]
+pub struct KrakenLevel {
+    pub price: f64,
+    pub amount: f64,
+    pub time: DateTime<Utc>,
+    pub price_precision: usize,
+    pub amount_precision: usize,
+}
+
+impl From<KrakenLevel> for Level {
+    fn from(level: KrakenLevel) -> Self {
+        Self {
+            price: level.price,
+            amount: level.amount,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for KrakenLevel {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        struct SeqVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for SeqVisitor {
+            type Value = KrakenLevel;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("KrakenLevel struct from the Kraken WebSocket API")
+            }
+
+            fn visit_seq<SeqAccessor>(
+                self,
+                mut seq: SeqAccessor,
+            ) -> Result<Self::Value, SeqAccessor::Error>
+            where
+                SeqAccessor: serde::de::SeqAccess<'de>,
+            {
+                // KrakenLevel Sequence Format:
+                // [price, volume, timestamp, (updateType)]
+                // <https://docs.kraken.com/websockets/#message-book>
+
+                // Extract String price & volume, parse to f64, and retain the decimal precision
+                let mut next_f64 = |field: &'static str| {
+                    let value = extract_next::<SeqAccessor, String>(&mut seq, field)?;
+                    let precision = value
+                        .split_once('.')
+                        .map(|(_, decimals)| decimals.len())
+                        .unwrap_or_default();
+
+                    value
+                        .parse::<f64>()
+                        .map(|value| (value, precision))
+                        .map_err(serde::de::Error::custom)
+                };
+                let (price, price_precision) = next_f64("price")?;
+                let (amount, amount_precision) = next_f64("volume")?;
+
+                // Extract String timestamp, parse to f64 seconds, map to DateTime<Utc>
+                let time = extract_next::<SeqAccessor, String>(&mut seq, "timestamp")?
+                    .parse()
+                    .map(|time| {
+                        datetime_utc_from_epoch_duration(std::time::Duration::from_secs_f64(time))
+                    })
+                    .map_err(serde::de::Error::custom)?;
+
+                // Ignore optional updateType (ie/ "r" for republished updates)
+                while seq.next_element::<serde::de::IgnoredAny>()?.is_some() {}
+
+                Ok(KrakenLevel {
+                    price,
+                    amount,
+                    time,
+                    price_precision,
+                    amount_precision,
+                })
+            }
+        }
+
+        // Use Visitor implementation to deserialise the KrakenLevel
+        deserializer.deserialize_seq(SeqVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_kraken_level() {
+            let input = r#"["0.05005", "0.00000500", "1582905487.684110", "r"]"#;
+            assert_eq!(
+                serde_json::from_str::<KrakenLevel>(input).unwrap(),
+                KrakenLevel {
+                    price: 0.05005,
+                    amount: 0.000005,
+                    time: datetime_utc_from_epoch_duration(std::time::Duration::from_secs_f64(
+                        1582905487.68411
+                    )),
+                    price_precision: 5,
+                    amount_precision: 8,
+                },
+            )
+        }
+    }
+}
@@ -0,0 +1,387 @@
+use super::{message::KrakenMessage, Kraken};
+use crate::{
+    error::DataError,
+    event::MarketEvent,
+    exchange::{Connector, ExchangeSub},
+    subscription::{
+        candle::{Candle, Candles},
+        Map,
+    },
+    transformer::ExchangeTransformer,
+    Identifier,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    de::extract_next,
+    model::{Exchange, SubscriptionId},
+    protocol::websocket::WsMessage,
+    Transformer,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tokio::sync::mpsc;
+
+/// Terse type alias for an [`Kraken`] real-time OHLC WebSocket message.
+pub type KrakenCandles = KrakenMessage<KrakenCandlesInner>;
+
+/// [`Kraken`] real-time OHLC data and the associated [`SubscriptionId`]
+/// (eg/ "ohlc-5|XBT/USD").
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.kraken.com/websockets/#message-ohlc>
+/// ```json
+/// [
+///     42,
+///     [
+///         "1542057314.748456",
+///         "1542057360.435743",
+///         "3586.70000",
+///         "3586.70000",
+///         "3586.60000",
+///         "3586.60000",
+///         "3586.68894",
+///         "0.03373000",
+///         2
+///     ],
+///     "ohlc-5",
+///     "XBT/USD"
+/// ]
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Serialize)]
+pub struct KrakenCandlesInner {
+    pub subscription_id: SubscriptionId,
+    pub candle: KrakenCandle,
+}
+
+/// [`Kraken`] OHLC candle for the interval ending at the `close_time`.
+///
+/// See [`KrakenCandlesInner`] for full raw payload examples.
+///
+/// See docs: <https://docs.kraken.com/websockets/#message-ohlc>
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct KrakenCandle {
+    #[serde(deserialize_with = "barter_integration::de::de_str_f64_epoch_s_as_datetime_utc")]
+    pub time: DateTime<Utc>,
+    #[serde(deserialize_with = "barter_integration::de::de_str_f64_epoch_s_as_datetime_utc")]
+    pub close_time: DateTime<Utc>,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub open: f64,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub high: f64,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub low: f64,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub close: f64,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub vwap: f64,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub volume: f64,
+    pub trade_count: u64,
+}
+
+impl Identifier<Option<SubscriptionId>> for KrakenCandlesInner {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(self.subscription_id.clone())
+    }
+}
+
+impl From<KrakenCandle> for Candle {
+    fn from(candle: KrakenCandle) -> Self {
+        Self {
+            close_time: candle.close_time,
+            open: candle.open,
+            high: candle.high,
+            low: candle.low,
+            close: candle.close,
+            volume: candle.volume,
+            trade_count: candle.trade_count,
+        }
+    }
+}
+
+/// [`Kraken`] [`Candles`] [`ExchangeTransformer`].
+///
+/// [`Kraken`] sends an OHLC update for the current interval after every trade, without
+/// communicating when an interval has closed. This transformer caches the latest update for each
+/// [`SubscriptionId`], and yields it as a closed [`Candle`] once the first update for a later
+/// interval is received.
+///
+/// Note that if no trades occur in the next interval, the closed [`Candle`] is yielded late.
+#[derive(Clone, PartialEq, Debug)]
+pub struct KrakenCandleTransformer<InstrumentId> {
+    instrument_map: Map<InstrumentId>,
+    candles: HashMap<SubscriptionId, KrakenCandle>,
+}
+
+#[async_trait]
+impl<InstrumentId> ExchangeTransformer<Kraken, InstrumentId, Candles>
+    for KrakenCandleTransformer<InstrumentId>
+where
+    InstrumentId: Clone + Send,
+{
+    async fn new(
+        _: mpsc::UnboundedSender<WsMessage>,
+        instrument_map: Map<InstrumentId>,
+    ) -> Result<Self, DataError> {
+        Ok(Self {
+            instrument_map,
+            candles: HashMap::new(),
+        })
+    }
+}
+
+impl<InstrumentId> Transformer for KrakenCandleTransformer<InstrumentId>
+where
+    InstrumentId: Clone,
+{
+    type Error = DataError;
+    type Input = KrakenCandles;
+    type Output = MarketEvent<InstrumentId, Candle>;
+    type OutputIter = Vec<Result<Self::Output, Self::Error>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        let KrakenCandlesInner {
+            subscription_id,
+            candle,
+        } = match input {
+            KrakenCandles::Data(inner) => inner,
+            KrakenCandles::Event(_) => return vec![],
+        };
+
+        // Find Instrument associated with Input
+        let instrument = match self.instrument_map.find(&subscription_id) {
+            Ok(instrument) => instrument.clone(),
+            Err(unidentifiable) => return vec![Err(DataError::Socket(unidentifiable))],
+        };
+
+        // Cache latest update, yielding the previous interval candle if it has now closed
+        match self.candles.insert(subscription_id, candle) {
+            Some(previous) if previous.close_time < candle.close_time => {
+                vec![Ok(MarketEvent {
+                    exchange_time: previous.close_time,
+                    received_time: Utc::now(),
+                    exchange: Exchange::from(Kraken::ID),
+                    instrument,
+                    kind: Candle::from(previous),
+                })]
+            }
+            _ => vec![],
+        }
+    }
+}
+
+impl<'de> serde::de::Deserialize<'de> for KrakenCandlesInner {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct SeqVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for SeqVisitor {
+            type Value = KrakenCandlesInner;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("KrakenCandlesInner struct from the Kraken WebSocket API")
+            }
+
+            fn visit_seq<SeqAccessor>(
+                self,
+                mut seq: SeqAccessor,
+            ) -> Result<Self::Value, SeqAccessor::Error>
+            where
+                SeqAccessor: serde::de::SeqAccess<'de>,
+            {
+                // KrakenCandlesInner Sequence Format:
+                // [channelID, [time, etime, open, high, low, close, vwap, volume, count], channelName, pair]
+                // <https://docs.kraken.com/websockets/#message-ohlc>
+
+                // Extract deprecated channelID & ignore
+                let _: serde::de::IgnoredAny = extract_next(&mut seq, "channelID")?;
+
+                // Extract candle
+                let candle = extract_next(&mut seq, "candle")?;
+
+                // Extract channelName (eg/ "ohlc-5")
+                let channel = extract_next::<SeqAccessor, String>(&mut seq, "channelName")?;
+
+                // Extract pair (eg/ "XBT/USD") & map to SubscriptionId (ie/ "ohlc-5|{pair}")
+                let subscription_id = extract_next::<SeqAccessor, String>(&mut seq, "pair")
+                    .map(|market| ExchangeSub::from((channel, market)).id())?;
+
+                // Ignore any additional elements or SerDe will fail
+                //  '--> Exchange may add fields without warning
+                while seq.next_element::<serde::de::IgnoredAny>()?.is_some() {}
+
+                Ok(KrakenCandlesInner {
+                    subscription_id,
+                    candle,
+                })
+            }
+        }
+
+        // Use Visitor implementation to deserialize the KrakenCandlesInner
+        deserializer.deserialize_seq(SeqVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::kraken::message::KrakenEvent;
+    use barter_integration::de::datetime_utc_from_epoch_duration;
+    use std::time::Duration;
+
+    mod de {
+        use super::*;
+        use crate::exchange::kraken::message::KrakenError;
+        use barter_integration::error::SocketError;
+
+        #[test]
+        fn test_kraken_message_candles() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<KrakenCandles, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid KrakenCandles::Data(KrakenCandlesInner)
+                    input: r#"
+                    [
+                        42,
+                        [
+                            "1542057314.748456",
+                            "1542057360.435743",
+                            "3586.70000",
+                            "3586.70000",
+                            "3586.60000",
+                            "3586.60000",
+                            "3586.68894",
+                            "0.03373000",
+                            2
+                        ],
+                        "ohlc-5",
+                        "XBT/USD"
+                    ]
+                    "#,
+                    expected: Ok(KrakenCandles::Data(KrakenCandlesInner {
+                        subscription_id: SubscriptionId::from("ohlc-5|XBT/USD"),
+                        candle: KrakenCandle {
+                            time: datetime_utc_from_epoch_duration(Duration::from_secs_f64(
+                                1542057314.748456,
+                            )),
+                            close_time: datetime_utc_from_epoch_duration(Duration::from_secs_f64(
+                                1542057360.435743,
+                            )),
+                            open: 3586.7,
+                            high: 3586.7,
+                            low: 3586.6,
+                            close: 3586.6,
+                            vwap: 3586.68894,
+                            volume: 0.03373,
+                            trade_count: 2,
+                        },
+                    })),
+                },
+                TestCase {
+                    // TC1: valid KrakenCandles::Event(KrakenEvent::Error(KrakenError))
+                    input: r#"{"errorMessage": "Malformed request", "event": "error"}"#,
+                    expected: Ok(KrakenCandles::Event(KrakenEvent::Error(KrakenError {
+                        message: "Malformed request".to_string(),
+                    }))),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<KrakenCandles>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_kraken_candle_transformer() {
+        struct TestCase {
+            input: KrakenCandles,
+            expected_close: Option<f64>,
+        }
+
+        let update = |close_time_secs: u64, close: f64| {
+            KrakenCandles::Data(KrakenCandlesInner {
+                subscription_id: SubscriptionId::from("ohlc-1|XBT/USD"),
+                candle: KrakenCandle {
+                    time: datetime_utc_from_epoch_duration(Duration::from_secs(
+                        close_time_secs - 30,
+                    )),
+                    close_time: datetime_utc_from_epoch_duration(Duration::from_secs(
+                        close_time_secs,
+                    )),
+                    open: 1.0,
+                    high: 2.0,
+                    low: 0.5,
+                    close,
+                    vwap: 1.0,
+                    volume: 1.0,
+                    trade_count: 1,
+                },
+            })
+        };
+
+        let mut transformer = KrakenCandleTransformer {
+            instrument_map: Map(HashMap::from([(
+                SubscriptionId::from("ohlc-1|XBT/USD"),
+                "instrument",
+            )])),
+            candles: HashMap::new(),
+        };
+
+        let tests = vec![
+            TestCase {
+                // TC0: first update for an interval is cached
+                input: update(60, 1.1),
+                expected_close: None,
+            },
+            TestCase {
+                // TC1: subsequent update for the same interval replaces the cached candle
+                input: update(60, 1.2),
+                expected_close: None,
+            },
+            TestCase {
+                // TC2: first update for the next interval yields the latest closed candle
+                input: update(120, 1.3),
+                expected_close: Some(1.2),
+            },
+            TestCase {
+                // TC3: Kraken events are ignored
+                input: KrakenCandles::Event(KrakenEvent::Heartbeat),
+                expected_close: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = transformer
+                .transform(test.input)
+                .into_iter()
+                .map(|event| event.unwrap().kind.close)
+                .collect::<Vec<_>>();
+            assert_eq!(
+                actual,
+                test.expected_close.into_iter().collect::<Vec<_>>(),
+                "TC{} failed",
+                index
+            );
+        }
+    }
+}
@@ -1,6 +1,11 @@
 use super::Kraken;
 use crate::{
-    subscription::{book::OrderBooksL1, trade::PublicTrades, Subscription},
+    subscription::{
+        book::{OrderBooksL1, OrderBooksL2},
+        candle::{CandleInterval, Candles},
+        trade::PublicTrades,
+        Subscription,
+    },
     Identifier,
 };
 use serde::Serialize;
@@ -22,6 +27,43 @@ impl KrakenChannel {
     ///
     /// See docs: <https://docs.kraken.com/websockets/#message-subscribe>
     pub const ORDER_BOOK_L1: Self = Self("spread");
+
+    /// [`Kraken`] real-time OrderBook Level2 channel name, formatted as "book-{depth}" to match
+    /// the channelName of received book messages.
+    ///
+    /// See docs: <https://docs.kraken.com/websockets/#message-book>
+    pub const ORDER_BOOK_L2: Self = Self("book-100");
+
+    /// [`Kraken`] OHLC channel name for the provided [`CandleInterval`], formatted as
+    /// "ohlc-{interval_minutes}" to match the channelName of received OHLC messages.
+    ///
+    /// See docs: <https://docs.kraken.com/websockets/#message-ohlc>
+    pub const fn candles(interval: CandleInterval) -> Self {
+        match interval {
+            CandleInterval::M1 => Self("ohlc-1"),
+            CandleInterval::M5 => Self("ohlc-5"),
+            CandleInterval::M15 => Self("ohlc-15"),
+            CandleInterval::M30 => Self("ohlc-30"),
+            CandleInterval::H1 => Self("ohlc-60"),
+            CandleInterval::H4 => Self("ohlc-240"),
+            CandleInterval::D1 => Self("ohlc-1440"),
+            CandleInterval::W1 => Self("ohlc-10080"),
+        }
+    }
+
+    /// Interval in minutes of an OHLC [`KrakenChannel`] (eg/ "ohlc-5" => 5).
+    pub fn ohlc_interval(&self) -> Option<u64> {
+        self.0
+            .strip_prefix("ohlc-")
+            .and_then(|interval| interval.parse().ok())
+    }
+
+    /// Depth of an OrderBook [`KrakenChannel`] (eg/ "book-100" => 100).
+    pub fn book_depth(&self) -> Option<u64> {
+        self.0
+            .strip_prefix("book-")
+            .and_then(|depth| depth.parse().ok())
+    }
 }
 
 impl<Instrument> Identifier<KrakenChannel> for Subscription<Kraken, Instrument, PublicTrades> {
@@ -36,6 +78,18 @@ impl<Instrument> Identifier<KrakenChannel> for Subscription<Kraken, Instrument,
     }
 }
 
+impl<Instrument> Identifier<KrakenChannel> for Subscription<Kraken, Instrument, OrderBooksL2> {
+    fn id(&self) -> KrakenChannel {
+        KrakenChannel::ORDER_BOOK_L2
+    }
+}
+
+impl<Instrument> Identifier<KrakenChannel> for Subscription<Kraken, Instrument, Candles> {
+    fn id(&self) -> KrakenChannel {
+        KrakenChannel::candles(self.kind.0)
+    }
+}
+
 impl AsRef<str> for KrakenChannel {
     fn as_ref(&self) -> &str {
         self.0
@@ -1,13 +1,22 @@
 use self::{
-    book::l1::KrakenOrderBookL1, channel::KrakenChannel, market::KrakenMarket,
-    message::KrakenMessage, subscription::KrakenSubResponse, trade::KrakenTrades,
+    book::{l1::KrakenOrderBookL1, l2::KrakenBookUpdater},
+    candle::KrakenCandleTransformer,
+    channel::KrakenChannel,
+    market::KrakenMarket,
+    message::KrakenMessage,
+    subscription::KrakenSubResponse,
+    trade::KrakenTrades,
 };
 use crate::{
     exchange::{Connector, ExchangeId, ExchangeSub, StreamSelector},
     instrument::InstrumentData,
     subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
-    subscription::{book::OrderBooksL1, trade::PublicTrades},
-    transformer::stateless::StatelessTransformer,
+    subscription::{
+        book::{OrderBooksL1, OrderBooksL2},
+        candle::Candles,
+        trade::PublicTrades,
+    },
+    transformer::{book::MultiBookTransformer, stateless::StatelessTransformer},
     ExchangeWsStream,
 };
 use barter_integration::{error::SocketError, protocol::websocket::WsMessage};
@@ -18,6 +27,9 @@ use url::Url;
 /// Order book types for [`Kraken`]
 pub mod book;
 
+/// Candle types and [`Candles`] transformer for [`Kraken`].
+pub mod candle;
+
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
 pub mod channel;
@@ -65,13 +77,25 @@ impl Connector for Kraken {
         exchange_subs
             .into_iter()
             .map(|ExchangeSub { channel, market }| {
+                let subscription = match (channel.ohlc_interval(), channel.book_depth()) {
+                    (Some(interval), _) => json!({
+                        "name": "ohlc",
+                        "interval": interval
+                    }),
+                    (_, Some(depth)) => json!({
+                        "name": "book",
+                        "depth": depth
+                    }),
+                    _ => json!({
+                        "name": channel.as_ref()
+                    }),
+                };
+
                 WsMessage::Text(
                     json!({
                         "event": "subscribe",
                         "pair": [market.as_ref()],
-                        "subscription": {
-                            "name": channel.as_ref()
-                        }
+                        "subscription": subscription
                     })
                     .to_string(),
                 )
@@ -96,3 +120,19 @@ where
         StatelessTransformer<Self, Instrument::Id, OrderBooksL1, KrakenOrderBookL1>,
     >;
 }
+
+impl<Instrument> StreamSelector<Instrument, OrderBooksL2> for Kraken
+where
+    Instrument: InstrumentData,
+{
+    type Stream = ExchangeWsStream<
+        MultiBookTransformer<Self, Instrument::Id, OrderBooksL2, KrakenBookUpdater>,
+    >;
+}
+
+impl<Instrument> StreamSelector<Instrument, Candles> for Kraken
+where
+    Instrument: InstrumentData,
+{
+    type Stream = ExchangeWsStream<KrakenCandleTransformer<Instrument::Id>>;
+}
@@ -31,6 +31,9 @@ pub mod bybit;
 /// `Coinbase` [`Connector`] and [`StreamSelector`] implementations.
 pub mod coinbase;
 
+/// `Deribit` [`Connector`] and [`StreamSelector`] implementations.
+pub mod deribit;
+
 /// `GateioSpot`, `GateioFuturesUsd` & `GateioFuturesBtc` [`Connector`] and [`StreamSelector`]
 /// implementations.
 pub mod gateio;
@@ -113,6 +116,13 @@ where
     /// Base [`Url`] of the exchange server being connected with.
     fn url() -> Result<Url, SocketError>;
 
+    /// Base [`Url`] of the exchange server that serves the provided [`Self::Channel`].
+    ///
+    /// Defaults to [`Self::url`], since most exchanges serve every channel from the same server.
+    fn channel_url(_: &Self::Channel) -> Result<Url, SocketError> {
+        Self::url()
+    }
+
     /// Defines [`PingInterval`] of custom application-level
     /// [`WebSocket`](barter_integration::protocol::websocket::WebSocket) pings for the exchange
     /// server being connected with.
@@ -177,6 +187,7 @@ pub enum ExchangeId {
     BybitSpot,
     BybitPerpetualsUsd,
     Coinbase,
+    Deribit,
     GateioSpot,
     GateioFuturesUsd,
     GateioFuturesBtc,
@@ -210,6 +221,7 @@ impl ExchangeId {
             ExchangeId::BybitSpot => "bybit_spot",
             ExchangeId::BybitPerpetualsUsd => "bybit_perpetuals_usd",
             ExchangeId::Coinbase => "coinbase",
+            ExchangeId::Deribit => "deribit",
             ExchangeId::GateioSpot => "gateio_spot",
             ExchangeId::GateioFuturesUsd => "gateio_futures_usd",
             ExchangeId::GateioFuturesBtc => "gateio_futures_btc",
@@ -227,21 +239,55 @@ impl ExchangeId {
         use InstrumentKind::*;
 
         match (self, instrument_kind, sub_kind) {
-            (BinanceSpot, Spot, PublicTrades | OrderBooksL1) => true,
-            (BinanceFuturesUsd, Perpetual, PublicTrades | OrderBooksL1 | Liquidations) => true,
-            (Bitfinex, Spot, PublicTrades) => true,
-            (Bitmex, Perpetual, PublicTrades) => true,
-            (BybitSpot, Spot, PublicTrades) => true,
-            (BybitPerpetualsUsd, Perpetual, PublicTrades) => true,
-            (Coinbase, Spot, PublicTrades) => true,
+            (
+                BinanceSpot,
+                Spot,
+                PublicTrades | OrderBooksL1 | OrderBooksL2 | Candles(_) | Tickers,
+            ) => true,
+            (
+                BinanceFuturesUsd,
+                Perpetual,
+                PublicTrades | OrderBooksL1 | OrderBooksL2 | Liquidations | Candles(_)
+                | FundingRates | MarkPrices | IndexPrices | Tickers,
+            ) => true,
+            (Bitfinex, Spot, PublicTrades | OrderBooksL3) => true,
+            (Bitmex, Perpetual, PublicTrades | Liquidations) => true,
+            (BybitSpot, Spot, PublicTrades | OrderBooksL2 | Candles(_) | Tickers) => true,
+            (
+                BybitPerpetualsUsd,
+                Perpetual,
+                PublicTrades | OrderBooksL2 | Candles(_) | FundingRates | MarkPrices | IndexPrices
+                | OpenInterests | Liquidations | Tickers,
+            ) => true,
+            (Coinbase, Spot, PublicTrades | OrderBooksL2 | OrderBooksL3) => true,
+            (
+                Deribit,
+                Future(_) | Perpetual | Option(_),
+                PublicTrades | OrderBooksL1 | OrderBooksL2,
+            ) => true,
             (GateioSpot, Spot, PublicTrades) => true,
             (GateioFuturesUsd, Future(_), PublicTrades) => true,
             (GateioFuturesBtc, Future(_), PublicTrades) => true,
-            (GateioPerpetualsUsd, Perpetual, PublicTrades) => true,
-            (GateioPerpetualsBtc, Perpetual, PublicTrades) => true,
+            (
+                GateioPerpetualsUsd,
+                Perpetual,
+                PublicTrades | FundingRates | MarkPrices | IndexPrices | Liquidations,
+            ) => true,
+            (
+                GateioPerpetualsBtc,
+                Perpetual,
+                PublicTrades | FundingRates | MarkPrices | IndexPrices | Liquidations,
+            ) => true,
             (GateioOptions, Option(_), PublicTrades) => true,
-            (Kraken, Spot, PublicTrades | OrderBooksL1) => true,
-            (Okx, Spot | Future(_) | Perpetual | Option(_), PublicTrades) => true,
+            (Kraken, Spot, PublicTrades | OrderBooksL1 | OrderBooksL2 | Candles(_)) => true,
+            (
+                Okx,
+                Spot | Future(_) | Perpetual | Option(_),
+                PublicTrades | OrderBooksL2 | Candles(_) | Tickers,
+            ) => true,
+            (Okx, Perpetual, FundingRates | Liquidations) => true,
+            (Okx, Future(_) | Perpetual | Option(_), MarkPrices | OpenInterests) => true,
+            (Okx, Spot, IndexPrices) => true,
 
             (_, _, _) => false,
         }
@@ -257,26 +303,26 @@ impl ExchangeId {
         match (self, instrument_kind) {
             // Spot
             (
-                BinanceFuturesUsd | Bitmex | BybitPerpetualsUsd | GateioPerpetualsUsd
+                BinanceFuturesUsd | Bitmex | BybitPerpetualsUsd | Deribit | GateioPerpetualsUsd
                 | GateioPerpetualsBtc,
                 Spot,
             ) => false,
             (_, Spot) => true,
 
             // Future
-            (GateioFuturesUsd | GateioFuturesBtc | Okx, Future(_)) => true,
+            (Deribit | GateioFuturesUsd | GateioFuturesBtc | Okx, Future(_)) => true,
             (_, Future(_)) => false,
 
             // Future Perpetual Swaps
             (
-                BinanceFuturesUsd | Bitmex | Okx | BybitPerpetualsUsd | GateioPerpetualsUsd
-                | GateioPerpetualsBtc,
+                BinanceFuturesUsd | Bitmex | Okx | BybitPerpetualsUsd | Deribit
+                | GateioPerpetualsUsd | GateioPerpetualsBtc,
                 Perpetual,
             ) => true,
             (_, Perpetual) => false,
 
             // Option
-            (GateioOptions | Okx, Option(_)) => true,
+            (Deribit | GateioOptions | Okx, Option(_)) => true,
             (_, Option(_)) => false,
         }
     }
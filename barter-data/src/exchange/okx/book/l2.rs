@@ -0,0 +1,362 @@
+use super::OkxLevel;
+use crate::{
+    error::DataError,
+    exchange::okx::trade::de_okx_message_arg_as_subscription_id,
+    subscription::book::{OrderBook, OrderBookSide},
+    transformer::book::{InstrumentOrderBook, OrderBookUpdater},
+    Identifier,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    model::{Side, SubscriptionId},
+    protocol::websocket::WsMessage,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
+
+/// [`Okx`](super::super::Okx) real-time OrderBook Level2 WebSocket message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.okx.com/docs-v5/en/#order-book-trading-market-data-ws-order-book-channel>
+/// ```json
+/// {
+///   "arg": {
+///     "channel": "books",
+///     "instId": "BTC-USDT"
+///   },
+///   "action": "snapshot",
+///   "data": [
+///     {
+///       "asks": [["8476.98", "415", "0", "13"], ["8477", "7", "0", "2"]],
+///       "bids": [["8476", "256", "0", "12"]],
+///       "ts": "1597026383085",
+///       "checksum": -855196043,
+///       "prevSeqId": -1,
+///       "seqId": 123456
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxOrderBookL2 {
+    #[serde(
+        rename = "arg",
+        deserialize_with = "de_okx_message_arg_as_subscription_id"
+    )]
+    pub subscription_id: SubscriptionId,
+    pub action: OkxOrderBookAction,
+    pub data: Vec<OkxOrderBookL2Data>,
+}
+
+/// [`Okx`](super::super::Okx) OrderBook Level2 message action, communicating if the
+/// [`OkxOrderBookL2`] data is a full snapshot, or an incremental update.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OkxOrderBookAction {
+    Snapshot,
+    Update,
+}
+
+/// [`Okx`](super::super::Okx) OrderBook Level2 snapshot or update data contained in an
+/// [`OkxOrderBookL2`] message.
+///
+/// See [`OkxOrderBookL2`] for full raw payload examples.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxOrderBookL2Data {
+    pub bids: Vec<OkxLevel>,
+    pub asks: Vec<OkxLevel>,
+    #[serde(
+        rename = "ts",
+        deserialize_with = "barter_integration::de::de_str_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    #[serde(rename = "prevSeqId")]
+    pub prev_seq_id: i64,
+    #[serde(rename = "seqId")]
+    pub seq_id: i64,
+}
+
+impl Identifier<Option<SubscriptionId>> for OkxOrderBookL2 {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(self.subscription_id.clone())
+    }
+}
+
+/// [`Okx`](super::super::Okx) [`OrderBookUpdater`].
+///
+/// Okx: How To Manage A Local OrderBook Correctly
+///
+/// 1. Subscribe to the "books" channel.
+/// 2. The first message received has action "snapshot", which initialises the local OrderBook.
+/// 3. Subsequent messages with action "update" contain the absolute quantity for each changed
+///    price level.
+/// 4. If the quantity is 0, remove the price level.
+/// 5. Each "update" prevSeqId should be equal to the previous message seqId, otherwise
+///    re-subscribe to receive a new snapshot.
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#overview-websocket-order-book-management>
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub struct OkxBookUpdater {
+    pub updates_processed: u64,
+    pub last_seq_id: Option<i64>,
+}
+
+impl OkxBookUpdater {
+    /// Okx: How To Manage A Local OrderBook Correctly: Step 5:
+    /// "Each "update" prevSeqId should be equal to the previous message seqId"
+    pub fn validate_next_update(&self, update: &OkxOrderBookL2Data) -> Result<(), DataError> {
+        let last_seq_id = self.last_seq_id.ok_or(DataError::MissingSnapshot)?;
+
+        if update.prev_seq_id == last_seq_id {
+            Ok(())
+        } else {
+            Err(DataError::InvalidSequence {
+                prev_last_update_id: last_seq_id as u64,
+                first_update_id: update.prev_seq_id as u64,
+            })
+        }
+    }
+}
+
+#[async_trait]
+impl OrderBookUpdater for OkxBookUpdater {
+    type OrderBook = OrderBook;
+    type Update = OkxOrderBookL2;
+    type Output = OrderBook;
+
+    async fn init<Exchange, Kind, InstrumentId>(
+        _: mpsc::UnboundedSender<WsMessage>,
+        _: SubscriptionId,
+        instrument: InstrumentId,
+    ) -> Result<InstrumentOrderBook<InstrumentId, Self>, DataError>
+    where
+        Exchange: Send,
+        Kind: Send,
+        InstrumentId: Send,
+    {
+        // Initial OrderBook snapshot is the first message received via the WebSocket
+        Ok(InstrumentOrderBook {
+            instrument,
+            updater: Self::default(),
+            book: OrderBook::default(),
+        })
+    }
+
+    fn update(
+        &mut self,
+        book: &mut Self::OrderBook,
+        update: Self::Update,
+    ) -> Result<Option<Self::Output>, DataError> {
+        // Okx: How To Manage A Local OrderBook Correctly
+        // See Self's Rust Docs for more information on each numbered step
+        for data in update.data {
+            match update.action {
+                OkxOrderBookAction::Snapshot => {
+                    // 2. Snapshot initialises the local OrderBook
+                    book.bids = OrderBookSide::new(Side::Buy, data.bids);
+                    book.asks = OrderBookSide::new(Side::Sell, data.asks);
+                }
+                OkxOrderBookAction::Update => {
+                    // 5. Each "update" prevSeqId should be equal to the previous seqId
+                    self.validate_next_update(&data)?;
+
+                    // 3. & 4. Upsert absolute quantity of each price level, removing 0 quantities
+                    book.bids.upsert(data.bids);
+                    book.asks.upsert(data.asks);
+                }
+            }
+
+            // Update OrderBook & OrderBookUpdater metadata
+            book.last_update_time = data.time;
+            self.updates_processed += 1;
+            self.last_seq_id = Some(data.seq_id);
+        }
+
+        Ok(Some(book.snapshot()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::de::datetime_utc_from_epoch_duration;
+    use std::time::Duration;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_okx_order_book_l2() {
+            let input = r#"
+            {
+                "arg": {"channel": "books", "instId": "BTC-USDT"},
+                "action": "update",
+                "data": [
+                    {
+                        "asks": [["8476.98", "415", "0", "13"]],
+                        "bids": [["8476", "0", "0", "0"]],
+                        "ts": "1597026383085",
+                        "checksum": -855196043,
+                        "prevSeqId": 123455,
+                        "seqId": 123456
+                    }
+                ]
+            }
+            "#;
+
+            assert_eq!(
+                serde_json::from_str::<OkxOrderBookL2>(input).unwrap(),
+                OkxOrderBookL2 {
+                    subscription_id: SubscriptionId::from("books|BTC-USDT"),
+                    action: OkxOrderBookAction::Update,
+                    data: vec![OkxOrderBookL2Data {
+                        bids: vec![OkxLevel {
+                            price: 8476.0,
+                            amount: 0.0
+                        }],
+                        asks: vec![OkxLevel {
+                            price: 8476.98,
+                            amount: 415.0
+                        }],
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1597026383085
+                        )),
+                        prev_seq_id: 123455,
+                        seq_id: 123456,
+                    }],
+                }
+            );
+        }
+    }
+
+    mod okx_book_updater {
+        use super::*;
+        use crate::subscription::book::Level;
+
+        fn message(
+            action: OkxOrderBookAction,
+            prev_seq_id: i64,
+            seq_id: i64,
+            asks: Vec<OkxLevel>,
+        ) -> OkxOrderBookL2 {
+            OkxOrderBookL2 {
+                subscription_id: SubscriptionId::from("books|BTC-USDT"),
+                action,
+                data: vec![OkxOrderBookL2Data {
+                    bids: vec![],
+                    asks,
+                    time: datetime_utc_from_epoch_duration(Duration::from_millis(seq_id as u64)),
+                    prev_seq_id,
+                    seq_id,
+                }],
+            }
+        }
+
+        #[test]
+        fn test_update() {
+            struct TestCase {
+                updater: OkxBookUpdater,
+                book: OrderBook,
+                input_update: OkxOrderBookL2,
+                expected: Result<Option<OrderBook>, DataError>,
+            }
+
+            let level = |price, amount| OkxLevel { price, amount };
+
+            let tests = vec![
+                TestCase {
+                    // TC0: update received before the initial snapshot is invalid
+                    updater: OkxBookUpdater::default(),
+                    book: OrderBook::default(),
+                    input_update: message(OkxOrderBookAction::Update, 9, 10, vec![]),
+                    expected: Err(DataError::MissingSnapshot),
+                },
+                TestCase {
+                    // TC1: snapshot initialises the OrderBook
+                    updater: OkxBookUpdater::default(),
+                    book: OrderBook::default(),
+                    input_update: message(
+                        OkxOrderBookAction::Snapshot,
+                        -1,
+                        10,
+                        vec![level(110.0, 1.0), level(100.0, 1.0)],
+                    ),
+                    expected: Ok(Some(OrderBook {
+                        last_update_time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            10,
+                        )),
+                        bids: OrderBookSide::new(Side::Buy, Vec::<Level>::new()),
+                        asks: OrderBookSide::new(
+                            Side::Sell,
+                            vec![Level::new(100, 1), Level::new(110, 1)],
+                        ),
+                    })),
+                },
+                TestCase {
+                    // TC2: valid update removes & upserts levels
+                    updater: OkxBookUpdater {
+                        updates_processed: 1,
+                        last_seq_id: Some(10),
+                    },
+                    book: OrderBook {
+                        last_update_time: Default::default(),
+                        bids: OrderBookSide::new(Side::Buy, Vec::<Level>::new()),
+                        asks: OrderBookSide::new(
+                            Side::Sell,
+                            vec![Level::new(100, 1), Level::new(110, 1)],
+                        ),
+                    },
+                    input_update: message(
+                        OkxOrderBookAction::Update,
+                        10,
+                        15,
+                        vec![level(100.0, 0.0), level(105.0, 3.0)],
+                    ),
+                    expected: Ok(Some(OrderBook {
+                        last_update_time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            15,
+                        )),
+                        bids: OrderBookSide::new(Side::Buy, Vec::<Level>::new()),
+                        asks: OrderBookSide::new(
+                            Side::Sell,
+                            vec![Level::new(105, 3), Level::new(110, 1)],
+                        ),
+                    })),
+                },
+                TestCase {
+                    // TC3: update w/ prevSeqId != last seqId is an invalid sequence
+                    updater: OkxBookUpdater {
+                        updates_processed: 1,
+                        last_seq_id: Some(10),
+                    },
+                    book: OrderBook::default(),
+                    input_update: message(OkxOrderBookAction::Update, 12, 15, vec![]),
+                    expected: Err(DataError::InvalidSequence {
+                        prev_last_update_id: 10,
+                        first_update_id: 12,
+                    }),
+                },
+            ];
+
+            for (index, mut test) in tests.into_iter().enumerate() {
+                let actual = test.updater.update(&mut test.book, test.input_update);
+
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,95 @@
+use crate::subscription::book::Level;
+use barter_integration::de::extract_next;
+use serde::{Deserialize, Serialize};
+
+/// Level 2 OrderBook types.
+pub mod l2;
+
+/// [`Okx`](super::Okx) OrderBook level.
+///
+/// #### Raw Payload Examples
+/// See docs: <https://www.okx.com/docs-v5/en/#order-book-trading-market-data-ws-order-book-channel>
+/// ```json
+/// ["8476.98", "415", "0", "13"]
+/// ```
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Serialize)]
+pub struct OkxLevel {
+    pub price: f64,
+    pub amount: f64,
+}
+
+impl From<OkxLevel> for Level {
+    fn from(level: OkxLevel) -> Self {
+        Self {
+            price: level.price,
+            amount: level.amount,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for OkxLevel {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        struct SeqVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for SeqVisitor {
+            type Value = OkxLevel;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("OkxLevel struct from the Okx WebSocket API")
+            }
+
+            fn visit_seq<SeqAccessor>(
+                self,
+                mut seq: SeqAccessor,
+            ) -> Result<Self::Value, SeqAccessor::Error>
+            where
+                SeqAccessor: serde::de::SeqAccess<'de>,
+            {
+                // OkxLevel Sequence Format:
+                // [price, amount, deprecated, order_count]
+                // <https://www.okx.com/docs-v5/en/#order-book-trading-market-data-ws-order-book-channel>
+
+                // Extract String price & amount, and parse to f64
+                let mut next_f64 = |field: &'static str| {
+                    extract_next::<SeqAccessor, String>(&mut seq, field)?
+                        .parse::<f64>()
+                        .map_err(serde::de::Error::custom)
+                };
+                let price = next_f64("price")?;
+                let amount = next_f64("amount")?;
+
+                // Ignore deprecated liquidated orders & number of orders elements
+                while seq.next_element::<serde::de::IgnoredAny>()?.is_some() {}
+
+                Ok(OkxLevel { price, amount })
+            }
+        }
+
+        // Use Visitor implementation to deserialise the OkxLevel
+        deserializer.deserialize_seq(SeqVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_okx_level() {
+            let input = r#"["8476.98", "415", "0", "13"]"#;
+            assert_eq!(
+                serde_json::from_str::<OkxLevel>(input).unwrap(),
+                OkxLevel {
+                    price: 8476.98,
+                    amount: 415.0
+                },
+            )
+        }
+    }
+}
@@ -0,0 +1,366 @@
+use super::channel::OkxChannel;
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::{ExchangeId, ExchangeSub},
+    subscription::candle::{Candle, CandleInterval},
+    Identifier,
+};
+use barter_integration::{
+    de::{datetime_utc_from_epoch_duration, extract_next},
+    model::{Exchange, SubscriptionId},
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// [`Okx`](super::Okx) real-time candlesticks WebSocket message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-candlesticks-channel>
+/// ```json
+/// {
+///   "arg": {
+///     "channel": "candle1m",
+///     "instId": "BTC-USDT"
+///   },
+///   "data": [
+///     [
+///       "1597026383085",
+///       "8533.02",
+///       "8553.74",
+///       "8527.17",
+///       "8548.26",
+///       "45247",
+///       "529.5858061",
+///       "529.5858061",
+///       "1"
+///     ]
+///   ]
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxCandles {
+    pub arg: OkxCandleArg,
+    pub data: Vec<OkxCandle>,
+}
+
+/// [`Okx`](super::Okx) candlesticks channel & instrument contained in an [`OkxCandles`] message.
+///
+/// See [`OkxCandles`] for full raw payload examples.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct OkxCandleArg {
+    #[serde(
+        rename = "channel",
+        deserialize_with = "de_okx_candle_channel_interval"
+    )]
+    pub interval: CandleInterval,
+    #[serde(rename = "instId")]
+    pub market: String,
+}
+
+/// [`Okx`](super::Okx) candlestick.
+///
+/// See [`OkxCandles`] for full raw payload examples.
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-candlesticks-channel>
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Serialize)]
+pub struct OkxCandle {
+    pub open_time: DateTime<Utc>,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+    pub volume: f64,
+    pub closed: bool,
+}
+
+impl Identifier<Option<SubscriptionId>> for OkxCandles {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(
+            ExchangeSub::from((
+                OkxChannel::candles(self.arg.interval),
+                self.arg.market.as_str(),
+            ))
+            .id(),
+        )
+    }
+}
+
+impl<InstrumentId: Clone> From<(ExchangeId, InstrumentId, OkxCandles)>
+    for MarketIter<InstrumentId, Candle>
+{
+    fn from((exchange_id, instrument, candles): (ExchangeId, InstrumentId, OkxCandles)) -> Self {
+        // Okx candlesticks are keyed by open time, so derive the inclusive close time
+        let duration = chrono::Duration::minutes(candles.arg.interval.minutes() as i64)
+            - chrono::Duration::milliseconds(1);
+
+        // Only yield closed candles, in-progress interval updates are ignored
+        candles
+            .data
+            .into_iter()
+            .filter(|candle| candle.closed)
+            .map(|candle| {
+                let close_time = candle.open_time + duration;
+                Ok(MarketEvent {
+                    exchange_time: close_time,
+                    received_time: Utc::now(),
+                    exchange: Exchange::from(exchange_id),
+                    instrument: instrument.clone(),
+                    kind: Candle {
+                        close_time,
+                        open: candle.open,
+                        high: candle.high,
+                        low: candle.low,
+                        close: candle.close,
+                        volume: candle.volume,
+                        // Okx does not provide the number of trades in a candlestick
+                        trade_count: 0,
+                    },
+                })
+            })
+            .collect()
+    }
+}
+
+/// Deserialize an [`OkxCandleArg`] "channel" (eg/ "candle1m") as the associated
+/// [`CandleInterval`].
+fn de_okx_candle_channel_interval<'de, D>(deserializer: D) -> Result<CandleInterval, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let channel = <&str as Deserialize>::deserialize(deserializer)?;
+
+    match channel {
+        "candle1m" => Ok(CandleInterval::M1),
+        "candle5m" => Ok(CandleInterval::M5),
+        "candle15m" => Ok(CandleInterval::M15),
+        "candle30m" => Ok(CandleInterval::M30),
+        "candle1H" => Ok(CandleInterval::H1),
+        "candle4H" => Ok(CandleInterval::H4),
+        "candle1Dutc" => Ok(CandleInterval::D1),
+        "candle1Wutc" => Ok(CandleInterval::W1),
+        _ => Err(serde::de::Error::invalid_value(
+            serde::de::Unexpected::Str(channel),
+            &"supported Okx candlesticks channel",
+        )),
+    }
+}
+
+impl<'de> Deserialize<'de> for OkxCandle {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        struct SeqVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for SeqVisitor {
+            type Value = OkxCandle;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("OkxCandle struct from the Okx WebSocket API")
+            }
+
+            fn visit_seq<SeqAccessor>(
+                self,
+                mut seq: SeqAccessor,
+            ) -> Result<Self::Value, SeqAccessor::Error>
+            where
+                SeqAccessor: serde::de::SeqAccess<'de>,
+            {
+                // OkxCandle Sequence Format:
+                // [ts, o, h, l, c, vol, volCcy, volCcyQuote, confirm]
+                // <https://www.okx.com/docs-v5/en/#public-data-websocket-candlesticks-channel>
+
+                // Extract String open time, parse to u64, map to DateTime<Utc>
+                let open_time = extract_next::<SeqAccessor, String>(&mut seq, "ts")?
+                    .parse()
+                    .map(|time| {
+                        datetime_utc_from_epoch_duration(std::time::Duration::from_millis(time))
+                    })
+                    .map_err(serde::de::Error::custom)?;
+
+                // Extract String prices & volume, and parse to f64
+                let mut next_f64 = |field: &'static str| {
+                    extract_next::<SeqAccessor, String>(&mut seq, field)?
+                        .parse::<f64>()
+                        .map_err(serde::de::Error::custom)
+                };
+                let open = next_f64("o")?;
+                let high = next_f64("h")?;
+                let low = next_f64("l")?;
+                let close = next_f64("c")?;
+                let volume = next_f64("vol")?;
+
+                // Extract volCcy & volCcyQuote & ignore
+                let _: serde::de::IgnoredAny = extract_next(&mut seq, "volCcy")?;
+                let _: serde::de::IgnoredAny = extract_next(&mut seq, "volCcyQuote")?;
+
+                // Extract String confirm, where "1" communicates a closed candlestick
+                let closed = extract_next::<SeqAccessor, String>(&mut seq, "confirm")? == "1";
+
+                // Ignore any additional elements or SerDe will fail
+                //  '--> Exchange may add fields without warning
+                while seq.next_element::<serde::de::IgnoredAny>()?.is_some() {}
+
+                Ok(OkxCandle {
+                    open_time,
+                    open,
+                    high,
+                    low,
+                    close,
+                    volume,
+                    closed,
+                })
+            }
+        }
+
+        // Use Visitor implementation to deserialise the OkxCandle
+        deserializer.deserialize_seq(SeqVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::error::SocketError;
+        use serde::de::Error;
+        use std::time::Duration;
+
+        #[test]
+        fn test_okx_candles() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<OkxCandles, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid closed 1m candlestick
+                    input: r#"
+                    {
+                        "arg": {"channel": "candle1m", "instId": "BTC-USDT"},
+                        "data": [
+                            [
+                                "1597026383085", "8533.02", "8553.74", "8527.17", "8548.26",
+                                "45247", "529.5858061", "529.5858061", "1"
+                            ]
+                        ]
+                    }
+                    "#,
+                    expected: Ok(OkxCandles {
+                        arg: OkxCandleArg {
+                            interval: CandleInterval::M1,
+                            market: "BTC-USDT".to_string(),
+                        },
+                        data: vec![OkxCandle {
+                            open_time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                1597026383085,
+                            )),
+                            open: 8533.02,
+                            high: 8553.74,
+                            low: 8527.17,
+                            close: 8548.26,
+                            volume: 45247.0,
+                            closed: true,
+                        }],
+                    }),
+                },
+                TestCase {
+                    // TC1: valid in-progress 1D candlestick
+                    input: r#"
+                    {
+                        "arg": {"channel": "candle1Dutc", "instId": "BTC-USDT-SWAP"},
+                        "data": [
+                            [
+                                "1597026383085", "8533.02", "8553.74", "8527.17", "8548.26",
+                                "45247", "529.5858061", "529.5858061", "0"
+                            ]
+                        ]
+                    }
+                    "#,
+                    expected: Ok(OkxCandles {
+                        arg: OkxCandleArg {
+                            interval: CandleInterval::D1,
+                            market: "BTC-USDT-SWAP".to_string(),
+                        },
+                        data: vec![OkxCandle {
+                            open_time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                1597026383085,
+                            )),
+                            open: 8533.02,
+                            high: 8553.74,
+                            low: 8527.17,
+                            close: 8548.26,
+                            volume: 45247.0,
+                            closed: false,
+                        }],
+                    }),
+                },
+                TestCase {
+                    // TC2: unsupported Hong Kong aligned daily channel is invalid
+                    input: r#"
+                    {
+                        "arg": {"channel": "candle1D", "instId": "BTC-USDT"},
+                        "data": []
+                    }
+                    "#,
+                    expected: Err(SocketError::Deserialise {
+                        error: serde_json::Error::custom(""),
+                        payload: "".to_owned(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<OkxCandles>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_okx_candles_to_market_iter() {
+        let open_time = datetime_utc_from_epoch_duration(std::time::Duration::from_secs(60));
+        let candle = |closed| OkxCandle {
+            open_time,
+            open: 1.0,
+            high: 2.0,
+            low: 0.5,
+            close: 1.5,
+            volume: 10.0,
+            closed,
+        };
+
+        let candles = OkxCandles {
+            arg: OkxCandleArg {
+                interval: CandleInterval::M5,
+                market: "BTC-USDT".to_string(),
+            },
+            data: vec![candle(false), candle(true)],
+        };
+        assert_eq!(
+            candles.id(),
+            Some(SubscriptionId::from("candle5m|BTC-USDT"))
+        );
+
+        let actual = MarketIter::<(), Candle>::from((ExchangeId::Okx, (), candles)).0;
+        assert_eq!(actual.len(), 1);
+        assert_eq!(
+            actual[0].as_ref().unwrap().kind.close_time,
+            datetime_utc_from_epoch_duration(std::time::Duration::from_millis(359_999))
+        );
+    }
+}
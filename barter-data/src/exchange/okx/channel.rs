@@ -1,6 +1,16 @@
 use super::Okx;
 use crate::{
-    subscription::{trade::PublicTrades, Subscription},
+    subscription::{
+        book::OrderBooksL2,
+        candle::{CandleInterval, Candles},
+        funding::FundingRates,
+        liquidation::Liquidations,
+        open_interest::OpenInterests,
+        price::{IndexPrices, MarkPrices},
+        ticker::Tickers,
+        trade::PublicTrades,
+        Subscription,
+    },
     Identifier,
 };
 use serde::Serialize;
@@ -17,6 +27,77 @@ impl OkxChannel {
     ///
     /// See docs: <https://www.okx.com/docs-v5/en/#websocket-api-public-channel-trades-channel>
     pub const TRADES: Self = Self("trades");
+
+    /// [`Okx`] real-time OrderBook Level2 (depth 400) channel.
+    ///
+    /// See docs: <https://www.okx.com/docs-v5/en/#order-book-trading-market-data-ws-order-book-channel>
+    pub const ORDER_BOOK_L2: Self = Self("books");
+
+    /// [`Okx`] real-time perpetual swap funding rate channel.
+    ///
+    /// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-funding-rate-channel>
+    pub const FUNDING_RATE: Self = Self("funding-rate");
+
+    /// [`Okx`] real-time mark price channel.
+    ///
+    /// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-mark-price-channel>
+    pub const MARK_PRICE: Self = Self("mark-price");
+
+    /// [`Okx`] real-time index tickers channel.
+    ///
+    /// Note that an index is identified by its spot market (eg/ "BTC-USDT"), so
+    /// [`IndexPrices`] must be subscribed to using a spot instrument.
+    ///
+    /// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-index-tickers-channel>
+    pub const INDEX_TICKERS: Self = Self("index-tickers");
+
+    /// [`Okx`] real-time rolling 24 hour tickers channel.
+    ///
+    /// See docs: <https://www.okx.com/docs-v5/en/#order-book-trading-market-data-ws-tickers-channel>
+    pub const TICKERS: Self = Self("tickers");
+
+    /// [`Okx`] real-time derivative open interest channel.
+    ///
+    /// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-open-interest-channel>
+    pub const OPEN_INTEREST: Self = Self("open-interest");
+
+    /// [`Okx`] real-time liquidation orders channel.
+    ///
+    /// Note that this channel is subscribed to by instrument type rather than by instrument, so
+    /// it yields the liquidation orders of every [`LIQUIDATION_ORDERS_INST_TYPE`](Self::LIQUIDATION_ORDERS_INST_TYPE)
+    /// instrument.
+    ///
+    /// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-liquidation-orders-channel>
+    pub const LIQUIDATION_ORDERS: Self = Self("liquidation-orders");
+
+    /// [`Okx`] instrument type subscribed to for the
+    /// [`LIQUIDATION_ORDERS`](Self::LIQUIDATION_ORDERS) channel.
+    pub const LIQUIDATION_ORDERS_INST_TYPE: &'static str = "SWAP";
+
+    /// [`Okx`] candlesticks channel for the provided [`CandleInterval`]. Daily & weekly
+    /// candlesticks use the UTC aligned channels.
+    ///
+    /// Note that candlesticks channels are only served by the
+    /// [`BASE_URL_OKX_BUSINESS`](super::BASE_URL_OKX_BUSINESS) server.
+    ///
+    /// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-candlesticks-channel>
+    pub const fn candles(interval: CandleInterval) -> Self {
+        match interval {
+            CandleInterval::M1 => Self("candle1m"),
+            CandleInterval::M5 => Self("candle5m"),
+            CandleInterval::M15 => Self("candle15m"),
+            CandleInterval::M30 => Self("candle30m"),
+            CandleInterval::H1 => Self("candle1H"),
+            CandleInterval::H4 => Self("candle4H"),
+            CandleInterval::D1 => Self("candle1Dutc"),
+            CandleInterval::W1 => Self("candle1Wutc"),
+        }
+    }
+
+    /// Determines if this [`OkxChannel`] is a candlesticks channel.
+    pub fn is_candles(&self) -> bool {
+        self.0.starts_with("candle")
+    }
 }
 
 impl<Instrument> Identifier<OkxChannel> for Subscription<Okx, Instrument, PublicTrades> {
@@ -25,6 +106,54 @@ impl<Instrument> Identifier<OkxChannel> for Subscription<Okx, Instrument, Public
     }
 }
 
+impl<Instrument> Identifier<OkxChannel> for Subscription<Okx, Instrument, Candles> {
+    fn id(&self) -> OkxChannel {
+        OkxChannel::candles(self.kind.0)
+    }
+}
+
+impl<Instrument> Identifier<OkxChannel> for Subscription<Okx, Instrument, OrderBooksL2> {
+    fn id(&self) -> OkxChannel {
+        OkxChannel::ORDER_BOOK_L2
+    }
+}
+
+impl<Instrument> Identifier<OkxChannel> for Subscription<Okx, Instrument, FundingRates> {
+    fn id(&self) -> OkxChannel {
+        OkxChannel::FUNDING_RATE
+    }
+}
+
+impl<Instrument> Identifier<OkxChannel> for Subscription<Okx, Instrument, MarkPrices> {
+    fn id(&self) -> OkxChannel {
+        OkxChannel::MARK_PRICE
+    }
+}
+
+impl<Instrument> Identifier<OkxChannel> for Subscription<Okx, Instrument, IndexPrices> {
+    fn id(&self) -> OkxChannel {
+        OkxChannel::INDEX_TICKERS
+    }
+}
+
+impl<Instrument> Identifier<OkxChannel> for Subscription<Okx, Instrument, Tickers> {
+    fn id(&self) -> OkxChannel {
+        OkxChannel::TICKERS
+    }
+}
+
+impl<Instrument> Identifier<OkxChannel> for Subscription<Okx, Instrument, OpenInterests> {
+    fn id(&self) -> OkxChannel {
+        OkxChannel::OPEN_INTEREST
+    }
+}
+
+impl<Instrument> Identifier<OkxChannel> for Subscription<Okx, Instrument, Liquidations> {
+    fn id(&self) -> OkxChannel {
+        OkxChannel::LIQUIDATION_ORDERS
+    }
+}
+
 impl AsRef<str> for OkxChannel {
     fn as_ref(&self) -> &str {
         self.0
@@ -0,0 +1,131 @@
+use super::trade::OkxMessage;
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::ExchangeId,
+    subscription::funding::FundingRate,
+};
+use barter_integration::model::Exchange;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for an [`Okx`](super::Okx) real-time funding rate WebSocket message.
+pub type OkxFundingRates = OkxMessage<OkxFundingRate>;
+
+/// [`Okx`](super::Okx) real-time perpetual swap funding rate.
+///
+/// Note that the "fundingTime" is the settlement time of the current "fundingRate", and is
+/// therefore the next funding time.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-funding-rate-channel>
+/// ```json
+/// {
+///   "arg": {
+///     "channel": "funding-rate",
+///     "instId": "BTC-USD-SWAP"
+///   },
+///   "data": [
+///     {
+///       "fundingRate": "0.0001875391284828",
+///       "fundingTime": "1700726400000",
+///       "instId": "BTC-USD-SWAP",
+///       "instType": "SWAP",
+///       "nextFundingRate": "",
+///       "nextFundingTime": "1700755200000",
+///       "ts": "1700724675402"
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxFundingRate {
+    #[serde(
+        rename = "fundingRate",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub rate: f64,
+    #[serde(
+        rename = "fundingTime",
+        deserialize_with = "barter_integration::de::de_str_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub next_funding_time: DateTime<Utc>,
+    #[serde(
+        rename = "ts",
+        deserialize_with = "barter_integration::de::de_str_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+}
+
+impl<InstrumentId: Clone> From<(ExchangeId, InstrumentId, OkxFundingRates)>
+    for MarketIter<InstrumentId, FundingRate>
+{
+    fn from((exchange_id, instrument, rates): (ExchangeId, InstrumentId, OkxFundingRates)) -> Self {
+        rates
+            .data
+            .into_iter()
+            .map(|rate| {
+                Ok(MarketEvent {
+                    exchange_time: rate.time,
+                    received_time: Utc::now(),
+                    exchange: Exchange::from(exchange_id),
+                    instrument: instrument.clone(),
+                    kind: FundingRate {
+                        rate: rate.rate,
+                        time: rate.time,
+                        next_funding_time: Some(rate.next_funding_time),
+                    },
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::{de::datetime_utc_from_epoch_duration, model::SubscriptionId};
+        use std::time::Duration;
+
+        #[test]
+        fn test_okx_message_funding_rates() {
+            let input = r#"
+            {
+                "arg": {
+                    "channel": "funding-rate",
+                    "instId": "BTC-USD-SWAP"
+                },
+                "data": [
+                    {
+                        "fundingRate": "0.0001875391284828",
+                        "fundingTime": "1700726400000",
+                        "instId": "BTC-USD-SWAP",
+                        "instType": "SWAP",
+                        "nextFundingRate": "",
+                        "nextFundingTime": "1700755200000",
+                        "ts": "1700724675402"
+                    }
+                ]
+            }
+            "#;
+
+            assert_eq!(
+                serde_json::from_str::<OkxFundingRates>(input).unwrap(),
+                OkxFundingRates {
+                    subscription_id: SubscriptionId::from("funding-rate|BTC-USD-SWAP"),
+                    data: vec![OkxFundingRate {
+                        rate: 0.0001875391284828,
+                        next_funding_time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1700726400000
+                        )),
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1700724675402
+                        )),
+                    }],
+                }
+            );
+        }
+    }
+}
@@ -0,0 +1,238 @@
+use super::{channel::OkxChannel, Okx};
+use crate::{
+    error::DataError,
+    event::MarketEvent,
+    exchange::{Connector, ExchangeSub},
+    subscription::{
+        liquidation::{Liquidation, Liquidations},
+        Map,
+    },
+    transformer::ExchangeTransformer,
+    Identifier,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    model::{Exchange, Side},
+    protocol::websocket::WsMessage,
+    Transformer,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
+
+/// [`Okx`] real-time liquidation orders WebSocket message.
+///
+/// Note that the "liquidation-orders" channel is subscribed to by instrument type (eg/ "SWAP"),
+/// so each message identifies its instrument in the "instId" of the data rather than the "arg".
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-liquidation-orders-channel>
+/// ```json
+/// {
+///   "arg": {
+///     "channel": "liquidation-orders",
+///     "instType": "SWAP"
+///   },
+///   "data": [
+///     {
+///       "details": [
+///         {
+///           "bkLoss": "0",
+///           "bkPx": "0.007831",
+///           "ccy": "",
+///           "posSide": "short",
+///           "side": "buy",
+///           "sz": "13",
+///           "ts": "1692266434010"
+///         }
+///       ],
+///       "instFamily": "IOST-USDT",
+///       "instId": "IOST-USDT-SWAP",
+///       "instType": "SWAP",
+///       "uly": "IOST-USDT"
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxLiquidations {
+    pub data: Vec<OkxLiquidation>,
+}
+
+/// [`Okx`] liquidation orders for a single instrument.
+///
+/// See [`OkxLiquidations`] for full raw payload examples.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxLiquidation {
+    #[serde(rename = "instId")]
+    pub market: String,
+    pub details: Vec<OkxLiquidationDetail>,
+}
+
+/// [`Okx`] liquidation order.
+///
+/// See [`OkxLiquidations`] for full raw payload examples.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxLiquidationDetail {
+    pub side: Side,
+    #[serde(rename = "bkPx", deserialize_with = "barter_integration::de::de_str")]
+    pub price: f64,
+    #[serde(rename = "sz", deserialize_with = "barter_integration::de::de_str")]
+    pub quantity: f64,
+    #[serde(
+        rename = "ts",
+        deserialize_with = "barter_integration::de::de_str_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+}
+
+/// [`Okx`] [`Liquidations`] [`ExchangeTransformer`].
+///
+/// [`Okx`] publishes the liquidation orders of every instrument of the subscribed instrument type,
+/// so liquidations of instruments that were not subscribed to are skipped.
+#[derive(Clone, PartialEq, Debug)]
+pub struct OkxLiquidationTransformer<InstrumentId> {
+    instrument_map: Map<InstrumentId>,
+}
+
+#[async_trait]
+impl<InstrumentId> ExchangeTransformer<Okx, InstrumentId, Liquidations>
+    for OkxLiquidationTransformer<InstrumentId>
+where
+    InstrumentId: Clone + Send,
+{
+    async fn new(
+        _: mpsc::UnboundedSender<WsMessage>,
+        instrument_map: Map<InstrumentId>,
+    ) -> Result<Self, DataError> {
+        Ok(Self { instrument_map })
+    }
+}
+
+impl<InstrumentId> Transformer for OkxLiquidationTransformer<InstrumentId>
+where
+    InstrumentId: Clone,
+{
+    type Error = DataError;
+    type Input = OkxLiquidations;
+    type Output = MarketEvent<InstrumentId, Liquidation>;
+    type OutputIter = Vec<Result<Self::Output, Self::Error>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        input
+            .data
+            .into_iter()
+            .filter_map(|liquidation| {
+                let subscription_id =
+                    ExchangeSub::from((OkxChannel::LIQUIDATION_ORDERS, liquidation.market)).id();
+
+                self.instrument_map
+                    .find(&subscription_id)
+                    .ok()
+                    .map(|instrument| (instrument.clone(), liquidation.details))
+            })
+            .flat_map(|(instrument, details)| {
+                details.into_iter().map(move |detail| {
+                    Ok(MarketEvent {
+                        exchange_time: detail.time,
+                        received_time: Utc::now(),
+                        exchange: Exchange::from(Okx::ID),
+                        instrument: instrument.clone(),
+                        kind: Liquidation {
+                            side: detail.side,
+                            price: detail.price,
+                            quantity: detail.quantity,
+                            time: detail.time,
+                        },
+                    })
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::{de::datetime_utc_from_epoch_duration, model::SubscriptionId};
+    use std::time::Duration;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_okx_message_liquidations() {
+            let input = r#"
+            {
+                "arg": {"channel": "liquidation-orders", "instType": "SWAP"},
+                "data": [
+                    {
+                        "details": [
+                            {
+                                "bkLoss": "0",
+                                "bkPx": "0.007831",
+                                "ccy": "",
+                                "posSide": "short",
+                                "side": "buy",
+                                "sz": "13",
+                                "ts": "1692266434010"
+                            }
+                        ],
+                        "instFamily": "IOST-USDT",
+                        "instId": "IOST-USDT-SWAP",
+                        "instType": "SWAP",
+                        "uly": "IOST-USDT"
+                    }
+                ]
+            }
+            "#;
+
+            assert_eq!(
+                serde_json::from_str::<OkxLiquidations>(input).unwrap(),
+                OkxLiquidations {
+                    data: vec![OkxLiquidation {
+                        market: "IOST-USDT-SWAP".to_string(),
+                        details: vec![OkxLiquidationDetail {
+                            side: Side::Buy,
+                            price: 0.007831,
+                            quantity: 13.0,
+                            time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                1692266434010
+                            )),
+                        }],
+                    }],
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn test_okx_liquidation_transformer_skips_unsubscribed_instruments() {
+        let mut transformer = OkxLiquidationTransformer {
+            instrument_map: Map::from_iter([(
+                SubscriptionId::from("liquidation-orders|BTC-USDT-SWAP"),
+                "btc_usdt_perp",
+            )]),
+        };
+
+        let liquidation = |market: &str| OkxLiquidation {
+            market: market.to_string(),
+            details: vec![OkxLiquidationDetail {
+                side: Side::Sell,
+                price: 25000.0,
+                quantity: 2.0,
+                time: datetime_utc_from_epoch_duration(Duration::from_millis(1692266434010)),
+            }],
+        };
+
+        let actual = transformer.transform(OkxLiquidations {
+            data: vec![liquidation("ETH-USDT-SWAP"), liquidation("BTC-USDT-SWAP")],
+        });
+
+        assert_eq!(actual.len(), 1);
+        let event = actual[0].as_ref().unwrap();
+        assert_eq!(event.instrument, "btc_usdt_perp");
+        assert_eq!(event.kind.side, Side::Sell);
+        assert_eq!(event.kind.quantity, 2.0);
+    }
+}
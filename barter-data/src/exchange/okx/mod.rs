@@ -1,12 +1,32 @@
 use self::{
-    channel::OkxChannel, market::OkxMarket, subscription::OkxSubResponse, trade::OkxTrades,
+    book::l2::OkxBookUpdater,
+    candle::OkxCandles,
+    channel::OkxChannel,
+    funding::OkxFundingRates,
+    liquidation::OkxLiquidationTransformer,
+    market::OkxMarket,
+    open_interest::OkxOpenInterests,
+    price::{OkxIndexPrices, OkxMarkPrices},
+    subscription::OkxSubResponse,
+    ticker::OkxTickers,
+    trade::OkxTrades,
 };
 use crate::{
     exchange::{Connector, ExchangeId, ExchangeSub, PingInterval, StreamSelector},
     instrument::InstrumentData,
     subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
-    subscription::trade::PublicTrades,
-    transformer::stateless::StatelessTransformer,
+    subscription::{
+        book::OrderBooksL2,
+        candle::Candles,
+        funding::FundingRates,
+        liquidation::Liquidations,
+        open_interest::OpenInterests,
+        price::{IndexPrices, MarkPrices},
+        ticker::Tickers,
+        trade::PublicTrades,
+        Map,
+    },
+    transformer::{book::MultiBookTransformer, stateless::StatelessTransformer},
     ExchangeWsStream,
 };
 use barter_integration::{error::SocketError, protocol::websocket::WsMessage};
@@ -15,18 +35,39 @@ use serde_json::json;
 use std::time::Duration;
 use url::Url;
 
+/// OrderBook types for [`Okx`].
+pub mod book;
+
+/// Candle types for [`Okx`].
+pub mod candle;
+
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
 pub mod channel;
 
+/// Funding rate types for [`Okx`].
+pub mod funding;
+
+/// Liquidation types and [`Liquidations`] transformer for [`Okx`].
+pub mod liquidation;
+
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`] specific market used for generating [`Connector::requests`].
 pub mod market;
 
+/// Open interest types for [`Okx`].
+pub mod open_interest;
+
+/// Mark price & index price types for [`Okx`].
+pub mod price;
+
 /// [`Subscription`](crate::subscription::Subscription) response type and response
 /// [`Validator`](barter_integration::Validator) for [`Okx`].
 pub mod subscription;
 
+/// Rolling 24 hour ticker types for [`Okx`].
+pub mod ticker;
+
 /// Public trade types for [`Okx`].
 pub mod trade;
 
@@ -35,6 +76,11 @@ pub mod trade;
 /// See docs: <https://www.okx.com/docs-v5/en/#overview-api-resources-and-support>
 pub const BASE_URL_OKX: &str = "wss://wsaws.okx.com:8443/ws/v5/public";
 
+/// [`Okx`] business server base url, which serves the candlesticks channels.
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#overview-production-trading-services>
+pub const BASE_URL_OKX_BUSINESS: &str = "wss://wsaws.okx.com:8443/ws/v5/business";
+
 /// [`Okx`] server [`PingInterval`] duration.
 ///
 /// See docs: <https://www.okx.com/docs-v5/en/#websocket-api-connect>
@@ -60,6 +106,14 @@ impl Connector for Okx {
         Url::parse(BASE_URL_OKX).map_err(SocketError::UrlParse)
     }
 
+    fn channel_url(channel: &Self::Channel) -> Result<Url, SocketError> {
+        if channel.is_candles() {
+            Url::parse(BASE_URL_OKX_BUSINESS).map_err(SocketError::UrlParse)
+        } else {
+            Self::url()
+        }
+    }
+
     fn ping_interval() -> Option<PingInterval> {
         Some(PingInterval {
             interval: tokio::time::interval(PING_INTERVAL_OKX),
@@ -68,6 +122,12 @@ impl Connector for Okx {
     }
 
     fn requests(exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
+        // Liquidation orders are subscribed to by instrument type, so only subscribe once
+        let (liquidations, mut exchange_subs): (Vec<_>, Vec<_>) = exchange_subs
+            .into_iter()
+            .partition(|sub| sub.channel == OkxChannel::LIQUIDATION_ORDERS);
+        exchange_subs.extend(liquidations.into_iter().next());
+
         vec![WsMessage::Text(
             json!({
                 "op": "subscribe",
@@ -76,6 +136,20 @@ impl Connector for Okx {
             .to_string(),
         )]
     }
+
+    fn expected_responses<InstrumentId>(map: &Map<InstrumentId>) -> usize {
+        // Liquidation orders Subscriptions share a single instrument type subscription
+        let liquidations = map
+            .0
+            .keys()
+            .filter(|id| {
+                id.0.strip_prefix(OkxChannel::LIQUIDATION_ORDERS.0)
+                    .is_some_and(|market| market.starts_with('|'))
+            })
+            .count();
+
+        map.0.len() - liquidations + usize::from(liquidations > 0)
+    }
 }
 
 impl<Instrument> StreamSelector<Instrument, PublicTrades> for Okx
@@ -85,3 +159,65 @@ where
     type Stream =
         ExchangeWsStream<StatelessTransformer<Self, Instrument::Id, PublicTrades, OkxTrades>>;
 }
+
+impl<Instrument> StreamSelector<Instrument, Candles> for Okx
+where
+    Instrument: InstrumentData,
+{
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, Instrument::Id, Candles, OkxCandles>>;
+}
+
+impl<Instrument> StreamSelector<Instrument, OrderBooksL2> for Okx
+where
+    Instrument: InstrumentData,
+{
+    type Stream =
+        ExchangeWsStream<MultiBookTransformer<Self, Instrument::Id, OrderBooksL2, OkxBookUpdater>>;
+}
+
+impl<Instrument> StreamSelector<Instrument, FundingRates> for Okx
+where
+    Instrument: InstrumentData,
+{
+    type Stream =
+        ExchangeWsStream<StatelessTransformer<Self, Instrument::Id, FundingRates, OkxFundingRates>>;
+}
+
+impl<Instrument> StreamSelector<Instrument, MarkPrices> for Okx
+where
+    Instrument: InstrumentData,
+{
+    type Stream =
+        ExchangeWsStream<StatelessTransformer<Self, Instrument::Id, MarkPrices, OkxMarkPrices>>;
+}
+
+impl<Instrument> StreamSelector<Instrument, IndexPrices> for Okx
+where
+    Instrument: InstrumentData,
+{
+    type Stream =
+        ExchangeWsStream<StatelessTransformer<Self, Instrument::Id, IndexPrices, OkxIndexPrices>>;
+}
+
+impl<Instrument> StreamSelector<Instrument, Tickers> for Okx
+where
+    Instrument: InstrumentData,
+{
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, Instrument::Id, Tickers, OkxTickers>>;
+}
+
+impl<Instrument> StreamSelector<Instrument, OpenInterests> for Okx
+where
+    Instrument: InstrumentData,
+{
+    type Stream = ExchangeWsStream<
+        StatelessTransformer<Self, Instrument::Id, OpenInterests, OkxOpenInterests>,
+    >;
+}
+
+impl<Instrument> StreamSelector<Instrument, Liquidations> for Okx
+where
+    Instrument: InstrumentData,
+{
+    type Stream = ExchangeWsStream<OkxLiquidationTransformer<Instrument::Id>>;
+}
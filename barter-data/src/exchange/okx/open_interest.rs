@@ -0,0 +1,115 @@
+use super::trade::OkxMessage;
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::ExchangeId,
+    subscription::open_interest::OpenInterest,
+};
+use barter_integration::model::Exchange;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for an [`Okx`](super::Okx) real-time open interest WebSocket message.
+pub type OkxOpenInterests = OkxMessage<OkxOpenInterest>;
+
+/// [`Okx`](super::Okx) real-time open interest.
+///
+/// Note that "oi" is denominated in contracts, so "oiCcy" is used as the base asset quantity.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-open-interest-channel>
+/// ```json
+/// {
+///   "arg": {
+///     "channel": "open-interest",
+///     "instId": "LTC-USD-SWAP"
+///   },
+///   "data": [
+///     {
+///       "instType": "SWAP",
+///       "instId": "LTC-USD-SWAP",
+///       "oi": "5000",
+///       "oiCcy": "555.55",
+///       "ts": "1597026383085"
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxOpenInterest {
+    #[serde(rename = "oiCcy", deserialize_with = "barter_integration::de::de_str")]
+    pub quantity: f64,
+    #[serde(
+        rename = "ts",
+        deserialize_with = "barter_integration::de::de_str_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+}
+
+impl<InstrumentId: Clone> From<(ExchangeId, InstrumentId, OkxOpenInterests)>
+    for MarketIter<InstrumentId, OpenInterest>
+{
+    fn from(
+        (exchange_id, instrument, interests): (ExchangeId, InstrumentId, OkxOpenInterests),
+    ) -> Self {
+        interests
+            .data
+            .into_iter()
+            .map(|interest| {
+                Ok(MarketEvent {
+                    exchange_time: interest.time,
+                    received_time: Utc::now(),
+                    exchange: Exchange::from(exchange_id),
+                    instrument: instrument.clone(),
+                    kind: OpenInterest {
+                        quantity: interest.quantity,
+                        // Okx open interest does not contain the notional value
+                        notional: None,
+                        time: interest.time,
+                    },
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::{de::datetime_utc_from_epoch_duration, model::SubscriptionId};
+        use std::time::Duration;
+
+        #[test]
+        fn test_okx_message_open_interests() {
+            let input = r#"
+            {
+                "arg": {"channel": "open-interest", "instId": "LTC-USD-SWAP"},
+                "data": [
+                    {
+                        "instType": "SWAP",
+                        "instId": "LTC-USD-SWAP",
+                        "oi": "5000",
+                        "oiCcy": "555.55",
+                        "ts": "1597026383085"
+                    }
+                ]
+            }
+            "#;
+
+            assert_eq!(
+                serde_json::from_str::<OkxOpenInterests>(input).unwrap(),
+                OkxOpenInterests {
+                    subscription_id: SubscriptionId::from("open-interest|LTC-USD-SWAP"),
+                    data: vec![OkxOpenInterest {
+                        quantity: 555.55,
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1597026383085
+                        )),
+                    }],
+                }
+            );
+        }
+    }
+}
@@ -0,0 +1,202 @@
+use super::trade::OkxMessage;
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::ExchangeId,
+    subscription::price::{IndexPrice, MarkPrice},
+};
+use barter_integration::model::Exchange;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for an [`Okx`](super::Okx) real-time mark price WebSocket message.
+pub type OkxMarkPrices = OkxMessage<OkxMarkPrice>;
+
+/// Terse type alias for an [`Okx`](super::Okx) real-time index ticker WebSocket message.
+pub type OkxIndexPrices = OkxMessage<OkxIndexPrice>;
+
+/// [`Okx`](super::Okx) real-time mark price.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-mark-price-channel>
+/// ```json
+/// {
+///   "arg": {
+///     "channel": "mark-price",
+///     "instId": "BTC-USDT-SWAP"
+///   },
+///   "data": [
+///     {
+///       "instType": "SWAP",
+///       "instId": "BTC-USDT-SWAP",
+///       "markPx": "42310.6",
+///       "ts": "1630049139746"
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxMarkPrice {
+    #[serde(rename = "markPx", deserialize_with = "barter_integration::de::de_str")]
+    pub price: f64,
+    #[serde(
+        rename = "ts",
+        deserialize_with = "barter_integration::de::de_str_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+}
+
+/// [`Okx`](super::Okx) real-time index price.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-index-tickers-channel>
+/// ```json
+/// {
+///   "arg": {
+///     "channel": "index-tickers",
+///     "instId": "BTC-USDT"
+///   },
+///   "data": [
+///     {
+///       "instId": "BTC-USDT",
+///       "idxPx": "42310.6",
+///       "high24h": "42500.1",
+///       "low24h": "41800.2",
+///       "open24h": "42000.5",
+///       "sodUtc0": "42100.3",
+///       "sodUtc8": "42050.7",
+///       "ts": "1597026383085"
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxIndexPrice {
+    #[serde(rename = "idxPx", deserialize_with = "barter_integration::de::de_str")]
+    pub price: f64,
+    #[serde(
+        rename = "ts",
+        deserialize_with = "barter_integration::de::de_str_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+}
+
+impl<InstrumentId: Clone> From<(ExchangeId, InstrumentId, OkxMarkPrices)>
+    for MarketIter<InstrumentId, MarkPrice>
+{
+    fn from((exchange_id, instrument, prices): (ExchangeId, InstrumentId, OkxMarkPrices)) -> Self {
+        prices
+            .data
+            .into_iter()
+            .map(|mark| {
+                Ok(MarketEvent {
+                    exchange_time: mark.time,
+                    received_time: Utc::now(),
+                    exchange: Exchange::from(exchange_id),
+                    instrument: instrument.clone(),
+                    kind: MarkPrice {
+                        price: mark.price,
+                        time: mark.time,
+                    },
+                })
+            })
+            .collect()
+    }
+}
+
+impl<InstrumentId: Clone> From<(ExchangeId, InstrumentId, OkxIndexPrices)>
+    for MarketIter<InstrumentId, IndexPrice>
+{
+    fn from((exchange_id, instrument, prices): (ExchangeId, InstrumentId, OkxIndexPrices)) -> Self {
+        prices
+            .data
+            .into_iter()
+            .map(|index| {
+                Ok(MarketEvent {
+                    exchange_time: index.time,
+                    received_time: Utc::now(),
+                    exchange: Exchange::from(exchange_id),
+                    instrument: instrument.clone(),
+                    kind: IndexPrice {
+                        price: index.price,
+                        time: index.time,
+                    },
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::{de::datetime_utc_from_epoch_duration, model::SubscriptionId};
+        use std::time::Duration;
+
+        #[test]
+        fn test_okx_message_mark_prices() {
+            let input = r#"
+            {
+                "arg": {"channel": "mark-price", "instId": "BTC-USDT-SWAP"},
+                "data": [
+                    {
+                        "instType": "SWAP",
+                        "instId": "BTC-USDT-SWAP",
+                        "markPx": "42310.6",
+                        "ts": "1630049139746"
+                    }
+                ]
+            }
+            "#;
+
+            assert_eq!(
+                serde_json::from_str::<OkxMarkPrices>(input).unwrap(),
+                OkxMarkPrices {
+                    subscription_id: SubscriptionId::from("mark-price|BTC-USDT-SWAP"),
+                    data: vec![OkxMarkPrice {
+                        price: 42310.6,
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1630049139746
+                        )),
+                    }],
+                }
+            );
+        }
+
+        #[test]
+        fn test_okx_message_index_prices() {
+            let input = r#"
+            {
+                "arg": {"channel": "index-tickers", "instId": "BTC-USDT"},
+                "data": [
+                    {
+                        "instId": "BTC-USDT",
+                        "idxPx": "42310.6",
+                        "high24h": "42500.1",
+                        "low24h": "41800.2",
+                        "open24h": "42000.5",
+                        "sodUtc0": "42100.3",
+                        "sodUtc8": "42050.7",
+                        "ts": "1597026383085"
+                    }
+                ]
+            }
+            "#;
+
+            assert_eq!(
+                serde_json::from_str::<OkxIndexPrices>(input).unwrap(),
+                OkxIndexPrices {
+                    subscription_id: SubscriptionId::from("index-tickers|BTC-USDT"),
+                    data: vec![OkxIndexPrice {
+                        price: 42310.6,
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1597026383085
+                        )),
+                    }],
+                }
+            );
+        }
+    }
+}
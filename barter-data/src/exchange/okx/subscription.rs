@@ -11,7 +11,11 @@ impl Serialize for ExchangeSub<OkxChannel, OkxMarket> {
     {
         let mut state = serializer.serialize_struct("OkxSubArg", 2)?;
         state.serialize_field("channel", self.channel.as_ref())?;
-        state.serialize_field("instId", self.market.as_ref())?;
+        if self.channel == OkxChannel::LIQUIDATION_ORDERS {
+            state.serialize_field("instType", OkxChannel::LIQUIDATION_ORDERS_INST_TYPE)?;
+        } else {
+            state.serialize_field("instId", self.market.as_ref())?;
+        }
         state.end()
     }
 }
@@ -153,4 +157,33 @@ mod tests {
             assert_eq!(actual, test.is_valid, "TestCase {} failed", index);
         }
     }
+
+    #[test]
+    fn test_serialise_okx_exchange_sub() {
+        struct TestCase {
+            input: ExchangeSub<OkxChannel, OkxMarket>,
+            expected: &'static str,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: instrument channel is subscribed to by instId
+                input: ExchangeSub::from((OkxChannel::TRADES, OkxMarket("BTC-USDT".to_string()))),
+                expected: r#"{"channel":"trades","instId":"BTC-USDT"}"#,
+            },
+            TestCase {
+                // TC1: liquidation orders channel is subscribed to by instType
+                input: ExchangeSub::from((
+                    OkxChannel::LIQUIDATION_ORDERS,
+                    OkxMarket("BTC-USDT-SWAP".to_string()),
+                )),
+                expected: r#"{"channel":"liquidation-orders","instType":"SWAP"}"#,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = serde_json::to_string(&test.input).unwrap();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
 }
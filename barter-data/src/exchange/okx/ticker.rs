@@ -0,0 +1,182 @@
+use super::trade::OkxMessage;
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::ExchangeId,
+    subscription::ticker::Ticker,
+};
+use barter_integration::model::Exchange;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for an [`Okx`](super::Okx) real-time ticker WebSocket message.
+pub type OkxTickers = OkxMessage<OkxTicker>;
+
+/// [`Okx`](super::Okx) real-time rolling 24 hour ticker.
+///
+/// Note that "vol24h" is denominated in the base asset for SPOT & MARGIN instruments, but in
+/// contracts for derivatives, so "volCcy24h" is used as the base volume for derivatives.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.okx.com/docs-v5/en/#order-book-trading-market-data-ws-tickers-channel>
+/// ```json
+/// {
+///   "arg": {
+///     "channel": "tickers",
+///     "instId": "BTC-USDT"
+///   },
+///   "data": [
+///     {
+///       "instType": "SPOT",
+///       "instId": "BTC-USDT",
+///       "last": "9999.99",
+///       "lastSz": "0.1",
+///       "askPx": "9999.99",
+///       "askSz": "11",
+///       "bidPx": "8888.88",
+///       "bidSz": "5",
+///       "open24h": "9000",
+///       "high24h": "10000",
+///       "low24h": "8888.88",
+///       "volCcy24h": "2222",
+///       "vol24h": "2222",
+///       "sodUtc0": "2222",
+///       "sodUtc8": "2222",
+///       "ts": "1597026383085"
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxTicker {
+    #[serde(rename = "instType")]
+    pub instrument_type: String,
+    #[serde(rename = "last", deserialize_with = "barter_integration::de::de_str")]
+    pub last_price: f64,
+    #[serde(
+        rename = "open24h",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub open_24h: f64,
+    #[serde(
+        rename = "high24h",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub high_24h: f64,
+    #[serde(rename = "low24h", deserialize_with = "barter_integration::de::de_str")]
+    pub low_24h: f64,
+    #[serde(rename = "vol24h", deserialize_with = "barter_integration::de::de_str")]
+    pub volume_24h: f64,
+    #[serde(
+        rename = "volCcy24h",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub volume_currency_24h: f64,
+    #[serde(
+        rename = "ts",
+        deserialize_with = "barter_integration::de::de_str_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+}
+
+impl OkxTicker {
+    /// Rolling 24 hour volume denominated in the base asset.
+    pub fn base_volume_24h(&self) -> f64 {
+        match self.instrument_type.as_str() {
+            "SPOT" | "MARGIN" => self.volume_24h,
+            _ => self.volume_currency_24h,
+        }
+    }
+}
+
+impl<InstrumentId: Clone> From<(ExchangeId, InstrumentId, OkxTickers)>
+    for MarketIter<InstrumentId, Ticker>
+{
+    fn from((exchange_id, instrument, tickers): (ExchangeId, InstrumentId, OkxTickers)) -> Self {
+        tickers
+            .data
+            .into_iter()
+            .map(|ticker| {
+                let price_change_24h = ticker.last_price - ticker.open_24h;
+                let price_change_percent_24h = if ticker.open_24h == 0.0 {
+                    0.0
+                } else {
+                    price_change_24h / ticker.open_24h * 100.0
+                };
+
+                Ok(MarketEvent {
+                    exchange_time: ticker.time,
+                    received_time: Utc::now(),
+                    exchange: Exchange::from(exchange_id),
+                    instrument: instrument.clone(),
+                    kind: Ticker {
+                        last_price: ticker.last_price,
+                        high_24h: ticker.high_24h,
+                        low_24h: ticker.low_24h,
+                        volume_24h: ticker.base_volume_24h(),
+                        price_change_24h,
+                        price_change_percent_24h,
+                        time: ticker.time,
+                    },
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::{de::datetime_utc_from_epoch_duration, model::SubscriptionId};
+        use std::time::Duration;
+
+        #[test]
+        fn test_okx_message_tickers() {
+            let input = r#"
+            {
+                "arg": {"channel": "tickers", "instId": "BTC-USDT-SWAP"},
+                "data": [
+                    {
+                        "instType": "SWAP",
+                        "instId": "BTC-USDT-SWAP",
+                        "last": "42000",
+                        "lastSz": "1",
+                        "askPx": "42000.1",
+                        "askSz": "11",
+                        "bidPx": "41999.9",
+                        "bidSz": "5",
+                        "open24h": "40000",
+                        "high24h": "42500",
+                        "low24h": "39500",
+                        "volCcy24h": "1500.5",
+                        "vol24h": "150050",
+                        "sodUtc0": "41000",
+                        "sodUtc8": "40500",
+                        "ts": "1597026383085"
+                    }
+                ]
+            }
+            "#;
+
+            let actual = serde_json::from_str::<OkxTickers>(input).unwrap();
+            let expected = OkxTickers {
+                subscription_id: SubscriptionId::from("tickers|BTC-USDT-SWAP"),
+                data: vec![OkxTicker {
+                    instrument_type: "SWAP".to_string(),
+                    last_price: 42000.0,
+                    open_24h: 40000.0,
+                    high_24h: 42500.0,
+                    low_24h: 39500.0,
+                    volume_24h: 150050.0,
+                    volume_currency_24h: 1500.5,
+                    time: datetime_utc_from_epoch_duration(Duration::from_millis(1597026383085)),
+                }],
+            };
+
+            assert_eq!(actual, expected);
+            assert_eq!(actual.data[0].base_volume_24h(), 1500.5);
+        }
+    }
+}
@@ -117,7 +117,7 @@ impl<InstrumentId: Clone> From<(ExchangeId, InstrumentId, OkxTrades)>
 }
 
 /// Deserialize an [`OkxMessage`] "arg" field as a Barter [`SubscriptionId`].
-fn de_okx_message_arg_as_subscription_id<'de, D>(
+pub fn de_okx_message_arg_as_subscription_id<'de, D>(
     deserializer: D,
 ) -> Result<SubscriptionId, D::Error>
 where
@@ -1,6 +1,6 @@
 #![forbid(unsafe_code)]
 #![warn(clippy::all)]
-#![allow(clippy::pedantic, clippy::type_complexity)]
+#![allow(clippy::pedantic, clippy::type_complexity, clippy::result_large_err)]
 #![warn(
     missing_debug_implementations,
     missing_copy_implementations,
@@ -95,7 +95,10 @@ use crate::{
 };
 use async_trait::async_trait;
 use barter_integration::{
-    protocol::websocket::{WebSocketParser, WsMessage, WsSink, WsStream},
+    protocol::{
+        websocket::{WebSocketParser, WsMessage, WsSink, WsStream},
+        StreamParser,
+    },
     ExchangeStream,
 };
 use futures::{SinkExt, Stream, StreamExt};
@@ -111,6 +114,11 @@ pub mod event;
 /// [`Connector`] implementations for each exchange.
 pub mod exchange;
 
+/// [`Recorder`](recorder::Recorder) that writes [`MarketEvent`]s to rotating JSON Lines or
+/// MessagePack files on disk, and a [`RecordReader`](recorder::reader::RecordReader) to read
+/// them back.
+pub mod recorder;
+
 /// High-level API types used for building [`MarketStream`]s from collections
 /// of Barter [`Subscription`]s.
 pub mod streams;
@@ -189,7 +197,7 @@ where
             Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
     {
         // Connect & subscribe
-        let (websocket, map) = Exchange::Subscriber::subscribe(subscriptions).await?;
+        let (websocket, map, buffered) = Exchange::Subscriber::subscribe(subscriptions).await?;
 
         // Split WebSocket into WsStream & WsSink components
         let (ws_sink, ws_stream) = websocket.split();
@@ -213,8 +221,18 @@ where
 
         // Construct Transformer associated with this Exchange and SubscriptionKind
         let transformer = Transformer::new(ws_sink_tx, map).await?;
+        let mut stream = ExchangeWsStream::new(ws_stream, transformer);
+
+        // Transform market data received during subscription validation (eg/ initial snapshots)
+        for message in buffered {
+            match WebSocketParser::parse::<Transformer::Input>(Ok(message)) {
+                Some(Ok(input)) => stream.buffer.extend(stream.transformer.transform(input)),
+                Some(Err(error)) => stream.buffer.push_back(Err(DataError::from(error))),
+                None => {}
+            }
+        }
 
-        Ok(ExchangeWsStream::new(ws_stream, transformer))
+        Ok(stream)
     }
 }
 
@@ -0,0 +1,581 @@
+use crate::{
+    event::{DataKind, MarketEvent},
+    streams::Streams,
+    subscription::{
+        book::{OrderBook, OrderBookL1, OrderBookL3Update},
+        candle::Candle,
+        funding::FundingRate,
+        liquidation::Liquidation,
+        open_interest::OpenInterest,
+        price::{IndexPrice, MarkPrice},
+        ticker::Ticker,
+        trade::PublicTrade,
+    },
+};
+use chrono::NaiveDate;
+use futures::{Stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs::{File, OpenOptions},
+    io::{BufWriter, ErrorKind, Write},
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+use thiserror::Error;
+use tokio_stream::wrappers::UnboundedReceiverStream;
+use tracing::debug;
+
+/// [`RecordReader`](reader::RecordReader) for reading recorded [`MarketEvent`]s back from disk.
+pub mod reader;
+
+/// Interval at which buffered [`MarketEvent`]s are flushed to disk by [`Recorder::record`].
+pub const RECORDER_FLUSH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// All errors generated by the [`Recorder`] and [`RecordReader`](reader::RecordReader).
+#[derive(Debug, Error)]
+pub enum RecorderError {
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("MessagePack encode error: {0}")]
+    Encode(#[from] rmp_serde::encode::Error),
+
+    #[error("MessagePack decode error: {0}")]
+    Decode(#[from] rmp_serde::decode::Error),
+
+    #[error("unsupported record file: {0}")]
+    UnsupportedFile(String),
+}
+
+/// On disk encoding of recorded [`MarketEvent`]s.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum RecordFormat {
+    /// One JSON encoded [`MarketEvent`] per line.
+    #[default]
+    JsonLines,
+
+    /// Compact binary frames, each a little-endian `u32` byte length followed by a MessagePack
+    /// encoded [`MarketEvent`].
+    MessagePack,
+}
+
+impl RecordFormat {
+    /// File extension used for record files of this [`RecordFormat`].
+    pub fn extension(&self) -> &'static str {
+        match self {
+            RecordFormat::JsonLines => "jsonl",
+            RecordFormat::MessagePack => "msgpack",
+        }
+    }
+
+    /// Determine the [`RecordFormat`] of a record file from its extension.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "jsonl" => Some(RecordFormat::JsonLines),
+            "msgpack" => Some(RecordFormat::MessagePack),
+            _ => None,
+        }
+    }
+
+    /// Encode a [`MarketEvent`] into a self-delimiting record of this [`RecordFormat`].
+    pub fn encode<InstrumentId, T>(
+        &self,
+        event: &MarketEvent<InstrumentId, T>,
+    ) -> Result<Vec<u8>, RecorderError>
+    where
+        InstrumentId: Serialize,
+        T: Serialize,
+    {
+        match self {
+            RecordFormat::JsonLines => {
+                let mut record = serde_json::to_vec(event)?;
+                record.push(b'\n');
+                Ok(record)
+            }
+            RecordFormat::MessagePack => {
+                let payload = rmp_serde::to_vec(event)?;
+                let length = u32::try_from(payload.len()).map_err(|_| {
+                    std::io::Error::new(
+                        ErrorKind::InvalidData,
+                        "MarketEvent exceeds u32::MAX bytes",
+                    )
+                })?;
+
+                let mut record = Vec::with_capacity(4 + payload.len());
+                record.extend_from_slice(&length.to_le_bytes());
+                record.extend(payload);
+                Ok(record)
+            }
+        }
+    }
+}
+
+/// Normalised Barter market data event that can be recorded by a [`Recorder`].
+///
+/// Record files are partitioned by the snake case name of the
+/// [`SubKind`](crate::subscription::SubKind) that yields the event (eg/ "public_trades").
+pub trait RecordKind {
+    fn sub_kind(&self) -> &'static str;
+}
+
+impl RecordKind for PublicTrade {
+    fn sub_kind(&self) -> &'static str {
+        "public_trades"
+    }
+}
+
+impl RecordKind for OrderBookL1 {
+    fn sub_kind(&self) -> &'static str {
+        "order_books_l1"
+    }
+}
+
+impl RecordKind for OrderBook {
+    fn sub_kind(&self) -> &'static str {
+        "order_books_l2"
+    }
+}
+
+impl RecordKind for OrderBookL3Update {
+    fn sub_kind(&self) -> &'static str {
+        "order_books_l3"
+    }
+}
+
+impl RecordKind for Candle {
+    fn sub_kind(&self) -> &'static str {
+        "candles"
+    }
+}
+
+impl RecordKind for Liquidation {
+    fn sub_kind(&self) -> &'static str {
+        "liquidations"
+    }
+}
+
+impl RecordKind for FundingRate {
+    fn sub_kind(&self) -> &'static str {
+        "funding_rates"
+    }
+}
+
+impl RecordKind for MarkPrice {
+    fn sub_kind(&self) -> &'static str {
+        "mark_prices"
+    }
+}
+
+impl RecordKind for IndexPrice {
+    fn sub_kind(&self) -> &'static str {
+        "index_prices"
+    }
+}
+
+impl RecordKind for Ticker {
+    fn sub_kind(&self) -> &'static str {
+        "tickers"
+    }
+}
+
+impl RecordKind for OpenInterest {
+    fn sub_kind(&self) -> &'static str {
+        "open_interests"
+    }
+}
+
+impl RecordKind for DataKind {
+    fn sub_kind(&self) -> &'static str {
+        match self {
+            DataKind::Trade(trade) => trade.sub_kind(),
+            DataKind::OrderBookL1(book) => book.sub_kind(),
+            DataKind::OrderBook(book) => book.sub_kind(),
+            DataKind::Candle(candle) => candle.sub_kind(),
+            DataKind::Liquidation(liquidation) => liquidation.sub_kind(),
+            DataKind::FundingRate(funding) => funding.sub_kind(),
+            DataKind::MarkPrice(price) => price.sub_kind(),
+            DataKind::IndexPrice(price) => price.sub_kind(),
+            DataKind::Ticker(ticker) => ticker.sub_kind(),
+            DataKind::OpenInterest(interest) => interest.sub_kind(),
+        }
+    }
+}
+
+/// Configuration for a [`Recorder`].
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct RecorderConfig {
+    /// Root directory that record files are written beneath.
+    pub directory: PathBuf,
+    pub format: RecordFormat,
+    /// Maximum size of a record file before it is rotated. If `None`, record files are only
+    /// rotated when the date changes.
+    pub max_file_bytes: Option<u64>,
+}
+
+/// Records [`MarketEvent`]s to rotating files on disk.
+///
+/// Record files are partitioned by exchange, [`RecordKind::sub_kind`], instrument, and the UTC
+/// date of the [`MarketEvent`] `exchange_time`:
+/// `<directory>/<exchange>/<sub_kind>/<instrument>/<yyyy-mm-dd>.<sequence>.<extension>`
+///
+/// eg/ "data/binance_spot/public_trades/btc_usdt_spot/2024-01-01.0000.jsonl"
+///
+/// Existing record files are never overwritten, a new `sequence` is used instead. Every
+/// [`MarketEvent`] field, including the `exchange_time` & `received_time`, is preserved.
+///
+/// ### Notes
+/// - The instrument directory name is the `InstrumentId` [`Display`] with every character that is
+///   not ASCII alphanumeric or "-" replaced by "_".
+/// - Writes are buffered, use [`Recorder::flush`] to flush them to disk.
+#[derive(Debug)]
+pub struct Recorder {
+    config: RecorderConfig,
+    files: HashMap<RecordPartition, RecordFile>,
+}
+
+/// Unique [`Recorder`] partition (excluding the date) that a [`MarketEvent`] is recorded to.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+struct RecordPartition {
+    exchange: String,
+    sub_kind: &'static str,
+    instrument: String,
+}
+
+/// Open record file for a [`RecordPartition`].
+#[derive(Debug)]
+struct RecordFile {
+    date: NaiveDate,
+    path: PathBuf,
+    bytes: u64,
+    writer: BufWriter<File>,
+}
+
+impl Recorder {
+    /// Construct a new [`Recorder`] using the provided [`RecorderConfig`].
+    pub fn new(config: RecorderConfig) -> Self {
+        Self {
+            config,
+            files: HashMap::new(),
+        }
+    }
+
+    /// Record every [`MarketEvent`] yielded by the provided `Stream` until it ends, flushing
+    /// buffered writes every [`RECORDER_FLUSH_INTERVAL`].
+    ///
+    /// Use this with a joined [`Streams`] receiver, or with
+    /// [`DynamicStreams::select_all`](crate::streams::builder::dynamic::DynamicStreams::select_all).
+    pub async fn record<Events, InstrumentId, T>(
+        mut self,
+        events: Events,
+    ) -> Result<(), RecorderError>
+    where
+        Events: Stream<Item = MarketEvent<InstrumentId, T>>,
+        InstrumentId: Display + Serialize,
+        T: RecordKind + Serialize,
+    {
+        futures::pin_mut!(events);
+
+        let mut last_flush = Instant::now();
+        while let Some(event) = events.next().await {
+            self.write(&event)?;
+
+            if last_flush.elapsed() >= RECORDER_FLUSH_INTERVAL {
+                self.flush()?;
+                last_flush = Instant::now();
+            }
+        }
+
+        self.flush()
+    }
+
+    /// Record every [`MarketEvent`] from every exchange in the provided [`Streams`] until they
+    /// all end.
+    pub async fn record_streams<InstrumentId, T>(
+        self,
+        streams: Streams<MarketEvent<InstrumentId, T>>,
+    ) -> Result<(), RecorderError>
+    where
+        InstrumentId: Display + Serialize + Send + 'static,
+        T: RecordKind + Serialize + Send + 'static,
+    {
+        let joined = streams.join().await;
+        self.record(UnboundedReceiverStream::new(joined)).await
+    }
+
+    /// Write a [`MarketEvent`] to the record file of its partition, rotating the file if the
+    /// date has changed or the [`RecorderConfig::max_file_bytes`] would be exceeded.
+    pub fn write<InstrumentId, T>(
+        &mut self,
+        event: &MarketEvent<InstrumentId, T>,
+    ) -> Result<(), RecorderError>
+    where
+        InstrumentId: Display + Serialize,
+        T: RecordKind + Serialize,
+    {
+        let record = self.config.format.encode(event)?;
+        let record_bytes = record.len() as u64;
+
+        let partition = RecordPartition {
+            exchange: event.exchange.to_string(),
+            sub_kind: event.kind.sub_kind(),
+            instrument: sanitise_path_component(&event.instrument.to_string()),
+        };
+        let date = event.exchange_time.date_naive();
+
+        let rotate = match self.files.get(&partition) {
+            Some(file) => {
+                file.date != date
+                    || self
+                        .config
+                        .max_file_bytes
+                        .is_some_and(|max| file.bytes > 0 && file.bytes + record_bytes > max)
+            }
+            None => true,
+        };
+
+        if rotate {
+            if let Some(mut previous) = self.files.remove(&partition) {
+                previous.writer.flush()?;
+                debug!(path = %previous.path.display(), "closed rotated record file");
+            }
+
+            let file =
+                RecordFile::create(&self.config.directory, &partition, date, self.config.format)?;
+            self.files.insert(partition.clone(), file);
+        }
+
+        let file = self
+            .files
+            .get_mut(&partition)
+            .expect("RecordFile inserted for partition above");
+        file.writer.write_all(&record)?;
+        file.bytes += record_bytes;
+
+        Ok(())
+    }
+
+    /// Flush the buffered writes of every open record file to disk.
+    pub fn flush(&mut self) -> Result<(), RecorderError> {
+        self.files
+            .values_mut()
+            .try_for_each(|file| file.writer.flush())
+            .map_err(RecorderError::from)
+    }
+}
+
+impl RecordFile {
+    /// Create the next unused record file for the provided [`RecordPartition`] & date.
+    fn create(
+        directory: &Path,
+        partition: &RecordPartition,
+        date: NaiveDate,
+        format: RecordFormat,
+    ) -> Result<Self, RecorderError> {
+        let directory = directory
+            .join(&partition.exchange)
+            .join(partition.sub_kind)
+            .join(&partition.instrument);
+        std::fs::create_dir_all(&directory)?;
+
+        let mut sequence = 0_u32;
+        loop {
+            let path = directory.join(format!("{date}.{sequence:04}.{}", format.extension()));
+
+            match OpenOptions::new().write(true).create_new(true).open(&path) {
+                Ok(file) => {
+                    debug!(path = %path.display(), "opened new record file");
+                    return Ok(Self {
+                        date,
+                        path,
+                        bytes: 0,
+                        writer: BufWriter::new(file),
+                    });
+                }
+                Err(error) if error.kind() == ErrorKind::AlreadyExists => sequence += 1,
+                Err(error) => return Err(RecorderError::Io(error)),
+            }
+        }
+    }
+}
+
+/// Replace every character that is not ASCII alphanumeric or "-" with a single "_".
+///
+/// eg/ "(btc_usdt, spot)" -> "btc_usdt_spot"
+fn sanitise_path_component(name: &str) -> String {
+    name.split(|c: char| !(c.is_ascii_alphanumeric() || c == '-'))
+        .filter(|token| !token.is_empty())
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{reader::RecordReader, *};
+    use barter_integration::model::{
+        instrument::{kind::InstrumentKind, Instrument},
+        Exchange, Side,
+    };
+    use chrono::{DateTime, TimeZone, Utc};
+
+    fn test_directory(name: &str) -> PathBuf {
+        let directory = std::env::temp_dir().join(format!(
+            "barter-data-recorder-{name}-{}-{}",
+            std::process::id(),
+            Utc::now().timestamp_nanos_opt().unwrap_or_default()
+        ));
+        let _ = std::fs::remove_dir_all(&directory);
+        directory
+    }
+
+    fn trade_event(time: DateTime<Utc>, id: &str) -> MarketEvent<Instrument, DataKind> {
+        MarketEvent {
+            exchange_time: time,
+            received_time: time + chrono::Duration::milliseconds(5),
+            exchange: Exchange::from("binance_spot"),
+            instrument: Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+            kind: DataKind::Trade(PublicTrade {
+                id: id.to_string(),
+                price: 20000.0,
+                amount: 1.0,
+                side: Side::Buy,
+            }),
+        }
+    }
+
+    fn record_files(directory: &Path) -> Vec<PathBuf> {
+        let mut files = std::fs::read_dir(directory)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .collect::<Vec<_>>();
+        files.sort();
+        files
+    }
+
+    #[test]
+    fn test_sanitise_path_component() {
+        struct TestCase {
+            input: &'static str,
+            expected: &'static str,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: Instrument Display is sanitised
+                input: "(btc_usdt, spot)",
+                expected: "btc_usdt_spot",
+            },
+            TestCase {
+                // TC1: path separators & dots are removed
+                input: "../eth/usd",
+                expected: "eth_usd",
+            },
+            TestCase {
+                // TC2: valid name is unchanged
+                input: "BTC-USDT-SWAP",
+                expected: "BTC-USDT-SWAP",
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = sanitise_path_component(test.input);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_recorder_round_trip() {
+        for format in [RecordFormat::JsonLines, RecordFormat::MessagePack] {
+            let directory = test_directory(format.extension());
+            let mut recorder = Recorder::new(RecorderConfig {
+                directory: directory.clone(),
+                format,
+                max_file_bytes: None,
+            });
+
+            let time = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+            let events = vec![trade_event(time, "1"), trade_event(time, "2")];
+            for event in &events {
+                recorder.write(event).unwrap();
+            }
+            recorder.flush().unwrap();
+
+            let partition = directory
+                .join("binance_spot")
+                .join("public_trades")
+                .join("btc_usdt_spot");
+            let files = record_files(&partition);
+            assert_eq!(
+                files,
+                vec![partition.join(format!("2024-01-01.0000.{}", format.extension()))]
+            );
+
+            let actual = RecordReader::<Instrument, DataKind>::open(&files[0])
+                .unwrap()
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+            assert_eq!(actual, events, "{format:?} failed");
+
+            std::fs::remove_dir_all(directory).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_recorder_rotates_record_files() {
+        let directory = test_directory("rotation");
+        let record_bytes = RecordFormat::JsonLines
+            .encode(&trade_event(Utc::now(), "1"))
+            .unwrap()
+            .len() as u64;
+
+        let mut recorder = Recorder::new(RecorderConfig {
+            directory: directory.clone(),
+            format: RecordFormat::JsonLines,
+            max_file_bytes: Some(2 * record_bytes),
+        });
+
+        let day_one = Utc.with_ymd_and_hms(2024, 1, 1, 23, 59, 59).unwrap();
+        let day_two = Utc.with_ymd_and_hms(2024, 1, 2, 0, 0, 0).unwrap();
+        for event in [
+            trade_event(day_one, "1"),
+            trade_event(day_one, "2"),
+            trade_event(day_one, "3"),
+            trade_event(day_two, "4"),
+        ] {
+            recorder.write(&event).unwrap();
+        }
+        recorder.flush().unwrap();
+
+        let partition = directory
+            .join("binance_spot")
+            .join("public_trades")
+            .join("btc_usdt_spot");
+        assert_eq!(
+            record_files(&partition),
+            vec![
+                partition.join("2024-01-01.0000.jsonl"),
+                partition.join("2024-01-01.0001.jsonl"),
+                partition.join("2024-01-02.0000.jsonl"),
+            ]
+        );
+
+        // Existing record files are not overwritten by a new Recorder
+        let mut recorder = Recorder::new(RecorderConfig {
+            directory: directory.clone(),
+            format: RecordFormat::JsonLines,
+            max_file_bytes: None,
+        });
+        recorder.write(&trade_event(day_two, "5")).unwrap();
+        recorder.flush().unwrap();
+        assert!(partition.join("2024-01-02.0001.jsonl").exists());
+
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+}
@@ -0,0 +1,82 @@
+use super::{RecordFormat, RecorderError};
+use crate::event::MarketEvent;
+use serde::de::DeserializeOwned;
+use std::{
+    fs::File,
+    io::{BufRead, BufReader, ErrorKind, Read},
+    marker::PhantomData,
+    path::Path,
+};
+
+/// Lazily reads the [`MarketEvent`]s from a record file written by a
+/// [`Recorder`](super::Recorder), in the order they were recorded.
+///
+/// The [`RecordFormat`] is determined from the record file extension.
+#[derive(Debug)]
+pub struct RecordReader<InstrumentId, T> {
+    format: RecordFormat,
+    reader: BufReader<File>,
+    line: String,
+    phantom: PhantomData<(InstrumentId, T)>,
+}
+
+impl<InstrumentId, T> RecordReader<InstrumentId, T> {
+    /// Open the record file at the provided path.
+    pub fn open<P>(path: P) -> Result<Self, RecorderError>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        let format = RecordFormat::from_path(path)
+            .ok_or_else(|| RecorderError::UnsupportedFile(path.display().to_string()))?;
+
+        Ok(Self {
+            format,
+            reader: BufReader::new(File::open(path)?),
+            line: String::new(),
+            phantom: PhantomData,
+        })
+    }
+}
+
+impl<InstrumentId, T> Iterator for RecordReader<InstrumentId, T>
+where
+    InstrumentId: DeserializeOwned,
+    T: DeserializeOwned,
+{
+    type Item = Result<MarketEvent<InstrumentId, T>, RecorderError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.format {
+            RecordFormat::JsonLines => loop {
+                self.line.clear();
+                match self.reader.read_line(&mut self.line) {
+                    Ok(0) => return None,
+                    Ok(_) if self.line.trim().is_empty() => continue,
+                    Ok(_) => {
+                        return Some(
+                            serde_json::from_str(self.line.trim_end()).map_err(RecorderError::from),
+                        )
+                    }
+                    Err(error) => return Some(Err(RecorderError::from(error))),
+                }
+            },
+            RecordFormat::MessagePack => {
+                // A missing length prefix marks the end of the record file
+                let mut length = [0_u8; 4];
+                match self.reader.read_exact(&mut length) {
+                    Ok(()) => {}
+                    Err(error) if error.kind() == ErrorKind::UnexpectedEof => return None,
+                    Err(error) => return Some(Err(RecorderError::from(error))),
+                }
+
+                let mut payload = vec![0_u8; u32::from_le_bytes(length) as usize];
+                if let Err(error) = self.reader.read_exact(&mut payload) {
+                    return Some(Err(RecorderError::from(error)));
+                }
+
+                Some(rmp_serde::from_slice(&payload).map_err(RecorderError::from))
+            }
+        }
+    }
+}
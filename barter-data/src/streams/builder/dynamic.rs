@@ -7,6 +7,7 @@ use crate::{
         bitmex::{market::BitmexMarket, Bitmex},
         bybit::{futures::BybitPerpetualsUsd, market::BybitMarket, spot::BybitSpot},
         coinbase::{market::CoinbaseMarket, Coinbase},
+        deribit::{market::DeribitMarket, Deribit},
         gateio::{
             future::{GateioFuturesBtc, GateioFuturesUsd},
             market::GateioMarket,
@@ -21,7 +22,8 @@ use crate::{
     instrument::InstrumentData,
     streams::{builder::ExchangeChannel, consumer::consume},
     subscription::{
-        book::{OrderBook, OrderBookL1, OrderBooksL1},
+        book::{OrderBook, OrderBookL1, OrderBooksL1, OrderBooksL2},
+        candle::{Candle, Candles},
         liquidation::{Liquidation, Liquidations},
         trade::{PublicTrade, PublicTrades},
         SubKind, Subscription,
@@ -45,6 +47,7 @@ pub struct DynamicStreams<InstrumentId> {
     pub l2s: VecMap<ExchangeId, UnboundedReceiverStream<MarketEvent<InstrumentId, OrderBook>>>,
     pub liquidations:
         VecMap<ExchangeId, UnboundedReceiverStream<MarketEvent<InstrumentId, Liquidation>>>,
+    pub candles: VecMap<ExchangeId, UnboundedReceiverStream<MarketEvent<InstrumentId, Candle>>>,
 }
 
 impl<InstrumentId> DynamicStreams<InstrumentId> {
@@ -69,23 +72,44 @@ impl<InstrumentId> DynamicStreams<InstrumentId> {
         Subscription<BinanceSpot, Instrument, PublicTrades>: Identifier<BinanceMarket>,
         Subscription<BinanceSpot, Instrument, PublicTrades>: Identifier<BinanceMarket>,
         Subscription<BinanceSpot, Instrument, OrderBooksL1>: Identifier<BinanceMarket>,
+        Subscription<BinanceSpot, Instrument, OrderBooksL2>: Identifier<BinanceMarket>,
         Subscription<BinanceFuturesUsd, Instrument, PublicTrades>: Identifier<BinanceMarket>,
         Subscription<BinanceFuturesUsd, Instrument, OrderBooksL1>: Identifier<BinanceMarket>,
+        Subscription<BinanceFuturesUsd, Instrument, OrderBooksL2>: Identifier<BinanceMarket>,
         Subscription<BinanceFuturesUsd, Instrument, Liquidations>: Identifier<BinanceMarket>,
+        Subscription<BinanceSpot, Instrument, Candles>: Identifier<BinanceMarket>,
+        Subscription<BinanceFuturesUsd, Instrument, Candles>: Identifier<BinanceMarket>,
         Subscription<Bitfinex, Instrument, PublicTrades>: Identifier<BitfinexMarket>,
         Subscription<Bitmex, Instrument, PublicTrades>: Identifier<BitmexMarket>,
+        Subscription<Bitmex, Instrument, Liquidations>: Identifier<BitmexMarket>,
         Subscription<BybitSpot, Instrument, PublicTrades>: Identifier<BybitMarket>,
         Subscription<BybitPerpetualsUsd, Instrument, PublicTrades>: Identifier<BybitMarket>,
+        Subscription<BybitSpot, Instrument, OrderBooksL2>: Identifier<BybitMarket>,
+        Subscription<BybitPerpetualsUsd, Instrument, OrderBooksL2>: Identifier<BybitMarket>,
+        Subscription<BybitSpot, Instrument, Candles>: Identifier<BybitMarket>,
+        Subscription<BybitPerpetualsUsd, Instrument, Candles>: Identifier<BybitMarket>,
+        Subscription<BybitPerpetualsUsd, Instrument, Liquidations>: Identifier<BybitMarket>,
         Subscription<Coinbase, Instrument, PublicTrades>: Identifier<CoinbaseMarket>,
+        Subscription<Coinbase, Instrument, OrderBooksL2>: Identifier<CoinbaseMarket>,
+        Subscription<Deribit, Instrument, PublicTrades>: Identifier<DeribitMarket>,
+        Subscription<Deribit, Instrument, OrderBooksL1>: Identifier<DeribitMarket>,
+        Subscription<Deribit, Instrument, OrderBooksL2>: Identifier<DeribitMarket>,
         Subscription<GateioSpot, Instrument, PublicTrades>: Identifier<GateioMarket>,
         Subscription<GateioFuturesUsd, Instrument, PublicTrades>: Identifier<GateioMarket>,
         Subscription<GateioFuturesBtc, Instrument, PublicTrades>: Identifier<GateioMarket>,
         Subscription<GateioPerpetualsUsd, Instrument, PublicTrades>: Identifier<GateioMarket>,
         Subscription<GateioPerpetualsBtc, Instrument, PublicTrades>: Identifier<GateioMarket>,
+        Subscription<GateioPerpetualsUsd, Instrument, Liquidations>: Identifier<GateioMarket>,
+        Subscription<GateioPerpetualsBtc, Instrument, Liquidations>: Identifier<GateioMarket>,
         Subscription<GateioOptions, Instrument, PublicTrades>: Identifier<GateioMarket>,
         Subscription<Kraken, Instrument, PublicTrades>: Identifier<KrakenMarket>,
         Subscription<Kraken, Instrument, OrderBooksL1>: Identifier<KrakenMarket>,
+        Subscription<Kraken, Instrument, OrderBooksL2>: Identifier<KrakenMarket>,
+        Subscription<Kraken, Instrument, Candles>: Identifier<KrakenMarket>,
         Subscription<Okx, Instrument, PublicTrades>: Identifier<OkxMarket>,
+        Subscription<Okx, Instrument, OrderBooksL2>: Identifier<OkxMarket>,
+        Subscription<Okx, Instrument, Candles>: Identifier<OkxMarket>,
+        Subscription<Okx, Instrument, Liquidations>: Identifier<OkxMarket>,
     {
         // Validate & dedup Subscription batches
         let batches = validate_batches(subscription_batches)?;
@@ -127,6 +151,34 @@ impl<InstrumentId> DynamicStreams<InstrumentId> {
                             channels.l1s.entry(exchange).or_default().tx.clone(),
                         ));
                     }
+                    (ExchangeId::BinanceSpot, SubKind::OrderBooksL2) => {
+                        tokio::spawn(consume::<BinanceSpot, Instrument, OrderBooksL2>(
+                            subs.into_iter()
+                                .map(|sub| {
+                                    Subscription::new(
+                                        BinanceSpot::default(),
+                                        sub.instrument,
+                                        OrderBooksL2,
+                                    )
+                                })
+                                .collect(),
+                            channels.l2s.entry(exchange).or_default().tx.clone(),
+                        ));
+                    }
+                    (ExchangeId::BinanceSpot, SubKind::Candles(interval)) => {
+                        tokio::spawn(consume::<BinanceSpot, Instrument, Candles>(
+                            subs.into_iter()
+                                .map(|sub| {
+                                    Subscription::new(
+                                        BinanceSpot::default(),
+                                        sub.instrument,
+                                        Candles(interval),
+                                    )
+                                })
+                                .collect(),
+                            channels.candles.entry(exchange).or_default().tx.clone(),
+                        ));
+                    }
                     (ExchangeId::BinanceFuturesUsd, SubKind::PublicTrades) => {
                         tokio::spawn(consume::<BinanceFuturesUsd, Instrument, PublicTrades>(
                             subs.into_iter()
@@ -155,6 +207,20 @@ impl<InstrumentId> DynamicStreams<InstrumentId> {
                             channels.l1s.entry(exchange).or_default().tx.clone(),
                         ));
                     }
+                    (ExchangeId::BinanceFuturesUsd, SubKind::OrderBooksL2) => {
+                        tokio::spawn(consume::<BinanceFuturesUsd, Instrument, OrderBooksL2>(
+                            subs.into_iter()
+                                .map(|sub| {
+                                    Subscription::new(
+                                        BinanceFuturesUsd::default(),
+                                        sub.instrument,
+                                        OrderBooksL2,
+                                    )
+                                })
+                                .collect(),
+                            channels.l2s.entry(exchange).or_default().tx.clone(),
+                        ));
+                    }
                     (ExchangeId::BinanceFuturesUsd, SubKind::Liquidations) => {
                         tokio::spawn(consume::<BinanceFuturesUsd, Instrument, Liquidations>(
                             subs.into_iter()
@@ -174,6 +240,20 @@ impl<InstrumentId> DynamicStreams<InstrumentId> {
                                 .clone(),
                         ));
                     }
+                    (ExchangeId::BinanceFuturesUsd, SubKind::Candles(interval)) => {
+                        tokio::spawn(consume::<BinanceFuturesUsd, Instrument, Candles>(
+                            subs.into_iter()
+                                .map(|sub| {
+                                    Subscription::new(
+                                        BinanceFuturesUsd::default(),
+                                        sub.instrument,
+                                        Candles(interval),
+                                    )
+                                })
+                                .collect(),
+                            channels.candles.entry(exchange).or_default().tx.clone(),
+                        ));
+                    }
                     (ExchangeId::Bitfinex, SubKind::PublicTrades) => {
                         tokio::spawn(consume::<Bitfinex, Instrument, PublicTrades>(
                             subs.into_iter()
@@ -192,6 +272,19 @@ impl<InstrumentId> DynamicStreams<InstrumentId> {
                             channels.trades.entry(exchange).or_default().tx.clone(),
                         ));
                     }
+                    (ExchangeId::Bitmex, SubKind::Liquidations) => {
+                        tokio::spawn(consume::<Bitmex, Instrument, Liquidations>(
+                            subs.into_iter()
+                                .map(|sub| Subscription::new(Bitmex, sub.instrument, Liquidations))
+                                .collect(),
+                            channels
+                                .liquidations
+                                .entry(exchange)
+                                .or_default()
+                                .tx
+                                .clone(),
+                        ));
+                    }
                     (ExchangeId::BybitSpot, SubKind::PublicTrades) => {
                         tokio::spawn(consume::<BybitSpot, Instrument, PublicTrades>(
                             subs.into_iter()
@@ -206,6 +299,34 @@ impl<InstrumentId> DynamicStreams<InstrumentId> {
                             channels.trades.entry(exchange).or_default().tx.clone(),
                         ));
                     }
+                    (ExchangeId::BybitSpot, SubKind::OrderBooksL2) => {
+                        tokio::spawn(consume::<BybitSpot, Instrument, OrderBooksL2>(
+                            subs.into_iter()
+                                .map(|sub| {
+                                    Subscription::new(
+                                        BybitSpot::default(),
+                                        sub.instrument,
+                                        OrderBooksL2,
+                                    )
+                                })
+                                .collect(),
+                            channels.l2s.entry(exchange).or_default().tx.clone(),
+                        ));
+                    }
+                    (ExchangeId::BybitSpot, SubKind::Candles(interval)) => {
+                        tokio::spawn(consume::<BybitSpot, Instrument, Candles>(
+                            subs.into_iter()
+                                .map(|sub| {
+                                    Subscription::new(
+                                        BybitSpot::default(),
+                                        sub.instrument,
+                                        Candles(interval),
+                                    )
+                                })
+                                .collect(),
+                            channels.candles.entry(exchange).or_default().tx.clone(),
+                        ));
+                    }
                     (ExchangeId::BybitPerpetualsUsd, SubKind::PublicTrades) => {
                         tokio::spawn(consume::<BybitPerpetualsUsd, Instrument, PublicTrades>(
                             subs.into_iter()
@@ -220,6 +341,53 @@ impl<InstrumentId> DynamicStreams<InstrumentId> {
                             channels.trades.entry(exchange).or_default().tx.clone(),
                         ));
                     }
+                    (ExchangeId::BybitPerpetualsUsd, SubKind::OrderBooksL2) => {
+                        tokio::spawn(consume::<BybitPerpetualsUsd, Instrument, OrderBooksL2>(
+                            subs.into_iter()
+                                .map(|sub| {
+                                    Subscription::new(
+                                        BybitPerpetualsUsd::default(),
+                                        sub.instrument,
+                                        OrderBooksL2,
+                                    )
+                                })
+                                .collect(),
+                            channels.l2s.entry(exchange).or_default().tx.clone(),
+                        ));
+                    }
+                    (ExchangeId::BybitPerpetualsUsd, SubKind::Candles(interval)) => {
+                        tokio::spawn(consume::<BybitPerpetualsUsd, Instrument, Candles>(
+                            subs.into_iter()
+                                .map(|sub| {
+                                    Subscription::new(
+                                        BybitPerpetualsUsd::default(),
+                                        sub.instrument,
+                                        Candles(interval),
+                                    )
+                                })
+                                .collect(),
+                            channels.candles.entry(exchange).or_default().tx.clone(),
+                        ));
+                    }
+                    (ExchangeId::BybitPerpetualsUsd, SubKind::Liquidations) => {
+                        tokio::spawn(consume::<BybitPerpetualsUsd, Instrument, Liquidations>(
+                            subs.into_iter()
+                                .map(|sub| {
+                                    Subscription::new(
+                                        BybitPerpetualsUsd::default(),
+                                        sub.instrument,
+                                        Liquidations,
+                                    )
+                                })
+                                .collect(),
+                            channels
+                                .liquidations
+                                .entry(exchange)
+                                .or_default()
+                                .tx
+                                .clone(),
+                        ));
+                    }
                     (ExchangeId::Coinbase, SubKind::PublicTrades) => {
                         tokio::spawn(consume::<Coinbase, Instrument, PublicTrades>(
                             subs.into_iter()
@@ -230,6 +398,40 @@ impl<InstrumentId> DynamicStreams<InstrumentId> {
                             channels.trades.entry(exchange).or_default().tx.clone(),
                         ));
                     }
+                    (ExchangeId::Coinbase, SubKind::OrderBooksL2) => {
+                        tokio::spawn(consume::<Coinbase, Instrument, OrderBooksL2>(
+                            subs.into_iter()
+                                .map(|sub| {
+                                    Subscription::new(Coinbase, sub.instrument, OrderBooksL2)
+                                })
+                                .collect(),
+                            channels.l2s.entry(exchange).or_default().tx.clone(),
+                        ));
+                    }
+                    (ExchangeId::Deribit, SubKind::PublicTrades) => {
+                        tokio::spawn(consume::<Deribit, Instrument, PublicTrades>(
+                            subs.into_iter()
+                                .map(|sub| Subscription::new(Deribit, sub.instrument, PublicTrades))
+                                .collect(),
+                            channels.trades.entry(exchange).or_default().tx.clone(),
+                        ));
+                    }
+                    (ExchangeId::Deribit, SubKind::OrderBooksL1) => {
+                        tokio::spawn(consume::<Deribit, Instrument, OrderBooksL1>(
+                            subs.into_iter()
+                                .map(|sub| Subscription::new(Deribit, sub.instrument, OrderBooksL1))
+                                .collect(),
+                            channels.l1s.entry(exchange).or_default().tx.clone(),
+                        ));
+                    }
+                    (ExchangeId::Deribit, SubKind::OrderBooksL2) => {
+                        tokio::spawn(consume::<Deribit, Instrument, OrderBooksL2>(
+                            subs.into_iter()
+                                .map(|sub| Subscription::new(Deribit, sub.instrument, OrderBooksL2))
+                                .collect(),
+                            channels.l2s.entry(exchange).or_default().tx.clone(),
+                        ));
+                    }
                     (ExchangeId::GateioSpot, SubKind::PublicTrades) => {
                         tokio::spawn(consume::<GateioSpot, Instrument, PublicTrades>(
                             subs.into_iter()
@@ -286,6 +488,25 @@ impl<InstrumentId> DynamicStreams<InstrumentId> {
                             channels.trades.entry(exchange).or_default().tx.clone(),
                         ));
                     }
+                    (ExchangeId::GateioPerpetualsUsd, SubKind::Liquidations) => {
+                        tokio::spawn(consume::<GateioPerpetualsUsd, Instrument, Liquidations>(
+                            subs.into_iter()
+                                .map(|sub| {
+                                    Subscription::new(
+                                        GateioPerpetualsUsd::default(),
+                                        sub.instrument,
+                                        Liquidations,
+                                    )
+                                })
+                                .collect(),
+                            channels
+                                .liquidations
+                                .entry(exchange)
+                                .or_default()
+                                .tx
+                                .clone(),
+                        ));
+                    }
                     (ExchangeId::GateioPerpetualsBtc, SubKind::PublicTrades) => {
                         tokio::spawn(consume::<GateioPerpetualsBtc, Instrument, PublicTrades>(
                             subs.into_iter()
@@ -300,6 +521,25 @@ impl<InstrumentId> DynamicStreams<InstrumentId> {
                             channels.trades.entry(exchange).or_default().tx.clone(),
                         ));
                     }
+                    (ExchangeId::GateioPerpetualsBtc, SubKind::Liquidations) => {
+                        tokio::spawn(consume::<GateioPerpetualsBtc, Instrument, Liquidations>(
+                            subs.into_iter()
+                                .map(|sub| {
+                                    Subscription::new(
+                                        GateioPerpetualsBtc::default(),
+                                        sub.instrument,
+                                        Liquidations,
+                                    )
+                                })
+                                .collect(),
+                            channels
+                                .liquidations
+                                .entry(exchange)
+                                .or_default()
+                                .tx
+                                .clone(),
+                        ));
+                    }
                     (ExchangeId::GateioOptions, SubKind::PublicTrades) => {
                         tokio::spawn(consume::<GateioOptions, Instrument, PublicTrades>(
                             subs.into_iter()
@@ -330,6 +570,24 @@ impl<InstrumentId> DynamicStreams<InstrumentId> {
                             channels.l1s.entry(exchange).or_default().tx.clone(),
                         ));
                     }
+                    (ExchangeId::Kraken, SubKind::OrderBooksL2) => {
+                        tokio::spawn(consume::<Kraken, Instrument, OrderBooksL2>(
+                            subs.into_iter()
+                                .map(|sub| Subscription::new(Kraken, sub.instrument, OrderBooksL2))
+                                .collect(),
+                            channels.l2s.entry(exchange).or_default().tx.clone(),
+                        ));
+                    }
+                    (ExchangeId::Kraken, SubKind::Candles(interval)) => {
+                        tokio::spawn(consume::<Kraken, Instrument, Candles>(
+                            subs.into_iter()
+                                .map(|sub| {
+                                    Subscription::new(Kraken, sub.instrument, Candles(interval))
+                                })
+                                .collect(),
+                            channels.candles.entry(exchange).or_default().tx.clone(),
+                        ));
+                    }
                     (ExchangeId::Okx, SubKind::PublicTrades) => {
                         tokio::spawn(consume::<Okx, Instrument, PublicTrades>(
                             subs.into_iter()
@@ -338,6 +596,37 @@ impl<InstrumentId> DynamicStreams<InstrumentId> {
                             channels.trades.entry(exchange).or_default().tx.clone(),
                         ));
                     }
+                    (ExchangeId::Okx, SubKind::OrderBooksL2) => {
+                        tokio::spawn(consume::<Okx, Instrument, OrderBooksL2>(
+                            subs.into_iter()
+                                .map(|sub| Subscription::new(Okx, sub.instrument, OrderBooksL2))
+                                .collect(),
+                            channels.l2s.entry(exchange).or_default().tx.clone(),
+                        ));
+                    }
+                    (ExchangeId::Okx, SubKind::Candles(interval)) => {
+                        tokio::spawn(consume::<Okx, Instrument, Candles>(
+                            subs.into_iter()
+                                .map(|sub| {
+                                    Subscription::new(Okx, sub.instrument, Candles(interval))
+                                })
+                                .collect(),
+                            channels.candles.entry(exchange).or_default().tx.clone(),
+                        ));
+                    }
+                    (ExchangeId::Okx, SubKind::Liquidations) => {
+                        tokio::spawn(consume::<Okx, Instrument, Liquidations>(
+                            subs.into_iter()
+                                .map(|sub| Subscription::new(Okx, sub.instrument, Liquidations))
+                                .collect(),
+                            channels
+                                .liquidations
+                                .entry(exchange)
+                                .or_default()
+                                .tx
+                                .clone(),
+                        ));
+                    }
                     (exchange, sub_kind) => {
                         return Err(DataError::Unsupported { exchange, sub_kind })
                     }
@@ -366,6 +655,11 @@ impl<InstrumentId> DynamicStreams<InstrumentId> {
                 .into_iter()
                 .map(|(exchange, channel)| (exchange, UnboundedReceiverStream::new(channel.rx)))
                 .collect(),
+            candles: channels
+                .candles
+                .into_iter()
+                .map(|(exchange, channel)| (exchange, UnboundedReceiverStream::new(channel.rx)))
+                .collect(),
         })
     }
 
@@ -441,6 +735,24 @@ impl<InstrumentId> DynamicStreams<InstrumentId> {
         select_all(std::mem::take(&mut self.liquidations).into_values())
     }
 
+    /// Remove an exchange [`Candle`] `Stream` from the [`DynamicStreams`] collection.
+    ///
+    /// Note that calling this method will permanently remove this `Stream` from [`Self`].
+    pub fn select_candles(
+        &mut self,
+        exchange: ExchangeId,
+    ) -> Option<UnboundedReceiverStream<MarketEvent<InstrumentId, Candle>>> {
+        self.candles.remove(&exchange)
+    }
+
+    /// Select and merge every exchange [`Candle`] `Stream` using
+    /// [`SelectAll`](futures_util::stream::select_all).
+    pub fn select_all_candles(
+        &mut self,
+    ) -> SelectAll<UnboundedReceiverStream<MarketEvent<InstrumentId, Candle>>> {
+        select_all(std::mem::take(&mut self.candles).into_values())
+    }
+
     /// Select and merge every exchange `Stream` for every data type using
     /// [`SelectAll`](futures_util::stream::select_all).
     ///
@@ -454,12 +766,14 @@ impl<InstrumentId> DynamicStreams<InstrumentId> {
         MarketEvent<InstrumentId, OrderBookL1>: Into<Output>,
         MarketEvent<InstrumentId, OrderBook>: Into<Output>,
         MarketEvent<InstrumentId, Liquidation>: Into<Output>,
+        MarketEvent<InstrumentId, Candle>: Into<Output>,
     {
         let Self {
             trades,
             l1s,
             l2s,
             liquidations,
+            candles,
         } = self;
 
         let trades = trades
@@ -478,7 +792,15 @@ impl<InstrumentId> DynamicStreams<InstrumentId> {
             .into_values()
             .map(|stream| stream.map(MarketEvent::into).boxed());
 
-        let all = trades.chain(l1s).chain(l2s).chain(liquidations);
+        let candles = candles
+            .into_values()
+            .map(|stream| stream.map(MarketEvent::into).boxed());
+
+        let all = trades
+            .chain(l1s)
+            .chain(l2s)
+            .chain(liquidations)
+            .chain(candles);
 
         select_all(all)
     }
@@ -517,6 +839,7 @@ struct Channels<InstrumentId> {
     l1s: HashMap<ExchangeId, ExchangeChannel<MarketEvent<InstrumentId, OrderBookL1>>>,
     l2s: HashMap<ExchangeId, ExchangeChannel<MarketEvent<InstrumentId, OrderBook>>>,
     liquidations: HashMap<ExchangeId, ExchangeChannel<MarketEvent<InstrumentId, Liquidation>>>,
+    candles: HashMap<ExchangeId, ExchangeChannel<MarketEvent<InstrumentId, Candle>>>,
 }
 
 impl<InstrumentId> Default for Channels<InstrumentId> {
@@ -526,6 +849,7 @@ impl<InstrumentId> Default for Channels<InstrumentId> {
             l1s: Default::default(),
             l2s: Default::default(),
             liquidations: Default::default(),
+            candles: Default::default(),
         }
     }
 }
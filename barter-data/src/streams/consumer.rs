@@ -49,7 +49,6 @@ where
     'retry: loop {
         // Increment retry parameters at start of every iteration
         attempt += 1;
-        backoff_ms *= 2;
         info!(%exchange, attempt, "attempting to initialise MarketStream");
 
         // Attempt to initialise MarketStream: if it fails on first attempt return DataError
@@ -63,10 +62,12 @@ where
             Err(error) => {
                 error!(%exchange, attempt, ?error, "failed to initialise MarketStream");
 
-                // Exit function if Stream::init failed the first attempt, else retry
+                // Exit function if Stream::init failed the first attempt, else retry after backoff
                 if attempt == 1 {
                     return Err(error);
                 } else {
+                    tokio::time::sleep(Duration::from_millis(backoff_ms)).await;
+                    backoff_ms *= 2;
                     continue;
                 }
             }
@@ -11,7 +11,7 @@ use crate::{
 use async_trait::async_trait;
 use barter_integration::{
     error::SocketError,
-    protocol::websocket::{connect, WebSocket},
+    protocol::websocket::{connect, WebSocket, WsMessage},
 };
 use futures::SinkExt;
 use serde::{Deserialize, Serialize};
@@ -26,13 +26,15 @@ pub mod mapper;
 pub mod validator;
 
 /// Defines how to connect to a socket and subscribe to market data streams.
+///
+/// Any market data received while validating the subscriptions is returned so it is not lost.
 #[async_trait]
 pub trait Subscriber {
     type SubMapper: SubscriptionMapper;
 
     async fn subscribe<Exchange, Instrument, Kind>(
         subscriptions: &[Subscription<Exchange, Instrument, Kind>],
-    ) -> Result<(WebSocket, Map<Instrument::Id>), SocketError>
+    ) -> Result<(WebSocket, Map<Instrument::Id>, Vec<WsMessage>), SocketError>
     where
         Exchange: Connector + Send + Sync,
         Kind: SubscriptionKind + Send + Sync,
@@ -51,7 +53,7 @@ impl Subscriber for WebSocketSubscriber {
 
     async fn subscribe<Exchange, Instrument, Kind>(
         subscriptions: &[Subscription<Exchange, Instrument, Kind>],
-    ) -> Result<(WebSocket, Map<Instrument::Id>), SocketError>
+    ) -> Result<(WebSocket, Map<Instrument::Id>, Vec<WsMessage>), SocketError>
     where
         Exchange: Connector + Send + Sync,
         Kind: SubscriptionKind + Send + Sync,
@@ -61,7 +63,12 @@ impl Subscriber for WebSocketSubscriber {
     {
         // Define variables for logging ergonomics
         let exchange = Exchange::ID;
-        let url = Exchange::url()?;
+        let url = match subscriptions.first() {
+            Some(subscription) => {
+                Exchange::channel_url(&Identifier::<Exchange::Channel>::id(subscription))?
+            }
+            None => Exchange::url()?,
+        };
         debug!(%exchange, %url, ?subscriptions, "subscribing to WebSocket");
 
         // Connect to exchange
@@ -81,13 +88,13 @@ impl Subscriber for WebSocketSubscriber {
         }
 
         // Validate Subscription responses
-        let map = Exchange::SubValidator::validate::<Exchange, Instrument, Kind>(
+        let (map, buffered) = Exchange::SubValidator::validate::<Exchange, Instrument, Kind>(
             instrument_map,
             &mut websocket,
         )
         .await?;
 
         info!(%exchange, "subscribed to WebSocket");
-        Ok((websocket, map))
+        Ok((websocket, map, buffered))
     }
 }
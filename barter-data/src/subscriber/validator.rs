@@ -7,7 +7,7 @@ use async_trait::async_trait;
 use barter_integration::{
     error::SocketError,
     protocol::{
-        websocket::{WebSocket, WebSocketParser},
+        websocket::{WebSocket, WebSocketParser, WsMessage},
         StreamParser,
     },
     Validator,
@@ -18,6 +18,10 @@ use tracing::debug;
 
 /// Defines how to validate that actioned market data
 /// [`Subscription`](crate::subscription::Subscription)s were accepted by the exchange.
+///
+/// Market data received before every [`Subscription`](crate::subscription::Subscription) is
+/// validated (eg/ initial OrderBook snapshots) is returned alongside the validated [`Map`], so it
+/// can be transformed ahead of any subsequent messages.
 #[async_trait]
 pub trait SubscriptionValidator {
     type Parser: StreamParser;
@@ -25,7 +29,7 @@ pub trait SubscriptionValidator {
     async fn validate<Exchange, Instrument, Kind>(
         instrument_map: Map<Instrument::Id>,
         websocket: &mut WebSocket,
-    ) -> Result<Map<Instrument::Id>, SocketError>
+    ) -> Result<(Map<Instrument::Id>, Vec<WsMessage>), SocketError>
     where
         Exchange: Connector + Send,
         Instrument: InstrumentData,
@@ -43,7 +47,7 @@ impl SubscriptionValidator for WebSocketSubValidator {
     async fn validate<Exchange, Instrument, Kind>(
         instrument_map: Map<Instrument::Id>,
         websocket: &mut WebSocket,
-    ) -> Result<Map<Instrument::Id>, SocketError>
+    ) -> Result<(Map<Instrument::Id>, Vec<WsMessage>), SocketError>
     where
         Exchange: Connector + Send,
         Instrument: InstrumentData,
@@ -56,11 +60,14 @@ impl SubscriptionValidator for WebSocketSubValidator {
         // Parameter to keep track of successful Subscription outcomes
         let mut success_responses = 0usize;
 
+        // Buffer market data received before all Subscriptions are validated
+        let mut buffered = Vec::new();
+
         loop {
             // Break if all Subscriptions were a success
             if success_responses == expected_responses {
                 debug!(exchange = %Exchange::ID, "validated exchange WebSocket subscriptions");
-                break Ok(instrument_map);
+                break Ok((instrument_map, buffered));
             }
 
             tokio::select! {
@@ -95,15 +102,16 @@ impl SubscriptionValidator for WebSocketSubValidator {
                             Err(err) => break Err(err)
                         }
                         Some(Err(SocketError::Deserialise { error, payload })) if success_responses >= 1 => {
-                            // Already active subscription payloads, so skip to next SubResponse
+                            // Already active subscription payloads, so buffer & skip to next SubResponse
                             debug!(
                                 exchange = %Exchange::ID,
                                 ?error,
                                 %success_responses,
                                 %expected_responses,
                                 %payload,
-                                "failed to deserialise non SubResponse payload"
+                                "buffering non SubResponse payload received during validation"
                             );
+                            buffered.push(WsMessage::Text(payload));
                             continue
                         }
                         Some(Err(SocketError::Terminated(close_frame))) => {
@@ -58,16 +58,281 @@ impl SubscriptionKind for OrderBooksL2 {
     type Event = OrderBook;
 }
 
-/// Barter [`Subscription`](super::Subscription) [`SubscriptionKind`] that yields level 3 [`OrderBook`]
-/// [`MarketEvent<T>`](MarketEvent) events.
+/// Barter [`Subscription`](super::Subscription) [`SubscriptionKind`] that yields level 3
+/// [`OrderBookL3Update`] [`MarketEvent<T>`](MarketEvent) events.
 ///
-/// Level 3 refers to the non-aggregated [`OrderBook`]. This is a direct replication of the exchange
-/// [`OrderBook`].
+/// Level 3 refers to the non-aggregated [`OrderBookL3`]. This is a direct replication of the
+/// exchange OrderBook, where each resting order is identifiable by its order id.
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, DeSubKind, SerSubKind)]
 pub struct OrderBooksL3;
 
 impl SubscriptionKind for OrderBooksL3 {
-    type Event = OrderBook;
+    type Event = OrderBookL3Update;
+}
+
+/// Normalised Barter level 3 OrderBook update yielded by an [`OrderBooksL3`] stream.
+///
+/// An [`OrderBookL3Update::Snapshot`] is yielded first, followed by an
+/// [`OrderBookL3Update::Event`] for every subsequent change. Use [`OrderBookL3::update`] to
+/// maintain a local [`OrderBookL3`], and [`OrderBook::from`] to project it into an aggregated
+/// level 2 [`OrderBook`] only when required.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub enum OrderBookL3Update {
+    /// Full [`OrderBookL3`] that replaces any local [`OrderBookL3`].
+    Snapshot(OrderBookL3),
+    /// [`OrderBookL3Event`] to apply to the local [`OrderBookL3`].
+    Event(OrderBookL3Event),
+}
+
+/// Normalised Barter [`OrderBookL3`] snapshot containing every resting [`OrderL3`].
+///
+/// Use [`OrderBook::from`] to project the [`OrderBookL3`] into an aggregated level 2 [`OrderBook`].
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OrderBookL3 {
+    pub last_update_time: DateTime<Utc>,
+    pub bids: OrderBookL3Side,
+    pub asks: OrderBookL3Side,
+}
+
+impl Default for OrderBookL3 {
+    fn default() -> Self {
+        Self {
+            last_update_time: Default::default(),
+            bids: OrderBookL3Side::new(Side::Buy, Vec::<OrderL3>::new()),
+            asks: OrderBookL3Side::new(Side::Sell, Vec::<OrderL3>::new()),
+        }
+    }
+}
+
+impl OrderBookL3 {
+    /// Apply an [`OrderBookL3Update`] yielded by an [`OrderBooksL3`] stream, either replacing
+    /// this [`OrderBookL3`] with a snapshot or applying an [`OrderBookL3Event`] to it.
+    pub fn update(&mut self, update: OrderBookL3Update, time: DateTime<Utc>) {
+        match update {
+            OrderBookL3Update::Snapshot(book) => *self = book,
+            OrderBookL3Update::Event(event) => {
+                self.apply(event);
+                self.last_update_time = time;
+            }
+        }
+    }
+
+    /// Apply an [`OrderBookL3Event`] to the [`OrderBookL3Side`] it is associated with.
+    pub fn apply(&mut self, event: OrderBookL3Event) {
+        match event.side() {
+            Side::Buy => self.bids.apply(event),
+            Side::Sell => self.asks.apply(event),
+        }
+    }
+}
+
+impl From<&OrderBookL3> for OrderBook {
+    fn from(book: &OrderBookL3) -> Self {
+        Self {
+            last_update_time: book.last_update_time,
+            bids: OrderBookSide::from(&book.bids),
+            asks: OrderBookSide::from(&book.asks),
+        }
+    }
+}
+
+/// Normalised Barter [`OrderL3`]s for one [`Side`] of the [`OrderBookL3`].
+///
+/// [`OrderL3`]s are kept in price-time priority (best price first, then oldest first), so the
+/// index of an [`OrderL3`] reflects its position in the exchange matching queue.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OrderBookL3Side {
+    side: Side,
+    orders: Vec<OrderL3>,
+}
+
+impl OrderBookL3Side {
+    /// Construct a new [`Self`] with the [`OrderL3`]s provided.
+    ///
+    /// [`OrderL3`]s with the same price retain their provided relative (time priority) ordering.
+    pub fn new<Iter, O>(side: Side, orders: Iter) -> Self
+    where
+        Iter: IntoIterator<Item = O>,
+        O: Into<OrderL3>,
+    {
+        let mut orders = orders.into_iter().map(O::into).collect::<Vec<_>>();
+
+        // Stable sort retains the time priority of OrderL3s at the same price
+        match side {
+            Side::Buy => orders.sort_by(|a, b| b.price.total_cmp(&a.price)),
+            Side::Sell => orders.sort_by(|a, b| a.price.total_cmp(&b.price)),
+        }
+
+        Self { side, orders }
+    }
+
+    /// Return the [`OrderL3`]s of this [`OrderBookL3Side`] in price-time priority.
+    pub fn orders(&self) -> &[OrderL3] {
+        &self.orders
+    }
+
+    /// Return the [`OrderL3`] associated with the provided order id, if it exists.
+    pub fn order(&self, id: &str) -> Option<&OrderL3> {
+        self.orders.iter().find(|order| order.id == id)
+    }
+
+    /// Apply an [`OrderBookL3Event`] to this [`OrderBookL3Side`].
+    ///
+    /// ### Apply Scenarios
+    /// #### 1 Add
+    /// 1a) Insert the [`OrderL3`] at the back of the price level queue
+    ///
+    /// #### 2 Modify
+    /// 2a) Order exists & new amount is 0, remove the order
+    /// 2b) Order exists & price is unchanged, replace the amount & retain queue position
+    /// 2c) Order exists & price is changed, re-insert at the back of the new price level queue
+    /// 2d) Order does not exist, log & continue
+    ///
+    /// #### 3 Delete
+    /// 3a) Order exists, remove the order
+    /// 3b) Order does not exist, log & continue
+    pub fn apply(&mut self, event: OrderBookL3Event) {
+        match event {
+            // Scenario 1a: Insert new OrderL3 at the back of the price level queue
+            OrderBookL3Event::Add { order, .. } => self.insert(order),
+
+            OrderBookL3Event::Modify { order, .. } => match self.position(&order.id) {
+                // Scenario 2a: OrderL3 exists & new amount is 0 => remove OrderL3
+                Some(index) if order.amount == 0.0 => {
+                    self.orders.remove(index);
+                }
+
+                // Scenario 2b: OrderL3 exists & price is unchanged => retain queue position
+                Some(index) if self.orders[index].price == order.price => {
+                    self.orders[index].amount = order.amount;
+                }
+
+                // Scenario 2c: OrderL3 exists & price is changed => re-insert OrderL3
+                Some(index) => {
+                    self.orders.remove(index);
+                    self.insert(order);
+                }
+
+                // Scenario 2d: OrderL3 does not exist => log & continue
+                None => {
+                    debug!(?order, side = %self.side, "OrderL3 to modify not found");
+                }
+            },
+
+            OrderBookL3Event::Delete { id, .. } => match self.position(&id) {
+                // Scenario 3a: OrderL3 exists => remove OrderL3
+                Some(index) => {
+                    self.orders.remove(index);
+                }
+
+                // Scenario 3b: OrderL3 does not exist => log & continue
+                None => {
+                    debug!(%id, side = %self.side, "OrderL3 to delete not found");
+                }
+            },
+        }
+    }
+
+    /// Determine the [`QueuePosition`] of the [`OrderL3`] associated with the provided order id,
+    /// relative to the other [`OrderL3`]s resting at the same price.
+    pub fn queue_position(&self, id: &str) -> Option<QueuePosition> {
+        let index = self.position(id)?;
+        let price = self.orders[index].price;
+
+        let ahead = self.orders[..index]
+            .iter()
+            .filter(|order| order.price == price)
+            .collect::<Vec<_>>();
+
+        Some(QueuePosition {
+            orders_ahead: ahead.len(),
+            amount_ahead: ahead.iter().map(|order| order.amount).sum(),
+        })
+    }
+
+    /// Find the index of the [`OrderL3`] associated with the provided order id.
+    fn position(&self, id: &str) -> Option<usize> {
+        self.orders.iter().position(|order| order.id == id)
+    }
+
+    /// Insert an [`OrderL3`] at the back of its price level queue.
+    fn insert(&mut self, order: OrderL3) {
+        let index = match self.side {
+            Side::Buy => self
+                .orders
+                .partition_point(|other| other.price >= order.price),
+            Side::Sell => self
+                .orders
+                .partition_point(|other| other.price <= order.price),
+        };
+        self.orders.insert(index, order);
+    }
+}
+
+impl From<&OrderBookL3Side> for OrderBookSide {
+    fn from(side: &OrderBookL3Side) -> Self {
+        // OrderL3s are in price-time priority, so each price level is contiguous
+        let levels = side
+            .orders
+            .chunk_by(|a, b| a.price == b.price)
+            .map(|orders| Level {
+                price: orders[0].price,
+                amount: orders.iter().map(|order| order.amount).sum(),
+            });
+
+        Self::new(side.side, levels)
+    }
+}
+
+/// Normalised Barter level 3 resting order, identifiable by its exchange order id.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OrderL3 {
+    pub id: String,
+    pub price: f64,
+    pub amount: f64,
+}
+
+impl OrderL3 {
+    pub fn new<Id, T>(id: Id, price: T, amount: T) -> Self
+    where
+        Id: Into<String>,
+        T: Into<f64>,
+    {
+        Self {
+            id: id.into(),
+            price: price.into(),
+            amount: amount.into(),
+        }
+    }
+}
+
+/// Normalised Barter level 3 OrderBook event that is applied to an [`OrderBookL3`].
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub enum OrderBookL3Event {
+    /// New resting [`OrderL3`], which joins the back of the price level queue.
+    Add { side: Side, order: OrderL3 },
+    /// Resting [`OrderL3`] with a new price and/or remaining amount.
+    Modify { side: Side, order: OrderL3 },
+    /// Resting [`OrderL3`] removed from the OrderBook (eg/ filled or cancelled).
+    Delete { side: Side, id: String },
+}
+
+impl OrderBookL3Event {
+    /// Return the [`Side`] of the [`OrderBookL3`] this event is associated with.
+    pub fn side(&self) -> Side {
+        match self {
+            Self::Add { side, .. } | Self::Modify { side, .. } | Self::Delete { side, .. } => *side,
+        }
+    }
+}
+
+/// Position of an [`OrderL3`] in the queue of [`OrderL3`]s resting at the same price.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+pub struct QueuePosition {
+    /// Number of [`OrderL3`]s ahead in the queue.
+    pub orders_ahead: usize,
+    /// Total amount of the [`OrderL3`]s ahead in the queue.
+    pub amount_ahead: f64,
 }
 
 /// Normalised Barter [`OrderBook`] snapshot.
@@ -78,6 +343,16 @@ pub struct OrderBook {
     pub asks: OrderBookSide,
 }
 
+impl Default for OrderBook {
+    fn default() -> Self {
+        Self {
+            last_update_time: Default::default(),
+            bids: OrderBookSide::new(Side::Buy, Vec::<Level>::new()),
+            asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
+        }
+    }
+}
+
 impl OrderBook {
     /// Generate an [`OrderBook`] snapshot by cloning [`Self`] after sorting each [`OrderBookSide`].
     pub fn snapshot(&mut self) -> Self {
@@ -135,6 +410,11 @@ impl OrderBookSide {
         }
     }
 
+    /// Return the [`Level`]s of this [`OrderBookSide`].
+    pub fn levels(&self) -> &[Level] {
+        &self.levels
+    }
+
     /// Upsert a collection of [`Level`]s into this [`OrderBookSide`].
     pub fn upsert<Iter, L>(&mut self, levels: Iter)
     where
@@ -202,6 +482,12 @@ impl OrderBookSide {
             self.levels.reverse();
         }
     }
+
+    /// Sort this [`OrderBookSide`] and remove all [`Level`]s beyond the provided depth.
+    pub fn truncate(&mut self, depth: usize) {
+        self.sort();
+        self.levels.truncate(depth);
+    }
 }
 
 /// Normalised Barter OrderBook [`Level`].
@@ -227,6 +513,8 @@ impl Ord for Level {
     }
 }
 
+// Ord panics on incomparable (eg/ NaN) prices, so PartialOrd holds the comparison logic
+#[allow(clippy::non_canonical_partial_ord_impl)]
 impl PartialOrd for Level {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         match self.price.partial_cmp(&other.price)? {
@@ -287,6 +575,26 @@ impl<InstrumentId> From<(ExchangeId, InstrumentId, OrderBook)>
     }
 }
 
+impl<InstrumentId> From<(ExchangeId, InstrumentId, (DateTime<Utc>, OrderBookL3Update))>
+    for MarketIter<InstrumentId, OrderBookL3Update>
+{
+    fn from(
+        (exchange_id, instrument, (time, update)): (
+            ExchangeId,
+            InstrumentId,
+            (DateTime<Utc>, OrderBookL3Update),
+        ),
+    ) -> Self {
+        Self(vec![Ok(MarketEvent {
+            exchange_time: time,
+            received_time: Utc::now(),
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: update,
+        })])
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -800,6 +1108,172 @@ mod tests {
         }
     }
 
+    mod order_book_l3_side {
+        use super::*;
+
+        fn bids() -> OrderBookL3Side {
+            OrderBookL3Side::new(
+                Side::Buy,
+                vec![
+                    OrderL3::new("a", 100.0, 1.0),
+                    OrderL3::new("b", 90.0, 1.0),
+                    OrderL3::new("c", 100.0, 2.0),
+                ],
+            )
+        }
+
+        #[test]
+        fn test_new() {
+            assert_eq!(
+                bids().orders(),
+                &[
+                    OrderL3::new("a", 100.0, 1.0),
+                    OrderL3::new("c", 100.0, 2.0),
+                    OrderL3::new("b", 90.0, 1.0),
+                ]
+            );
+        }
+
+        #[test]
+        fn test_apply() {
+            struct TestCase {
+                input: OrderBookL3Event,
+                expected: Vec<OrderL3>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: Add => insert at the back of the price level queue
+                    input: OrderBookL3Event::Add {
+                        side: Side::Buy,
+                        order: OrderL3::new("d", 100.0, 3.0),
+                    },
+                    expected: vec![
+                        OrderL3::new("a", 100.0, 1.0),
+                        OrderL3::new("c", 100.0, 2.0),
+                        OrderL3::new("d", 100.0, 3.0),
+                        OrderL3::new("b", 90.0, 1.0),
+                    ],
+                },
+                TestCase {
+                    // TC1: Modify w/ amount 0 => remove OrderL3
+                    input: OrderBookL3Event::Modify {
+                        side: Side::Buy,
+                        order: OrderL3::new("a", 100.0, 0.0),
+                    },
+                    expected: vec![OrderL3::new("c", 100.0, 2.0), OrderL3::new("b", 90.0, 1.0)],
+                },
+                TestCase {
+                    // TC2: Modify w/ unchanged price => retain queue position
+                    input: OrderBookL3Event::Modify {
+                        side: Side::Buy,
+                        order: OrderL3::new("a", 100.0, 0.5),
+                    },
+                    expected: vec![
+                        OrderL3::new("a", 100.0, 0.5),
+                        OrderL3::new("c", 100.0, 2.0),
+                        OrderL3::new("b", 90.0, 1.0),
+                    ],
+                },
+                TestCase {
+                    // TC3: Modify w/ changed price => re-insert at the back of the new price level
+                    input: OrderBookL3Event::Modify {
+                        side: Side::Buy,
+                        order: OrderL3::new("a", 90.0, 1.0),
+                    },
+                    expected: vec![
+                        OrderL3::new("c", 100.0, 2.0),
+                        OrderL3::new("b", 90.0, 1.0),
+                        OrderL3::new("a", 90.0, 1.0),
+                    ],
+                },
+                TestCase {
+                    // TC4: Modify non-existent OrderL3 => no change
+                    input: OrderBookL3Event::Modify {
+                        side: Side::Buy,
+                        order: OrderL3::new("z", 100.0, 1.0),
+                    },
+                    expected: bids().orders().to_vec(),
+                },
+                TestCase {
+                    // TC5: Delete => remove OrderL3
+                    input: OrderBookL3Event::Delete {
+                        side: Side::Buy,
+                        id: "c".to_string(),
+                    },
+                    expected: vec![OrderL3::new("a", 100.0, 1.0), OrderL3::new("b", 90.0, 1.0)],
+                },
+                TestCase {
+                    // TC6: Delete non-existent OrderL3 => no change
+                    input: OrderBookL3Event::Delete {
+                        side: Side::Buy,
+                        id: "z".to_string(),
+                    },
+                    expected: bids().orders().to_vec(),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let mut book_side = bids();
+                book_side.apply(test.input);
+                assert_eq!(book_side.orders(), test.expected, "TC{} failed", index);
+            }
+        }
+
+        #[test]
+        fn test_queue_position() {
+            struct TestCase {
+                input: &'static str,
+                expected: Option<QueuePosition>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: OrderL3 at the front of the queue
+                    input: "a",
+                    expected: Some(QueuePosition {
+                        orders_ahead: 0,
+                        amount_ahead: 0.0,
+                    }),
+                },
+                TestCase {
+                    // TC1: OrderL3 behind another OrderL3 at the same price
+                    input: "c",
+                    expected: Some(QueuePosition {
+                        orders_ahead: 1,
+                        amount_ahead: 1.0,
+                    }),
+                },
+                TestCase {
+                    // TC2: OrderL3 behind better priced OrderL3s only
+                    input: "b",
+                    expected: Some(QueuePosition {
+                        orders_ahead: 0,
+                        amount_ahead: 0.0,
+                    }),
+                },
+                TestCase {
+                    // TC3: OrderL3 does not exist
+                    input: "z",
+                    expected: None,
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = bids().queue_position(test.input);
+                assert_eq!(actual, test.expected, "TC{} failed", index);
+            }
+        }
+
+        #[test]
+        fn test_into_order_book_side() {
+            assert_eq!(
+                OrderBookSide::from(&bids()),
+                OrderBookSide::new(Side::Buy, vec![Level::new(100, 3), Level::new(90, 1)])
+            );
+        }
+    }
+
     mod level {
         use super::*;
 
@@ -1,16 +1,66 @@
 use super::SubscriptionKind;
 use chrono::{DateTime, Utc};
+use derive_more::Display;
 use serde::{Deserialize, Serialize};
 
 /// Barter [`Subscription`](super::Subscription) [`SubscriptionKind`] that yields [`Candle`]
-/// [`MarketEvent<T>`](crate::event::MarketEvent) events.
+/// [`MarketEvent<T>`](crate::event::MarketEvent) events for the contained [`CandleInterval`].
+///
+/// Only closed [`Candle`]s are yielded, in-progress interval updates are not.
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
-pub struct Candles;
+pub struct Candles(pub CandleInterval);
 
 impl SubscriptionKind for Candles {
     type Event = Candle;
 }
 
+/// [`Candle`] interval supported by every exchange that implements a [`Candles`] stream.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize, Display,
+)]
+pub enum CandleInterval {
+    #[serde(rename = "1m")]
+    #[display(fmt = "1m")]
+    M1,
+    #[serde(rename = "5m")]
+    #[display(fmt = "5m")]
+    M5,
+    #[serde(rename = "15m")]
+    #[display(fmt = "15m")]
+    M15,
+    #[serde(rename = "30m")]
+    #[display(fmt = "30m")]
+    M30,
+    #[serde(rename = "1h")]
+    #[display(fmt = "1h")]
+    H1,
+    #[serde(rename = "4h")]
+    #[display(fmt = "4h")]
+    H4,
+    #[serde(rename = "1d")]
+    #[display(fmt = "1d")]
+    D1,
+    #[serde(rename = "1w")]
+    #[display(fmt = "1w")]
+    W1,
+}
+
+impl CandleInterval {
+    /// Length of the [`CandleInterval`] in minutes.
+    pub fn minutes(&self) -> u64 {
+        match self {
+            Self::M1 => 1,
+            Self::M5 => 5,
+            Self::M15 => 15,
+            Self::M30 => 30,
+            Self::H1 => 60,
+            Self::H4 => 240,
+            Self::D1 => 1440,
+            Self::W1 => 10080,
+        }
+    }
+}
+
 /// Normalised Barter OHLCV [`Candle`] model.
 #[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub struct Candle {
@@ -0,0 +1,24 @@
+use super::SubscriptionKind;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Barter [`Subscription`](super::Subscription) [`SubscriptionKind`] that yields [`FundingRate`]
+/// [`MarketEvent<T>`](crate::event::MarketEvent) events.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct FundingRates;
+
+impl SubscriptionKind for FundingRates {
+    type Event = FundingRate;
+}
+
+/// Normalised Barter perpetual [`FundingRate`] model.
+///
+/// ### Notes
+/// `next_funding_time` is `None` if the exchange does not stream the next funding settlement
+/// time alongside the funding rate (eg/ Gateio perpetuals).
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct FundingRate {
+    pub rate: f64,
+    pub time: DateTime<Utc>,
+    pub next_funding_time: Option<DateTime<Utc>>,
+}
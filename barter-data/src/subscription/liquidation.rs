@@ -13,6 +13,10 @@ impl SubscriptionKind for Liquidations {
 }
 
 /// Normalised Barter [`Liquidation`] model.
+///
+/// ### Notes
+/// - `side` is the [`Side`] of the liquidation order, so a liquidated long position is a
+///   [`Side::Sell`], and a liquidated short position is a [`Side::Buy`].
 #[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub struct Liquidation {
     pub side: Side,
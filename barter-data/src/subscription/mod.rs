@@ -1,3 +1,4 @@
+use self::candle::CandleInterval;
 use crate::{
     exchange::{Connector, ExchangeId},
     instrument::{InstrumentData, KeyedInstrument},
@@ -26,9 +27,21 @@ pub mod book;
 /// Candle [`SubscriptionKind`] and the associated Barter output data model.
 pub mod candle;
 
+/// Funding rate [`SubscriptionKind`] and the associated Barter output data model.
+pub mod funding;
+
 /// Liquidation [`SubscriptionKind`] and the associated Barter output data model.
 pub mod liquidation;
 
+/// Open interest [`SubscriptionKind`] and the associated Barter output data model.
+pub mod open_interest;
+
+/// Mark price & index price [`SubscriptionKind`]s and the associated Barter output data models.
+pub mod price;
+
+/// Rolling 24 hour ticker [`SubscriptionKind`] and the associated Barter output data model.
+pub mod ticker;
+
 /// Public trade [`SubscriptionKind`] and the associated Barter output data model.
 pub mod trade;
 
@@ -60,7 +73,13 @@ pub enum SubKind {
     OrderBooksL2,
     OrderBooksL3,
     Liquidations,
-    Candles,
+    FundingRates,
+    MarkPrices,
+    IndexPrices,
+    Tickers,
+    OpenInterests,
+    #[display(fmt = "Candles({})", _0)]
+    Candles(CandleInterval),
 }
 
 impl<Exchange, Instrument, Kind> Display for Subscription<Exchange, Instrument, Kind>
@@ -432,6 +451,95 @@ mod tests {
                 }
             }
         }
+
+        #[test]
+        fn test_validate_exchange_id_order_books_l2() {
+            struct TestCase {
+                input: Subscription<ExchangeId, Instrument, SubKind>,
+                expected: Result<Subscription<ExchangeId, Instrument, SubKind>, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: Valid BinanceSpot Spot OrderBooksL2 subscription
+                    input: Subscription::from((
+                        ExchangeId::BinanceSpot,
+                        "base",
+                        "quote",
+                        InstrumentKind::Spot,
+                        SubKind::OrderBooksL2,
+                    )),
+                    expected: Ok(Subscription::from((
+                        ExchangeId::BinanceSpot,
+                        "base",
+                        "quote",
+                        InstrumentKind::Spot,
+                        SubKind::OrderBooksL2,
+                    ))),
+                },
+                TestCase {
+                    // TC1: Valid Okx FuturePerpetual OrderBooksL2 subscription
+                    input: Subscription::from((
+                        ExchangeId::Okx,
+                        "base",
+                        "quote",
+                        InstrumentKind::Perpetual,
+                        SubKind::OrderBooksL2,
+                    )),
+                    expected: Ok(Subscription::from((
+                        ExchangeId::Okx,
+                        "base",
+                        "quote",
+                        InstrumentKind::Perpetual,
+                        SubKind::OrderBooksL2,
+                    ))),
+                },
+                TestCase {
+                    // TC2: Invalid Coinbase FuturePerpetual OrderBooksL2 subscription
+                    input: Subscription::from((
+                        ExchangeId::Coinbase,
+                        "base",
+                        "quote",
+                        InstrumentKind::Perpetual,
+                        SubKind::OrderBooksL2,
+                    )),
+                    expected: Err(SocketError::Unsupported {
+                        entity: "",
+                        item: "".to_string(),
+                    }),
+                },
+                TestCase {
+                    // TC3: Invalid Bitfinex Spot OrderBooksL2 subscription
+                    input: Subscription::from((
+                        ExchangeId::Bitfinex,
+                        "base",
+                        "quote",
+                        InstrumentKind::Spot,
+                        SubKind::OrderBooksL2,
+                    )),
+                    expected: Err(SocketError::Unsupported {
+                        entity: "",
+                        item: "".to_string(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = test.input.validate();
+                match (actual, &test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(&actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
     }
 
     mod instrument_map {
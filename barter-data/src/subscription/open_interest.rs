@@ -0,0 +1,25 @@
+use super::SubscriptionKind;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Barter [`Subscription`](super::Subscription) [`SubscriptionKind`] that yields [`OpenInterest`]
+/// [`MarketEvent<T>`](crate::event::MarketEvent) events.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct OpenInterests;
+
+impl SubscriptionKind for OpenInterests {
+    type Event = OpenInterest;
+}
+
+/// Normalised Barter derivative [`OpenInterest`] model.
+///
+/// ### Notes
+/// - `quantity` is denominated in the base asset (eg/ BTC for a btc_usdt instrument).
+/// - `notional` is the quote denominated value of the open interest, and is `None` if the
+///   exchange does not provide it.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OpenInterest {
+    pub quantity: f64,
+    pub notional: Option<f64>,
+    pub time: DateTime<Utc>,
+}
@@ -0,0 +1,36 @@
+use super::SubscriptionKind;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Barter [`Subscription`](super::Subscription) [`SubscriptionKind`] that yields [`MarkPrice`]
+/// [`MarketEvent<T>`](crate::event::MarketEvent) events.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct MarkPrices;
+
+impl SubscriptionKind for MarkPrices {
+    type Event = MarkPrice;
+}
+
+/// Normalised Barter [`MarkPrice`] model, used by exchanges to value derivative positions.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct MarkPrice {
+    pub price: f64,
+    pub time: DateTime<Utc>,
+}
+
+/// Barter [`Subscription`](super::Subscription) [`SubscriptionKind`] that yields [`IndexPrice`]
+/// [`MarketEvent<T>`](crate::event::MarketEvent) events.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct IndexPrices;
+
+impl SubscriptionKind for IndexPrices {
+    type Event = IndexPrice;
+}
+
+/// Normalised Barter [`IndexPrice`] model, the exchange's reference price of the underlying
+/// derived from a basket of spot markets.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct IndexPrice {
+    pub price: f64,
+    pub time: DateTime<Utc>,
+}
@@ -0,0 +1,28 @@
+use super::SubscriptionKind;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Barter [`Subscription`](super::Subscription) [`SubscriptionKind`] that yields [`Ticker`]
+/// [`MarketEvent<T>`](crate::event::MarketEvent) events.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct Tickers;
+
+impl SubscriptionKind for Tickers {
+    type Event = Ticker;
+}
+
+/// Normalised Barter rolling 24 hour [`Ticker`] model.
+///
+/// ### Notes
+/// - `volume_24h` is denominated in the base asset (eg/ BTC for a btc_usdt instrument).
+/// - `price_change_percent_24h` is a percentage (eg/ 1.5 for a 1.5% price increase).
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct Ticker {
+    pub last_price: f64,
+    pub high_24h: f64,
+    pub low_24h: f64,
+    pub volume_24h: f64,
+    pub price_change_24h: f64,
+    pub price_change_percent_24h: f64,
+    pub time: DateTime<Utc>,
+}
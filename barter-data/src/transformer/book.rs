@@ -1,78 +1,89 @@
 use crate::{
     error::DataError,
     event::{MarketEvent, MarketIter},
-    exchange::Connector,
-    subscription::{book::OrderBook, Map, SubscriptionKind},
+    exchange::{Connector, ExchangeId},
+    subscription::{Map, SubscriptionKind},
     transformer::ExchangeTransformer,
     Identifier,
 };
 use async_trait::async_trait;
-use barter_integration::{
-    model::{instrument::Instrument, SubscriptionId},
-    protocol::websocket::WsMessage,
-    Transformer,
-};
+use barter_integration::{model::SubscriptionId, protocol::websocket::WsMessage, Transformer};
 use serde::{Deserialize, Serialize};
-use std::marker::PhantomData;
+use std::{fmt::Debug, marker::PhantomData};
 use tokio::sync::mpsc;
 
-/// Defines how to apply a [`Self::Update`] to an [`Self::OrderBook`].
+/// Defines how to apply a [`Self::Update`] to an [`Self::OrderBook`], and the [`Self::Output`]
+/// that is yielded as a result.
 #[async_trait]
 pub trait OrderBookUpdater
 where
     Self: Sized,
 {
-    type OrderBook;
+    type OrderBook: Clone + PartialEq + Debug + Serialize + for<'de> Deserialize<'de>;
     type Update;
+    type Output;
 
-    /// Initialises the [`InstrumentOrderBook`] for the provided [`Instrument`]. This often requires
-    /// a HTTP call to receive a starting [`OrderBook`] snapshot.
-    async fn init<Exchange, Kind>(
+    /// Initialises the [`InstrumentOrderBook`] for the provided `InstrumentId`, which is associated
+    /// with the provided [`SubscriptionId`]. This often requires a HTTP call to receive a starting
+    /// [`Self::OrderBook`] snapshot.
+    async fn init<Exchange, Kind, InstrumentId>(
         ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
-        instrument: Instrument,
-    ) -> Result<InstrumentOrderBook<Instrument, Self>, DataError>
+        subscription_id: SubscriptionId,
+        instrument: InstrumentId,
+    ) -> Result<InstrumentOrderBook<InstrumentId, Self>, DataError>
     where
         Exchange: Send,
-        Kind: Send;
+        Kind: Send,
+        InstrumentId: Send;
 
-    /// Apply the [`Self::Update`] to the provided mutable [`Self::OrderBook`].
+    /// Apply the [`Self::Update`] to the provided mutable [`Self::OrderBook`], returning the
+    /// [`Self::Output`] to yield (eg/ an updated [`Self::OrderBook`] snapshot), if any.
     fn update(
         &mut self,
         book: &mut Self::OrderBook,
         update: Self::Update,
-    ) -> Result<Option<Self::OrderBook>, DataError>;
+    ) -> Result<Option<Self::Output>, DataError>;
 }
 
-/// [`OrderBook`] for an [`Instrument`] with an exchange specific [`OrderBookUpdater`] to define
-/// how to update it.
+/// OrderBook (eg/ [`OrderBook`](crate::subscription::book::OrderBook), [`OrderBookL3`](crate::subscription::book::OrderBookL3)) for an
+/// instrument with an exchange specific [`OrderBookUpdater`] to define how to update it.
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Deserialize, Serialize)]
-pub struct InstrumentOrderBook<InstrumentId, Updater> {
+pub struct InstrumentOrderBook<InstrumentId, Updater>
+where
+    Updater: OrderBookUpdater,
+{
     pub instrument: InstrumentId,
     pub updater: Updater,
-    pub book: OrderBook,
+    pub book: Updater::OrderBook,
 }
 
 /// Standard generic [`ExchangeTransformer`] to translate exchange specific OrderBook types into
 /// normalised Barter OrderBook types. Requires an exchange specific [`OrderBookUpdater`]
 /// implementation.
-#[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
-pub struct MultiBookTransformer<Exchange, InstrumentId, Kind, Updater> {
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct MultiBookTransformer<Exchange, InstrumentId, Kind, Updater>
+where
+    Updater: OrderBookUpdater,
+{
     pub book_map: Map<InstrumentOrderBook<InstrumentId, Updater>>,
     phantom: PhantomData<(Exchange, Kind)>,
 }
 
 #[async_trait]
-impl<Exchange, Kind, Updater> ExchangeTransformer<Exchange, Instrument, Kind>
-    for MultiBookTransformer<Exchange, Instrument, Kind, Updater>
+impl<Exchange, InstrumentId, Kind, Updater> ExchangeTransformer<Exchange, InstrumentId, Kind>
+    for MultiBookTransformer<Exchange, InstrumentId, Kind, Updater>
 where
     Exchange: Connector + Send,
-    Kind: SubscriptionKind<Event = OrderBook> + Send,
-    Updater: OrderBookUpdater<OrderBook = Kind::Event> + Send,
+    InstrumentId: Clone + Send,
+    Kind: SubscriptionKind + Send,
+    Updater::OrderBook: Send,
+    Updater: OrderBookUpdater + Send,
     Updater::Update: Identifier<Option<SubscriptionId>> + for<'de> Deserialize<'de>,
+    MarketIter<InstrumentId, Kind::Event>: From<(ExchangeId, InstrumentId, Updater::Output)>,
 {
     async fn new(
         ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
-        map: Map<Instrument>,
+        map: Map<InstrumentId>,
     ) -> Result<Self, DataError> {
         // Initialise InstrumentOrderBooks for all Subscriptions
         let (sub_ids, init_book_requests): (Vec<_>, Vec<_>) = map
@@ -80,8 +91,12 @@ where
             .into_iter()
             .map(|(sub_id, instrument)| {
                 (
-                    sub_id,
-                    Updater::init::<Exchange, Kind>(ws_sink_tx.clone(), instrument),
+                    sub_id.clone(),
+                    Updater::init::<Exchange, Kind, InstrumentId>(
+                        ws_sink_tx.clone(),
+                        sub_id,
+                        instrument,
+                    ),
                 )
             })
             .unzip();
@@ -90,13 +105,13 @@ where
         let init_order_books = futures::future::join_all(init_book_requests)
             .await
             .into_iter()
-            .collect::<Result<Vec<InstrumentOrderBook<Instrument, Updater>>, DataError>>()?;
+            .collect::<Result<Vec<InstrumentOrderBook<InstrumentId, Updater>>, DataError>>()?;
 
         // Construct OrderBookMap if all requests successful
         let book_map = sub_ids
             .into_iter()
-            .zip(init_order_books.into_iter())
-            .collect::<Map<InstrumentOrderBook<Instrument, Updater>>>();
+            .zip(init_order_books)
+            .collect::<Map<InstrumentOrderBook<InstrumentId, Updater>>>();
 
         Ok(Self {
             book_map,
@@ -110,9 +125,10 @@ impl<Exchange, InstrumentId, Kind, Updater> Transformer
 where
     Exchange: Connector,
     InstrumentId: Clone,
-    Kind: SubscriptionKind<Event = OrderBook>,
-    Updater: OrderBookUpdater<OrderBook = Kind::Event>,
+    Kind: SubscriptionKind,
+    Updater: OrderBookUpdater,
     Updater::Update: Identifier<Option<SubscriptionId>> + for<'de> Deserialize<'de>,
+    MarketIter<InstrumentId, Kind::Event>: From<(ExchangeId, InstrumentId, Updater::Output)>,
 {
     type Error = DataError;
     type Input = Updater::Update;
@@ -139,13 +155,13 @@ where
             updater,
         } = book;
 
-        // Apply update (snapshot or delta) to OrderBook & generate Market<OrderBook> snapshot
+        // Apply update (snapshot or delta) to OrderBook & generate Market<Kind::Event>
         match updater.update(book, update) {
-            Ok(Some(book)) => {
-                MarketIter::<InstrumentId, OrderBook>::from((
+            Ok(Some(output)) => {
+                MarketIter::<InstrumentId, Kind::Event>::from((
                     Exchange::ID,
                     instrument.clone(),
-                    book,
+                    output,
                 ))
                 .0
             }
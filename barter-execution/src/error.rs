@@ -1,5 +1,5 @@
 use crate::model::{order::OrderKind, ClientOrderId};
-use barter_integration::model::instrument::symbol::Symbol;
+use barter_integration::{error::SocketError, model::instrument::symbol::Symbol};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
@@ -19,4 +19,30 @@ pub enum ExecutionError {
 
     #[error("failed to open Order due to unsupported OrderKind: {0}")]
     UnsupportedOrderKind(OrderKind),
+
+    #[error("PostOnly Order with ClientOrderId {0} rejected since it would cross the spread")]
+    PostOnlyRejected(ClientOrderId),
+
+    #[error(
+        "amended quantity of Order with ClientOrderId {0} does not exceed it's filled quantity"
+    )]
+    AmendQuantityFilled(ClientOrderId),
+
+    #[error("amended Order with ClientOrderId {0} rejected since it would cross the spread")]
+    AmendCrossesSpread(ClientOrderId),
+
+    #[error("conditional Order with ClientOrderId {0} has a missing or invalid OrderTrigger")]
+    InvalidOrderTrigger(ClientOrderId),
+
+    #[error("SocketError: {0}")]
+    Socket(String),
+
+    #[error("exchange API error: {0}")]
+    Api(String),
+}
+
+impl From<SocketError> for ExecutionError {
+    fn from(error: SocketError) -> Self {
+        Self::Socket(error.to_string())
+    }
 }
@@ -1 +1,180 @@
+use crate::error::ExecutionError;
+use barter_integration::{
+    error::SocketError,
+    model::instrument::Instrument,
+    protocol::http::{
+        private::{encoder::HexEncoder, RequestSigner, Signer},
+        rest::{client::RestClient, RestRequest},
+        BuildStrategy, HttpParser,
+    },
+};
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use reqwest::{RequestBuilder, StatusCode};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
 
+/// `BinanceSpot` [`ExecutionClient`](crate::ExecutionClient) implementation.
+pub mod spot;
+
+/// Binance Http header containing the account API key.
+pub const HEADER_BINANCE_API_KEY: &str = "X-MBX-APIKEY";
+
+/// Convenient type alias for a [`RestClient`] that signs every [`RestRequest`] using the
+/// [`BinanceSigner`].
+pub type BinanceSignedClient =
+    RestClient<'static, RequestSigner<BinanceSigner, Hmac<Sha256>, HexEncoder>, BinanceParser>;
+
+/// Convenient type alias for a [`RestClient`] that only attaches the account API key to each
+/// [`RestRequest`] (eg/ user data stream `listenKey` management).
+pub type BinanceApiKeyClient = RestClient<'static, BinanceApiKey, BinanceParser>;
+
+/// Construct a [`BinanceSignedClient`] using the provided base url & API credentials.
+pub fn signed_client(base_url: String, api_key: String, api_secret: &str) -> BinanceSignedClient {
+    let mac = Hmac::<Sha256>::new_from_slice(api_secret.as_bytes())
+        .expect("HMAC-SHA256 accepts keys of any length");
+
+    RestClient::new(
+        base_url,
+        RequestSigner::new(BinanceSigner { api_key }, mac, HexEncoder),
+        BinanceParser,
+    )
+}
+
+/// Binance API [`Signer`] for `SIGNED` endpoints.
+///
+/// The HMAC-SHA256 signature is generated from the full query string (which must already
+/// contain the request `timestamp`), and appended as the final `signature` query parameter.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#signed-trade-user_data-and-margin-endpoint-security>
+#[derive(Clone, Debug)]
+pub struct BinanceSigner {
+    pub api_key: String,
+}
+
+/// Configuration required to sign every Binance `SIGNED` [`RestRequest`].
+#[derive(Debug)]
+pub struct BinanceSignConfig<'a> {
+    pub api_key: &'a str,
+    pub query: String,
+}
+
+impl Signer for BinanceSigner {
+    type Config<'a>
+        = BinanceSignConfig<'a>
+    where
+        Self: 'a;
+
+    fn config<'a, Request>(
+        &'a self,
+        _: Request,
+        builder: &RequestBuilder,
+    ) -> Result<Self::Config<'a>, SocketError>
+    where
+        Request: RestRequest,
+    {
+        // Build a copy of the reqwest::Request to extract the encoded query string to sign
+        let query = builder
+            .try_clone()
+            .ok_or_else(|| SocketError::Unsupported {
+                entity: "BinanceSigner",
+                item: "streaming request body".to_string(),
+            })?
+            .build()?
+            .url()
+            .query()
+            .unwrap_or_default()
+            .to_owned();
+
+        Ok(BinanceSignConfig {
+            api_key: self.api_key.as_str(),
+            query,
+        })
+    }
+
+    fn add_bytes_to_sign<M>(mac: &mut M, config: &Self::Config<'_>)
+    where
+        M: Mac,
+    {
+        mac.update(config.query.as_bytes());
+    }
+
+    fn build_signed_request(
+        config: Self::Config<'_>,
+        builder: RequestBuilder,
+        signature: String,
+    ) -> Result<reqwest::Request, SocketError> {
+        builder
+            .query(&[("signature", signature)])
+            .header(HEADER_BINANCE_API_KEY, config.api_key)
+            .build()
+            .map_err(SocketError::from)
+    }
+}
+
+/// [`RestRequest`] [`BuildStrategy`] for Binance `USER_STREAM` endpoints, which only require the
+/// account API key header and no signature.
+#[derive(Clone, Debug)]
+pub struct BinanceApiKey {
+    pub api_key: String,
+}
+
+impl BuildStrategy for BinanceApiKey {
+    fn build<Request>(
+        &self,
+        _: Request,
+        builder: RequestBuilder,
+    ) -> Result<reqwest::Request, SocketError>
+    where
+        Request: RestRequest,
+    {
+        builder
+            .header(HEADER_BINANCE_API_KEY, self.api_key.as_str())
+            .build()
+            .map_err(SocketError::from)
+    }
+}
+
+/// Binance [`HttpParser`] that maps API errors to an [`ExecutionError`].
+#[derive(Copy, Clone, Debug)]
+pub struct BinanceParser;
+
+impl HttpParser for BinanceParser {
+    type ApiError = BinanceApiError;
+    type OutputError = ExecutionError;
+
+    fn parse_api_error(&self, status: StatusCode, error: Self::ApiError) -> Self::OutputError {
+        ExecutionError::Api(format!(
+            "status: {status}, code: {}, msg: {}",
+            error.code, error.msg
+        ))
+    }
+}
+
+/// Binance API error response.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#error-codes>
+/// ```json
+/// {
+///     "code": -2011,
+///     "msg": "Unknown order sent."
+/// }
+/// ```
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct BinanceApiError {
+    pub code: i64,
+    pub msg: String,
+}
+
+/// Generate the Binance symbol `String` for the provided [`Instrument`].
+///
+/// eg/ "BTCUSDT"
+pub fn binance_symbol(instrument: &Instrument) -> String {
+    format!("{}{}", instrument.base, instrument.quote).to_uppercase()
+}
+
+/// Current epoch milliseconds timestamp required by every Binance `SIGNED` [`RestRequest`].
+pub fn timestamp() -> i64 {
+    Utc::now().timestamp_millis()
+}
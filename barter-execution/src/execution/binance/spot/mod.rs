@@ -0,0 +1,442 @@
+use self::{
+    request::{
+        BinanceListenKey, CancelOrderParams, CancelOrderRequest, CancelOrdersSymbolRequest,
+        CancelReplaceOrderRequest, FetchAccountRequest, FetchOrderParams, FetchOrderRequest,
+        FetchOrdersOpenRequest, ListenKeyCreateRequest, ListenKeyKeepaliveRequest,
+        OpenOrderRequest, SymbolParams, TimestampParams, HTTP_BASE_URL_BINANCE_SPOT,
+    },
+    user_data::{BinanceUserData, WEBSOCKET_BASE_URL_BINANCE_SPOT_USER_DATA},
+};
+use super::{
+    binance_symbol, signed_client, timestamp, BinanceApiKey, BinanceApiKeyClient, BinanceParser,
+    BinanceSignedClient,
+};
+use crate::{
+    error::ExecutionError,
+    model::{
+        balance::SymbolBalance,
+        order::{Cancelled, Open, Order, OrderId, RequestAmend, RequestCancel, RequestOpen},
+        AccountEvent, ClientOrderId,
+    },
+    ExecutionClient, ExecutionId,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    error::SocketError,
+    model::{instrument::Instrument, Exchange},
+    protocol::{
+        http::rest::client::RestClient,
+        websocket::{connect, WebSocketParser},
+        StreamParser,
+    },
+};
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, time::Duration};
+use tokio::sync::mpsc;
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+/// [`BinanceSpot`] Http requests & responses.
+pub mod request;
+
+/// [`BinanceSpot`] user data stream messages.
+pub mod user_data;
+
+/// Interval at which the user data stream `listenKey` is kept alive. Binance closes the stream
+/// after 60 minutes without a keepalive.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#listen-key-spot>
+const LISTEN_KEY_KEEPALIVE_INTERVAL: Duration = Duration::from_secs(30 * 60);
+
+/// Delay before re-establishing a disconnected user data stream.
+const USER_DATA_RECONNECT_DELAY: Duration = Duration::from_secs(1);
+
+/// Configuration for constructing a [`BinanceSpot`] [`ExecutionClient`].
+#[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+pub struct BinanceSpotConfig {
+    pub api_key: String,
+    pub api_secret: String,
+    pub instruments: Vec<Instrument>,
+    pub http_base_url: String,
+    pub websocket_base_url: String,
+}
+
+impl BinanceSpotConfig {
+    /// Construct a new [`BinanceSpotConfig`] that uses the production Binance Spot servers.
+    pub fn new<S>(api_key: S, api_secret: S, instruments: Vec<Instrument>) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            api_key: api_key.into(),
+            api_secret: api_secret.into(),
+            instruments,
+            http_base_url: HTTP_BASE_URL_BINANCE_SPOT.to_string(),
+            websocket_base_url: WEBSOCKET_BASE_URL_BINANCE_SPOT_USER_DATA.to_string(),
+        }
+    }
+}
+
+/// Binance Spot [`ExecutionClient`] implementation.
+///
+/// Orders are opened & cancelled via signed Http requests. Order, trade & balance updates are
+/// consumed from the account user data stream and sent to the client as [`AccountEvent`]s.
+#[derive(Debug)]
+pub struct BinanceSpot {
+    pub http_client: BinanceSignedClient,
+    pub instruments: HashMap<String, Instrument>,
+}
+
+#[async_trait]
+impl ExecutionClient for BinanceSpot {
+    const CLIENT: ExecutionId = ExecutionId::BinanceSpot;
+    type Config = BinanceSpotConfig;
+
+    async fn init(config: Self::Config, event_tx: mpsc::UnboundedSender<AccountEvent>) -> Self {
+        // Map Binance symbols (eg/ "BTCUSDT") to their associated Instrument
+        let instruments = config
+            .instruments
+            .iter()
+            .map(|instrument| (binance_symbol(instrument), instrument.clone()))
+            .collect::<HashMap<String, Instrument>>();
+
+        // Consume the account user data stream on it's own Tokio task
+        tokio::spawn(run_user_data_stream(
+            RestClient::new(
+                config.http_base_url.clone(),
+                BinanceApiKey {
+                    api_key: config.api_key.clone(),
+                },
+                BinanceParser,
+            ),
+            config.websocket_base_url,
+            instruments.clone(),
+            event_tx,
+        ));
+
+        Self {
+            http_client: signed_client(config.http_base_url, config.api_key, &config.api_secret),
+            instruments,
+        }
+    }
+
+    async fn fetch_orders_open(&self) -> Result<Vec<Order<Open>>, ExecutionError> {
+        let (orders, _) = self
+            .http_client
+            .execute(FetchOrdersOpenRequest {
+                params: TimestampParams::now(),
+            })
+            .await?;
+
+        Ok(orders
+            .into_iter()
+            .filter_map(|order| {
+                let instrument = self.instrument(&order.symbol)?;
+                Some(Order {
+                    exchange: Exchange::from(ExecutionId::BinanceSpot),
+                    instrument,
+                    cid: parse_cid(&order.client_order_id)?,
+                    side: order.side,
+                    state: Open {
+                        id: OrderId::from(order.order_id),
+                        price: order.price,
+                        quantity: order.orig_qty,
+                        filled_quantity: order.executed_qty,
+                    },
+                })
+            })
+            .collect())
+    }
+
+    async fn fetch_balances(&self) -> Result<Vec<SymbolBalance>, ExecutionError> {
+        let (account, _) = self
+            .http_client
+            .execute(FetchAccountRequest {
+                params: TimestampParams::now(),
+            })
+            .await?;
+
+        Ok(account
+            .balances
+            .into_iter()
+            .map(SymbolBalance::from)
+            .collect())
+    }
+
+    async fn open_orders(
+        &self,
+        open_requests: Vec<Order<RequestOpen>>,
+    ) -> Vec<Result<Order<Open>, ExecutionError>> {
+        futures::future::join_all(
+            open_requests
+                .into_iter()
+                .map(|request| self.try_open_order(request)),
+        )
+        .await
+    }
+
+    async fn amend_orders(
+        &self,
+        amend_requests: Vec<Order<RequestAmend>>,
+    ) -> Vec<Result<Order<Open>, ExecutionError>> {
+        futures::future::join_all(
+            amend_requests
+                .into_iter()
+                .map(|request| self.try_amend_order(request)),
+        )
+        .await
+    }
+
+    async fn cancel_orders(
+        &self,
+        cancel_requests: Vec<Order<RequestCancel>>,
+    ) -> Vec<Result<Order<Cancelled>, ExecutionError>> {
+        futures::future::join_all(
+            cancel_requests
+                .into_iter()
+                .map(|request| self.try_cancel_order(request)),
+        )
+        .await
+    }
+
+    async fn cancel_orders_all(&self) -> Result<Vec<Order<Cancelled>>, ExecutionError> {
+        // Binance can only cancel all open orders per symbol, so determine which symbols have
+        // open orders to avoid unnecessary "Unknown order sent" errors
+        let mut symbols = self
+            .fetch_orders_open()
+            .await?
+            .into_iter()
+            .map(|order| binance_symbol(&order.instrument))
+            .collect::<Vec<String>>();
+        symbols.sort();
+        symbols.dedup();
+
+        let mut cancelled = Vec::new();
+        for symbol in symbols {
+            let (orders, _) = self
+                .http_client
+                .execute(CancelOrdersSymbolRequest {
+                    params: SymbolParams {
+                        symbol,
+                        timestamp: timestamp(),
+                    },
+                })
+                .await?;
+
+            cancelled.extend(orders.into_iter().filter_map(|order| {
+                Some(Order {
+                    exchange: Exchange::from(ExecutionId::BinanceSpot),
+                    instrument: self.instrument(&order.symbol)?,
+                    cid: parse_cid(&order.orig_client_order_id)?,
+                    side: order.side,
+                    state: Cancelled::from(order.order_id),
+                })
+            }));
+        }
+
+        Ok(cancelled)
+    }
+}
+
+impl BinanceSpot {
+    /// Execute an open order request.
+    pub async fn try_open_order(
+        &self,
+        request: Order<RequestOpen>,
+    ) -> Result<Order<Open>, ExecutionError> {
+        let (response, _) = self
+            .http_client
+            .execute(OpenOrderRequest::from(&request))
+            .await?;
+
+        Ok(Order::from((OrderId::from(response.order_id), request)))
+    }
+
+    /// Execute an amend order request.
+    ///
+    /// Binance Spot amends are cancel-replace operations, so the amended [`Order<Open>`] loses
+    /// queue priority and has a new [`OrderId`]. The original order is fetched first so the
+    /// replacement keeps it's [`OrderKind`](crate::model::order::OrderKind) and is sized to the
+    /// quantity that remains unfilled.
+    pub async fn try_amend_order(
+        &self,
+        request: Order<RequestAmend>,
+    ) -> Result<Order<Open>, ExecutionError> {
+        let (original, _) = self
+            .http_client
+            .execute(FetchOrderRequest {
+                params: FetchOrderParams {
+                    symbol: binance_symbol(&request.instrument),
+                    order_id: request.state.id.0.clone(),
+                    timestamp: timestamp(),
+                },
+            })
+            .await?;
+
+        let (response, _) = self
+            .http_client
+            .execute(CancelReplaceOrderRequest::new(&request, &original)?)
+            .await?;
+
+        Ok(Order {
+            exchange: request.exchange,
+            instrument: request.instrument,
+            cid: request.cid,
+            side: request.side,
+            state: Open {
+                id: OrderId::from(response.new_order_response.order_id),
+                price: request.state.price,
+                quantity: request.state.quantity,
+                filled_quantity: original.executed_qty,
+            },
+        })
+    }
+
+    /// Execute a cancel order request.
+    pub async fn try_cancel_order(
+        &self,
+        request: Order<RequestCancel>,
+    ) -> Result<Order<Cancelled>, ExecutionError> {
+        let (response, _) = self
+            .http_client
+            .execute(CancelOrderRequest {
+                params: CancelOrderParams {
+                    symbol: binance_symbol(&request.instrument),
+                    order_id: request.state.id.0.clone(),
+                    timestamp: timestamp(),
+                },
+            })
+            .await?;
+
+        Ok(Order {
+            exchange: request.exchange,
+            instrument: request.instrument,
+            cid: request.cid,
+            side: request.side,
+            state: Cancelled::from(response.order_id),
+        })
+    }
+
+    /// Find the [`Instrument`] associated with the provided Binance symbol.
+    fn instrument(&self, symbol: &str) -> Option<Instrument> {
+        let instrument = self.instruments.get(symbol).cloned();
+        if instrument.is_none() {
+            warn!(%symbol, "ignoring Binance order for unrecognised symbol");
+        }
+        instrument
+    }
+}
+
+/// Parse a Binance `clientOrderId` into a [`ClientOrderId`].
+///
+/// Orders opened outside of Barter (eg/ via the Binance UI) do not have a [`Uuid`]
+/// `clientOrderId`, and are therefore ignored.
+pub fn parse_cid(client_order_id: &str) -> Option<ClientOrderId> {
+    match Uuid::parse_str(client_order_id) {
+        Ok(cid) => Some(ClientOrderId(cid)),
+        Err(_) => {
+            warn!(%client_order_id, "ignoring Binance order with non-Barter clientOrderId");
+            None
+        }
+    }
+}
+
+/// Consume the Binance Spot account user data stream, sending normalised [`AccountEvent`]s to
+/// the client. Re-establishes the stream (with a new `listenKey`) upon disconnection.
+pub async fn run_user_data_stream(
+    http_client: BinanceApiKeyClient,
+    websocket_base_url: String,
+    instruments: HashMap<String, Instrument>,
+    event_tx: mpsc::UnboundedSender<AccountEvent>,
+) {
+    loop {
+        // Create a new listenKey for the user data stream
+        let listen_key = match http_client.execute(ListenKeyCreateRequest).await {
+            Ok((listen_key, _)) => listen_key,
+            Err(error) => {
+                error!(
+                    ?error,
+                    "failed to create Binance user data stream listenKey"
+                );
+                tokio::time::sleep(USER_DATA_RECONNECT_DELAY).await;
+                continue;
+            }
+        };
+
+        // Connect to the user data stream
+        let url = format!("{}/{}", websocket_base_url, listen_key.listen_key);
+        let mut websocket = match connect(url).await {
+            Ok(websocket) => websocket,
+            Err(error) => {
+                error!(?error, "failed to connect to Binance user data stream");
+                tokio::time::sleep(USER_DATA_RECONNECT_DELAY).await;
+                continue;
+            }
+        };
+        info!("connected to Binance user data stream");
+
+        let mut keepalive = tokio::time::interval_at(
+            tokio::time::Instant::now() + LISTEN_KEY_KEEPALIVE_INTERVAL,
+            LISTEN_KEY_KEEPALIVE_INTERVAL,
+        );
+
+        loop {
+            tokio::select! {
+                _ = keepalive.tick() => {
+                    keepalive_listen_key(&http_client, &listen_key).await;
+                }
+                message = websocket.next() => {
+                    let Some(message) = message else {
+                        warn!("Binance user data stream ended");
+                        break;
+                    };
+
+                    let user_data = match WebSocketParser::parse::<BinanceUserData>(message) {
+                        Some(Ok(user_data)) => user_data,
+                        Some(Err(error)) => {
+                            if let SocketError::WebSocket(_) | SocketError::Terminated(_) = error {
+                                warn!(?error, "Binance user data stream disconnected");
+                                break;
+                            }
+                            warn!(?error, "failed to parse Binance user data stream message");
+                            continue;
+                        }
+                        None => continue,
+                    };
+
+                    if user_data == BinanceUserData::ListenKeyExpired {
+                        warn!("Binance user data stream listenKey expired");
+                        break;
+                    }
+
+                    let Some(event) = user_data.into_account_event(&instruments) else {
+                        continue;
+                    };
+
+                    if event_tx.send(event).is_err() {
+                        warn!("AccountEvent receiver dropped - closing Binance user data stream");
+                        return;
+                    }
+                }
+            }
+        }
+
+        tokio::time::sleep(USER_DATA_RECONNECT_DELAY).await;
+    }
+}
+
+/// Keepalive the provided user data stream `listenKey`.
+async fn keepalive_listen_key(http_client: &BinanceApiKeyClient, listen_key: &BinanceListenKey) {
+    if let Err(error) = http_client
+        .execute(ListenKeyKeepaliveRequest {
+            params: listen_key.clone(),
+        })
+        .await
+    {
+        error!(
+            ?error,
+            "failed to keepalive Binance user data stream listenKey"
+        );
+    }
+}
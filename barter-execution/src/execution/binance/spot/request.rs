@@ -0,0 +1,809 @@
+use crate::{
+    error::ExecutionError,
+    execution::binance::{binance_symbol, timestamp},
+    model::order::{Order, OrderKind, OrderTrigger, RequestAmend, RequestOpen},
+};
+use barter_integration::{
+    model::{instrument::symbol::Symbol, Side},
+    protocol::http::rest::RestRequest,
+};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+/// [`BinanceSpot`](super::BinanceSpot) Http REST base url.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#general-api-information>
+pub const HTTP_BASE_URL_BINANCE_SPOT: &str = "https://api.binance.com";
+
+/// Open a new order.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#new-order-trade>
+#[derive(Clone, Debug, Serialize)]
+pub struct OpenOrderRequest {
+    pub params: OpenOrderParams,
+}
+
+/// [`OpenOrderRequest`] query parameters.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenOrderParams {
+    pub symbol: String,
+    pub side: &'static str,
+    #[serde(rename = "type")]
+    pub kind: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_in_force: Option<&'static str>,
+    pub quantity: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop_price: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trailing_delta: Option<u64>,
+    pub new_client_order_id: String,
+    pub new_order_resp_type: &'static str,
+    pub timestamp: i64,
+}
+
+impl From<&Order<RequestOpen>> for OpenOrderRequest {
+    fn from(request: &Order<RequestOpen>) -> Self {
+        let (kind, time_in_force) = binance_order_kind(request.state.kind);
+
+        let price = match request.state.kind.activated() {
+            OrderKind::Market => None,
+            _ => Some(request.state.price.to_string()),
+        };
+
+        // Map the OrderTrigger to a Binance stop price or trailing delta (in basis points)
+        let (stop_price, trailing_delta) = match request.state.trigger {
+            Some(OrderTrigger::Price(price)) => (Some(price.to_string()), None),
+            Some(OrderTrigger::Trailing { offset_percent }) => {
+                (None, Some((offset_percent * 10_000.0).round() as u64))
+            }
+            None => (None, None),
+        };
+
+        Self {
+            params: OpenOrderParams {
+                symbol: binance_symbol(&request.instrument),
+                side: binance_side(request.side),
+                kind,
+                time_in_force,
+                quantity: request.state.quantity.to_string(),
+                price,
+                stop_price,
+                trailing_delta,
+                new_client_order_id: request.cid.to_string(),
+                new_order_resp_type: "ACK",
+                timestamp: timestamp(),
+            },
+        }
+    }
+}
+
+impl RestRequest for OpenOrderRequest {
+    type Response = BinanceOrderAck;
+    type QueryParams = OpenOrderParams;
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/api/v3/order")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn query_params(&self) -> Option<&Self::QueryParams> {
+        Some(&self.params)
+    }
+}
+
+/// Cancel an active order and atomically open a replacement order of the same [`OrderKind`] with
+/// the amended price & remaining unfilled quantity. The replacement order is assigned a new
+/// `orderId`, but retains the `clientOrderId`.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#cancel-an-existing-order-and-send-a-new-order-trade>
+#[derive(Clone, Debug, Serialize)]
+pub struct CancelReplaceOrderRequest {
+    pub params: CancelReplaceOrderParams,
+}
+
+/// [`CancelReplaceOrderRequest`] query parameters.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelReplaceOrderParams {
+    pub symbol: String,
+    pub side: &'static str,
+    #[serde(rename = "type")]
+    pub kind: &'static str,
+    pub cancel_replace_mode: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_in_force: Option<&'static str>,
+    pub quantity: String,
+    pub price: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop_price: Option<String>,
+    pub cancel_order_id: String,
+    pub new_client_order_id: String,
+    pub new_order_resp_type: &'static str,
+    pub timestamp: i64,
+}
+
+impl CancelReplaceOrderRequest {
+    /// Construct a [`CancelReplaceOrderRequest`] for the [`Order<RequestAmend>`] using the
+    /// [`BinanceOrder`] it amends, keeping it's [`OrderKind`] & stop price.
+    ///
+    /// The amended quantity is the new total quantity, so the replacement order is sized to the
+    /// quantity that remains unfilled. Amends of [`OrderKind`]s without a limit price are rejected.
+    pub fn new(
+        request: &Order<RequestAmend>,
+        original: &BinanceOrder,
+    ) -> Result<Self, ExecutionError> {
+        let order_kind = original.order_kind().ok_or_else(|| {
+            ExecutionError::Api(format!(
+                "unrecognised Binance order type {} with timeInForce {}",
+                original.kind, original.time_in_force
+            ))
+        })?;
+
+        let stop_price = match order_kind {
+            OrderKind::Limit | OrderKind::PostOnly | OrderKind::ImmediateOrCancel => None,
+            OrderKind::StopLimit => Some(original.stop_price.to_string()),
+            unsupported => return Err(ExecutionError::UnsupportedOrderKind(unsupported)),
+        };
+
+        let remaining_quantity = request.state.quantity - original.executed_qty;
+        if remaining_quantity <= 0.0 {
+            return Err(ExecutionError::AmendQuantityFilled(request.cid));
+        }
+
+        let (kind, time_in_force) = binance_order_kind(order_kind);
+
+        Ok(Self {
+            params: CancelReplaceOrderParams {
+                symbol: binance_symbol(&request.instrument),
+                side: binance_side(request.side),
+                kind,
+                cancel_replace_mode: "STOP_ON_FAILURE",
+                time_in_force,
+                quantity: remaining_quantity.to_string(),
+                price: request.state.price.to_string(),
+                stop_price,
+                cancel_order_id: request.state.id.0.clone(),
+                new_client_order_id: request.cid.to_string(),
+                new_order_resp_type: "ACK",
+                timestamp: timestamp(),
+            },
+        })
+    }
+}
+
+impl RestRequest for CancelReplaceOrderRequest {
+    type Response = BinanceCancelReplaceResponse;
+    type QueryParams = CancelReplaceOrderParams;
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/api/v3/order/cancelReplace")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn query_params(&self) -> Option<&Self::QueryParams> {
+        Some(&self.params)
+    }
+}
+
+/// Fetch an order.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#query-order-user_data>
+#[derive(Clone, Debug, Serialize)]
+pub struct FetchOrderRequest {
+    pub params: FetchOrderParams,
+}
+
+/// [`FetchOrderRequest`] query parameters.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FetchOrderParams {
+    pub symbol: String,
+    pub order_id: String,
+    pub timestamp: i64,
+}
+
+impl RestRequest for FetchOrderRequest {
+    type Response = BinanceOrder;
+    type QueryParams = FetchOrderParams;
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/api/v3/order")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+
+    fn query_params(&self) -> Option<&Self::QueryParams> {
+        Some(&self.params)
+    }
+}
+
+/// Cancel an active order.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#cancel-order-trade>
+#[derive(Clone, Debug, Serialize)]
+pub struct CancelOrderRequest {
+    pub params: CancelOrderParams,
+}
+
+/// [`CancelOrderRequest`] query parameters.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelOrderParams {
+    pub symbol: String,
+    pub order_id: String,
+    pub timestamp: i64,
+}
+
+impl RestRequest for CancelOrderRequest {
+    type Response = BinanceOrderAck;
+    type QueryParams = CancelOrderParams;
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/api/v3/order")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::DELETE
+    }
+
+    fn query_params(&self) -> Option<&Self::QueryParams> {
+        Some(&self.params)
+    }
+}
+
+/// Cancel all active orders on a symbol.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#cancel-all-open-orders-on-a-symbol-trade>
+#[derive(Clone, Debug, Serialize)]
+pub struct CancelOrdersSymbolRequest {
+    pub params: SymbolParams,
+}
+
+/// Query parameters for a [`RestRequest`] scoped to a single symbol.
+#[derive(Clone, Debug, Serialize)]
+pub struct SymbolParams {
+    pub symbol: String,
+    pub timestamp: i64,
+}
+
+impl RestRequest for CancelOrdersSymbolRequest {
+    type Response = Vec<BinanceCancelledOrder>;
+    type QueryParams = SymbolParams;
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/api/v3/openOrders")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::DELETE
+    }
+
+    fn query_params(&self) -> Option<&Self::QueryParams> {
+        Some(&self.params)
+    }
+}
+
+/// Fetch all open orders on every symbol.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#current-open-orders-user_data>
+#[derive(Copy, Clone, Debug, Serialize)]
+pub struct FetchOrdersOpenRequest {
+    pub params: TimestampParams,
+}
+
+/// Query parameters for a [`RestRequest`] that only requires the mandatory `timestamp`.
+#[derive(Copy, Clone, Debug, Serialize)]
+pub struct TimestampParams {
+    pub timestamp: i64,
+}
+
+impl TimestampParams {
+    /// Construct [`TimestampParams`] timestamped with the current time.
+    pub fn now() -> Self {
+        Self {
+            timestamp: timestamp(),
+        }
+    }
+}
+
+impl RestRequest for FetchOrdersOpenRequest {
+    type Response = Vec<BinanceOrder>;
+    type QueryParams = TimestampParams;
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/api/v3/openOrders")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+
+    fn query_params(&self) -> Option<&Self::QueryParams> {
+        Some(&self.params)
+    }
+}
+
+/// Fetch account information, including balances.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#account-information-user_data>
+#[derive(Copy, Clone, Debug, Serialize)]
+pub struct FetchAccountRequest {
+    pub params: TimestampParams,
+}
+
+impl RestRequest for FetchAccountRequest {
+    type Response = BinanceAccount;
+    type QueryParams = TimestampParams;
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/api/v3/account")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+
+    fn query_params(&self) -> Option<&Self::QueryParams> {
+        Some(&self.params)
+    }
+}
+
+/// Start a new user data stream, returning a `listenKey`.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#listen-key-spot>
+#[derive(Copy, Clone, Debug, Serialize)]
+pub struct ListenKeyCreateRequest;
+
+impl RestRequest for ListenKeyCreateRequest {
+    type Response = BinanceListenKey;
+    type QueryParams = ();
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/api/v3/userDataStream")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::POST
+    }
+}
+
+/// Keepalive a user data stream to prevent a time out. User data streams close after 60 minutes.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#listen-key-spot>
+#[derive(Clone, Debug, Serialize)]
+pub struct ListenKeyKeepaliveRequest {
+    pub params: BinanceListenKey,
+}
+
+impl RestRequest for ListenKeyKeepaliveRequest {
+    type Response = serde_json::Value;
+    type QueryParams = BinanceListenKey;
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/api/v3/userDataStream")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::PUT
+    }
+
+    fn query_params(&self) -> Option<&Self::QueryParams> {
+        Some(&self.params)
+    }
+}
+
+/// Binance `ACK` response to an order request.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#new-order-trade>
+/// ```json
+/// {
+///     "symbol": "BTCUSDT",
+///     "orderId": 28,
+///     "orderListId": -1,
+///     "clientOrderId": "6gCrw2kRUAF9CvJDGP16IP",
+///     "transactTime": 1507725176595
+/// }
+/// ```
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BinanceOrderAck {
+    pub symbol: String,
+    pub order_id: u64,
+}
+
+/// Binance response to a [`CancelReplaceOrderRequest`].
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#cancel-an-existing-order-and-send-a-new-order-trade>
+/// ```json
+/// {
+///     "cancelResult": "SUCCESS",
+///     "newOrderResult": "SUCCESS",
+///     "cancelResponse": {
+///         "symbol": "BTCUSDT",
+///         "origClientOrderId": "DnLo3vTAQcjha43lAZhZ0y",
+///         "orderId": 9,
+///         "status": "CANCELED"
+///     },
+///     "newOrderResponse": {
+///         "symbol": "BTCUSDT",
+///         "orderId": 10,
+///         "orderListId": -1,
+///         "clientOrderId": "wOceeeOzNORyLiQfw7jd8S",
+///         "transactTime": 1652928801803
+///     }
+/// }
+/// ```
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BinanceCancelReplaceResponse {
+    pub new_order_response: BinanceOrderAck,
+}
+
+/// Binance cancelled order, as returned when cancelling all open orders on a symbol.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#cancel-all-open-orders-on-a-symbol-trade>
+/// ```json
+/// {
+///     "symbol": "BTCUSDT",
+///     "origClientOrderId": "E6APeyTJvkMvLMYMqu1KQ4",
+///     "orderId": 11,
+///     "orderListId": -1,
+///     "clientOrderId": "pXLV6Hz6mprAcVYpVMTGgx",
+///     "price": "0.089853",
+///     "origQty": "0.178622",
+///     "executedQty": "0.000000",
+///     "cummulativeQuoteQty": "0.000000",
+///     "status": "CANCELED",
+///     "timeInForce": "GTC",
+///     "type": "LIMIT",
+///     "side": "BUY"
+/// }
+/// ```
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BinanceCancelledOrder {
+    pub symbol: String,
+    pub orig_client_order_id: String,
+    pub order_id: u64,
+    pub side: Side,
+}
+
+/// Binance open order.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#current-open-orders-user_data>
+/// ```json
+/// {
+///     "symbol": "LTCBTC",
+///     "orderId": 1,
+///     "orderListId": -1,
+///     "clientOrderId": "myOrder1",
+///     "price": "0.1",
+///     "origQty": "1.0",
+///     "executedQty": "0.0",
+///     "cummulativeQuoteQty": "0.0",
+///     "status": "NEW",
+///     "timeInForce": "GTC",
+///     "type": "LIMIT",
+///     "side": "BUY",
+///     "stopPrice": "0.0",
+///     "icebergQty": "0.0",
+///     "time": 1499827319559,
+///     "updateTime": 1499827319559,
+///     "isWorking": true,
+///     "origQuoteOrderQty": "0.000000"
+/// }
+/// ```
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BinanceOrder {
+    pub symbol: String,
+    pub order_id: u64,
+    pub client_order_id: String,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub price: f64,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub orig_qty: f64,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub executed_qty: f64,
+    pub time_in_force: String,
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub side: Side,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub stop_price: f64,
+}
+
+impl BinanceOrder {
+    /// Map the Binance order type & time in force to a normalised [`OrderKind`].
+    pub fn order_kind(&self) -> Option<OrderKind> {
+        match (self.kind.as_str(), self.time_in_force.as_str()) {
+            ("MARKET", _) => Some(OrderKind::Market),
+            ("LIMIT", "IOC") => Some(OrderKind::ImmediateOrCancel),
+            ("LIMIT", _) => Some(OrderKind::Limit),
+            ("LIMIT_MAKER", _) => Some(OrderKind::PostOnly),
+            ("STOP_LOSS", _) => Some(OrderKind::StopMarket),
+            ("STOP_LOSS_LIMIT", _) => Some(OrderKind::StopLimit),
+            ("TAKE_PROFIT", _) => Some(OrderKind::TakeProfit),
+            _ => None,
+        }
+    }
+}
+
+/// Binance account information.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#account-information-user_data>
+/// ```json
+/// {
+///     "makerCommission": 15,
+///     "takerCommission": 15,
+///     "canTrade": true,
+///     "accountType": "SPOT",
+///     "balances": [
+///         { "asset": "BTC", "free": "4723846.89208129", "locked": "0.00000000" },
+///         { "asset": "LTC", "free": "4763368.68006011", "locked": "0.00000000" }
+///     ],
+///     "permissions": ["SPOT"]
+/// }
+/// ```
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct BinanceAccount {
+    pub balances: Vec<BinanceBalance>,
+}
+
+/// Binance asset balance.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct BinanceBalance {
+    #[serde(alias = "a")]
+    pub asset: Symbol,
+    #[serde(alias = "f", deserialize_with = "barter_integration::de::de_str")]
+    pub free: f64,
+    #[serde(alias = "l", deserialize_with = "barter_integration::de::de_str")]
+    pub locked: f64,
+}
+
+/// Binance user data stream `listenKey`.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#listen-key-spot>
+/// ```json
+/// {
+///     "listenKey": "pqia91ma19a5s61cv6a81va65sdf19v8a65a1a5s61cv6a81va65sdf19v8a65a1"
+/// }
+/// ```
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BinanceListenKey {
+    pub listen_key: String,
+}
+
+/// Binance representation of a [`Side`].
+pub fn binance_side(side: Side) -> &'static str {
+    match side {
+        Side::Buy => "BUY",
+        Side::Sell => "SELL",
+    }
+}
+
+/// Map a normalised [`OrderKind`] to a Binance order type & time in force.
+pub fn binance_order_kind(kind: OrderKind) -> (&'static str, Option<&'static str>) {
+    match kind {
+        OrderKind::Market => ("MARKET", None),
+        OrderKind::Limit => ("LIMIT", Some("GTC")),
+        OrderKind::PostOnly => ("LIMIT_MAKER", None),
+        OrderKind::ImmediateOrCancel => ("LIMIT", Some("IOC")),
+        OrderKind::StopMarket | OrderKind::TrailingStop => ("STOP_LOSS", None),
+        OrderKind::StopLimit => ("STOP_LOSS_LIMIT", Some("GTC")),
+        OrderKind::TakeProfit => ("TAKE_PROFIT", None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_de_binance_order() {
+        let input = r#"
+        {
+            "symbol": "LTCBTC",
+            "orderId": 1,
+            "orderListId": -1,
+            "clientOrderId": "myOrder1",
+            "price": "0.1",
+            "origQty": "1.0",
+            "executedQty": "0.25",
+            "cummulativeQuoteQty": "0.0",
+            "status": "NEW",
+            "timeInForce": "GTC",
+            "type": "LIMIT",
+            "side": "BUY",
+            "stopPrice": "0.0",
+            "icebergQty": "0.0",
+            "time": 1499827319559,
+            "updateTime": 1499827319559,
+            "isWorking": true,
+            "origQuoteOrderQty": "0.000000"
+        }
+        "#;
+
+        assert_eq!(
+            serde_json::from_str::<BinanceOrder>(input).unwrap(),
+            BinanceOrder {
+                symbol: "LTCBTC".to_string(),
+                order_id: 1,
+                client_order_id: "myOrder1".to_string(),
+                price: 0.1,
+                orig_qty: 1.0,
+                executed_qty: 0.25,
+                time_in_force: "GTC".to_string(),
+                kind: "LIMIT".to_string(),
+                side: Side::Buy,
+                stop_price: 0.0,
+            }
+        );
+    }
+
+    #[test]
+    fn test_cancel_replace_order_request_keeps_order_kind() {
+        struct TestCase {
+            kind: &'static str,
+            time_in_force: &'static str,
+            quantity: f64,
+            expected: Result<(&'static str, Option<&'static str>, &'static str), ExecutionError>,
+        }
+
+        let cid = crate::model::ClientOrderId(uuid::Uuid::nil());
+
+        let tests = vec![
+            TestCase {
+                // TC0: Limit order is replaced with the remaining unfilled quantity
+                kind: "LIMIT",
+                time_in_force: "GTC",
+                quantity: 2.0,
+                expected: Ok(("LIMIT", Some("GTC"), "1.75")),
+            },
+            TestCase {
+                // TC1: PostOnly order remains LIMIT_MAKER
+                kind: "LIMIT_MAKER",
+                time_in_force: "GTC",
+                quantity: 2.0,
+                expected: Ok(("LIMIT_MAKER", None, "1.75")),
+            },
+            TestCase {
+                // TC2: ImmediateOrCancel order remains IOC
+                kind: "LIMIT",
+                time_in_force: "IOC",
+                quantity: 2.0,
+                expected: Ok(("LIMIT", Some("IOC"), "1.75")),
+            },
+            TestCase {
+                // TC3: StopLimit order keeps it's stop price
+                kind: "STOP_LOSS_LIMIT",
+                time_in_force: "GTC",
+                quantity: 2.0,
+                expected: Ok(("STOP_LOSS_LIMIT", Some("GTC"), "1.75")),
+            },
+            TestCase {
+                // TC4: StopMarket order has no limit price to amend
+                kind: "STOP_LOSS",
+                time_in_force: "GTC",
+                quantity: 2.0,
+                expected: Err(ExecutionError::UnsupportedOrderKind(OrderKind::StopMarket)),
+            },
+            TestCase {
+                // TC5: Amended quantity that is already filled is rejected
+                kind: "LIMIT",
+                time_in_force: "GTC",
+                quantity: 0.25,
+                expected: Err(ExecutionError::AmendQuantityFilled(cid)),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let original = BinanceOrder {
+                symbol: "BTCUSDT".to_string(),
+                order_id: 1,
+                client_order_id: cid.to_string(),
+                price: 100.0,
+                orig_qty: 1.0,
+                executed_qty: 0.25,
+                time_in_force: test.time_in_force.to_string(),
+                kind: test.kind.to_string(),
+                side: Side::Buy,
+                stop_price: 95.0,
+            };
+
+            let request = Order {
+                exchange: barter_integration::model::Exchange::from("binance_spot"),
+                instrument: barter_integration::model::instrument::Instrument::from((
+                    "btc",
+                    "usdt",
+                    barter_integration::model::instrument::kind::InstrumentKind::Spot,
+                )),
+                cid,
+                side: Side::Buy,
+                state: RequestAmend {
+                    id: crate::model::order::OrderId::from("1"),
+                    price: 101.0,
+                    quantity: test.quantity,
+                },
+            };
+
+            let actual = CancelReplaceOrderRequest::new(&request, &original);
+            match (actual, test.expected) {
+                (Ok(actual), Ok((kind, time_in_force, quantity))) => {
+                    assert_eq!(actual.params.kind, kind, "TC{} failed", index);
+                    assert_eq!(
+                        actual.params.time_in_force, time_in_force,
+                        "TC{} failed",
+                        index
+                    );
+                    assert_eq!(actual.params.quantity, quantity, "TC{} failed", index);
+                    assert_eq!(actual.params.price, "101", "TC{} failed", index);
+                    assert_eq!(
+                        actual.params.stop_price.is_some(),
+                        kind == "STOP_LOSS_LIMIT",
+                        "TC{} failed",
+                        index
+                    );
+                }
+                (Err(actual), Err(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index);
+                }
+                (actual, expected) => {
+                    panic!("TC{index} failed: actual: {actual:?}, expected: {expected:?}")
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_de_binance_account() {
+        let input = r#"
+        {
+            "makerCommission": 15,
+            "canTrade": true,
+            "balances": [
+                { "asset": "BTC", "free": "1.5", "locked": "0.5" }
+            ]
+        }
+        "#;
+
+        assert_eq!(
+            serde_json::from_str::<BinanceAccount>(input).unwrap(),
+            BinanceAccount {
+                balances: vec![BinanceBalance {
+                    asset: Symbol::from("btc"),
+                    free: 1.5,
+                    locked: 0.5,
+                }]
+            }
+        );
+    }
+}
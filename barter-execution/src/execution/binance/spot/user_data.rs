@@ -0,0 +1,363 @@
+use super::{parse_cid, request::BinanceBalance};
+use crate::{
+    model::{
+        balance::{Balance, SymbolBalance},
+        order::{Cancelled, Open, Order, OrderId},
+        trade::{SymbolFees, Trade, TradeId},
+        AccountEvent, AccountEventKind,
+    },
+    ExecutionId,
+};
+use barter_integration::model::{
+    instrument::{symbol::Symbol, Instrument},
+    Exchange, Side,
+};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tracing::debug;
+
+/// [`BinanceSpot`](super::BinanceSpot) user data stream WebSocket base url.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#user-data-streams>
+pub const WEBSOCKET_BASE_URL_BINANCE_SPOT_USER_DATA: &str = "wss://stream.binance.com:9443/ws";
+
+/// [`BinanceSpot`](super::BinanceSpot) user data stream message.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#user-data-streams>
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(tag = "e")]
+pub enum BinanceUserData {
+    #[serde(rename = "executionReport")]
+    ExecutionReport(BinanceExecutionReport),
+    #[serde(rename = "outboundAccountPosition")]
+    AccountPosition(BinanceAccountPosition),
+    #[serde(rename = "listenKeyExpired")]
+    ListenKeyExpired,
+    #[serde(other)]
+    Other,
+}
+
+/// [`BinanceSpot`](super::BinanceSpot) order update.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#order-update>
+/// ```json
+/// {
+///     "e": "executionReport",
+///     "E": 1499405658658,
+///     "s": "ETHBTC",
+///     "c": "mUvoqJxFIILMdfAW5iGSOW",
+///     "S": "BUY",
+///     "o": "LIMIT",
+///     "f": "GTC",
+///     "q": "1.00000000",
+///     "p": "0.10264410",
+///     "C": "",
+///     "x": "TRADE",
+///     "X": "PARTIALLY_FILLED",
+///     "i": 4293153,
+///     "l": "0.50000000",
+///     "z": "0.50000000",
+///     "L": "0.10264410",
+///     "n": "0.00050000",
+///     "N": "ETH",
+///     "T": 1499405658657,
+///     "t": 1042
+/// }
+/// ```
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct BinanceExecutionReport {
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "c")]
+    pub client_order_id: String,
+    #[serde(rename = "C", default)]
+    pub orig_client_order_id: String,
+    #[serde(rename = "S")]
+    pub side: Side,
+    #[serde(rename = "q", deserialize_with = "barter_integration::de::de_str")]
+    pub quantity: f64,
+    #[serde(rename = "p", deserialize_with = "barter_integration::de::de_str")]
+    pub price: f64,
+    #[serde(rename = "x")]
+    pub execution_kind: BinanceExecutionKind,
+    #[serde(rename = "i")]
+    pub order_id: u64,
+    #[serde(rename = "l", deserialize_with = "barter_integration::de::de_str")]
+    pub last_quantity: f64,
+    #[serde(rename = "z", deserialize_with = "barter_integration::de::de_str")]
+    pub cumulative_quantity: f64,
+    #[serde(rename = "L", deserialize_with = "barter_integration::de::de_str")]
+    pub last_price: f64,
+    #[serde(rename = "n", deserialize_with = "barter_integration::de::de_str")]
+    pub commission: f64,
+    #[serde(rename = "N")]
+    pub commission_asset: Option<Symbol>,
+    #[serde(rename = "t")]
+    pub trade_id: i64,
+}
+
+/// [`BinanceExecutionReport`] execution type.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#public-api-definitions>
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum BinanceExecutionKind {
+    New,
+    Canceled,
+    Replaced,
+    Rejected,
+    Trade,
+    Expired,
+    TradePrevention,
+}
+
+/// [`BinanceSpot`](super::BinanceSpot) account balance update, sent whenever an account
+/// balance has changed.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#account-update>
+/// ```json
+/// {
+///     "e": "outboundAccountPosition",
+///     "E": 1564034571105,
+///     "u": 1564034571073,
+///     "B": [
+///         { "a": "ETH", "f": "10000.000000", "l": "0.000000" }
+///     ]
+/// }
+/// ```
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct BinanceAccountPosition {
+    #[serde(rename = "B")]
+    pub balances: Vec<BinanceBalance>,
+}
+
+impl BinanceUserData {
+    /// Map this [`BinanceUserData`] into a normalised [`AccountEvent`], if relevant.
+    ///
+    /// Symbols that do not map to one of the provided [`Instrument`]s, and orders that were not
+    /// opened with a Barter [`ClientOrderId`](crate::model::ClientOrderId), are ignored.
+    pub fn into_account_event(
+        self,
+        instruments: &HashMap<String, Instrument>,
+    ) -> Option<AccountEvent> {
+        let kind = match self {
+            BinanceUserData::ExecutionReport(report) => {
+                let Some(instrument) = instruments.get(&report.symbol).cloned() else {
+                    debug!(symbol = %report.symbol, "ignoring executionReport for unrecognised symbol");
+                    return None;
+                };
+                report.into_account_event_kind(instrument)?
+            }
+            BinanceUserData::AccountPosition(position) => AccountEventKind::Balances(
+                position
+                    .balances
+                    .into_iter()
+                    .map(SymbolBalance::from)
+                    .collect(),
+            ),
+            BinanceUserData::ListenKeyExpired | BinanceUserData::Other => return None,
+        };
+
+        Some(AccountEvent {
+            received_time: Utc::now(),
+            exchange: Exchange::from(ExecutionId::BinanceSpot),
+            kind,
+        })
+    }
+}
+
+impl BinanceExecutionReport {
+    /// Map this [`BinanceExecutionReport`] into the associated [`AccountEventKind`], if relevant.
+    pub fn into_account_event_kind(self, instrument: Instrument) -> Option<AccountEventKind> {
+        let exchange = Exchange::from(ExecutionId::BinanceSpot);
+
+        match self.execution_kind {
+            BinanceExecutionKind::New => Some(AccountEventKind::OrdersNew(vec![Order {
+                exchange,
+                instrument,
+                cid: parse_cid(&self.client_order_id)?,
+                side: self.side,
+                state: Open {
+                    id: OrderId::from(self.order_id),
+                    price: self.price,
+                    quantity: self.quantity,
+                    filled_quantity: self.cumulative_quantity,
+                },
+            }])),
+            BinanceExecutionKind::Canceled | BinanceExecutionKind::Expired => {
+                // Cancelled orders report the original ClientOrderId in the "C" field
+                let cid = match self.orig_client_order_id.is_empty() {
+                    true => parse_cid(&self.client_order_id)?,
+                    false => parse_cid(&self.orig_client_order_id)?,
+                };
+
+                Some(AccountEventKind::OrdersCancelled(vec![Order {
+                    exchange,
+                    instrument,
+                    cid,
+                    side: self.side,
+                    state: Cancelled::from(self.order_id),
+                }]))
+            }
+            BinanceExecutionKind::Trade => {
+                // Binance reports a null commission asset if no commission was charged
+                let fees_symbol = self
+                    .commission_asset
+                    .unwrap_or_else(|| instrument.quote.clone());
+
+                Some(AccountEventKind::Trade(Trade {
+                    id: TradeId::from(self.trade_id.to_string()),
+                    order_id: OrderId::from(self.order_id),
+                    instrument,
+                    side: self.side,
+                    price: self.last_price,
+                    quantity: self.last_quantity,
+                    fees: SymbolFees::new(fees_symbol, self.commission),
+                }))
+            }
+            BinanceExecutionKind::Replaced
+            | BinanceExecutionKind::Rejected
+            | BinanceExecutionKind::TradePrevention => None,
+        }
+    }
+}
+
+impl From<BinanceBalance> for SymbolBalance {
+    fn from(balance: BinanceBalance) -> Self {
+        SymbolBalance::new(
+            balance.asset,
+            Balance::new(balance.free + balance.locked, balance.free),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::ClientOrderId;
+    use barter_integration::model::instrument::kind::InstrumentKind;
+    use uuid::Uuid;
+
+    fn instruments() -> HashMap<String, Instrument> {
+        HashMap::from([(
+            "ETHBTC".to_string(),
+            Instrument::from(("eth", "btc", InstrumentKind::Spot)),
+        )])
+    }
+
+    #[test]
+    fn test_de_binance_user_data_execution_report_trade() {
+        let cid = Uuid::new_v4();
+        let input = format!(
+            r#"
+            {{
+                "e": "executionReport", "E": 1499405658658, "s": "ETHBTC", "c": "{cid}",
+                "S": "BUY", "o": "LIMIT", "f": "GTC", "q": "1.00000000", "p": "0.10264410",
+                "P": "0.00000000", "F": "0.00000000", "g": -1, "C": "", "x": "TRADE",
+                "X": "PARTIALLY_FILLED", "r": "NONE", "i": 4293153, "l": "0.50000000",
+                "z": "0.50000000", "L": "0.10264410", "n": "0.00050000", "N": "ETH",
+                "T": 1499405658657, "t": 1042, "I": 8641984, "w": false, "m": false,
+                "M": false, "O": 1499405658657, "Z": "0.05132205", "Y": "0.05132205",
+                "Q": "0.00000000"
+            }}
+            "#
+        );
+
+        let event = serde_json::from_str::<BinanceUserData>(&input)
+            .unwrap()
+            .into_account_event(&instruments())
+            .unwrap();
+
+        match event.kind {
+            AccountEventKind::Trade(trade) => assert_eq!(
+                trade,
+                Trade {
+                    id: TradeId::from("1042"),
+                    order_id: OrderId::from(4293153),
+                    instrument: Instrument::from(("eth", "btc", InstrumentKind::Spot)),
+                    side: Side::Buy,
+                    price: 0.10264410,
+                    quantity: 0.5,
+                    fees: SymbolFees::new("eth", 0.0005),
+                }
+            ),
+            other => panic!("expected AccountEventKind::Trade, but received: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_de_binance_user_data_execution_report_cancelled() {
+        let cid = Uuid::new_v4();
+        let input = format!(
+            r#"
+            {{
+                "e": "executionReport", "E": 1499405658658, "s": "ETHBTC", "c": "cancel_id",
+                "S": "SELL", "o": "LIMIT", "f": "GTC", "q": "1.00000000", "p": "0.10264410",
+                "C": "{cid}", "x": "CANCELED", "X": "CANCELED", "i": 4293153, "l": "0.00000000",
+                "z": "0.00000000", "L": "0.00000000", "n": "0", "N": null, "T": 1499405658657,
+                "t": -1
+            }}
+            "#
+        );
+
+        let event = serde_json::from_str::<BinanceUserData>(&input)
+            .unwrap()
+            .into_account_event(&instruments())
+            .unwrap();
+
+        match event.kind {
+            AccountEventKind::OrdersCancelled(cancelled) => assert_eq!(
+                cancelled,
+                vec![Order {
+                    exchange: Exchange::from(ExecutionId::BinanceSpot),
+                    instrument: Instrument::from(("eth", "btc", InstrumentKind::Spot)),
+                    cid: ClientOrderId(cid),
+                    side: Side::Sell,
+                    state: Cancelled::from(4293153),
+                }]
+            ),
+            other => panic!("expected AccountEventKind::OrdersCancelled, but received: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_de_binance_user_data_account_position() {
+        let input = r#"
+        {
+            "e": "outboundAccountPosition", "E": 1564034571105, "u": 1564034571073,
+            "B": [{ "a": "ETH", "f": "8.0", "l": "2.0" }]
+        }
+        "#;
+
+        let event = serde_json::from_str::<BinanceUserData>(input)
+            .unwrap()
+            .into_account_event(&instruments())
+            .unwrap();
+
+        match event.kind {
+            AccountEventKind::Balances(balances) => assert_eq!(
+                balances,
+                vec![SymbolBalance::new("eth", Balance::new(10.0, 8.0))]
+            ),
+            other => panic!("expected AccountEventKind::Balances, but received: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_de_binance_user_data_other() {
+        let input = r#"{ "e": "listenKeyExpired", "E": 1576653824250 }"#;
+        assert_eq!(
+            serde_json::from_str::<BinanceUserData>(input).unwrap(),
+            BinanceUserData::ListenKeyExpired
+        );
+
+        let input = r#"{ "e": "balanceUpdate", "E": 1573200697110, "a": "BTC", "d": "100.0" }"#;
+        assert_eq!(
+            serde_json::from_str::<BinanceUserData>(input).unwrap(),
+            BinanceUserData::Other
+        );
+    }
+}
@@ -1,4 +1,4 @@
-/// `Binance` & `BinanceFuturesUsd` [`ExecutionClient`](crate::ExecutionClient) implementations.
+/// `Binance` [`ExecutionClient`](crate::ExecutionClient) implementations.
 pub mod binance;
 
 /// `Ftx` [`ExecutionClient`](crate::ExecutionClient) implementation.
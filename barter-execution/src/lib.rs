@@ -13,11 +13,11 @@
 //! `ExecutionClient` instance.
 //! **It is:**
 //! * **Easy**: ExecutionClient trait provides a unified and simple language for interacting with
-//! exchanges.
+//!   exchanges.
 //! * **Normalised**: Allow your strategy to communicate with every real or simulated exchange
-//! using the same interface.
+//!   using the same interface.
 //! * **Extensible**: Barter-Execution is highly extensible, making it easy to contribute by adding
-//! new exchange integrations!
+//!   new exchange integrations!
 //!
 //! See `README.md` for more information and examples.
 
@@ -25,7 +25,7 @@ use crate::{
     error::ExecutionError,
     model::{
         balance::SymbolBalance,
-        order::{Cancelled, Open, Order, OrderId, RequestCancel, RequestOpen},
+        order::{Cancelled, Open, Order, OrderId, RequestAmend, RequestCancel, RequestOpen},
         AccountEvent,
     },
 };
@@ -76,6 +76,12 @@ pub trait ExecutionClient {
         open_requests: Vec<Order<RequestOpen>>,
     ) -> Vec<Result<Order<Open>, ExecutionError>>;
 
+    /// Amend the price and/or quantity of [`Order<Open>`]s.
+    async fn amend_orders(
+        &self,
+        amend_requests: Vec<Order<RequestAmend>>,
+    ) -> Vec<Result<Order<Open>, ExecutionError>>;
+
     /// Cancel [`Order<Open>`]s.
     async fn cancel_orders(
         &self,
@@ -92,6 +98,7 @@ pub trait ExecutionClient {
 pub enum ExecutionId {
     Simulated,
     Ftx,
+    BinanceSpot,
 }
 
 impl From<ExecutionId> for Exchange {
@@ -111,6 +118,7 @@ impl ExecutionId {
         match self {
             ExecutionId::Simulated => "simulated",
             ExecutionId::Ftx => "ftx",
+            ExecutionId::BinanceSpot => "binance_spot",
         }
     }
 }
@@ -122,7 +130,10 @@ pub mod test_util {
             trade::{SymbolFees, Trade, TradeId},
             ClientOrderId,
         },
-        simulated::exchange::account::order::Orders,
+        simulated::exchange::account::{
+            fees::FeeCurrency,
+            order::{Liquidity, Orders, QueueModel},
+        },
         Open, Order, OrderId,
     };
     use barter_data::subscription::trade::PublicTrade;
@@ -130,6 +141,7 @@ pub mod test_util {
         instrument::{kind::InstrumentKind, Instrument},
         Exchange, Side,
     };
+    use std::collections::HashMap;
 
     pub fn client_orders(
         trade_number: u64,
@@ -140,6 +152,12 @@ pub mod test_util {
             trade_counter: trade_number,
             bids,
             asks,
+            liquidity: Liquidity::default(),
+            last_trade_price: None,
+            queue_model: QueueModel::None,
+            queue_ahead: HashMap::new(),
+            fee_currency: FeeCurrency::Received,
+            conditional: vec![],
         }
     }
 
@@ -28,11 +28,13 @@ pub enum AccountEventKind {
     // HTTP Only
     OrdersOpen(Vec<Order<Open>>),
     OrdersNew(Vec<Order<Open>>),
+    OrdersAmended(Vec<Order<Open>>),
     OrdersCancelled(Vec<Order<Cancelled>>),
 
     // WebSocket Only
     Balance(SymbolBalance),
     Trade(Trade),
+    OrdersTriggered(Vec<Order<Open>>),
 
     // HTTP & WebSocket
     Balances(Vec<SymbolBalance>),
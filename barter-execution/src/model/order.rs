@@ -10,12 +10,44 @@ use std::{
 };
 
 /// Type of [`Order`].
+///
+/// Conditional kinds (eg/ [`OrderKind::StopMarket`]) require an [`OrderTrigger`], and are held
+/// untriggered until the market price crosses it.
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
 pub enum OrderKind {
     Market,
     Limit,
     PostOnly,
     ImmediateOrCancel,
+    StopMarket,
+    StopLimit,
+    TakeProfit,
+    TrailingStop,
+}
+
+impl OrderKind {
+    /// Determine if the [`OrderKind`] is conditional, requiring an [`OrderTrigger`].
+    pub fn is_conditional(&self) -> bool {
+        matches!(
+            self,
+            OrderKind::StopMarket
+                | OrderKind::StopLimit
+                | OrderKind::TakeProfit
+                | OrderKind::TrailingStop
+        )
+    }
+
+    /// [`OrderKind`] that a conditional [`OrderKind`] is executed as once triggered. Returns self
+    /// for non-conditional [`OrderKind`]s.
+    pub fn activated(&self) -> OrderKind {
+        match self {
+            OrderKind::StopLimit => OrderKind::Limit,
+            OrderKind::StopMarket | OrderKind::TakeProfit | OrderKind::TrailingStop => {
+                OrderKind::Market
+            }
+            kind => *kind,
+        }
+    }
 }
 
 impl Display for OrderKind {
@@ -28,6 +60,10 @@ impl Display for OrderKind {
                 OrderKind::Limit => "limit",
                 OrderKind::PostOnly => "post_only",
                 OrderKind::ImmediateOrCancel => "immediate_or_cancel",
+                OrderKind::StopMarket => "stop_market",
+                OrderKind::StopLimit => "stop_limit",
+                OrderKind::TakeProfit => "take_profit",
+                OrderKind::TrailingStop => "trailing_stop",
             }
         )
     }
@@ -50,6 +86,31 @@ pub struct RequestOpen {
     pub kind: OrderKind,
     pub price: f64,
     pub quantity: f64,
+    pub trigger: Option<OrderTrigger>,
+}
+
+/// Trigger condition of a conditional [`OrderKind`].
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub enum OrderTrigger {
+    /// Trigger price of [`OrderKind::StopMarket`], [`OrderKind::StopLimit`] &
+    /// [`OrderKind::TakeProfit`] orders.
+    Price(f64),
+    /// Offset from the best price traded since opening an [`OrderKind::TrailingStop`] order, in
+    /// decimal form (eg/ 0.01 for 1%).
+    Trailing { offset_percent: f64 },
+}
+
+impl OrderTrigger {
+    /// Determine if the [`OrderTrigger`] is valid for the provided [`OrderKind`].
+    pub fn is_valid_for(&self, kind: OrderKind) -> bool {
+        match self {
+            OrderTrigger::Price(_) => matches!(
+                kind,
+                OrderKind::StopMarket | OrderKind::StopLimit | OrderKind::TakeProfit
+            ),
+            OrderTrigger::Trailing { .. } => kind == OrderKind::TrailingStop,
+        }
+    }
 }
 
 impl Order<RequestOpen> {
@@ -84,6 +145,16 @@ where
     }
 }
 
+/// State of an [`Order`] after a request has been made for it's price and/or quantity to be
+/// amended. The `quantity` is the new total quantity of the [`Order`], including any quantity
+/// that has already been filled.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct RequestAmend {
+    pub id: OrderId,
+    pub price: f64,
+    pub quantity: f64,
+}
+
 /// Todo:
 #[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
 pub struct Open {
@@ -112,6 +183,8 @@ impl Ord for Order<Open> {
     }
 }
 
+// Ord panics on incomparable (eg/ NaN) prices, so PartialOrd holds the comparison logic
+#[allow(clippy::non_canonical_partial_ord_impl)]
 impl PartialOrd for Order<Open> {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         match (self.side, other.side) {
@@ -60,6 +60,30 @@ impl ClientBalances {
         }
     }
 
+    /// Determine if the client has sufficient available [`Balance`] to cover every required
+    /// amount, summing the amounts required of the same [`Symbol`].
+    pub fn has_sufficient_available_balances<'a>(
+        &self,
+        required: impl IntoIterator<Item = (&'a Symbol, f64)>,
+    ) -> Result<(), ExecutionError> {
+        let mut totals: Vec<(&Symbol, f64)> = Vec::new();
+        for (symbol, required_balance) in required {
+            match totals
+                .iter_mut()
+                .find(|(total_symbol, _)| *total_symbol == symbol)
+            {
+                Some((_, total)) => *total += required_balance,
+                None => totals.push((symbol, required_balance)),
+            }
+        }
+
+        totals
+            .into_iter()
+            .try_for_each(|(symbol, required_balance)| {
+                self.has_sufficient_available_balance(symbol, required_balance)
+            })
+    }
+
     /// Updates the associated [`Symbol`] [`Balance`] when a client creates an [`Order<Open>`]. The
     /// nature of the [`Balance`] change will depend on if the [`Order<Open>`] is a
     /// [`Side::Buy`] or [`Side::Sell`].
@@ -90,6 +114,24 @@ impl ClientBalances {
         }
     }
 
+    /// Updates the associated [`Symbol`] [`Balance`] when a client amends an [`Order<Open>`],
+    /// reserving (or releasing) the difference between the amended and original [`Order<Open>`]
+    /// required balance.
+    pub fn update_from_amend(
+        &mut self,
+        original: &Order<Open>,
+        amended: &Order<Open>,
+    ) -> SymbolBalance {
+        let (symbol, required_delta) = amend_required_balance_delta(original, amended);
+
+        let balance = self
+            .balance_mut(symbol)
+            .expect("Balance existence checked when opening Order");
+
+        balance.available -= required_delta;
+        SymbolBalance::new(symbol.clone(), *balance)
+    }
+
     /// Updates the associated [`Symbol`] [`Balance`] when a client cancels an [`Order<Open>`]. The
     /// nature of the [`Balance`] change will depend on if the [`Order<Open>`] was a
     /// [`Side::Buy`] or [`Side::Sell`].
@@ -123,17 +165,21 @@ impl ClientBalances {
     ///
     /// A [`Side::Sell`] match causes the [`Symbol`] [`Balance`] of the base to decrease by the
     /// `trade_quantity`, and the quote to increase by the `trade_quantity * price`.
-    pub fn update_from_trade(&mut self, trade: &Trade) -> AccountEvent {
+    ///
+    /// The trade fees are deducted from the total [`Balance`] of the fees [`Symbol`], which may be
+    /// the base, quote, or a third [`Symbol`]. The available [`Balance`] is only reduced by the
+    /// fees that exceed the `fees_reserved` when the order was opened, and is increased by any
+    /// unused reserve.
+    pub fn update_from_trade(&mut self, trade: &Trade, fees_reserved: f64) -> AccountEvent {
         let Instrument { base, quote, .. } = &trade.instrument;
 
         // Calculate the base & quote Balance deltas
-        let (base_delta, quote_delta) = match trade.side {
+        let (mut base_delta, mut quote_delta) = match trade.side {
             Side::Buy => {
-                // Base total & available increase by trade.quantity minus base trade.fees
-                let base_increase = trade.quantity - trade.fees.fees;
+                // Base total & available increase by trade.quantity
                 let base_delta = BalanceDelta {
-                    total: base_increase,
-                    available: base_increase,
+                    total: trade.quantity,
+                    available: trade.quantity,
                 };
 
                 // Quote total decreases by (trade.quantity * price)
@@ -153,8 +199,8 @@ impl ClientBalances {
                     available: 0.0,
                 };
 
-                // Quote total & available increase by (trade.quantity * price) minus quote fees
-                let quote_increase = (trade.quantity * trade.price) - trade.fees.fees;
+                // Quote total & available increase by (trade.quantity * price)
+                let quote_increase = trade.quantity * trade.price;
                 let quote_delta = BalanceDelta {
                     total: quote_increase,
                     available: quote_increase,
@@ -164,6 +210,26 @@ impl ClientBalances {
             }
         };
 
+        // Fees Symbol total decreases by trade.fees, and available by any fees not reserved
+        let fees = trade.fees.fees;
+        let fees_available = fees - fees_reserved;
+        let fees_balance = match &trade.fees.symbol {
+            symbol if symbol == base => {
+                base_delta.total -= fees;
+                base_delta.available -= fees_available;
+                None
+            }
+            symbol if symbol == quote => {
+                quote_delta.total -= fees;
+                quote_delta.available -= fees_available;
+                None
+            }
+            symbol => Some(SymbolBalance::new(
+                symbol.clone(),
+                self.update(symbol, BalanceDelta::new(-fees, -fees_available)),
+            )),
+        };
+
         // Apply BalanceDelta & return updated Balance
         let base_balance = self.update(base, base_delta);
         let quote_balance = self.update(quote, quote_delta);
@@ -171,10 +237,15 @@ impl ClientBalances {
         AccountEvent {
             received_time: Utc::now(),
             exchange: Exchange::from(ExecutionId::Simulated),
-            kind: AccountEventKind::Balances(vec![
-                SymbolBalance::new(base.clone(), base_balance),
-                SymbolBalance::new(quote.clone(), quote_balance),
-            ]),
+            kind: AccountEventKind::Balances(
+                [
+                    SymbolBalance::new(base.clone(), base_balance),
+                    SymbolBalance::new(quote.clone(), quote_balance),
+                ]
+                .into_iter()
+                .chain(fees_balance)
+                .collect(),
+            ),
         }
     }
 
@@ -189,6 +260,25 @@ impl ClientBalances {
     }
 }
 
+/// Calculate the change in required available [`Balance`] between an original [`Order<Open>`] and
+/// it's amended equivalent. Positive values require additional balance to be reserved.
+pub fn amend_required_balance_delta<'a>(
+    original: &'a Order<Open>,
+    amended: &Order<Open>,
+) -> (&'a Symbol, f64) {
+    match original.side {
+        Side::Buy => (
+            &original.instrument.quote,
+            amended.state.price * amended.state.remaining_quantity()
+                - original.state.price * original.state.remaining_quantity(),
+        ),
+        Side::Sell => (
+            &original.instrument.base,
+            amended.state.remaining_quantity() - original.state.remaining_quantity(),
+        ),
+    }
+}
+
 impl std::ops::Deref for ClientBalances {
     type Target = HashMap<Symbol, Balance>;
 
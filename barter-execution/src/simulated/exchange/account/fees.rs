@@ -0,0 +1,340 @@
+use crate::model::trade::SymbolFees;
+use barter_integration::model::{
+    instrument::{symbol::Symbol, Instrument},
+    Side,
+};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+
+/// Maker & taker fee schedule used by the [`ClientAccount`](super::ClientAccount) to calculate the
+/// [`SymbolFees`] of every simulated [`Trade`](crate::model::trade::Trade).
+///
+/// Rates are selected from volume [`FeeTier`]s using the rolling [`TradeVolume`] of the account,
+/// with optional per-[`Instrument`] [`FeeTier`] overrides.
+#[derive(Clone, PartialEq, Debug, Default, Deserialize, Serialize)]
+pub struct FeeSchedule {
+    pub tiers: Vec<FeeTier>,
+    pub instruments: HashMap<Instrument, Vec<FeeTier>>,
+    pub currency: FeeCurrency,
+}
+
+impl FeeSchedule {
+    /// Construct a new [`FeeSchedule`] from the provided default volume [`FeeTier`]s.
+    pub fn new(tiers: Vec<FeeTier>) -> Self {
+        Self {
+            tiers,
+            instruments: HashMap::new(),
+            currency: FeeCurrency::default(),
+        }
+    }
+
+    /// Construct a new [`FeeSchedule`] that applies the same rate to every maker & taker trade,
+    /// regardless of volume.
+    pub fn flat(fees_percent: f64) -> Self {
+        Self::new(vec![FeeTier::new(0.0, fees_percent, fees_percent)])
+    }
+
+    /// Override the volume [`FeeTier`]s used for the provided [`Instrument`].
+    pub fn with_instrument(mut self, instrument: Instrument, tiers: Vec<FeeTier>) -> Self {
+        self.instruments.insert(instrument, tiers);
+        self
+    }
+
+    /// Set the [`FeeCurrency`] that trade fees are denominated in.
+    pub fn with_currency(mut self, currency: FeeCurrency) -> Self {
+        self.currency = currency;
+        self
+    }
+
+    /// Determine the [`FeeRates`] for the provided [`Instrument`] given the rolling trade volume
+    /// of the account.
+    ///
+    /// The [`FeeTier`] with the highest `min_volume` that the volume satisfies is selected. If no
+    /// [`FeeTier`] is satisfied, no fees are charged.
+    pub fn rates(&self, instrument: &Instrument, volume: f64) -> FeeRates {
+        self.instruments
+            .get(instrument)
+            .unwrap_or(&self.tiers)
+            .iter()
+            .filter(|tier| tier.min_volume <= volume)
+            .max_by(|a, b| a.min_volume.total_cmp(&b.min_volume))
+            .map(|tier| tier.rates)
+            .unwrap_or_default()
+    }
+}
+
+/// Maker & taker rates applied once the rolling trade volume of the account (denominated in the
+/// quote asset) reaches the `min_volume`.
+#[derive(Copy, Clone, PartialEq, Debug, Default, Deserialize, Serialize)]
+pub struct FeeTier {
+    pub min_volume: f64,
+    pub rates: FeeRates,
+}
+
+impl FeeTier {
+    /// Construct a new [`FeeTier`].
+    pub fn new(min_volume: f64, maker: f64, taker: f64) -> Self {
+        Self {
+            min_volume,
+            rates: FeeRates { maker, taker },
+        }
+    }
+}
+
+/// Maker & taker fee rates in decimal form (eg/ 0.001 for 0.1%).
+///
+/// Maker rates apply to resting orders that are matched, and taker rates to marketable orders that
+/// take liquidity upon opening.
+#[derive(Copy, Clone, PartialEq, Debug, Default, Deserialize, Serialize)]
+pub struct FeeRates {
+    pub maker: f64,
+    pub taker: f64,
+}
+
+/// Currency that simulated trade fees are denominated in.
+#[derive(Clone, PartialEq, Debug, Default, Deserialize, Serialize)]
+pub enum FeeCurrency {
+    /// Asset received from the trade - base for [`Side::Buy`], and quote for [`Side::Sell`].
+    #[default]
+    Received,
+    Base,
+    Quote,
+    /// Third [`Symbol`] (eg/ an exchange token), converted from the quote asset using the
+    /// provided price of one unit of the [`Symbol`] in the quote asset.
+    Symbol {
+        symbol: Symbol,
+        quote_price: f64,
+    },
+}
+
+impl FeeCurrency {
+    /// Calculate the [`SymbolFees`] of a trade with the provided [`Side`], price & quantity.
+    pub fn fees(
+        &self,
+        instrument: &Instrument,
+        side: Side,
+        price: f64,
+        quantity: f64,
+        fees_percent: f64,
+    ) -> SymbolFees {
+        let fees_base = fees_percent * quantity;
+        let fees_quote = fees_base * price;
+
+        match (self, side) {
+            (Self::Received, Side::Buy) | (Self::Base, _) => {
+                SymbolFees::new(instrument.base.clone(), fees_base)
+            }
+            (Self::Received, Side::Sell) | (Self::Quote, _) => {
+                SymbolFees::new(instrument.quote.clone(), fees_quote)
+            }
+            (
+                Self::Symbol {
+                    symbol,
+                    quote_price,
+                },
+                _,
+            ) => SymbolFees::new(symbol.clone(), fees_quote / quote_price),
+        }
+    }
+
+    /// Determine the [`FeeReserve`] required per unit of quantity for a resting order with the
+    /// provided [`Side`] & price.
+    ///
+    /// Fees denominated in the asset received from the trade are deducted from it's proceeds, so
+    /// require no reserve.
+    pub fn reserve(
+        &self,
+        instrument: &Instrument,
+        side: Side,
+        price: f64,
+        fees_percent: f64,
+    ) -> Option<FeeReserve> {
+        let received = match side {
+            Side::Buy => &instrument.base,
+            Side::Sell => &instrument.quote,
+        };
+
+        let fees = self.fees(instrument, side, price, 1.0, fees_percent);
+        (fees.symbol != *received).then_some(FeeReserve {
+            symbol: fees.symbol,
+            per_unit: fees.fees,
+        })
+    }
+}
+
+/// Fees reserved from the available [`Balance`](crate::model::balance::Balance) of a resting
+/// order, since they cannot be deducted from the asset it receives. The reserve is released as
+/// the order is filled, amended, or cancelled.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct FeeReserve {
+    pub symbol: Symbol,
+    /// Fees reserved per unit of remaining order quantity.
+    pub per_unit: f64,
+}
+
+/// Rolling trade volume of the account (denominated in the quote asset), used to determine the
+/// [`FeeTier`] of the [`FeeSchedule`]. Defaults to a 30 day window.
+#[derive(Clone, PartialEq, Debug)]
+pub struct TradeVolume {
+    pub window: Duration,
+    pub trades: VecDeque<(DateTime<Utc>, f64)>,
+}
+
+impl Default for TradeVolume {
+    fn default() -> Self {
+        Self::new(Duration::days(30))
+    }
+}
+
+impl TradeVolume {
+    /// Construct a new [`TradeVolume`] with the provided rolling window.
+    pub fn new(window: Duration) -> Self {
+        Self {
+            window,
+            trades: VecDeque::new(),
+        }
+    }
+
+    /// Record the volume of a trade that occurred at the provided time.
+    pub fn record(&mut self, time: DateTime<Utc>, volume: f64) {
+        self.trades.push_back((time, volume));
+    }
+
+    /// Calculate the total volume within the rolling window ending at the provided time,
+    /// discarding trades that have fallen out of the window.
+    pub fn total(&mut self, now: DateTime<Utc>) -> f64 {
+        while let Some((time, _)) = self.trades.front() {
+            if now - *time < self.window {
+                break;
+            }
+            self.trades.pop_front();
+        }
+
+        self.trades.iter().map(|(_, volume)| volume).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::model::instrument::kind::InstrumentKind;
+
+    #[test]
+    fn test_fee_schedule_rates() {
+        struct TestCase {
+            instrument: Instrument,
+            volume: f64,
+            expected: FeeRates,
+        }
+
+        let btc_usdt = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+        let eth_usdt = Instrument::from(("eth", "usdt", InstrumentKind::Spot));
+
+        let schedule = FeeSchedule::new(vec![
+            FeeTier::new(1_000_000.0, 0.0008, 0.0009),
+            FeeTier::new(0.0, 0.001, 0.002),
+        ])
+        .with_instrument(eth_usdt.clone(), vec![FeeTier::new(0.0, 0.0, 0.0005)]);
+
+        let tests = vec![
+            TestCase {
+                // TC0: Zero volume uses the lowest default FeeTier
+                instrument: btc_usdt.clone(),
+                volume: 0.0,
+                expected: FeeRates {
+                    maker: 0.001,
+                    taker: 0.002,
+                },
+            },
+            TestCase {
+                // TC1: Volume satisfying a higher FeeTier uses it's rates
+                instrument: btc_usdt,
+                volume: 1_000_000.0,
+                expected: FeeRates {
+                    maker: 0.0008,
+                    taker: 0.0009,
+                },
+            },
+            TestCase {
+                // TC2: Instrument override FeeTiers replace the default FeeTiers
+                instrument: eth_usdt,
+                volume: 1_000_000.0,
+                expected: FeeRates {
+                    maker: 0.0,
+                    taker: 0.0005,
+                },
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = schedule.rates(&test.instrument, test.volume);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_fee_currency_fees() {
+        struct TestCase {
+            currency: FeeCurrency,
+            side: Side,
+            expected: SymbolFees,
+        }
+
+        let instrument = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+
+        let tests = vec![
+            TestCase {
+                // TC0: Received Buy fees are denominated in base
+                currency: FeeCurrency::Received,
+                side: Side::Buy,
+                expected: SymbolFees::new("btc", 0.1 * 2.0),
+            },
+            TestCase {
+                // TC1: Received Sell fees are denominated in quote
+                currency: FeeCurrency::Received,
+                side: Side::Sell,
+                expected: SymbolFees::new("usdt", 0.1 * 2.0 * 100.0),
+            },
+            TestCase {
+                // TC2: Quote Buy fees are denominated in quote
+                currency: FeeCurrency::Quote,
+                side: Side::Buy,
+                expected: SymbolFees::new("usdt", 0.1 * 2.0 * 100.0),
+            },
+            TestCase {
+                // TC3: Base Sell fees are denominated in base
+                currency: FeeCurrency::Base,
+                side: Side::Sell,
+                expected: SymbolFees::new("btc", 0.1 * 2.0),
+            },
+            TestCase {
+                // TC4: Symbol fees are converted from quote using the Symbol quote price
+                currency: FeeCurrency::Symbol {
+                    symbol: Symbol::from("bnb"),
+                    quote_price: 10.0,
+                },
+                side: Side::Buy,
+                expected: SymbolFees::new("bnb", 0.1 * 2.0 * 100.0 / 10.0),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = test.currency.fees(&instrument, test.side, 100.0, 2.0, 0.1);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_trade_volume_total() {
+        let now = Utc::now();
+        let mut volume = TradeVolume::default();
+
+        volume.record(now - Duration::days(31), 100.0);
+        volume.record(now - Duration::days(29), 10.0);
+        volume.record(now, 1.0);
+
+        assert_eq!(volume.total(now), 11.0);
+        assert_eq!(volume.trades.len(), 2);
+    }
+}
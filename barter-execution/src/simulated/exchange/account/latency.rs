@@ -0,0 +1,235 @@
+use crate::ExecutionError;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use rand_distr::StandardNormal;
+use serde::{Deserialize, Serialize};
+use std::{path::Path, time::Duration};
+
+/// Upper bound of every sampled latency, ensuring a long tailed [`LatencyModel`] cannot overflow
+/// the [`SimulatedClock`](crate::simulated::exchange::clock::SimulatedClock) time it is added to.
+pub const MAX_LATENCY: Duration = Duration::from_secs(60 * 60);
+
+/// Distribution that simulated network latencies are sampled from.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub enum LatencyModel {
+    /// Every sample is the same [`Duration`].
+    Constant(Duration),
+    /// Samples are uniformly distributed between the inclusive `min` & `max`.
+    Uniform { min: Duration, max: Duration },
+    /// Samples are log-normally distributed around the `median`, with `sigma` being the standard
+    /// deviation of the natural logarithm of the latency. Models the long tail of real networks.
+    LogNormal { median: Duration, sigma: f64 },
+    /// Samples are replayed in order from recorded latencies, restarting once exhausted.
+    Replay(Vec<Duration>),
+}
+
+impl Default for LatencyModel {
+    fn default() -> Self {
+        Self::Constant(Duration::ZERO)
+    }
+}
+
+impl LatencyModel {
+    /// Construct a [`LatencyModel::Replay`] from a file containing one latency in milliseconds
+    /// per line (eg/ "12.5"). Empty lines & lines starting with '#' are ignored.
+    pub fn replay_file<P>(path: P) -> Result<Self, ExecutionError>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path).map_err(|error| {
+            ExecutionError::Simulated(format!(
+                "failed to read latency replay file {}: {error}",
+                path.display()
+            ))
+        })?;
+
+        contents
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(|line| {
+                line.parse::<f64>()
+                    .ok()
+                    .filter(|millis| millis.is_finite() && *millis >= 0.0)
+                    .map(|millis| Duration::from_nanos((millis * 1_000_000.0).round() as u64))
+                    .ok_or_else(|| {
+                        ExecutionError::Simulated(format!(
+                            "invalid latency in replay file {}: {line}",
+                            path.display()
+                        ))
+                    })
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(Self::Replay)
+    }
+}
+
+/// Seeded sampler of simulated network latencies from a [`LatencyModel`].
+///
+/// Samples are deterministic for a given [`LatencyModel`] and seed, allowing backtests to be
+/// reproduced exactly.
+#[derive(Clone, Debug)]
+pub struct Latency {
+    pub model: LatencyModel,
+    rng: StdRng,
+    replay_index: usize,
+}
+
+impl Default for Latency {
+    fn default() -> Self {
+        Self::new(LatencyModel::default(), 0)
+    }
+}
+
+impl From<Duration> for Latency {
+    fn from(latency: Duration) -> Self {
+        Self::new(LatencyModel::Constant(latency), 0)
+    }
+}
+
+impl Latency {
+    /// Construct a new [`Latency`] sampler using the provided [`LatencyModel`] & random seed.
+    pub fn new(model: LatencyModel, seed: u64) -> Self {
+        Self {
+            model,
+            rng: StdRng::seed_from_u64(seed),
+            replay_index: 0,
+        }
+    }
+
+    /// Sample the next simulated latency, capped at [`MAX_LATENCY`].
+    pub fn sample(&mut self) -> Duration {
+        let latency = match &self.model {
+            LatencyModel::Constant(latency) => *latency,
+            LatencyModel::Uniform { min, max } if min >= max => *min,
+            LatencyModel::Uniform { min, max } => self.rng.gen_range(*min..=*max),
+            LatencyModel::LogNormal { median, sigma } => {
+                let z: f64 = self.rng.sample(StandardNormal);
+                Duration::try_from_secs_f64(median.as_secs_f64() * (sigma * z).exp())
+                    .unwrap_or(MAX_LATENCY)
+            }
+            LatencyModel::Replay(latencies) if latencies.is_empty() => Duration::ZERO,
+            LatencyModel::Replay(latencies) => {
+                let latency = latencies[self.replay_index % latencies.len()];
+                self.replay_index += 1;
+                latency
+            }
+        };
+
+        latency.min(MAX_LATENCY)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_latency_sample() {
+        struct TestCase {
+            model: LatencyModel,
+            expected_min: Duration,
+            expected_max: Duration,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: Constant always samples the same Duration
+                model: LatencyModel::Constant(Duration::from_millis(5)),
+                expected_min: Duration::from_millis(5),
+                expected_max: Duration::from_millis(5),
+            },
+            TestCase {
+                // TC1: Uniform samples are within the inclusive bounds
+                model: LatencyModel::Uniform {
+                    min: Duration::from_millis(10),
+                    max: Duration::from_millis(20),
+                },
+                expected_min: Duration::from_millis(10),
+                expected_max: Duration::from_millis(20),
+            },
+            TestCase {
+                // TC2: Uniform with inverted bounds samples the min
+                model: LatencyModel::Uniform {
+                    min: Duration::from_millis(20),
+                    max: Duration::from_millis(10),
+                },
+                expected_min: Duration::from_millis(20),
+                expected_max: Duration::from_millis(20),
+            },
+            TestCase {
+                // TC3: LogNormal samples are strictly positive
+                model: LatencyModel::LogNormal {
+                    median: Duration::from_millis(10),
+                    sigma: 0.5,
+                },
+                expected_min: Duration::from_nanos(1),
+                expected_max: Duration::from_secs(10),
+            },
+            TestCase {
+                // TC4: Empty Replay samples zero latency
+                model: LatencyModel::Replay(vec![]),
+                expected_min: Duration::ZERO,
+                expected_max: Duration::ZERO,
+            },
+            TestCase {
+                // TC5: LogNormal with a large sigma is capped at the MAX_LATENCY
+                model: LatencyModel::LogNormal {
+                    median: Duration::from_millis(10),
+                    sigma: 1000.0,
+                },
+                expected_min: Duration::ZERO,
+                expected_max: MAX_LATENCY,
+            },
+            TestCase {
+                // TC6: Constant exceeding the MAX_LATENCY is capped
+                model: LatencyModel::Constant(Duration::MAX),
+                expected_min: MAX_LATENCY,
+                expected_max: MAX_LATENCY,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let mut latency = Latency::new(test.model.clone(), 42);
+            let mut same_seed = Latency::new(test.model, 42);
+
+            for _ in 0..100 {
+                let actual = latency.sample();
+                assert!(
+                    actual >= test.expected_min && actual <= test.expected_max,
+                    "TC{index} failed: {actual:?} out of bounds"
+                );
+                assert_eq!(actual, same_seed.sample(), "TC{} failed", index);
+            }
+        }
+    }
+
+    #[test]
+    fn test_latency_replay_cycles() {
+        let mut latency = Latency::new(
+            LatencyModel::Replay(vec![Duration::from_millis(1), Duration::from_millis(2)]),
+            0,
+        );
+
+        let actual = (0..5).map(|_| latency.sample()).collect::<Vec<_>>();
+        let expected = [1, 2, 1, 2, 1].map(Duration::from_millis).to_vec();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_latency_model_replay_file() {
+        let path = std::env::temp_dir().join(format!("latency_{}.txt", uuid::Uuid::new_v4()));
+
+        std::fs::write(&path, "# recorded latencies\n1.5\n\n20\n").unwrap();
+        assert_eq!(
+            LatencyModel::replay_file(&path).unwrap(),
+            LatencyModel::Replay(vec![Duration::from_micros(1500), Duration::from_millis(20)])
+        );
+
+        std::fs::write(&path, "1.5\n-3\n").unwrap();
+        assert!(LatencyModel::replay_file(&path).is_err());
+
+        std::fs::remove_file(&path).unwrap();
+        assert!(LatencyModel::replay_file(&path).is_err());
+    }
+}
@@ -1,16 +1,30 @@
-use self::{balance::ClientBalances, order::ClientOrders};
+use self::{
+    balance::{amend_required_balance_delta, ClientBalances},
+    fees::{FeeCurrency, FeeRates, FeeReserve, FeeSchedule, TradeVolume},
+    latency::{Latency, LatencyModel},
+    order::{ClientOrders, ConditionalOrder, Liquidity, Orders, QueueModel},
+};
 use crate::{
     model::{
-        balance::{Balance, SymbolBalance},
-        order::OrderKind,
+        balance::{Balance, BalanceDelta, SymbolBalance},
+        order::{OrderId, OrderKind},
+        trade::Trade,
         AccountEvent, AccountEventKind,
     },
-    Cancelled, ExecutionError, ExecutionId, Open, Order, RequestCancel, RequestOpen,
+    simulated::exchange::clock::SimulatedClock,
+    Cancelled, ExecutionError, ExecutionId, Open, Order, RequestAmend, RequestCancel, RequestOpen,
+};
+use barter_data::subscription::{book::Level, trade::PublicTrade};
+use barter_integration::model::{
+    instrument::{symbol::Symbol, Instrument},
+    Exchange, Side,
+};
+use chrono::{DateTime, Utc};
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt::Debug,
+    time::Duration,
 };
-use barter_data::subscription::trade::PublicTrade;
-use barter_integration::model::{instrument::Instrument, Exchange, Side};
-use chrono::Utc;
-use std::{fmt::Debug, time::Duration};
 use tokio::sync::{mpsc, oneshot};
 use tracing::warn;
 
@@ -21,12 +35,32 @@ pub mod balance;
 /// [`ClientAccount`] [`ClientOrders`] management & matching logic.
 pub mod order;
 
+/// [`ClientAccount`] maker & taker [`FeeSchedule`] and rolling [`TradeVolume`].
+pub mod fees;
+
+/// [`ClientAccount`] request & account event [`Latency`] sampled from a [`LatencyModel`].
+pub mod latency;
+
 /// Simulated account state containing [`ClientBalances`] and [`ClientOrders`]. Details the
 /// simulated account fees and latency.
+///
+/// Latency is simulated for both inbound requests (`request_latency`), and outbound
+/// [`AccountEvent`]s (`event_latency`). [`AccountEvent`]s are delivered in the order they are
+/// generated, with any delayed [`AccountEvent`]s held until the [`SimulatedClock`] passes their
+/// `received_time`.
+///
+/// Fees that cannot be deducted from the asset received by a trade are reserved from the
+/// available [`Balance`] of each resting order as a [`FeeReserve`].
 #[derive(Clone, Debug)]
 pub struct ClientAccount {
-    pub latency: Duration,
-    pub fees_percent: f64,
+    pub clock: SimulatedClock,
+    pub request_latency: Latency,
+    pub event_latency: Latency,
+    pub events_in_flight: VecDeque<AccountEvent>,
+    pub fee_schedule: FeeSchedule,
+    pub trade_volume: TradeVolume,
+    pub fee_reserves: HashMap<OrderId, FeeReserve>,
+    pub slippage_percent: f64,
     pub event_account_tx: mpsc::UnboundedSender<AccountEvent>,
     pub balances: ClientBalances,
     pub orders: ClientOrders,
@@ -40,18 +74,20 @@ impl ClientAccount {
 
     /// Send every [`Order<Open>`] for every [`Instrument`] to the client.
     pub fn fetch_orders_open(
-        &self,
+        &mut self,
         response_tx: oneshot::Sender<Result<Vec<Order<Open>>, ExecutionError>>,
     ) {
-        respond_with_latency(self.latency, response_tx, Ok(self.orders.fetch_all()));
+        let orders = self.orders.fetch_all();
+        self.respond(response_tx, Ok(orders));
     }
 
     /// Send the [`Balance`] for every [`Symbol`](barter_integration::model::Symbol) to the client.
     pub fn fetch_balances(
-        &self,
+        &mut self,
         response_tx: oneshot::Sender<Result<Vec<SymbolBalance>, ExecutionError>>,
     ) {
-        respond_with_latency(self.latency, response_tx, Ok(self.balances.fetch_all()));
+        let balances = self.balances.fetch_all();
+        self.respond(response_tx, Ok(balances));
     }
 
     /// Execute open order requests and send the response via the provided [`oneshot::Sender`].
@@ -65,56 +101,428 @@ impl ClientAccount {
             .map(|request| self.try_open_order_atomic(request))
             .collect();
 
-        respond_with_latency(self.latency, response_tx, open_results);
+        self.respond(response_tx, open_results);
     }
 
     /// Execute an open order request, adding it to [`ClientOrders`] and updating the associated
     /// [`Balance`]. Sends an [`AccountEvent`] for both the new order and balance update.
+    ///
+    /// Marketable orders are immediately matched against the latest known [`Liquidity`]. Any
+    /// remaining quantity of [`OrderKind::Market`] & [`OrderKind::ImmediateOrCancel`] orders that
+    /// cannot be filled is cancelled, and [`OrderKind::PostOnly`] orders that would cross the
+    /// spread are rejected.
+    ///
+    /// Conditional orders (eg/ [`OrderKind::StopMarket`]) are held untriggered without reserving
+    /// any [`Balance`] until a [`PublicTrade`] crosses their
+    /// [`OrderTrigger`](crate::model::order::OrderTrigger).
     pub fn try_open_order_atomic(
         &mut self,
         request: Order<RequestOpen>,
     ) -> Result<Order<Open>, ExecutionError> {
         Self::check_order_kind_support(request.state.kind)?;
 
+        // Hold conditional orders untriggered
+        if request.state.kind.is_conditional() {
+            return self.try_open_conditional_order(request);
+        }
+
+        // Reject PostOnly orders that would take liquidity upon opening
+        if request.state.kind == OrderKind::PostOnly
+            && self
+                .orders
+                .orders_mut(&request.instrument)?
+                .would_cross(request.side, request.state.price)
+        {
+            return Err(ExecutionError::PostOnlyRejected(request.cid));
+        }
+
+        self.try_execute_order(request, None)
+    }
+
+    /// Execute an [`Order<RequestOpen>`], matching it against the latest known [`Liquidity`]
+    /// and resting any remaining quantity of [`OrderKind::Limit`] & [`OrderKind::PostOnly`]
+    /// orders.
+    ///
+    /// Triggered [`ConditionalOrder`]s provide their existing [`OrderId`], and send an
+    /// [`AccountEventKind::OrdersTriggered`] in place of an [`AccountEventKind::OrdersNew`].
+    fn try_execute_order(
+        &mut self,
+        request: Order<RequestOpen>,
+        triggered_id: Option<OrderId>,
+    ) -> Result<Order<Open>, ExecutionError> {
+        let kind = request.state.kind;
+
+        // Determine the Liquidity Levels the order will take upon opening
+        let fills = self.liquidity_fills(&request)?;
+
         // Calculate required available balance to open order
-        let (symbol, required_balance) = request.required_available_balance();
+        let (symbol, required_balance) = match kind {
+            OrderKind::Market => market_required_available_balance(&request, &fills),
+            _ => request.required_available_balance(),
+        };
+
+        // Calculate the taker fees of the fills, and the FeeReserve of any resting quantity
+        let fee_rates = self.fee_rates(&request.instrument);
+        let resting_quantity = match kind {
+            OrderKind::Limit | OrderKind::PostOnly => {
+                request.state.quantity - fills.iter().map(|fill| fill.amount).sum::<f64>()
+            }
+            _ => 0.0,
+        };
+        let fee_reserve = self.fee_schedule.currency.reserve(
+            &request.instrument,
+            request.side,
+            request.state.price,
+            fee_rates.maker,
+        );
+        let required_fees = fee_reserve.as_ref().map(|reserve| {
+            let taker_fees = fills
+                .iter()
+                .map(|fill| {
+                    self.fee_schedule
+                        .currency
+                        .fees(
+                            &request.instrument,
+                            request.side,
+                            fill.price,
+                            fill.amount,
+                            fee_rates.taker,
+                        )
+                        .fees
+                })
+                .sum::<f64>();
 
-        // Check available balance is sufficient
-        self.balances
-            .has_sufficient_available_balance(symbol, required_balance)?;
+            (
+                &reserve.symbol,
+                taker_fees + reserve.per_unit * resting_quantity,
+            )
+        });
+
+        // Check available balance is sufficient to cover the order and any required fees
+        self.balances.has_sufficient_available_balances(
+            [(symbol, required_balance)]
+                .into_iter()
+                .chain(required_fees),
+        )?;
+        let symbol = symbol.clone();
 
         // Build Open<Order>
-        let open = self.orders.build_order_open(request);
+        let is_triggered = triggered_id.is_some();
+        let open = match triggered_id {
+            Some(id) => Order::from((id, request)),
+            None => self.orders.build_order_open(request),
+        };
 
         // Retrieve client Instrument Orders
         let orders = self.orders.orders_mut(&open.instrument)?;
 
         // Now that fallible operations have succeeded, mutate ClientBalances & ClientOrders
-        orders.add_order_open(open.clone());
+        let (trades, open) = orders.fill_order(open, fills, fee_rates.taker);
+        let is_remaining = open.state.remaining_quantity() > 0.0;
+        let is_resting = is_remaining && matches!(kind, OrderKind::Limit | OrderKind::PostOnly);
+        if is_resting {
+            orders.add_order_open(open.clone());
+        }
+        let fee_reserve = fee_reserve.filter(|_| is_resting);
+        let fee_balance = fee_reserve.as_ref().map(|reserve| {
+            SymbolBalance::new(
+                reserve.symbol.clone(),
+                self.balances.update(
+                    &reserve.symbol,
+                    BalanceDelta::new(0.0, -reserve.per_unit * open.state.remaining_quantity()),
+                ),
+            )
+        });
         let balance_event = self.balances.update_from_open(&open, required_balance);
 
         // Send AccountEvents to client
-        self.event_account_tx
-            .send(balance_event)
-            .expect("Client is offline - failed to send AccountEvent::Balance");
-
-        self.event_account_tx
-            .send(AccountEvent {
-                received_time: Utc::now(),
-                exchange: Exchange::from(ExecutionId::Simulated),
-                kind: AccountEventKind::OrdersNew(vec![open.clone()]),
-            })
-            .expect("Client is offline - failed to send AccountEvent::Trade");
+        self.send_event(balance_event.kind);
+
+        if let Some(fee_balance) = fee_balance.filter(|balance| balance.symbol != symbol) {
+            self.send_event(AccountEventKind::Balance(fee_balance));
+        }
+
+        self.send_event(match is_triggered {
+            true => AccountEventKind::OrdersTriggered(vec![open.clone()]),
+            false => AccountEventKind::OrdersNew(vec![open.clone()]),
+        });
+
+        // Release the Side::Buy quote balance reserved above each Trade price (price improvement)
+        // '--> Market orders only reserve the exact Trade value
+        for trade in &trades {
+            if trade.side == Side::Buy && kind != OrderKind::Market {
+                self.balances.update(
+                    &trade.instrument.quote,
+                    BalanceDelta {
+                        total: 0.0,
+                        available: (open.state.price - trade.price) * trade.quantity,
+                    },
+                );
+            }
+        }
+        self.send_trades(trades);
+
+        // Reserved fees are released as the resting quantity is filled, amended, or cancelled
+        if let Some(reserve) = fee_reserve {
+            self.fee_reserves.insert(open.state.id.clone(), reserve);
+        }
+
+        // Cancel remaining quantity of Market & ImmediateOrCancel orders
+        if is_remaining && matches!(kind, OrderKind::Market | OrderKind::ImmediateOrCancel) {
+            self.cancel_remaining(&open, kind);
+        }
 
         Ok(open)
     }
 
+    /// Hold a conditional [`Order<RequestOpen>`] untriggered until a [`PublicTrade`] crosses it's
+    /// [`OrderTrigger`](crate::model::order::OrderTrigger). Sends an [`AccountEvent`] for the new
+    /// order.
+    pub fn try_open_conditional_order(
+        &mut self,
+        request: Order<RequestOpen>,
+    ) -> Result<Order<Open>, ExecutionError> {
+        // Validate the OrderTrigger is appropriate for the OrderKind
+        if !request
+            .state
+            .trigger
+            .is_some_and(|trigger| trigger.is_valid_for(request.state.kind))
+        {
+            return Err(ExecutionError::InvalidOrderTrigger(request.cid));
+        }
+
+        // Validate the Instrument is configured before generating a new OrderId
+        self.orders.orders_mut(&request.instrument)?;
+        let open = self.orders.build_order_open(request.clone());
+        self.orders
+            .orders_mut(&open.instrument)?
+            .conditional
+            .push(ConditionalOrder::new(open.state.id.clone(), request));
+
+        // Send AccountEvents to client
+        self.send_event(AccountEventKind::OrdersNew(vec![open.clone()]));
+
+        Ok(open)
+    }
+
+    /// Execute a triggered [`ConditionalOrder`] as it's activated [`OrderKind`]. If the
+    /// activated order cannot be executed (eg/ insufficient balance), it is cancelled.
+    pub fn activate_conditional_order(&mut self, conditional: ConditionalOrder) {
+        if let Err(error) =
+            self.try_execute_order(conditional.activated(), Some(conditional.id.clone()))
+        {
+            warn!(
+                ?error,
+                ?conditional,
+                "failed to execute triggered conditional order"
+            );
+
+            self.send_event(AccountEventKind::OrdersCancelled(vec![Order::from(
+                conditional.open(),
+            )]));
+        }
+    }
+
+    /// Determine the [`Level`]s of the latest known [`Liquidity`] that the
+    /// [`Order<RequestOpen>`] will take upon opening.
+    ///
+    /// [`OrderKind::Market`] orders walk every opposing [`Level`] with the configured slippage
+    /// applied to each fill price. If no [`Level`]s are known, the full quantity is filled at the
+    /// last traded price.
+    pub fn liquidity_fills(
+        &mut self,
+        request: &Order<RequestOpen>,
+    ) -> Result<Vec<Level>, ExecutionError> {
+        let orders = self.orders.orders_mut(&request.instrument)?;
+
+        // Market orders are not limited by price
+        let limit = match request.state.kind {
+            OrderKind::Market => None,
+            _ => Some(request.state.price),
+        };
+
+        let fills = orders
+            .liquidity
+            .take(request.side, limit, request.state.quantity);
+
+        if request.state.kind != OrderKind::Market {
+            return Ok(fills);
+        }
+
+        // Fallback to the last traded price if no Liquidity Levels are known
+        let fills = match (fills.is_empty(), orders.last_trade_price) {
+            (false, _) => fills,
+            (true, Some(price)) => vec![Level::new(price, request.state.quantity)],
+            (true, None) => {
+                return Err(ExecutionError::Simulated(format!(
+                    "no liquidity available to fill Market order for Instrument: {}",
+                    request.instrument
+                )))
+            }
+        };
+
+        // Apply configured slippage to each Market order fill price
+        let slippage = match request.side {
+            Side::Buy => 1.0 + self.slippage_percent,
+            Side::Sell => 1.0 - self.slippage_percent,
+        };
+
+        Ok(fills
+            .into_iter()
+            .map(|fill| Level::new(fill.price * slippage, fill.amount))
+            .collect())
+    }
+
+    /// Cancel the remaining quantity of a [`OrderKind::Market`] or
+    /// [`OrderKind::ImmediateOrCancel`] [`Order<Open>`] that could not be filled upon opening.
+    /// Sends an [`AccountEvent`] for both the order cancel and balance update.
+    fn cancel_remaining(&mut self, open: &Order<Open>, kind: OrderKind) {
+        // Market orders do not reserve balance for the remaining quantity
+        let balance = match kind {
+            OrderKind::Market => {
+                let symbol = match open.side {
+                    Side::Buy => &open.instrument.quote,
+                    Side::Sell => &open.instrument.base,
+                };
+                let balance = self
+                    .balances
+                    .balance(symbol)
+                    .expect("Balance existence checked when opening Order");
+                SymbolBalance::new(symbol.clone(), *balance)
+            }
+            _ => self.balances.update_from_cancel(open),
+        };
+
+        // Send AccountEvents to client
+        self.send_event(AccountEventKind::OrdersCancelled(vec![Order::from(
+            open.clone(),
+        )]));
+
+        self.send_event(AccountEventKind::Balance(balance));
+    }
+
     /// Check if the [`Order<RequestOpen>`] [`OrderKind`] is supported.
     pub fn check_order_kind_support(kind: OrderKind) -> Result<(), ExecutionError> {
         match kind {
-            OrderKind::Limit | OrderKind::PostOnly => Ok(()),
-            unsupported => Err(ExecutionError::UnsupportedOrderKind(unsupported)),
+            OrderKind::Market
+            | OrderKind::Limit
+            | OrderKind::PostOnly
+            | OrderKind::ImmediateOrCancel
+            | OrderKind::StopMarket
+            | OrderKind::StopLimit
+            | OrderKind::TakeProfit
+            | OrderKind::TrailingStop => Ok(()),
+        }
+    }
+
+    /// Execute amend order requests and send the response via the provided [`oneshot::Sender`].
+    pub fn amend_orders(
+        &mut self,
+        amend_requests: Vec<Order<RequestAmend>>,
+        response_tx: oneshot::Sender<Vec<Result<Order<Open>, ExecutionError>>>,
+    ) {
+        let amend_results = amend_requests
+            .into_iter()
+            .map(|request| self.try_amend_order_atomic(request))
+            .collect();
+
+        self.respond(response_tx, amend_results);
+    }
+
+    /// Execute an amend order request, replacing the price and/or quantity of the [`Order<Open>`]
+    /// and updating the associated [`Balance`] reservation. Sends an [`AccountEvent`] for both the
+    /// amended order and balance update.
+    ///
+    /// Amends that change the price or increase the quantity lose queue priority. Amends that
+    /// would cross the spread are rejected.
+    pub fn try_amend_order_atomic(
+        &mut self,
+        request: Order<RequestAmend>,
+    ) -> Result<Order<Open>, ExecutionError> {
+        // Retrieve client Instrument Orders
+        let orders = self.orders.orders_mut(&request.instrument)?;
+
+        // Find Order<Open> associated with the Order<RequestAmend>
+        let original = orders
+            .find_order_open(request.side, &request.state.id)
+            .ok_or(ExecutionError::OrderNotFound(request.cid))?
+            .clone();
+
+        // Validate the amended quantity is not already filled
+        if request.state.quantity <= original.state.filled_quantity {
+            return Err(ExecutionError::AmendQuantityFilled(request.cid));
+        }
+
+        // Reject amends that would take liquidity
+        if orders.would_cross(request.side, request.state.price) {
+            return Err(ExecutionError::AmendCrossesSpread(request.cid));
         }
+
+        // Build amended Order<Open>
+        let mut amended = original.clone();
+        amended.state.price = request.state.price;
+        amended.state.quantity = request.state.quantity;
+
+        // Calculate the FeeReserve of the amended Order<Open>, and the change in reserved fees
+        let fee_reserve = match self.fee_reserves.get(&original.state.id).cloned() {
+            Some(reserve) => {
+                let maker = self.fee_rates(&amended.instrument).maker;
+                self.fee_schedule
+                    .currency
+                    .reserve(
+                        &amended.instrument,
+                        amended.side,
+                        amended.state.price,
+                        maker,
+                    )
+                    .map(|amended_reserve| {
+                        let fees_delta = amended_reserve.per_unit
+                            * amended.state.remaining_quantity()
+                            - reserve.per_unit * original.state.remaining_quantity();
+                        (amended_reserve, fees_delta)
+                    })
+            }
+            None => None,
+        };
+
+        // Check available balance is sufficient to reserve any additional required balance
+        let (symbol, required_delta) = amend_required_balance_delta(&original, &amended);
+        self.balances.has_sufficient_available_balances(
+            [(symbol, required_delta)]
+                .into_iter()
+                .chain(
+                    fee_reserve
+                        .as_ref()
+                        .map(|(reserve, fees_delta)| (&reserve.symbol, *fees_delta)),
+                )
+                .filter(|(_, required)| *required > 0.0),
+        )?;
+
+        // Now that fallible operations have succeeded, mutate ClientBalances & ClientOrders
+        self.orders
+            .orders_mut(&amended.instrument)?
+            .amend_order_open(amended.clone());
+        let fee_balance = fee_reserve.map(|(reserve, fees_delta)| {
+            let balance = SymbolBalance::new(
+                reserve.symbol.clone(),
+                self.balances
+                    .update(&reserve.symbol, BalanceDelta::new(0.0, -fees_delta)),
+            );
+            self.fee_reserves.insert(amended.state.id.clone(), reserve);
+            balance
+        });
+        let balance = self.balances.update_from_amend(&original, &amended);
+
+        // Send AccountEvents to client
+        self.send_event(AccountEventKind::OrdersAmended(vec![amended.clone()]));
+
+        if let Some(fee_balance) = fee_balance.filter(|fee| fee.symbol != balance.symbol) {
+            self.send_event(AccountEventKind::Balance(fee_balance));
+        }
+
+        self.send_event(AccountEventKind::Balance(balance));
+
+        Ok(amended)
     }
 
     /// Execute cancel order requests and send the response via the provided [`oneshot::Sender`].
@@ -128,7 +536,7 @@ impl ClientAccount {
             .map(|request| self.try_cancel_order_atomic(request))
             .collect();
 
-        respond_with_latency(self.latency, response_tx, cancel_results);
+        self.respond(response_tx, cancel_results);
     }
 
     /// Execute a cancel order request, removing it from the [`ClientOrders`] and updating the
@@ -141,51 +549,34 @@ impl ClientAccount {
         // Retrieve client Instrument Orders
         let orders = self.orders.orders_mut(&request.instrument)?;
 
+        // Untriggered conditional orders do not reserve any Balance
+        if let Some(conditional) = orders.remove_conditional(&request.state.id) {
+            let cancelled = Order::from(conditional.open());
+            self.send_event(AccountEventKind::OrdersCancelled(vec![cancelled.clone()]));
+
+            return Ok(cancelled);
+        }
+
         // Find & remove Order<Open> associated with the Order<RequestCancel>
-        let removed = match request.side {
-            Side::Buy => {
-                // Search for Order<Open> using OrderId
-                let index = orders
-                    .bids
-                    .iter()
-                    .position(|bid| bid.state.id == request.state.id)
-                    .ok_or(ExecutionError::OrderNotFound(request.cid))?;
-                orders.bids.remove(index)
-            }
-            Side::Sell => {
-                // Search for Order<Open> using OrderId
-                let index = orders
-                    .asks
-                    .iter()
-                    .position(|ask| ask.state.id == request.state.id)
-                    .ok_or(ExecutionError::OrderNotFound(request.cid))?;
-
-                orders.asks.remove(index)
-            }
-        };
+        let removed = orders
+            .remove_order_open(request.side, &request.state.id)
+            .ok_or(ExecutionError::OrderNotFound(request.cid))?;
 
         // Now that fallible operations have succeeded, mutate ClientBalances
+        let fee_balance = self.release_fee_reserve(&removed);
         let balance_event = self.balances.update_from_cancel(&removed);
 
         // Map Order<Open> to Order<Cancelled>
         let cancelled = Order::from(removed);
 
         // Send AccountEvents to client
-        self.event_account_tx
-            .send(AccountEvent {
-                received_time: Utc::now(),
-                exchange: Exchange::from(ExecutionId::Simulated),
-                kind: AccountEventKind::OrdersCancelled(vec![cancelled.clone()]),
-            })
-            .expect("Client is offline - failed to send AccountEvent::Trade");
+        self.send_event(AccountEventKind::OrdersCancelled(vec![cancelled.clone()]));
 
-        self.event_account_tx
-            .send(AccountEvent {
-                received_time: Utc::now(),
-                exchange: Exchange::from(ExecutionId::Simulated),
-                kind: AccountEventKind::Balance(balance_event),
-            })
-            .expect("Client is offline - failed to send AccountEvent::Balance");
+        if let Some(fee_balance) = fee_balance.filter(|fee| fee.symbol != balance_event.symbol) {
+            self.send_event(AccountEventKind::Balance(fee_balance));
+        }
+
+        self.send_event(AccountEventKind::Balance(balance_event));
 
         Ok(cancelled)
     }
@@ -200,50 +591,49 @@ impl ClientAccount {
             .orders
             .all
             .values_mut()
-            .flat_map(|orders| {
-                let bids = orders.bids.drain(..);
-                let asks = orders.asks.drain(..);
-
-                bids.chain(asks)
-            })
+            .flat_map(Orders::remove_orders_all)
             .collect::<Vec<Order<Open>>>();
 
         let balance_updates = removed_orders
             .iter()
-            .map(|cancelled| self.balances.update_from_cancel(cancelled))
+            .flat_map(|cancelled| {
+                let fee_balance = self.release_fee_reserve(cancelled);
+                fee_balance
+                    .into_iter()
+                    .chain([self.balances.update_from_cancel(cancelled)])
+                    .collect::<Vec<_>>()
+            })
             .collect();
 
+        // Untriggered conditional orders do not reserve any Balance
+        let removed_conditional = self
+            .orders
+            .all
+            .values_mut()
+            .flat_map(|orders| orders.conditional.drain(..))
+            .map(|conditional| conditional.open())
+            .collect::<Vec<Order<Open>>>();
+
         let cancelled_orders = removed_orders
             .into_iter()
+            .chain(removed_conditional)
             .map(Order::from)
             .collect::<Vec<Order<Cancelled>>>();
 
         // Send AccountEvents to client
-        self.event_account_tx
-            .send(AccountEvent {
-                received_time: Utc::now(),
-                exchange: Exchange::from(ExecutionId::Simulated),
-                kind: AccountEventKind::OrdersCancelled(cancelled_orders.clone()),
-            })
-            .expect("Client is offline - failed to send AccountEvent::OrdersCancelled");
+        self.send_event(AccountEventKind::OrdersCancelled(cancelled_orders.clone()));
 
-        self.event_account_tx
-            .send(AccountEvent {
-                received_time: Utc::now(),
-                exchange: Exchange::from(ExecutionId::Simulated),
-                kind: AccountEventKind::Balances(balance_updates),
-            })
-            .expect("Client is offline - failed to send AccountEvent::Balances");
+        self.send_event(AccountEventKind::Balances(balance_updates));
 
-        respond_with_latency(self.latency, response_tx, Ok(cancelled_orders))
+        self.respond(response_tx, Ok(cancelled_orders))
     }
 
     /// Determine if the incoming [`PublicTrade`] liquidity matches any [`ClientOrders`] relating
     /// to the [`Instrument`]. If there are matches, trades are simulated by client orders being
     /// taken.
     pub fn match_orders(&mut self, instrument: Instrument, trade: PublicTrade) {
-        // Client fees
-        let fees_percent = self.fees_percent;
+        // Client maker fees of resting orders
+        let fees_percent = self.fee_rates(&instrument).maker;
 
         // Access the ClientOrders relating to the Instrument of the PublicTrade
         let orders = match self.orders.orders_mut(&instrument) {
@@ -256,33 +646,197 @@ impl ClientAccount {
             }
         };
 
+        // Track the last traded price used to fill Market orders
+        orders.last_trade_price = Some(trade.price);
+
+        // Determine which untriggered ConditionalOrders the PublicTrade price triggers
+        let triggered = orders.trigger_conditional(trade.price);
+
         // Match client Order<Open>s to incoming PublicTrade if the liquidity intersects
         let trades = match orders.has_matching_order(&trade) {
             Some(Side::Buy) => orders.match_bids(&trade, fees_percent),
             Some(Side::Sell) => orders.match_asks(&trade, fees_percent),
-            None => return,
+            None => vec![],
         };
 
-        // Apply Balance updates for each client Trade and send AccountEvents to client
+        self.send_trades(trades);
+
+        // Execute triggered ConditionalOrders as their activated OrderKind
+        for conditional in triggered {
+            self.activate_conditional_order(conditional);
+        }
+    }
+
+    /// Update the latest known [`Liquidity`] of the [`Instrument`]. If the opposing best
+    /// [`Level`]s now cross any [`ClientOrders`], trades are simulated by client orders being
+    /// taken.
+    pub fn match_orders_liquidity(&mut self, instrument: Instrument, liquidity: Liquidity) {
+        // Client maker fees of resting orders
+        let fees_percent = self.fee_rates(&instrument).maker;
+
+        // Access the ClientOrders relating to the Instrument of the Liquidity
+        let orders = match self.orders.orders_mut(&instrument) {
+            Ok(orders) => orders,
+            Err(error) => {
+                warn!(
+                    ?error, %instrument, ?liquidity, "cannot match orders with unrecognised Instrument"
+                );
+                return;
+            }
+        };
+
+        let trades = orders.update_liquidity(liquidity, fees_percent);
+        self.send_trades(trades);
+    }
+
+    /// Determine the maker & taker [`FeeRates`] of the [`Instrument`] using the rolling
+    /// [`TradeVolume`] of the account.
+    pub fn fee_rates(&mut self, instrument: &Instrument) -> FeeRates {
+        let volume = self.trade_volume.total(self.clock.now());
+        self.fee_schedule.rates(instrument, volume)
+    }
+
+    /// Apply [`Balance`] updates for each client [`Trade`], record it's volume, and send the
+    /// associated [`AccountEvent`]s to the client.
+    pub fn send_trades(&mut self, trades: Vec<Trade>) {
         for trade in trades {
-            // Update Balances
-            let balances_event = self.balances.update_from_trade(&trade);
+            // Update Balances & rolling TradeVolume
+            let fees_reserved = self.release_fee_reserve_filled(&trade);
+            let balances_event = self.balances.update_from_trade(&trade, fees_reserved);
+            self.trade_volume
+                .record(self.clock.now(), trade.price * trade.quantity);
 
-            self.event_account_tx
-                .send(balances_event)
-                .expect("Client is offline - failed to send AccountEvent::Balances");
+            self.send_event(balances_event.kind);
+
+            self.send_event(AccountEventKind::Trade(trade));
+        }
+    }
+
+    /// Release the [`FeeReserve`] held for the quantity filled by a [`Trade`], returning the fees
+    /// that were reserved. The [`FeeReserve`] is removed once the order is fully filled.
+    fn release_fee_reserve_filled(&mut self, trade: &Trade) -> f64 {
+        let Some(reserve) = self.fee_reserves.get(&trade.order_id) else {
+            return 0.0;
+        };
+        let fees_reserved = reserve.per_unit * trade.quantity;
+
+        let is_open = self
+            .orders
+            .orders_mut(&trade.instrument)
+            .is_ok_and(|orders| {
+                orders
+                    .find_order_open(trade.side, &trade.order_id)
+                    .is_some()
+            });
+        if !is_open {
+            self.fee_reserves.remove(&trade.order_id);
+        }
+
+        fees_reserved
+    }
 
+    /// Release the [`FeeReserve`] held for the remaining quantity of an [`Order<Open>`] that is no
+    /// longer resting, returning the updated fees [`SymbolBalance`] if any fees were reserved.
+    fn release_fee_reserve(&mut self, order: &Order<Open>) -> Option<SymbolBalance> {
+        let reserve = self.fee_reserves.remove(&order.state.id)?;
+        let balance = self.balances.update(
+            &reserve.symbol,
+            BalanceDelta::new(0.0, reserve.per_unit * order.state.remaining_quantity()),
+        );
+
+        Some(SymbolBalance::new(reserve.symbol, balance))
+    }
+
+    /// Send an [`AccountEvent`] to the client after the sampled `event_latency`.
+    ///
+    /// [`AccountEvent`]s are never delivered before an earlier [`AccountEvent`], so the
+    /// `received_time` is at least that of the latest [`AccountEvent`] still in flight.
+    pub fn send_event(&mut self, kind: AccountEventKind) {
+        let now = self.clock.now();
+        let received_time = now + self.event_latency.sample();
+        let received_time = match self.events_in_flight.back() {
+            Some(latest) => received_time.max(latest.received_time),
+            None => received_time,
+        };
+
+        self.events_in_flight.push_back(AccountEvent {
+            received_time,
+            exchange: Exchange::from(ExecutionId::Simulated),
+            kind,
+        });
+
+        self.flush_events(now);
+    }
+
+    /// Deliver every in flight [`AccountEvent`] with a `received_time` at or before the provided
+    /// time to the client.
+    pub fn flush_events(&mut self, time: DateTime<Utc>) {
+        while self
+            .events_in_flight
+            .front()
+            .is_some_and(|event| event.received_time <= time)
+        {
+            let event = self.events_in_flight.pop_front().unwrap();
             self.event_account_tx
-                .send(AccountEvent {
-                    received_time: Utc::now(),
-                    exchange: Exchange::from(ExecutionId::Simulated),
-                    kind: AccountEventKind::Trade(trade),
-                })
-                .expect("Client is offline - failed to send AccountEvent::Trade");
+                .send(event)
+                .expect("Client is offline - failed to send AccountEvent");
+        }
+    }
+
+    /// Time that the next in flight [`AccountEvent`] will be received by the client.
+    pub fn next_event_time(&self) -> Option<DateTime<Utc>> {
+        self.events_in_flight
+            .front()
+            .map(|event| event.received_time)
+    }
+
+    /// Advance the [`SimulatedClock`] to the provided time, delivering any in flight
+    /// [`AccountEvent`]s that have now been received.
+    pub fn advance_clock(&mut self, time: DateTime<Utc>) {
+        self.clock.advance(time);
+        self.flush_events(self.clock.now());
+    }
+
+    /// Send the provided `Response` to the client.
+    ///
+    /// With a [`SimulatedClock::RealTime`] clock the response is delayed by the sampled
+    /// `request_latency`. Event-time requests have already been delayed by the
+    /// [`SimulatedExchange`](super::SimulatedExchange) before being processed, so the response is
+    /// sent immediately.
+    pub fn respond<Response>(&mut self, response_tx: oneshot::Sender<Response>, response: Response)
+    where
+        Response: Debug + Send + 'static,
+    {
+        match self.clock {
+            SimulatedClock::RealTime => {
+                respond_with_latency(self.request_latency.sample(), response_tx, response)
+            }
+            SimulatedClock::EventTime(_) => {
+                // Client may have stopped awaiting the response, which is not an error
+                let _ = response_tx.send(response);
+            }
         }
     }
 }
 
+/// Calculate the required available [`Balance`] to open a [`OrderKind::Market`] order that
+/// will take the provided fill [`Level`]s.
+pub fn market_required_available_balance<'a>(
+    request: &'a Order<RequestOpen>,
+    fills: &[Level],
+) -> (&'a Symbol, f64) {
+    match request.side {
+        Side::Buy => (
+            &request.instrument.quote,
+            fills.iter().map(|fill| fill.price * fill.amount).sum(),
+        ),
+        Side::Sell => (
+            &request.instrument.base,
+            fills.iter().map(|fill| fill.amount).sum(),
+        ),
+    }
+}
+
 /// Sends the provided `Response` via the [`oneshot::Sender`] after waiting for the latency
 /// [`Duration`]. Used to simulate network latency between the exchange and client.
 pub fn respond_with_latency<Response>(
@@ -302,8 +856,13 @@ pub fn respond_with_latency<Response>(
 
 #[derive(Debug, Default)]
 pub struct ClientAccountBuilder {
-    latency: Option<Duration>,
-    fees_percent: Option<f64>,
+    clock: Option<SimulatedClock>,
+    request_latency: Option<LatencyModel>,
+    event_latency: Option<LatencyModel>,
+    latency_seed: Option<u64>,
+    fee_schedule: Option<FeeSchedule>,
+    slippage_percent: Option<f64>,
+    queue_model: Option<QueueModel>,
     event_account_tx: Option<mpsc::UnboundedSender<AccountEvent>>,
     instruments: Option<Vec<Instrument>>,
     balances: Option<ClientBalances>,
@@ -316,16 +875,75 @@ impl ClientAccountBuilder {
         }
     }
 
+    /// Constant latency applied to every request. Equivalent to a
+    /// [`LatencyModel::Constant`] `request_latency`.
     pub fn latency(self, value: Duration) -> Self {
+        self.request_latency(LatencyModel::Constant(value))
+    }
+
+    /// [`LatencyModel`] of requests sent from the client to the exchange.
+    pub fn request_latency(self, value: LatencyModel) -> Self {
+        Self {
+            request_latency: Some(value),
+            ..self
+        }
+    }
+
+    /// [`LatencyModel`] of [`AccountEvent`]s sent from the exchange to the client. Defaults to
+    /// zero latency.
+    pub fn event_latency(self, value: LatencyModel) -> Self {
         Self {
-            latency: Some(value),
+            event_latency: Some(value),
             ..self
         }
     }
 
+    /// Seed used to sample every [`LatencyModel`], making simulated latencies reproducible.
+    /// Defaults to zero.
+    pub fn latency_seed(self, value: u64) -> Self {
+        Self {
+            latency_seed: Some(value),
+            ..self
+        }
+    }
+
+    /// [`SimulatedClock`] used to timestamp [`AccountEvent`]s & simulate latency. Defaults to
+    /// [`SimulatedClock::RealTime`].
+    pub fn clock(self, value: SimulatedClock) -> Self {
+        Self {
+            clock: Some(value),
+            ..self
+        }
+    }
+
+    /// Flat fee rate applied to every maker & taker trade in decimal form (eg/ 0.001 for 0.1%).
+    /// Equivalent to a [`FeeSchedule::flat`].
     pub fn fees_percent(self, value: f64) -> Self {
+        self.fee_schedule(FeeSchedule::flat(value))
+    }
+
+    /// [`FeeSchedule`] used to calculate the fees of every simulated [`Trade`].
+    pub fn fee_schedule(self, value: FeeSchedule) -> Self {
+        Self {
+            fee_schedule: Some(value),
+            ..self
+        }
+    }
+
+    /// Slippage applied to the fill price of [`OrderKind::Market`] orders in decimal form
+    /// (eg/ 0.001 for 0.1%). Defaults to zero slippage.
+    pub fn slippage_percent(self, value: f64) -> Self {
         Self {
-            fees_percent: Some(value),
+            slippage_percent: Some(value),
+            ..self
+        }
+    }
+
+    /// [`QueueModel`] used to simulate the queue position of resting orders. Defaults to
+    /// [`QueueModel::None`].
+    pub fn queue_model(self, value: QueueModel) -> Self {
+        Self {
+            queue_model: Some(value),
             ..self
         }
     }
@@ -352,14 +970,30 @@ impl ClientAccountBuilder {
     }
 
     pub fn build(self) -> Result<ClientAccount, ExecutionError> {
+        let fee_schedule = self
+            .fee_schedule
+            .ok_or_else(|| ExecutionError::BuilderIncomplete("fee_schedule".to_string()))?;
+        let fee_currency = fee_schedule.currency.clone();
+
+        // Sample request & AccountEvent latencies independently
+        let latency_seed = self.latency_seed.unwrap_or_default();
+
         // Construct ClientAccount
         let client_account = ClientAccount {
-            latency: self
-                .latency
+            clock: self.clock.unwrap_or_default(),
+            request_latency: self
+                .request_latency
+                .map(|model| Latency::new(model, latency_seed))
                 .ok_or_else(|| ExecutionError::BuilderIncomplete("latency".to_string()))?,
-            fees_percent: self
-                .fees_percent
-                .ok_or_else(|| ExecutionError::BuilderIncomplete("fees_percent".to_string()))?,
+            event_latency: Latency::new(
+                self.event_latency.unwrap_or_default(),
+                latency_seed.wrapping_add(1),
+            ),
+            events_in_flight: VecDeque::new(),
+            fee_schedule,
+            trade_volume: TradeVolume::default(),
+            fee_reserves: HashMap::new(),
+            slippage_percent: self.slippage_percent.unwrap_or_default(),
             event_account_tx: self
                 .event_account_tx
                 .ok_or_else(|| ExecutionError::BuilderIncomplete("event_account_tx".to_string()))?,
@@ -368,7 +1002,13 @@ impl ClientAccountBuilder {
                 .ok_or_else(|| ExecutionError::BuilderIncomplete("balances".to_string()))?,
             orders: self
                 .instruments
-                .map(ClientOrders::new)
+                .map(|instruments| {
+                    ClientOrders::new(
+                        instruments,
+                        self.queue_model.unwrap_or_default(),
+                        fee_currency,
+                    )
+                })
                 .ok_or_else(|| ExecutionError::BuilderIncomplete("instruments".to_string()))?,
         };
 
@@ -381,6 +1021,11 @@ impl ClientAccountBuilder {
             .map(|symbol| client_account.balances.balance(symbol))
             .collect::<Result<Vec<&Balance>, ExecutionError>>()?;
 
+        // Validate any third fees Symbol has an associated Balance
+        if let FeeCurrency::Symbol { symbol, .. } = &client_account.fee_schedule.currency {
+            client_account.balances.balance(symbol)?;
+        }
+
         Ok(client_account)
     }
 }
@@ -388,6 +1033,13 @@ impl ClientAccountBuilder {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{
+        model::{order::OrderTrigger, ClientOrderId},
+        simulated::exchange::account::fees::FeeTier,
+    };
+    use barter_integration::model::instrument::kind::InstrumentKind;
+    use std::collections::HashMap;
+    use uuid::Uuid;
 
     #[test]
     fn test_check_order_kind_support() {
@@ -400,7 +1052,7 @@ mod tests {
             TestCase {
                 // TC0: Market
                 kind: OrderKind::Market,
-                expected: Err(ExecutionError::UnsupportedOrderKind(OrderKind::Market)),
+                expected: Ok(()),
             },
             TestCase {
                 // TC1: Limit
@@ -415,9 +1067,27 @@ mod tests {
             TestCase {
                 // TC3: Immediate Or Cancel
                 kind: OrderKind::ImmediateOrCancel,
-                expected: Err(ExecutionError::UnsupportedOrderKind(
-                    OrderKind::ImmediateOrCancel,
-                )),
+                expected: Ok(()),
+            },
+            TestCase {
+                // TC4: Stop Market
+                kind: OrderKind::StopMarket,
+                expected: Ok(()),
+            },
+            TestCase {
+                // TC5: Stop Limit
+                kind: OrderKind::StopLimit,
+                expected: Ok(()),
+            },
+            TestCase {
+                // TC6: Take Profit
+                kind: OrderKind::TakeProfit,
+                expected: Ok(()),
+            },
+            TestCase {
+                // TC7: Trailing Stop
+                kind: OrderKind::TrailingStop,
+                expected: Ok(()),
             },
         ];
 
@@ -429,4 +1099,677 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_try_open_order_atomic() {
+        struct TestCase {
+            liquidity: Liquidity,
+            last_trade_price: Option<f64>,
+            request: Order<RequestOpen>,
+            expected_filled: Result<f64, ExecutionError>,
+            expected_resting: usize,
+            expected_cancelled: bool,
+            expected_base: Balance,
+            expected_quote: Balance,
+        }
+
+        let asks = Liquidity::new([], [Level::new(100.0, 1.0), Level::new(200.0, 1.0)]);
+
+        let tests = vec![
+            TestCase {
+                // TC0: Marketable Limit bid takes the crossed ask Level & rests the remainder
+                liquidity: asks.clone(),
+                last_trade_price: None,
+                request: open_request(OrderKind::Limit, Side::Buy, 150.0, 2.0),
+                expected_filled: Ok(1.0),
+                expected_resting: 1,
+                expected_cancelled: false,
+                expected_base: Balance::new(11.0, 11.0),
+                // Remaining bid reserves 150.0, and the Trade price improvement is released
+                expected_quote: Balance::new(900.0, 750.0),
+            },
+            TestCase {
+                // TC1: Market bid walks every ask Level with slippage & cancels the remainder
+                liquidity: asks.clone(),
+                last_trade_price: None,
+                request: open_request(OrderKind::Market, Side::Buy, 0.0, 3.0),
+                expected_filled: Ok(2.0),
+                expected_resting: 0,
+                expected_cancelled: true,
+                expected_base: Balance::new(12.0, 12.0),
+                expected_quote: Balance::new(1000.0 - 125.0 - 250.0, 1000.0 - 125.0 - 250.0),
+            },
+            TestCase {
+                // TC2: Market ask with no Levels fills at the last traded price with slippage
+                liquidity: Liquidity::default(),
+                last_trade_price: Some(100.0),
+                request: open_request(OrderKind::Market, Side::Sell, 0.0, 1.0),
+                expected_filled: Ok(1.0),
+                expected_resting: 0,
+                expected_cancelled: false,
+                expected_base: Balance::new(9.0, 9.0),
+                expected_quote: Balance::new(1075.0, 1075.0),
+            },
+            TestCase {
+                // TC3: Market ask with no Levels or last traded price is rejected
+                liquidity: Liquidity::default(),
+                last_trade_price: None,
+                request: open_request(OrderKind::Market, Side::Sell, 0.0, 1.0),
+                expected_filled: Err(ExecutionError::Simulated("no liquidity".to_string())),
+                expected_resting: 0,
+                expected_cancelled: false,
+                expected_base: Balance::new(10.0, 10.0),
+                expected_quote: Balance::new(1000.0, 1000.0),
+            },
+            TestCase {
+                // TC4: Market bid with insufficient balance for the slipped fill value is rejected
+                liquidity: Liquidity::new([], [Level::new(1000.0, 2.0)]),
+                last_trade_price: None,
+                request: open_request(OrderKind::Market, Side::Buy, 0.0, 1.0),
+                expected_filled: Err(ExecutionError::InsufficientBalance(Symbol::from("quote"))),
+                expected_resting: 0,
+                expected_cancelled: false,
+                expected_base: Balance::new(10.0, 10.0),
+                expected_quote: Balance::new(1000.0, 1000.0),
+            },
+            TestCase {
+                // TC5: ImmediateOrCancel bid takes the crossed ask Level & cancels the remainder
+                liquidity: asks.clone(),
+                last_trade_price: None,
+                request: open_request(OrderKind::ImmediateOrCancel, Side::Buy, 150.0, 2.0),
+                expected_filled: Ok(1.0),
+                expected_resting: 0,
+                expected_cancelled: true,
+                expected_base: Balance::new(11.0, 11.0),
+                expected_quote: Balance::new(900.0, 900.0),
+            },
+            TestCase {
+                // TC6: PostOnly bid that crosses the best ask is rejected
+                liquidity: asks.clone(),
+                last_trade_price: None,
+                request: open_request(OrderKind::PostOnly, Side::Buy, 150.0, 1.0),
+                expected_filled: Err(ExecutionError::PostOnlyRejected(ClientOrderId(Uuid::nil()))),
+                expected_resting: 0,
+                expected_cancelled: false,
+                expected_base: Balance::new(10.0, 10.0),
+                expected_quote: Balance::new(1000.0, 1000.0),
+            },
+            TestCase {
+                // TC7: PostOnly bid below the best ask rests without taking liquidity
+                liquidity: asks,
+                last_trade_price: None,
+                request: open_request(OrderKind::PostOnly, Side::Buy, 50.0, 1.0),
+                expected_filled: Ok(0.0),
+                expected_resting: 1,
+                expected_cancelled: false,
+                expected_base: Balance::new(10.0, 10.0),
+                expected_quote: Balance::new(1000.0, 950.0),
+            },
+            TestCase {
+                // TC8: PostOnly ask with no Levels that crosses the last traded price is rejected
+                liquidity: Liquidity::default(),
+                last_trade_price: Some(100.0),
+                request: open_request(OrderKind::PostOnly, Side::Sell, 90.0, 1.0),
+                expected_filled: Err(ExecutionError::PostOnlyRejected(ClientOrderId(Uuid::nil()))),
+                expected_resting: 0,
+                expected_cancelled: false,
+                expected_base: Balance::new(10.0, 10.0),
+                expected_quote: Balance::new(1000.0, 1000.0),
+            },
+            TestCase {
+                // TC9: PostOnly ask with no Levels above the last traded price rests
+                liquidity: Liquidity::default(),
+                last_trade_price: Some(100.0),
+                request: open_request(OrderKind::PostOnly, Side::Sell, 110.0, 1.0),
+                expected_filled: Ok(0.0),
+                expected_resting: 1,
+                expected_cancelled: false,
+                expected_base: Balance::new(10.0, 9.0),
+                expected_quote: Balance::new(1000.0, 1000.0),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let (event_account_tx, mut event_account_rx) = mpsc::unbounded_channel();
+            let mut account = client_account(event_account_tx);
+            let orders = account.orders.orders_mut(&instrument()).unwrap();
+            orders.liquidity = test.liquidity;
+            orders.last_trade_price = test.last_trade_price;
+
+            let actual = account.try_open_order_atomic(test.request);
+            match (actual, test.expected_filled) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual.state.filled_quantity, expected, "TC{} failed", index)
+                }
+                (Err(actual), Err(expected))
+                    if std::mem::discriminant(&actual) == std::mem::discriminant(&expected) => {}
+                (actual, expected) => {
+                    panic!("TC{index} failed: actual: {actual:?}, expected: {expected:?}")
+                }
+            }
+
+            let cancelled = std::iter::from_fn(|| event_account_rx.try_recv().ok())
+                .any(|event| matches!(event.kind, AccountEventKind::OrdersCancelled(_)));
+
+            assert_eq!(
+                account.orders.fetch_all().len(),
+                test.expected_resting,
+                "TC{} failed",
+                index
+            );
+            assert_eq!(cancelled, test.expected_cancelled, "TC{} failed", index);
+            assert_eq!(
+                account.balances.balance(&Symbol::from("base")).unwrap(),
+                &test.expected_base,
+                "TC{} failed",
+                index
+            );
+            assert_eq!(
+                account.balances.balance(&Symbol::from("quote")).unwrap(),
+                &test.expected_quote,
+                "TC{} failed",
+                index
+            );
+        }
+    }
+
+    #[test]
+    fn test_conditional_orders() {
+        struct TestCase {
+            request: Order<RequestOpen>,
+            input_prices: Vec<f64>,
+            expected_open: Result<(), ExecutionError>,
+            expected_triggered: bool,
+            expected_resting: usize,
+        }
+
+        let conditional = |kind, side, price, trigger| {
+            let mut request = open_request(kind, side, price, 1.0);
+            request.state.trigger = trigger;
+            request
+        };
+
+        let tests = vec![
+            TestCase {
+                // TC0: StopMarket ask is not triggered by prices above the stop
+                request: conditional(
+                    OrderKind::StopMarket,
+                    Side::Sell,
+                    0.0,
+                    Some(OrderTrigger::Price(90.0)),
+                ),
+                input_prices: vec![95.0],
+                expected_open: Ok(()),
+                expected_triggered: false,
+                expected_resting: 1,
+            },
+            TestCase {
+                // TC1: StopMarket ask is triggered by a price through the stop
+                request: conditional(
+                    OrderKind::StopMarket,
+                    Side::Sell,
+                    0.0,
+                    Some(OrderTrigger::Price(90.0)),
+                ),
+                input_prices: vec![95.0, 89.0],
+                expected_open: Ok(()),
+                expected_triggered: true,
+                expected_resting: 0,
+            },
+            TestCase {
+                // TC2: TakeProfit ask is triggered by a price through the target
+                request: conditional(
+                    OrderKind::TakeProfit,
+                    Side::Sell,
+                    0.0,
+                    Some(OrderTrigger::Price(110.0)),
+                ),
+                input_prices: vec![105.0, 111.0],
+                expected_open: Ok(()),
+                expected_triggered: true,
+                expected_resting: 0,
+            },
+            TestCase {
+                // TC3: StopLimit bid is triggered & rests as a Limit order without liquidity
+                request: conditional(
+                    OrderKind::StopLimit,
+                    Side::Buy,
+                    120.0,
+                    Some(OrderTrigger::Price(110.0)),
+                ),
+                input_prices: vec![111.0],
+                expected_open: Ok(()),
+                expected_triggered: true,
+                expected_resting: 1,
+            },
+            TestCase {
+                // TC4: TrailingStop ask trails the best price, and is not yet triggered
+                request: conditional(
+                    OrderKind::TrailingStop,
+                    Side::Sell,
+                    0.0,
+                    Some(OrderTrigger::Trailing {
+                        offset_percent: 0.1,
+                    }),
+                ),
+                input_prices: vec![100.0, 120.0, 110.0],
+                expected_open: Ok(()),
+                expected_triggered: false,
+                expected_resting: 1,
+            },
+            TestCase {
+                // TC5: TrailingStop ask is triggered once the price retraces by the offset
+                request: conditional(
+                    OrderKind::TrailingStop,
+                    Side::Sell,
+                    0.0,
+                    Some(OrderTrigger::Trailing {
+                        offset_percent: 0.1,
+                    }),
+                ),
+                input_prices: vec![100.0, 120.0, 107.0],
+                expected_open: Ok(()),
+                expected_triggered: true,
+                expected_resting: 0,
+            },
+            TestCase {
+                // TC6: StopMarket with a trailing OrderTrigger is rejected
+                request: conditional(
+                    OrderKind::StopMarket,
+                    Side::Sell,
+                    0.0,
+                    Some(OrderTrigger::Trailing {
+                        offset_percent: 0.1,
+                    }),
+                ),
+                input_prices: vec![],
+                expected_open: Err(ExecutionError::InvalidOrderTrigger(ClientOrderId(
+                    Uuid::nil(),
+                ))),
+                expected_triggered: false,
+                expected_resting: 0,
+            },
+            TestCase {
+                // TC7: TakeProfit without an OrderTrigger is rejected
+                request: conditional(OrderKind::TakeProfit, Side::Sell, 0.0, None),
+                input_prices: vec![],
+                expected_open: Err(ExecutionError::InvalidOrderTrigger(ClientOrderId(
+                    Uuid::nil(),
+                ))),
+                expected_triggered: false,
+                expected_resting: 0,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let (event_account_tx, mut event_account_rx) = mpsc::unbounded_channel();
+            let mut account = client_account(event_account_tx);
+
+            let actual = account.try_open_order_atomic(test.request);
+            match (actual, test.expected_open) {
+                (Ok(_), Ok(())) => {}
+                (Err(actual), Err(expected))
+                    if std::mem::discriminant(&actual) == std::mem::discriminant(&expected) => {}
+                (actual, expected) => {
+                    panic!("TC{index} failed: actual: {actual:?}, expected: {expected:?}")
+                }
+            }
+
+            // Untriggered conditional orders do not reserve any Balance
+            assert_eq!(
+                account.balances.balance(&Symbol::from("base")).unwrap(),
+                &Balance::new(10.0, 10.0),
+                "TC{} failed",
+                index
+            );
+
+            for price in test.input_prices {
+                account.match_orders(
+                    instrument(),
+                    PublicTrade {
+                        id: "trade_id".to_string(),
+                        price,
+                        amount: 0.0,
+                        side: Side::Buy,
+                    },
+                );
+            }
+
+            let triggered = std::iter::from_fn(|| event_account_rx.try_recv().ok())
+                .any(|event| matches!(event.kind, AccountEventKind::OrdersTriggered(_)));
+
+            assert_eq!(triggered, test.expected_triggered, "TC{} failed", index);
+            assert_eq!(
+                account.orders.fetch_all().len(),
+                test.expected_resting,
+                "TC{} failed",
+                index
+            );
+        }
+    }
+
+    #[test]
+    fn test_try_amend_order_atomic() {
+        struct TestCase {
+            order_id: Option<OrderId>,
+            price: f64,
+            quantity: f64,
+            expected: Result<(f64, f64), fn(ClientOrderId) -> ExecutionError>,
+            expected_quote: Balance,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: Increasing the price of a bid reserves the additional quote Balance
+                order_id: None,
+                price: 60.0,
+                quantity: 2.0,
+                expected: Ok((60.0, 2.0)),
+                expected_quote: Balance::new(1000.0, 880.0),
+            },
+            TestCase {
+                // TC1: Decreasing the quantity of a bid releases the unused quote Balance
+                order_id: None,
+                price: 50.0,
+                quantity: 1.0,
+                expected: Ok((50.0, 1.0)),
+                expected_quote: Balance::new(1000.0, 950.0),
+            },
+            TestCase {
+                // TC2: Amending an unknown OrderId is rejected
+                order_id: Some(OrderId::from("unknown")),
+                price: 60.0,
+                quantity: 2.0,
+                expected: Err(ExecutionError::OrderNotFound),
+                expected_quote: Balance::new(1000.0, 900.0),
+            },
+            TestCase {
+                // TC3: Amending the quantity to zero is rejected
+                order_id: None,
+                price: 50.0,
+                quantity: 0.0,
+                expected: Err(ExecutionError::AmendQuantityFilled),
+                expected_quote: Balance::new(1000.0, 900.0),
+            },
+            TestCase {
+                // TC4: Amending a bid price that crosses the best ask is rejected
+                order_id: None,
+                price: 150.0,
+                quantity: 2.0,
+                expected: Err(ExecutionError::AmendCrossesSpread),
+                expected_quote: Balance::new(1000.0, 900.0),
+            },
+            TestCase {
+                // TC5: Amending a bid with insufficient quote Balance is rejected
+                order_id: None,
+                price: 50.0,
+                quantity: 30.0,
+                expected: Err(|_| ExecutionError::InsufficientBalance(Symbol::from("quote"))),
+                expected_quote: Balance::new(1000.0, 900.0),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let (event_account_tx, mut event_account_rx) = mpsc::unbounded_channel();
+            let mut account = client_account(event_account_tx);
+            account.orders.orders_mut(&instrument()).unwrap().liquidity =
+                Liquidity::new([], [Level::new(100.0, 1.0)]);
+
+            let open = account
+                .try_open_order_atomic(open_request(OrderKind::Limit, Side::Buy, 50.0, 2.0))
+                .unwrap();
+
+            let actual = account.try_amend_order_atomic(Order {
+                exchange: open.exchange.clone(),
+                instrument: open.instrument.clone(),
+                cid: open.cid,
+                side: open.side,
+                state: RequestAmend {
+                    id: test.order_id.unwrap_or_else(|| open.state.id.clone()),
+                    price: test.price,
+                    quantity: test.quantity,
+                },
+            });
+
+            match (actual, test.expected) {
+                (Ok(actual), Ok((price, quantity))) => {
+                    assert_eq!(actual.state.price, price, "TC{} failed", index);
+                    assert_eq!(actual.state.quantity, quantity, "TC{} failed", index);
+                    assert_eq!(
+                        account.orders.fetch_all(),
+                        vec![actual],
+                        "TC{} failed",
+                        index
+                    );
+
+                    let amended = std::iter::from_fn(|| event_account_rx.try_recv().ok())
+                        .any(|event| matches!(event.kind, AccountEventKind::OrdersAmended(_)));
+                    assert!(amended, "TC{} failed", index);
+                }
+                (Err(actual), Err(expected)) => {
+                    assert_eq!(actual, expected(open.cid), "TC{} failed", index);
+                    assert_eq!(account.orders.fetch_all(), vec![open], "TC{} failed", index);
+                }
+                (actual, expected) => {
+                    panic!(
+                        "TC{index} failed: actual: {actual:?}, expected: {:?}",
+                        expected.map_err(|expected| expected(open.cid))
+                    )
+                }
+            }
+
+            assert_eq!(
+                account.balances.balance(&Symbol::from("quote")).unwrap(),
+                &test.expected_quote,
+                "TC{} failed",
+                index
+            );
+        }
+    }
+
+    #[test]
+    fn test_fee_schedule_maker_taker_fees() {
+        let (event_account_tx, _event_account_rx) = mpsc::unbounded_channel();
+        let mut account = ClientAccount::builder()
+            .latency(Duration::default())
+            .fee_schedule(
+                FeeSchedule::new(vec![FeeTier::new(0.0, 0.0, 0.01)]).with_currency(
+                    FeeCurrency::Symbol {
+                        symbol: Symbol::from("bnb"),
+                        quote_price: 10.0,
+                    },
+                ),
+            )
+            .event_account_tx(event_account_tx)
+            .instruments(vec![instrument()])
+            .balances(ClientBalances(HashMap::from([
+                (Symbol::from("base"), Balance::new(10.0, 10.0)),
+                (Symbol::from("quote"), Balance::new(1000.0, 1000.0)),
+                (Symbol::from("bnb"), Balance::new(10.0, 10.0)),
+            ])))
+            .build()
+            .unwrap();
+        account.orders.orders_mut(&instrument()).unwrap().liquidity =
+            Liquidity::new([], [Level::new(100.0, 1.0)]);
+
+        // Marketable Limit bid takes the ask Level, paying taker fees in bnb
+        account
+            .try_open_order_atomic(open_request(OrderKind::Limit, Side::Buy, 150.0, 2.0))
+            .unwrap();
+        assert_eq!(
+            account.balances.balance(&Symbol::from("bnb")).unwrap(),
+            &Balance::new(10.0 - 0.01 * 100.0 / 10.0, 10.0 - 0.01 * 100.0 / 10.0)
+        );
+
+        // Resting remainder is matched by a PublicTrade, paying zero maker fees
+        account.match_orders(
+            instrument(),
+            PublicTrade {
+                id: "trade_id".to_string(),
+                price: 150.0,
+                amount: 1.0,
+                side: Side::Sell,
+            },
+        );
+        assert_eq!(
+            account.balances.balance(&Symbol::from("bnb")).unwrap(),
+            &Balance::new(9.9, 9.9)
+        );
+        assert_eq!(
+            account.balances.balance(&Symbol::from("base")).unwrap(),
+            &Balance::new(12.0, 12.0)
+        );
+        assert_eq!(account.trade_volume.total(Utc::now()), 100.0 + 150.0);
+    }
+
+    #[test]
+    fn test_fee_reserve_for_fees_not_deducted_from_received_asset() {
+        let (event_account_tx, _event_account_rx) = mpsc::unbounded_channel();
+        let mut account = ClientAccount::builder()
+            .latency(Duration::default())
+            .fee_schedule(FeeSchedule::flat(0.01).with_currency(FeeCurrency::Symbol {
+                symbol: Symbol::from("bnb"),
+                quote_price: 10.0,
+            }))
+            .event_account_tx(event_account_tx)
+            .instruments(vec![instrument()])
+            .balances(ClientBalances(HashMap::from([
+                (Symbol::from("base"), Balance::new(10.0, 10.0)),
+                (Symbol::from("quote"), Balance::new(1000.0, 1000.0)),
+                (Symbol::from("bnb"), Balance::new(0.15, 0.15)),
+            ])))
+            .build()
+            .unwrap();
+        let assert_bnb = |account: &ClientAccount, total: f64, available: f64| {
+            let actual = account.balances.balance(&Symbol::from("bnb")).unwrap();
+            assert!((actual.total - total).abs() < 1e-12, "{actual:?}");
+            assert!((actual.available - available).abs() < 1e-12, "{actual:?}");
+        };
+
+        // Resting bid reserves its maker fees: 0.01 * 2.0 * 50.0 / 10.0
+        let open = account
+            .try_open_order_atomic(open_request(OrderKind::Limit, Side::Buy, 50.0, 2.0))
+            .unwrap();
+        assert_bnb(&account, 0.15, 0.05);
+
+        // Bid with fees exceeding the available bnb Balance is rejected
+        assert_eq!(
+            account.try_open_order_atomic(open_request(OrderKind::Limit, Side::Buy, 50.0, 2.0)),
+            Err(ExecutionError::InsufficientBalance(Symbol::from("bnb")))
+        );
+
+        // Filling the bid deducts the fees from the total, consuming the reserve
+        account.match_orders(
+            instrument(),
+            PublicTrade {
+                id: "trade_id".to_string(),
+                price: 50.0,
+                amount: 2.0,
+                side: Side::Sell,
+            },
+        );
+        assert_bnb(&account, 0.05, 0.05);
+        assert!(!account.fee_reserves.contains_key(&open.state.id));
+
+        // Amending a bid reserves the additional fees if the available bnb Balance is sufficient
+        let open = account
+            .try_open_order_atomic(open_request(OrderKind::Limit, Side::Buy, 40.0, 1.0))
+            .unwrap();
+        assert_bnb(&account, 0.05, 0.01);
+
+        let amend = |quantity: f64| Order {
+            exchange: open.exchange.clone(),
+            instrument: open.instrument.clone(),
+            cid: open.cid,
+            side: open.side,
+            state: RequestAmend {
+                id: open.state.id.clone(),
+                price: 40.0,
+                quantity,
+            },
+        };
+        assert_eq!(
+            account.try_amend_order_atomic(amend(1.5)),
+            Err(ExecutionError::InsufficientBalance(Symbol::from("bnb")))
+        );
+        account.try_amend_order_atomic(amend(1.2)).unwrap();
+        assert_bnb(&account, 0.05, 0.002);
+
+        // Cancelling the bid releases the remaining reserve
+        account
+            .try_cancel_order_atomic(Order {
+                exchange: open.exchange.clone(),
+                instrument: open.instrument.clone(),
+                cid: open.cid,
+                side: open.side,
+                state: RequestCancel::from(open.state.id.clone()),
+            })
+            .unwrap();
+        assert_bnb(&account, 0.05, 0.05);
+        assert!(account.fee_reserves.is_empty());
+    }
+
+    #[test]
+    fn test_fee_reserve_same_symbol_as_order_balance() {
+        let (event_account_tx, _event_account_rx) = mpsc::unbounded_channel();
+        let mut account = ClientAccount::builder()
+            .latency(Duration::default())
+            .fee_schedule(FeeSchedule::flat(0.01).with_currency(FeeCurrency::Quote))
+            .event_account_tx(event_account_tx)
+            .instruments(vec![instrument()])
+            .balances(ClientBalances(HashMap::from([
+                (Symbol::from("base"), Balance::new(10.0, 10.0)),
+                (Symbol::from("quote"), Balance::new(1000.0, 1000.0)),
+            ])))
+            .build()
+            .unwrap();
+
+        // Quote Balance must cover both the bid value and it's quote fees
+        assert_eq!(
+            account.try_open_order_atomic(open_request(OrderKind::Limit, Side::Buy, 100.0, 10.0)),
+            Err(ExecutionError::InsufficientBalance(Symbol::from("quote")))
+        );
+
+        account
+            .try_open_order_atomic(open_request(OrderKind::Limit, Side::Buy, 100.0, 9.0))
+            .unwrap();
+        assert_eq!(
+            account.balances.balance(&Symbol::from("quote")).unwrap(),
+            &Balance::new(1000.0, 1000.0 - 900.0 - 9.0)
+        );
+    }
+
+    fn instrument() -> Instrument {
+        Instrument::from(("base", "quote", InstrumentKind::Spot))
+    }
+
+    fn client_account(event_account_tx: mpsc::UnboundedSender<AccountEvent>) -> ClientAccount {
+        ClientAccount::builder()
+            .latency(Duration::default())
+            .fees_percent(0.0)
+            .slippage_percent(0.25)
+            .event_account_tx(event_account_tx)
+            .instruments(vec![instrument()])
+            .balances(ClientBalances(HashMap::from([
+                (Symbol::from("base"), Balance::new(10.0, 10.0)),
+                (Symbol::from("quote"), Balance::new(1000.0, 1000.0)),
+            ])))
+            .build()
+            .unwrap()
+    }
+
+    fn open_request(kind: OrderKind, side: Side, price: f64, quantity: f64) -> Order<RequestOpen> {
+        Order {
+            exchange: Exchange::from(ExecutionId::Simulated),
+            instrument: instrument(),
+            cid: ClientOrderId(Uuid::new_v4()),
+            side,
+            state: RequestOpen {
+                kind,
+                price,
+                quantity,
+                trigger: None,
+            },
+        }
+    }
 }
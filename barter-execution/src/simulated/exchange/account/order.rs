@@ -1,27 +1,47 @@
+use super::fees::FeeCurrency;
 use crate::{
-    model::trade::{SymbolFees, Trade, TradeId},
+    model::{
+        order::{OrderKind, OrderTrigger},
+        trade::{SymbolFees, Trade, TradeId},
+    },
     ExecutionError, Open, Order, OrderId, RequestOpen,
 };
-use barter_data::subscription::trade::PublicTrade;
+use barter_data::subscription::{
+    book::{Level, OrderBook, OrderBookL1},
+    trade::PublicTrade,
+};
 use barter_integration::model::{instrument::Instrument, Side};
 use serde::{Deserialize, Serialize};
 use std::{cmp::Ordering, collections::HashMap};
 
 /// [`ClientAccount`](super::ClientAccount) [`Orders`] for each [`Instrument`].
-#[derive(Clone, Eq, PartialEq, Debug, Default, Deserialize, Serialize)]
+#[derive(Clone, PartialEq, Debug, Default, Deserialize, Serialize)]
 pub struct ClientOrders {
     pub request_counter: u64,
     pub all: HashMap<Instrument, Orders>,
 }
 
 impl ClientOrders {
-    /// Construct a new [`ClientOrders`] from the provided selection of [`Instrument`]s.
-    pub fn new(instruments: Vec<Instrument>) -> Self {
+    /// Construct a new [`ClientOrders`] from the provided selection of [`Instrument`]s, using the
+    /// provided [`QueueModel`] to simulate the queue position of resting orders, and denominating
+    /// trade fees in the provided [`FeeCurrency`].
+    pub fn new(
+        instruments: Vec<Instrument>,
+        queue_model: QueueModel,
+        fee_currency: FeeCurrency,
+    ) -> Self {
         Self {
             request_counter: 0,
             all: instruments
                 .into_iter()
-                .map(|instrument| (instrument, Orders::default()))
+                .map(|instrument| {
+                    let orders = Orders {
+                        queue_model,
+                        fee_currency: fee_currency.clone(),
+                        ..Orders::default()
+                    };
+                    (instrument, orders)
+                })
                 .collect(),
         }
     }
@@ -35,13 +55,19 @@ impl ClientOrders {
         })
     }
 
-    /// Fetch the bid and ask [`Order<Open>`]s for every [`Instrument`].
+    /// Fetch the bid, ask and untriggered [`ConditionalOrder`] [`Order<Open>`]s for every
+    /// [`Instrument`].
     pub fn fetch_all(&self) -> Vec<Order<Open>> {
         self.all
             .values()
-            .flat_map(|market| [&market.bids, &market.asks])
-            .flatten()
-            .cloned()
+            .flat_map(|market| {
+                market
+                    .bids
+                    .iter()
+                    .chain(market.asks.iter())
+                    .cloned()
+                    .chain(market.conditional.iter().map(ConditionalOrder::open))
+            })
             .collect()
     }
 
@@ -66,16 +92,34 @@ impl ClientOrders {
 
 /// Client [`Orders`] for an [`Instrument`]. Simulates client orders in an real
 /// multi-participant OrderBook.
-#[derive(Clone, Eq, PartialEq, Debug, Default, Deserialize, Serialize)]
+#[derive(Clone, PartialEq, Debug, Default, Deserialize, Serialize)]
 pub struct Orders {
     pub trade_counter: u64,
     pub bids: Vec<Order<Open>>,
     pub asks: Vec<Order<Open>>,
+    pub liquidity: Liquidity,
+    pub last_trade_price: Option<f64>,
+    pub queue_model: QueueModel,
+    /// Visible [`Level`] size queued ahead of each resting [`Order<Open>`] at it's price.
+    pub queue_ahead: HashMap<OrderId, f64>,
+    pub fee_currency: FeeCurrency,
+    pub conditional: Vec<ConditionalOrder>,
 }
 
 impl Orders {
     /// Add an [`Order<Open>`] to the bids or asks depending on it's [`Side`].
+    ///
+    /// If the [`QueueModel`] is enabled, the [`Order<Open>`] joins the back of the queue behind
+    /// the visible [`Level`] size at it's price.
     pub fn add_order_open(&mut self, open: Order<Open>) {
+        if self.queue_model == QueueModel::VisibleSizeAhead {
+            let size_ahead = self
+                .liquidity
+                .size_at(open.side, open.state.price)
+                .unwrap_or_default();
+            self.queue_ahead.insert(open.state.id.clone(), size_ahead);
+        }
+
         match open.side {
             Side::Buy => {
                 // Add Order<Open> to open bids
@@ -157,6 +201,12 @@ impl Orders {
                 break Some(best_bid);
             }
 
+            // Trade liquidity must first consume any visible size queued ahead of the best bid
+            remaining_liquidity = self.consume_queue_ahead(&best_bid, trade, remaining_liquidity);
+            if remaining_liquidity <= 0.0 {
+                break Some(best_bid);
+            }
+
             // Remaining liquidity is either a full-fill or a partial-fill
             self.trade_counter += 1;
             match OrderFill::kind(&best_bid, remaining_liquidity) {
@@ -167,6 +217,7 @@ impl Orders {
                     remaining_liquidity -= trade_quantity;
 
                     // Generate execution Trade from full Order<Open> fill
+                    self.queue_ahead.remove(&best_bid.state.id);
                     trades.push(self.generate_trade(best_bid, trade_quantity, fees_percent));
 
                     // If exact full fill with zero remaining liquidity (highly unlikely), break
@@ -201,6 +252,31 @@ impl Orders {
         trades
     }
 
+    /// Consume the visible size queued ahead of the provided [`Order<Open>`] using the
+    /// [`PublicTrade`] liquidity, returning the liquidity remaining to fill the order with.
+    ///
+    /// A [`PublicTrade`] that prints through the order price implies the queue at that price has
+    /// been exhausted.
+    pub fn consume_queue_ahead(
+        &mut self,
+        order: &Order<Open>,
+        trade: &PublicTrade,
+        liquidity: f64,
+    ) -> f64 {
+        let Some(size_ahead) = self.queue_ahead.get_mut(&order.state.id) else {
+            return liquidity;
+        };
+
+        if order.state.price != trade.price {
+            *size_ahead = 0.0;
+            return liquidity;
+        }
+
+        let consumed = size_ahead.min(liquidity);
+        *size_ahead -= consumed;
+        liquidity - consumed
+    }
+
     /// Generate a client [`Trade`] with a unique [`TradeId`] for this [`Instrument`] market.
     pub fn generate_trade(
         &self,
@@ -208,8 +284,27 @@ impl Orders {
         trade_quantity: f64,
         fees_percent: f64,
     ) -> Trade {
-        // Calculate the trade fees (denominated in base or quote depending on Order Side)
-        let fees = calculate_fees(&order, trade_quantity, fees_percent);
+        let price = order.state.price;
+        self.generate_trade_at_price(order, price, trade_quantity, fees_percent)
+    }
+
+    /// Generate a client [`Trade`] with a unique [`TradeId`] for this [`Instrument`] market,
+    /// executed at the provided price (eg/ the price of a [`Level`] taken by a marketable order).
+    pub fn generate_trade_at_price(
+        &self,
+        order: Order<Open>,
+        price: f64,
+        trade_quantity: f64,
+        fees_percent: f64,
+    ) -> Trade {
+        // Calculate the trade fees (denominated in the configured FeeCurrency)
+        let fees = self.fee_currency.fees(
+            &order.instrument,
+            order.side,
+            price,
+            trade_quantity,
+            fees_percent,
+        );
 
         // Generate execution Trade from the Order<Open> match
         Trade {
@@ -217,7 +312,7 @@ impl Orders {
             order_id: order.state.id,
             instrument: order.instrument,
             side: order.side,
-            price: order.state.price,
+            price,
             quantity: trade_quantity,
             fees,
         }
@@ -250,6 +345,12 @@ impl Orders {
                 break Some(best_ask);
             }
 
+            // Trade liquidity must first consume any visible size queued ahead of the best ask
+            remaining_liquidity = self.consume_queue_ahead(&best_ask, trade, remaining_liquidity);
+            if remaining_liquidity <= 0.0 {
+                break Some(best_ask);
+            }
+
             // Remaining liquidity is either a full-fill or a partial-fill
             self.trade_counter += 1;
             match OrderFill::kind(&best_ask, remaining_liquidity) {
@@ -260,6 +361,7 @@ impl Orders {
                     remaining_liquidity -= trade_quantity;
 
                     // Generate execution Trade from full Order<Open> fill
+                    self.queue_ahead.remove(&best_ask.state.id);
                     trades.push(self.generate_trade(best_ask, trade_quantity, fees_percent));
 
                     // If exact full fill with zero remaining liquidity (highly unlikely), break
@@ -294,10 +396,433 @@ impl Orders {
         trades
     }
 
+    /// Find the [`Order<Open>`] associated with the provided [`OrderId`] in the bids or asks
+    /// depending on the [`Side`].
+    pub fn find_order_open(&self, side: Side, id: &OrderId) -> Option<&Order<Open>> {
+        let orders = match side {
+            Side::Buy => &self.bids,
+            Side::Sell => &self.asks,
+        };
+
+        orders.iter().find(|order| &order.state.id == id)
+    }
+
+    /// Replace the [`Order<Open>`] with the same [`OrderId`] as the provided amended
+    /// [`Order<Open>`], returning the original.
+    ///
+    /// Amends that change the price or increase the quantity lose queue priority, re-joining the
+    /// back of the queue behind the visible [`Level`] size at the amended price.
+    pub fn amend_order_open(&mut self, amended: Order<Open>) -> Option<Order<Open>> {
+        let orders = match amended.side {
+            Side::Buy => &mut self.bids,
+            Side::Sell => &mut self.asks,
+        };
+
+        let index = orders
+            .iter()
+            .position(|order| order.state.id == amended.state.id)?;
+        let original = std::mem::replace(&mut orders[index], amended.clone());
+        orders.sort();
+
+        let retains_priority = amended.state.price == original.state.price
+            && amended.state.quantity <= original.state.quantity;
+
+        if !retains_priority && self.queue_model == QueueModel::VisibleSizeAhead {
+            let size_ahead = self
+                .liquidity
+                .size_at(amended.side, amended.state.price)
+                .unwrap_or_default();
+            self.queue_ahead.insert(amended.state.id, size_ahead);
+        }
+
+        Some(original)
+    }
+
+    /// Remove the [`Order<Open>`] associated with the provided [`OrderId`] from the bids or asks
+    /// depending on the [`Side`], discarding it's queue position.
+    pub fn remove_order_open(&mut self, side: Side, id: &OrderId) -> Option<Order<Open>> {
+        let orders = match side {
+            Side::Buy => &mut self.bids,
+            Side::Sell => &mut self.asks,
+        };
+
+        let index = orders.iter().position(|order| &order.state.id == id)?;
+        self.queue_ahead.remove(id);
+        Some(orders.remove(index))
+    }
+
+    /// Remove every bid and ask [`Order<Open>`], discarding their queue positions.
+    pub fn remove_orders_all(&mut self) -> Vec<Order<Open>> {
+        self.queue_ahead.clear();
+        self.bids.drain(..).chain(self.asks.drain(..)).collect()
+    }
+
+    /// Remove the untriggered [`ConditionalOrder`] associated with the provided [`OrderId`].
+    pub fn remove_conditional(&mut self, id: &OrderId) -> Option<ConditionalOrder> {
+        let index = self
+            .conditional
+            .iter()
+            .position(|conditional| &conditional.id == id)?;
+        Some(self.conditional.remove(index))
+    }
+
+    /// Update every untriggered [`ConditionalOrder`] with the latest traded price, removing and
+    /// returning those that are now triggered.
+    pub fn trigger_conditional(&mut self, price: f64) -> Vec<ConditionalOrder> {
+        let mut triggered = Vec::new();
+        for mut conditional in std::mem::take(&mut self.conditional) {
+            match conditional.is_triggered(price) {
+                true => triggered.push(conditional),
+                false => self.conditional.push(conditional),
+            }
+        }
+        triggered
+    }
+
     /// Calculates the total number of open bids and asks.
     pub fn num_orders(&self) -> usize {
         self.bids.len() + self.asks.len()
     }
+
+    /// Update the latest known market [`Liquidity`], and simulate trades for every open client
+    /// [`Order<Open>`] that the opposing best [`Level`]s now cross.
+    ///
+    /// Visible size queued ahead of each resting [`Order<Open>`] cannot exceed the updated
+    /// [`Level`] size at it's price, since any reduction is assumed to be cancellations ahead.
+    pub fn update_liquidity(&mut self, liquidity: Liquidity, fees_percent: f64) -> Vec<Trade> {
+        self.liquidity = liquidity;
+
+        for order in self.bids.iter().chain(self.asks.iter()) {
+            let size_ahead = self.queue_ahead.get_mut(&order.state.id);
+            let level_size = self.liquidity.size_at(order.side, order.state.price);
+            if let (Some(size_ahead), Some(level_size)) = (size_ahead, level_size) {
+                *size_ahead = size_ahead.min(level_size);
+            }
+        }
+
+        let mut trades = self.match_liquidity(Side::Buy, fees_percent);
+        trades.extend(self.match_liquidity(Side::Sell, fees_percent));
+        trades
+    }
+
+    /// Simulates trades by matching the open client [`Order<Open>`]s of the provided [`Side`]
+    /// against the crossing opposing [`Level`]s of the latest [`Liquidity`].
+    ///
+    /// Resting [`Order<Open>`]s are filled at their own price, consuming the [`Level`] liquidity
+    /// they match with.
+    pub fn match_liquidity(&mut self, side: Side, fees_percent: f64) -> Vec<Trade> {
+        // Collection of execution Trades generated from Order<Open> matches
+        let mut trades = vec![];
+
+        loop {
+            let (orders, levels) = self.orders_and_opposing_levels_mut(side);
+
+            // Pop the best Order<Open>
+            let Some(mut best_order) = orders.pop() else {
+                break;
+            };
+
+            // Break with remaining best order if it's not crossed by the best opposing Level
+            let Some(level) = levels
+                .first_mut()
+                .filter(|level| crosses(side, best_order.state.price, level.price))
+            else {
+                orders.push(best_order);
+                break;
+            };
+
+            // Level liquidity is either a full-fill or a partial-fill
+            let order_fill = OrderFill::kind(&best_order, level.amount);
+            let trade_quantity = match order_fill {
+                OrderFill::Full => best_order.state.remaining_quantity(),
+                OrderFill::Partial => level.amount,
+            };
+
+            // Consume the matched Level liquidity
+            level.amount -= trade_quantity;
+            if level.amount <= 0.0 {
+                levels.remove(0);
+            }
+
+            // Generate execution Trade from the Order<Open> match
+            self.trade_counter += 1;
+            best_order.state.filled_quantity += trade_quantity;
+            trades.push(self.generate_trade(best_order.clone(), trade_quantity, fees_percent));
+
+            // Partially filled Order<Open> remains the best order, and may match the next Level
+            match order_fill {
+                OrderFill::Full => {
+                    self.queue_ahead.remove(&best_order.state.id);
+                }
+                OrderFill::Partial => {
+                    self.orders_and_opposing_levels_mut(side).0.push(best_order);
+                }
+            }
+        }
+
+        trades
+    }
+
+    /// Simulates trades for an [`Order<Open>`] using the provided fill [`Level`]s taken from the
+    /// latest [`Liquidity`], consuming the taken liquidity.
+    ///
+    /// Returns the generated [`Trade`]s, and the [`Order<Open>`] with an updated filled quantity.
+    pub fn fill_order(
+        &mut self,
+        mut order: Order<Open>,
+        fills: Vec<Level>,
+        fees_percent: f64,
+    ) -> (Vec<Trade>, Order<Open>) {
+        // Consume the taken Level liquidity
+        self.liquidity
+            .consume(order.side, fills.iter().map(|fill| fill.amount).sum());
+
+        // Generate execution Trades from each fill Level
+        let trades = fills
+            .into_iter()
+            .map(|fill| {
+                self.trade_counter += 1;
+                order.state.filled_quantity += fill.amount;
+                self.generate_trade_at_price(order.clone(), fill.price, fill.amount, fees_percent)
+            })
+            .collect();
+
+        (trades, order)
+    }
+
+    /// Determine if an order of the provided [`Side`] & price would cross the spread upon opening.
+    ///
+    /// The best opposing [`Level`] of the latest [`Liquidity`] is used if known, otherwise the
+    /// last traded price. If neither are known the order cannot cross.
+    pub fn would_cross(&self, side: Side, price: f64) -> bool {
+        let best_opposing_level = match side {
+            Side::Buy => self.liquidity.asks.first(),
+            Side::Sell => self.liquidity.bids.first(),
+        };
+
+        match (best_opposing_level, self.last_trade_price) {
+            (Some(level), _) => crosses(side, price, level.price),
+            (None, Some(last_trade_price)) => match side {
+                Side::Buy => price > last_trade_price,
+                Side::Sell => price < last_trade_price,
+            },
+            (None, None) => false,
+        }
+    }
+
+    /// Return mutable references to the open client [`Order<Open>`]s of the provided [`Side`],
+    /// and the opposing [`Level`]s of the latest [`Liquidity`] they can match with.
+    fn orders_and_opposing_levels_mut(
+        &mut self,
+        side: Side,
+    ) -> (&mut Vec<Order<Open>>, &mut Vec<Level>) {
+        match side {
+            Side::Buy => (&mut self.bids, &mut self.liquidity.asks),
+            Side::Sell => (&mut self.asks, &mut self.liquidity.bids),
+        }
+    }
+}
+
+/// Untriggered conditional client order (eg/ [`OrderKind::StopMarket`]), held until the traded
+/// price crosses it's [`OrderTrigger`].
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct ConditionalOrder {
+    pub id: OrderId,
+    pub request: Order<RequestOpen>,
+    /// Best price traded since opening, used to trail the trigger of
+    /// [`OrderKind::TrailingStop`] orders.
+    pub best_price: Option<f64>,
+}
+
+impl ConditionalOrder {
+    /// Construct a new untriggered [`ConditionalOrder`].
+    pub fn new(id: OrderId, request: Order<RequestOpen>) -> Self {
+        Self {
+            id,
+            request,
+            best_price: None,
+        }
+    }
+
+    /// [`Order<Open>`] representation of the untriggered [`ConditionalOrder`].
+    pub fn open(&self) -> Order<Open> {
+        Order::from((self.id.clone(), self.request.clone()))
+    }
+
+    /// [`Order<RequestOpen>`] to execute once triggered, using the activated [`OrderKind`].
+    pub fn activated(&self) -> Order<RequestOpen> {
+        let mut request = self.request.clone();
+        request.state.kind = request.state.kind.activated();
+        request.state.trigger = None;
+        request
+    }
+
+    /// Update the [`ConditionalOrder`] with the latest traded price, and determine if it's
+    /// [`OrderTrigger`] has been crossed.
+    ///
+    /// Stops trigger once the price moves against the order [`Side`] (eg/ a [`Side::Sell`] stop
+    /// triggers at or below it's price), whereas take profits trigger once the price moves in
+    /// favour of it.
+    pub fn is_triggered(&mut self, price: f64) -> bool {
+        let side = self.request.side;
+        match (self.request.state.kind, self.request.state.trigger) {
+            (OrderKind::StopMarket | OrderKind::StopLimit, Some(OrderTrigger::Price(stop))) => {
+                match side {
+                    Side::Buy => price >= stop,
+                    Side::Sell => price <= stop,
+                }
+            }
+            (OrderKind::TakeProfit, Some(OrderTrigger::Price(target))) => match side {
+                Side::Buy => price <= target,
+                Side::Sell => price >= target,
+            },
+            (OrderKind::TrailingStop, Some(OrderTrigger::Trailing { offset_percent })) => {
+                // Trail the best price in favour of the position being exited
+                let best_price = match (side, self.best_price) {
+                    (_, None) => price,
+                    (Side::Buy, Some(best_price)) => best_price.min(price),
+                    (Side::Sell, Some(best_price)) => best_price.max(price),
+                };
+                self.best_price = Some(best_price);
+
+                match side {
+                    Side::Buy => price >= best_price * (1.0 + offset_percent),
+                    Side::Sell => price <= best_price * (1.0 - offset_percent),
+                }
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Latest known market [`Level`]s for an [`Instrument`], sourced from [`OrderBookL1`] and
+/// [`OrderBook`] snapshots. Used to match client orders against the visible liquidity.
+#[derive(Clone, Eq, PartialEq, Debug, Default, Deserialize, Serialize)]
+pub struct Liquidity {
+    /// Bid [`Level`]s sorted from best (highest price) to worst.
+    pub bids: Vec<Level>,
+    /// Ask [`Level`]s sorted from best (lowest price) to worst.
+    pub asks: Vec<Level>,
+}
+
+impl Liquidity {
+    /// Construct a new [`Liquidity`] from the provided bid and ask [`Level`]s, discarding empty
+    /// [`Level`]s and sorting each side from best to worst.
+    pub fn new<Bids, Asks>(bids: Bids, asks: Asks) -> Self
+    where
+        Bids: IntoIterator<Item = Level>,
+        Asks: IntoIterator<Item = Level>,
+    {
+        let mut bids = bids
+            .into_iter()
+            .filter(|level| level.amount > 0.0)
+            .collect::<Vec<_>>();
+        let mut asks = asks
+            .into_iter()
+            .filter(|level| level.amount > 0.0)
+            .collect::<Vec<_>>();
+
+        bids.sort_unstable_by(|a, b| b.cmp(a));
+        asks.sort_unstable();
+
+        Self { bids, asks }
+    }
+
+    /// Determine the [`Level`]s an order of the provided [`Side`] & quantity would take by walking
+    /// the opposing [`Level`]s, stopping at the (optional) limit price. Does not consume the
+    /// [`Level`] liquidity.
+    pub fn take(&self, side: Side, limit: Option<f64>, quantity: f64) -> Vec<Level> {
+        let levels = match side {
+            Side::Buy => &self.asks,
+            Side::Sell => &self.bids,
+        };
+
+        let mut remaining_quantity = quantity;
+        levels
+            .iter()
+            .take_while(|level| limit.is_none_or(|limit| crosses(side, limit, level.price)))
+            .map_while(|level| {
+                if remaining_quantity <= 0.0 {
+                    return None;
+                }
+                let amount = level.amount.min(remaining_quantity);
+                remaining_quantity -= amount;
+                Some(Level::new(level.price, amount))
+            })
+            .collect()
+    }
+
+    /// Visible size of the [`Level`] at the provided price on the same [`Side`] as an order, if
+    /// known.
+    pub fn size_at(&self, side: Side, price: f64) -> Option<f64> {
+        let levels = match side {
+            Side::Buy => &self.bids,
+            Side::Sell => &self.asks,
+        };
+
+        levels
+            .iter()
+            .find(|level| level.price == price)
+            .map(|level| level.amount)
+    }
+
+    /// Consume the provided quantity from the best opposing [`Level`]s of an order of the provided
+    /// [`Side`].
+    pub fn consume(&mut self, side: Side, mut quantity: f64) {
+        let levels = match side {
+            Side::Buy => &mut self.asks,
+            Side::Sell => &mut self.bids,
+        };
+
+        while quantity > 0.0 {
+            let Some(level) = levels.first_mut() else {
+                break;
+            };
+
+            let amount = level.amount.min(quantity);
+            level.amount -= amount;
+            quantity -= amount;
+
+            if level.amount <= 0.0 {
+                levels.remove(0);
+            }
+        }
+    }
+}
+
+impl From<&OrderBookL1> for Liquidity {
+    fn from(book: &OrderBookL1) -> Self {
+        Self::new([book.best_bid], [book.best_ask])
+    }
+}
+
+impl From<&OrderBook> for Liquidity {
+    fn from(book: &OrderBook) -> Self {
+        Self::new(
+            book.bids.levels().iter().copied(),
+            book.asks.levels().iter().copied(),
+        )
+    }
+}
+
+/// Model used by the [`Orders`] of an [`Instrument`] to simulate the queue position of resting
+/// client [`Order<Open>`]s.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default, Deserialize, Serialize)]
+pub enum QueueModel {
+    /// Resting orders are filled as soon as any [`PublicTrade`] prints at their price.
+    #[default]
+    None,
+    /// Resting orders join the back of the visible [`Level`] at their price, and are only filled
+    /// once [`PublicTrade`]s at that price have consumed the size queued ahead of them.
+    VisibleSizeAhead,
+}
+
+/// Determine if an order of the provided [`Side`] and price crosses an opposing [`Level`] price.
+pub fn crosses(side: Side, order_price: f64, level_price: f64) -> bool {
+    match side {
+        Side::Buy => level_price <= order_price,
+        Side::Sell => level_price >= order_price,
+    }
 }
 
 /// Communicates if an [`Order<Open>`] liquidity match is a full or partial fill. Partial fills
@@ -319,14 +844,19 @@ impl OrderFill {
 }
 
 /// Calculate the [`SymbolFees`] of a [`Order<Open>`] match (trade).
+///
+/// Only supports a flat fee deducted from the received asset, so the
+/// [`ClientAccount`](super::ClientAccount) uses it's [`FeeSchedule`](super::fees::FeeSchedule)
+/// instead.
+#[deprecated(note = "use FeeSchedule::rates & FeeCurrency::fees instead")]
 pub fn calculate_fees(order: &Order<Open>, trade_quantity: f64, fees_percent: f64) -> SymbolFees {
-    match order.side {
-        Side::Buy => SymbolFees::new(order.instrument.base.clone(), fees_percent * trade_quantity),
-        Side::Sell => SymbolFees::new(
-            order.instrument.quote.clone(),
-            fees_percent * order.state.price * trade_quantity,
-        ),
-    }
+    FeeCurrency::Received.fees(
+        &order.instrument,
+        order.side,
+        order.state.price,
+        trade_quantity,
+        fees_percent,
+    )
 }
 
 #[cfg(test)]
@@ -797,6 +1327,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn test_calculate_fees() {
         struct TestCase {
             order: Order<Open>,
@@ -829,4 +1360,250 @@ mod tests {
             assert_eq!(actual, test.expected, "TC{} failed", index);
         }
     }
+
+    #[test]
+    fn test_client_orders_update_liquidity() {
+        struct TestCase {
+            orders: Orders,
+            input_liquidity: Liquidity,
+            input_fees_percent: f64,
+            expected_orders: Orders,
+            expected_trades: Vec<Trade>,
+        }
+
+        let cid = ClientOrderId(Uuid::new_v4());
+
+        let tests = vec![
+            TestCase {
+                // TC0: Best ask does not cross the best bid
+                orders: client_orders(0, vec![order_open(cid, Side::Buy, 100.0, 1.0, 0.0)], vec![]),
+                input_liquidity: Liquidity::new([], [Level::new(101.0, 5.0)]),
+                input_fees_percent: 0.1,
+                expected_orders: Orders {
+                    liquidity: Liquidity::new([], [Level::new(101.0, 5.0)]),
+                    ..client_orders(0, vec![order_open(cid, Side::Buy, 100.0, 1.0, 0.0)], vec![])
+                },
+                expected_trades: vec![],
+            },
+            TestCase {
+                // TC1: Crossing ask Levels fully fill the best bid at it's own price
+                orders: client_orders(0, vec![order_open(cid, Side::Buy, 100.0, 1.0, 0.0)], vec![]),
+                input_liquidity: Liquidity::new(
+                    [],
+                    [Level::new(100.0, 2.0), Level::new(99.0, 0.25)],
+                ),
+                input_fees_percent: 0.1,
+                expected_orders: Orders {
+                    liquidity: Liquidity::new([], [Level::new(100.0, 1.25)]),
+                    ..client_orders(2, vec![], vec![])
+                },
+                expected_trades: vec![
+                    trade(
+                        TradeId::from("1"),
+                        Side::Buy,
+                        100.0,
+                        0.25,
+                        SymbolFees::new("base", 0.1 * 0.25),
+                    ),
+                    trade(
+                        TradeId::from("2"),
+                        Side::Buy,
+                        100.0,
+                        0.75,
+                        SymbolFees::new("base", 0.1 * 0.75),
+                    ),
+                ],
+            },
+            TestCase {
+                // TC2: Crossing bid Level partially fills the best ask at it's own price
+                orders: client_orders(
+                    0,
+                    vec![],
+                    vec![order_open(cid, Side::Sell, 100.0, 1.0, 0.0)],
+                ),
+                input_liquidity: Liquidity::new([Level::new(101.0, 0.5)], []),
+                input_fees_percent: 0.1,
+                expected_orders: client_orders(
+                    1,
+                    vec![],
+                    vec![order_open(cid, Side::Sell, 100.0, 1.0, 0.5)],
+                ),
+                expected_trades: vec![trade(
+                    TradeId::from("1"),
+                    Side::Sell,
+                    100.0,
+                    0.5,
+                    SymbolFees::new("quote", 0.1 * 100.0 * 0.5),
+                )],
+            },
+        ];
+
+        for (index, mut test) in tests.into_iter().enumerate() {
+            let actual_trades = test
+                .orders
+                .update_liquidity(test.input_liquidity, test.input_fees_percent);
+            assert_eq!(actual_trades, test.expected_trades, "TC{} failed", index);
+            assert_eq!(test.orders, test.expected_orders, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_liquidity_from_order_book() {
+        let book = OrderBook {
+            last_update_time: chrono::Utc::now(),
+            bids: barter_data::subscription::book::OrderBookSide::new(
+                Side::Buy,
+                [(99.0, 1.0), (100.0, 2.0), (98.0, 0.0)],
+            ),
+            asks: barter_data::subscription::book::OrderBookSide::new(
+                Side::Sell,
+                [(102.0, 1.0), (101.0, 2.0)],
+            ),
+        };
+
+        assert_eq!(
+            Liquidity::from(&book),
+            Liquidity {
+                bids: vec![Level::new(100.0, 2.0), Level::new(99.0, 1.0)],
+                asks: vec![Level::new(101.0, 2.0), Level::new(102.0, 1.0)],
+            }
+        );
+    }
+
+    #[test]
+    fn test_client_orders_queue_ahead() {
+        struct TestCase {
+            queue_model: QueueModel,
+            input_liquidity: Option<Liquidity>,
+            input_trades: Vec<PublicTrade>,
+            expected_filled: f64,
+            expected_queue_ahead: Option<f64>,
+        }
+
+        let cid = ClientOrderId(Uuid::new_v4());
+
+        let tests = vec![
+            TestCase {
+                // TC0: QueueModel::None fills the bid as soon as a trade prints at it's price
+                queue_model: QueueModel::None,
+                input_liquidity: None,
+                input_trades: vec![public_trade(Side::Sell, 100.0, 1.0)],
+                expected_filled: 1.0,
+                expected_queue_ahead: None,
+            },
+            TestCase {
+                // TC1: Trade at the bid price only consumes the visible size queued ahead
+                queue_model: QueueModel::VisibleSizeAhead,
+                input_liquidity: None,
+                input_trades: vec![public_trade(Side::Sell, 100.0, 1.5)],
+                expected_filled: 0.0,
+                expected_queue_ahead: Some(0.5),
+            },
+            TestCase {
+                // TC2: Trades at the bid price consume the queue ahead, then partially fill
+                queue_model: QueueModel::VisibleSizeAhead,
+                input_liquidity: None,
+                input_trades: vec![
+                    public_trade(Side::Sell, 100.0, 1.5),
+                    public_trade(Side::Sell, 100.0, 1.0),
+                ],
+                expected_filled: 0.5,
+                expected_queue_ahead: Some(0.0),
+            },
+            TestCase {
+                // TC3: Trade through the bid price exhausts the queue ahead & fully fills
+                queue_model: QueueModel::VisibleSizeAhead,
+                input_liquidity: None,
+                input_trades: vec![public_trade(Side::Sell, 99.0, 1.0)],
+                expected_filled: 1.0,
+                expected_queue_ahead: None,
+            },
+            TestCase {
+                // TC4: Level size reduction implies cancellations ahead, shrinking the queue
+                queue_model: QueueModel::VisibleSizeAhead,
+                input_liquidity: Some(Liquidity::new([Level::new(100.0, 0.5)], [])),
+                input_trades: vec![public_trade(Side::Sell, 100.0, 1.0)],
+                expected_filled: 0.5,
+                expected_queue_ahead: Some(0.0),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let mut orders = Orders {
+                queue_model: test.queue_model,
+                liquidity: Liquidity::new([Level::new(100.0, 2.0)], []),
+                ..client_orders(0, vec![], vec![])
+            };
+            orders.add_order_open(order_open(cid, Side::Buy, 100.0, 1.0, 0.0));
+
+            if let Some(liquidity) = test.input_liquidity {
+                orders.update_liquidity(liquidity, 0.0);
+            }
+
+            let actual_filled = test
+                .input_trades
+                .iter()
+                .flat_map(|trade| orders.match_bids(trade, 0.0))
+                .map(|trade| trade.quantity)
+                .sum::<f64>();
+
+            assert_eq!(actual_filled, test.expected_filled, "TC{} failed", index);
+            assert_eq!(
+                orders.queue_ahead.get(&OrderId::from("order_id")).copied(),
+                test.expected_queue_ahead,
+                "TC{} failed",
+                index
+            );
+        }
+    }
+
+    #[test]
+    fn test_client_orders_amend_order_open_queue_priority() {
+        struct TestCase {
+            input_amend: (f64, f64),
+            expected_queue_ahead: Option<f64>,
+        }
+
+        let cid = ClientOrderId(Uuid::new_v4());
+
+        let tests = vec![
+            TestCase {
+                // TC0: Reducing the quantity retains the consumed queue position
+                input_amend: (100.0, 0.5),
+                expected_queue_ahead: Some(0.5),
+            },
+            TestCase {
+                // TC1: Increasing the quantity re-joins the back of the queue
+                input_amend: (100.0, 2.0),
+                expected_queue_ahead: Some(2.0),
+            },
+            TestCase {
+                // TC2: Changing the price joins the back of the queue at the amended price
+                input_amend: (101.0, 1.0),
+                expected_queue_ahead: Some(3.0),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let mut orders = Orders {
+                queue_model: QueueModel::VisibleSizeAhead,
+                liquidity: Liquidity::new([Level::new(101.0, 3.0), Level::new(100.0, 2.0)], []),
+                ..client_orders(0, vec![], vec![])
+            };
+            orders.add_order_open(order_open(cid, Side::Buy, 100.0, 1.0, 0.0));
+            orders.match_bids(&public_trade(Side::Sell, 100.0, 1.5), 0.0);
+
+            let (price, quantity) = test.input_amend;
+            let original =
+                orders.amend_order_open(order_open(cid, Side::Buy, price, quantity, 0.0));
+
+            assert!(original.is_some(), "TC{} failed", index);
+            assert_eq!(
+                orders.queue_ahead.get(&OrderId::from("order_id")).copied(),
+                test.expected_queue_ahead,
+                "TC{} failed",
+                index
+            );
+        }
+    }
 }
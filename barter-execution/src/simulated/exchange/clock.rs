@@ -0,0 +1,64 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Clock used by the [`SimulatedExchange`](super::SimulatedExchange) to timestamp
+/// [`AccountEvent`](crate::model::AccountEvent)s and to simulate latency.
+#[derive(Copy, Clone, PartialEq, Debug, Default, Deserialize, Serialize)]
+pub enum SimulatedClock {
+    /// Wall-clock time via [`Utc::now`], with latency simulated by sleeping the Tokio runtime.
+    /// Suitable for paper trading against live market data.
+    #[default]
+    RealTime,
+    /// Event-time driven by the `exchange_time` of every
+    /// [`SimulatedEvent::Market`](crate::simulated::SimulatedEvent::Market), starting from the
+    /// provided time. Latency is simulated by delaying requests & account events until the clock
+    /// passes their arrival time, so backtests are deterministic and run faster than real time.
+    EventTime(DateTime<Utc>),
+}
+
+impl SimulatedClock {
+    /// Current time of the [`SimulatedClock`].
+    pub fn now(&self) -> DateTime<Utc> {
+        match self {
+            Self::RealTime => Utc::now(),
+            Self::EventTime(now) => *now,
+        }
+    }
+
+    /// Advance an [`SimulatedClock::EventTime`] clock to the provided time. Times before the
+    /// current time are ignored, ensuring the clock never moves backwards.
+    pub fn advance(&mut self, time: DateTime<Utc>) {
+        if let Self::EventTime(now) = self {
+            *now = (*now).max(time);
+        }
+    }
+
+    /// Determine if the [`SimulatedClock`] is driven by event-time.
+    pub fn is_event_time(&self) -> bool {
+        matches!(self, Self::EventTime(_))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn test_simulated_clock_advance() {
+        let start = DateTime::<Utc>::MIN_UTC;
+        let mut clock = SimulatedClock::EventTime(start);
+
+        clock.advance(start + Duration::seconds(10));
+        assert_eq!(clock.now(), start + Duration::seconds(10));
+
+        // Out of order times do not rewind the clock
+        clock.advance(start + Duration::seconds(5));
+        assert_eq!(clock.now(), start + Duration::seconds(10));
+
+        // RealTime clocks cannot be advanced
+        let mut clock = SimulatedClock::RealTime;
+        clock.advance(start);
+        assert!(clock.now() > start);
+    }
+}
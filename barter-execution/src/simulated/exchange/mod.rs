@@ -1,15 +1,31 @@
-use super::{exchange::account::ClientAccount, SimulatedEvent};
+use super::{
+    exchange::account::{order::Liquidity, ClientAccount},
+    SimulatedEvent,
+};
 use crate::ExecutionError;
+use barter_data::event::DataKind;
+use chrono::{DateTime, Utc};
+use std::collections::VecDeque;
 use tokio::sync::mpsc;
+use tracing::warn;
 
 /// [`SimulatedExchange`] account balances, open orders, fees, and latency.
 pub mod account;
 
+/// [`SimulatedExchange`] [`SimulatedClock`](clock::SimulatedClock) used to timestamp events and
+/// simulate latency.
+pub mod clock;
+
 /// [`SimulatedExchange`] that responds to [`SimulatedEvent`]s.
+///
+/// With an event-time [`SimulatedClock`](clock::SimulatedClock), client requests are held in
+/// flight for the sampled request latency, and only processed once a
+/// [`SimulatedEvent::Market`] advances the clock past their arrival time.
 #[derive(Debug)]
 pub struct SimulatedExchange {
     pub event_simulated_rx: mpsc::UnboundedReceiver<SimulatedEvent>,
     pub account: ClientAccount,
+    pub requests_in_flight: VecDeque<(DateTime<Utc>, SimulatedEvent)>,
 }
 
 impl SimulatedExchange {
@@ -20,28 +36,158 @@ impl SimulatedExchange {
 
     /// Run the [`SimulatedExchange`] by responding to [`SimulatedEvent`]s.
     pub async fn run(mut self) {
-        while let Some(event) = self.event_simulated_rx.recv().await {
-            match event {
-                SimulatedEvent::FetchOrdersOpen(response_tx) => {
-                    self.account.fetch_orders_open(response_tx)
-                }
-                SimulatedEvent::FetchBalances(response_tx) => {
-                    self.account.fetch_balances(response_tx)
-                }
-                SimulatedEvent::OpenOrders((open_requests, response_tx)) => {
-                    self.account.open_orders(open_requests, response_tx)
-                }
-                SimulatedEvent::CancelOrders((cancel_requests, response_tx)) => {
-                    self.account.cancel_orders(cancel_requests, response_tx)
-                }
-                SimulatedEvent::CancelOrdersAll(response_tx) => {
-                    self.account.cancel_orders_all(response_tx)
-                }
-                SimulatedEvent::MarketTrade((instrument, trade)) => {
-                    self.account.match_orders(instrument, trade)
+        loop {
+            let next_event_time = self.account.next_event_time();
+
+            let event = match next_event_time {
+                // Deliver delayed RealTime AccountEvents once they have been received
+                Some(received_time) if !self.account.clock.is_event_time() => {
+                    let delay = (received_time - Utc::now()).to_std().unwrap_or_default();
+                    tokio::select! {
+                        event = self.event_simulated_rx.recv() => event,
+                        _ = tokio::time::sleep(delay) => {
+                            self.account.flush_events(Utc::now());
+                            continue;
+                        }
+                    }
                 }
+                _ => self.event_simulated_rx.recv().await,
+            };
+
+            match event {
+                Some(event) => self.process(event),
+                None => break,
             }
         }
+
+        self.flush();
+    }
+
+    /// Process every remaining event-time request & [`AccountEvent`](crate::AccountEvent) in
+    /// flight, advancing the clock to their arrival times (eg/ at the end of a backtest).
+    pub fn flush(&mut self) {
+        if let Some(time) = self
+            .requests_in_flight
+            .back()
+            .map(|(arrival_time, _)| *arrival_time)
+        {
+            self.advance_clock(time);
+        }
+        if let Some(time) = self
+            .account
+            .events_in_flight
+            .back()
+            .map(|event| event.received_time)
+        {
+            self.account.advance_clock(time);
+        }
+    }
+
+    /// Process a [`SimulatedEvent`].
+    ///
+    /// Requests received while using an event-time [`SimulatedClock`](clock::SimulatedClock) are
+    /// held in flight until the clock passes their arrival time. Untimestamped market events
+    /// (eg/ [`SimulatedEvent::MarketTrade`]) cannot advance an event-time clock, so are ignored.
+    pub fn process(&mut self, event: SimulatedEvent) {
+        match &event {
+            SimulatedEvent::Market(market) => self.advance_clock(market.exchange_time),
+            untimestamped if untimestamped.is_market() && self.account.clock.is_event_time() => {
+                warn!(
+                    event = ?untimestamped,
+                    action = "ignoring event",
+                    "SimulatedExchange with an event-time SimulatedClock requires timestamped \
+                     SimulatedEvent::Market events"
+                );
+                return;
+            }
+            _ => {}
+        }
+
+        if self.account.clock.is_event_time() && !event.is_market() {
+            self.send_request_in_flight(event);
+        } else {
+            self.execute(event);
+        }
+    }
+
+    /// Hold an event-time request in flight until the sampled request latency has elapsed.
+    fn send_request_in_flight(&mut self, request: SimulatedEvent) {
+        let now = self.account.clock.now();
+        let arrival_time = now + self.account.request_latency.sample();
+
+        if arrival_time <= now {
+            return self.execute(request);
+        }
+
+        // Requests with equal arrival times are processed in the order they were sent
+        let index = self
+            .requests_in_flight
+            .partition_point(|(time, _)| *time <= arrival_time);
+        self.requests_in_flight
+            .insert(index, (arrival_time, request));
+    }
+
+    /// Advance the event-time [`SimulatedClock`](clock::SimulatedClock) to the provided time,
+    /// first processing every request in flight that arrives at or before it.
+    pub fn advance_clock(&mut self, time: DateTime<Utc>) {
+        while self
+            .requests_in_flight
+            .front()
+            .is_some_and(|(arrival_time, _)| *arrival_time <= time)
+        {
+            let (arrival_time, request) = self.requests_in_flight.pop_front().unwrap();
+            self.account.advance_clock(arrival_time);
+            self.execute(request);
+        }
+
+        self.account.advance_clock(time);
+    }
+
+    /// Execute a [`SimulatedEvent`] against the [`ClientAccount`].
+    fn execute(&mut self, event: SimulatedEvent) {
+        match event {
+            SimulatedEvent::FetchOrdersOpen(response_tx) => {
+                self.account.fetch_orders_open(response_tx)
+            }
+            SimulatedEvent::FetchBalances(response_tx) => self.account.fetch_balances(response_tx),
+            SimulatedEvent::OpenOrders((open_requests, response_tx)) => {
+                self.account.open_orders(open_requests, response_tx)
+            }
+            SimulatedEvent::AmendOrders((amend_requests, response_tx)) => {
+                self.account.amend_orders(amend_requests, response_tx)
+            }
+            SimulatedEvent::CancelOrders((cancel_requests, response_tx)) => {
+                self.account.cancel_orders(cancel_requests, response_tx)
+            }
+            SimulatedEvent::CancelOrdersAll(response_tx) => {
+                self.account.cancel_orders_all(response_tx)
+            }
+            SimulatedEvent::Market(market) => match market.kind {
+                DataKind::Trade(trade) => self.account.match_orders(market.instrument, trade),
+                DataKind::OrderBookL1(book) => self
+                    .account
+                    .match_orders_liquidity(market.instrument, Liquidity::from(&book)),
+                DataKind::OrderBook(book) => self
+                    .account
+                    .match_orders_liquidity(market.instrument, Liquidity::from(&book)),
+                DataKind::Candle(_)
+                | DataKind::Liquidation(_)
+                | DataKind::FundingRate(_)
+                | DataKind::MarkPrice(_)
+                | DataKind::IndexPrice(_)
+                | DataKind::Ticker(_)
+                | DataKind::OpenInterest(_) => {}
+            },
+            SimulatedEvent::MarketTrade((instrument, trade)) => {
+                self.account.match_orders(instrument, trade)
+            }
+            SimulatedEvent::MarketOrderBookL1((instrument, book)) => self
+                .account
+                .match_orders_liquidity(instrument, Liquidity::from(&book)),
+            SimulatedEvent::MarketOrderBook((instrument, book)) => self
+                .account
+                .match_orders_liquidity(instrument, Liquidity::from(&book)),
+        }
     }
 }
 
@@ -80,6 +226,186 @@ impl ExchangeBuilder {
             account: self
                 .account
                 .ok_or_else(|| ExecutionError::BuilderIncomplete("account".to_string()))?,
+            requests_in_flight: VecDeque::new(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        model::{balance::Balance, AccountEventKind, ClientOrderId},
+        simulated::exchange::{
+            account::{balance::ClientBalances, latency::LatencyModel},
+            clock::SimulatedClock,
+        },
+        ExecutionId, Order, RequestOpen,
+    };
+    use barter_data::{event::MarketEvent, subscription::trade::PublicTrade};
+    use barter_integration::model::{
+        instrument::{kind::InstrumentKind, symbol::Symbol, Instrument},
+        Exchange, Side,
+    };
+    use chrono::Duration;
+    use std::collections::HashMap;
+    use tokio::sync::oneshot;
+    use uuid::Uuid;
+
+    #[test]
+    fn test_event_time_latency() {
+        let start = DateTime::<Utc>::MIN_UTC;
+        let (event_account_tx, mut event_account_rx) = mpsc::unbounded_channel();
+        let (_event_simulated_tx, event_simulated_rx) = mpsc::unbounded_channel();
+
+        let mut exchange = SimulatedExchange::builder()
+            .event_simulated_rx(event_simulated_rx)
+            .account(
+                ClientAccount::builder()
+                    .clock(SimulatedClock::EventTime(start))
+                    .request_latency(LatencyModel::Constant(std::time::Duration::from_millis(
+                        100,
+                    )))
+                    .event_latency(LatencyModel::Constant(std::time::Duration::from_millis(10)))
+                    .fees_percent(0.0)
+                    .event_account_tx(event_account_tx)
+                    .instruments(vec![instrument()])
+                    .balances(ClientBalances(HashMap::from([
+                        (Symbol::from("base"), Balance::new(10.0, 10.0)),
+                        (Symbol::from("quote"), Balance::new(1000.0, 1000.0)),
+                    ])))
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+
+        // Open request is held in flight for the request latency
+        let (response_tx, mut response_rx) = oneshot::channel();
+        exchange.process(SimulatedEvent::OpenOrders((
+            vec![open_request()],
+            response_tx,
+        )));
+        exchange.process(market_trade(start + Duration::milliseconds(50), 90.0));
+        assert!(response_rx.try_recv().is_err());
+        assert_eq!(exchange.requests_in_flight.len(), 1);
+
+        // Market event after the arrival time processes the request before matching the trade
+        exchange.process(market_trade(start + Duration::milliseconds(150), 100.0));
+        assert_eq!(response_rx.try_recv().unwrap().len(), 1);
+        assert_eq!(
+            exchange.account.clock.now(),
+            start + Duration::milliseconds(150)
+        );
+
+        // Only AccountEvents received by the client before the current time are delivered
+        let received = std::iter::from_fn(|| event_account_rx.try_recv().ok()).collect::<Vec<_>>();
+        assert_eq!(received.len(), 2);
+        assert!(received
+            .iter()
+            .all(|event| event.received_time == start + Duration::milliseconds(110)));
+        assert!(matches!(received[1].kind, AccountEventKind::OrdersNew(_)));
+
+        // Trade generated at 150ms is received by the client at 160ms
+        exchange.process(market_trade(start + Duration::milliseconds(155), 100.0));
+        assert!(event_account_rx.try_recv().is_err());
+
+        exchange.process(market_trade(start + Duration::milliseconds(160), 100.0));
+        let received = std::iter::from_fn(|| event_account_rx.try_recv().ok()).collect::<Vec<_>>();
+        assert_eq!(received.len(), 2);
+        assert!(matches!(received[1].kind, AccountEventKind::Trade(_)));
+        assert_eq!(
+            received[1].received_time,
+            start + Duration::milliseconds(160)
+        );
+    }
+
+    #[test]
+    fn test_event_time_ignores_untimestamped_market_events() {
+        let start = DateTime::<Utc>::MIN_UTC;
+        let (event_account_tx, mut event_account_rx) = mpsc::unbounded_channel();
+        let (_event_simulated_tx, event_simulated_rx) = mpsc::unbounded_channel();
+
+        let mut exchange = SimulatedExchange::builder()
+            .event_simulated_rx(event_simulated_rx)
+            .account(
+                ClientAccount::builder()
+                    .clock(SimulatedClock::EventTime(start))
+                    .request_latency(LatencyModel::Constant(std::time::Duration::ZERO))
+                    .fees_percent(0.0)
+                    .event_account_tx(event_account_tx)
+                    .instruments(vec![instrument()])
+                    .balances(ClientBalances(HashMap::from([
+                        (Symbol::from("base"), Balance::new(10.0, 10.0)),
+                        (Symbol::from("quote"), Balance::new(1000.0, 1000.0)),
+                    ])))
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+
+        // Zero latency open request is processed immediately
+        let (response_tx, mut response_rx) = oneshot::channel();
+        exchange.process(SimulatedEvent::OpenOrders((
+            vec![open_request()],
+            response_tx,
+        )));
+        assert_eq!(response_rx.try_recv().unwrap().len(), 1);
+        let _ = std::iter::from_fn(|| event_account_rx.try_recv().ok()).count();
+
+        // Untimestamped MarketTrade that crosses the open bid is ignored
+        exchange.process(SimulatedEvent::MarketTrade((
+            instrument(),
+            PublicTrade {
+                id: "trade_id".to_string(),
+                price: 90.0,
+                amount: 1.0,
+                side: Side::Sell,
+            },
+        )));
+        assert!(event_account_rx.try_recv().is_err());
+        assert_eq!(exchange.account.clock.now(), start);
+
+        // Timestamped Market event matches the open bid
+        exchange.process(market_trade(start + Duration::milliseconds(1), 90.0));
+        let received = std::iter::from_fn(|| event_account_rx.try_recv().ok()).collect::<Vec<_>>();
+        assert!(received
+            .iter()
+            .any(|event| matches!(event.kind, AccountEventKind::Trade(_))));
+    }
+
+    fn instrument() -> Instrument {
+        Instrument::from(("base", "quote", InstrumentKind::Spot))
+    }
+
+    fn open_request() -> Order<RequestOpen> {
+        Order {
+            exchange: Exchange::from(ExecutionId::Simulated),
+            instrument: instrument(),
+            cid: ClientOrderId(Uuid::new_v4()),
+            side: Side::Buy,
+            state: RequestOpen {
+                kind: crate::model::order::OrderKind::Limit,
+                price: 100.0,
+                quantity: 1.0,
+                trigger: None,
+            },
+        }
+    }
+
+    fn market_trade(time: DateTime<Utc>, price: f64) -> SimulatedEvent {
+        SimulatedEvent::Market(MarketEvent {
+            exchange_time: time,
+            received_time: time,
+            exchange: Exchange::from("binance_spot"),
+            instrument: instrument(),
+            kind: DataKind::Trade(PublicTrade {
+                id: "trade_id".to_string(),
+                price,
+                amount: 1.0,
+                side: Side::Sell,
+            }),
         })
     }
 }
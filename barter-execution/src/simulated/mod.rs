@@ -1,5 +1,13 @@
-use crate::{Cancelled, ExecutionError, Open, Order, RequestCancel, RequestOpen, SymbolBalance};
-use barter_data::subscription::trade::PublicTrade;
+use crate::{
+    Cancelled, ExecutionError, Open, Order, RequestAmend, RequestCancel, RequestOpen, SymbolBalance,
+};
+use barter_data::{
+    event::{DataKind, MarketEvent},
+    subscription::{
+        book::{OrderBook, OrderBookL1},
+        trade::PublicTrade,
+    },
+};
 use barter_integration::model::instrument::Instrument;
 use tokio::sync::oneshot;
 
@@ -17,6 +25,13 @@ pub mod execution;
 /// 1. Request sent from the [`SimulatedExecution`](execution::SimulatedExecution)
 ///    [`ExecutionClient`](crate::ExecutionClient).
 /// 2. Market events used to model available liquidity and trigger matches with open client orders.
+///
+/// Only [`SimulatedEvent::Market`] events are timestamped, and therefore advance an event-time
+/// [`SimulatedClock`](exchange::clock::SimulatedClock). The untimestamped
+/// [`SimulatedEvent::MarketTrade`], [`SimulatedEvent::MarketOrderBookL1`] &
+/// [`SimulatedEvent::MarketOrderBook`] events are only supported by a
+/// [`SimulatedClock::RealTime`](exchange::clock::SimulatedClock::RealTime) clock, and are ignored
+/// by an event-time clock.
 #[derive(Debug)]
 pub enum SimulatedEvent {
     FetchOrdersOpen(oneshot::Sender<Result<Vec<Order<Open>>, ExecutionError>>),
@@ -27,6 +42,12 @@ pub enum SimulatedEvent {
             oneshot::Sender<Vec<Result<Order<Open>, ExecutionError>>>,
         ),
     ),
+    AmendOrders(
+        (
+            Vec<Order<RequestAmend>>,
+            oneshot::Sender<Vec<Result<Order<Open>, ExecutionError>>>,
+        ),
+    ),
     CancelOrders(
         (
             Vec<Order<RequestCancel>>,
@@ -34,5 +55,21 @@ pub enum SimulatedEvent {
         ),
     ),
     CancelOrdersAll(oneshot::Sender<Result<Vec<Order<Cancelled>>, ExecutionError>>),
+    Market(MarketEvent<Instrument, DataKind>),
     MarketTrade((Instrument, PublicTrade)),
+    MarketOrderBookL1((Instrument, OrderBookL1)),
+    MarketOrderBook((Instrument, OrderBook)),
+}
+
+impl SimulatedEvent {
+    /// Determine if the [`SimulatedEvent`] is a market event, rather than a client request.
+    pub fn is_market(&self) -> bool {
+        matches!(
+            self,
+            Self::Market(_)
+                | Self::MarketTrade(_)
+                | Self::MarketOrderBookL1(_)
+                | Self::MarketOrderBook(_)
+        )
+    }
 }
@@ -0,0 +1,375 @@
+use barter_execution::{
+    error::ExecutionError,
+    execution::binance::spot::{BinanceSpot, BinanceSpotConfig},
+    model::{
+        balance::{Balance, SymbolBalance},
+        order::{
+            Cancelled, Open, Order, OrderId, OrderKind, RequestAmend, RequestCancel, RequestOpen,
+        },
+        trade::{SymbolFees, Trade, TradeId},
+        AccountEvent, AccountEventKind, ClientOrderId,
+    },
+    ExecutionClient, ExecutionId,
+};
+use barter_integration::model::{
+    instrument::{kind::InstrumentKind, Instrument},
+    Exchange, Side,
+};
+use futures::SinkExt;
+use hmac::{Hmac, Mac};
+use std::time::Duration;
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpListener,
+    sync::mpsc,
+};
+use tokio_tungstenite::tungstenite::Message;
+use uuid::Uuid;
+
+const API_KEY: &str = "api_key";
+const API_SECRET: &str = "api_secret";
+
+// Valid Barter ClientOrderId used by every mock Binance order
+const CID: &str = "4b1b4a3c-3d0e-4b8c-9c2b-3f0e8a7a0c11";
+
+#[tokio::test]
+async fn main() {
+    // Run mock Binance Spot Http & WebSocket servers
+    let http_url = run_mock_http_server().await;
+    let websocket_url = run_mock_websocket_server().await;
+
+    let config = BinanceSpotConfig {
+        http_base_url: http_url,
+        websocket_base_url: websocket_url,
+        ..BinanceSpotConfig::new(API_KEY, API_SECRET, vec![btc_usdt()])
+    };
+
+    let (event_tx, mut event_rx) = mpsc::unbounded_channel();
+    let client = BinanceSpot::init(config, event_tx).await;
+
+    // 1. User data stream executionReport TRADE is sent as an AccountEvent Trade
+    match next_event(&mut event_rx).await.kind {
+        AccountEventKind::Trade(trade) => assert_eq!(
+            trade,
+            Trade {
+                id: TradeId::from("1042"),
+                order_id: OrderId::from(1),
+                instrument: btc_usdt(),
+                side: Side::Buy,
+                price: 20000.0,
+                quantity: 0.5,
+                fees: SymbolFees::new("bnb", 0.001),
+            }
+        ),
+        other => panic!("expected AccountEventKind::Trade, but received: {other:?}"),
+    }
+
+    // 2. User data stream outboundAccountPosition is sent as an AccountEvent Balances
+    match next_event(&mut event_rx).await.kind {
+        AccountEventKind::Balances(balances) => assert_eq!(
+            balances,
+            vec![SymbolBalance::new("usdt", Balance::new(1000.0, 900.0))]
+        ),
+        other => panic!("expected AccountEventKind::Balances, but received: {other:?}"),
+    }
+
+    // 3. Open a limit order
+    let cid = ClientOrderId(Uuid::parse_str(CID).unwrap());
+    let open = client
+        .open_orders(vec![Order {
+            exchange: Exchange::from(ExecutionId::BinanceSpot),
+            instrument: btc_usdt(),
+            cid,
+            side: Side::Buy,
+            state: RequestOpen {
+                kind: OrderKind::Limit,
+                price: 20000.0,
+                quantity: 0.5,
+                trigger: None,
+            },
+        }])
+        .await;
+    assert_eq!(open, vec![Ok(order_open(cid))]);
+
+    // 4. Open an order rejected by the exchange
+    let rejected = client
+        .open_orders(vec![Order {
+            exchange: Exchange::from(ExecutionId::BinanceSpot),
+            instrument: btc_usdt(),
+            cid,
+            side: Side::Buy,
+            state: RequestOpen {
+                kind: OrderKind::Limit,
+                price: 20000.0,
+                quantity: 1000.0,
+                trigger: None,
+            },
+        }])
+        .await;
+    assert!(matches!(rejected.as_slice(), [Err(ExecutionError::Api(_))]));
+
+    // 5. Fetch open orders
+    assert_eq!(
+        client.fetch_orders_open().await.unwrap(),
+        vec![order_open(cid)]
+    );
+
+    // 6. Fetch balances
+    assert_eq!(
+        client.fetch_balances().await.unwrap(),
+        vec![
+            SymbolBalance::new("btc", Balance::new(2.0, 1.5)),
+            SymbolBalance::new("usdt", Balance::new(1000.0, 1000.0)),
+        ]
+    );
+
+    // 7. Amend the partially filled open order via cancel-replace, receiving a new OrderId
+    let amended = client
+        .amend_orders(vec![Order {
+            exchange: Exchange::from(ExecutionId::BinanceSpot),
+            instrument: btc_usdt(),
+            cid,
+            side: Side::Buy,
+            state: RequestAmend {
+                id: OrderId::from(1),
+                price: 19000.0,
+                quantity: 0.5,
+            },
+        }])
+        .await;
+    let mut expected = order_open(cid);
+    expected.state.id = OrderId::from(3);
+    expected.state.price = 19000.0;
+    expected.state.filled_quantity = 0.2;
+    assert_eq!(amended, vec![Ok(expected)]);
+
+    // 8. Cancel the open order
+    let cancelled = client
+        .cancel_orders(vec![Order {
+            exchange: Exchange::from(ExecutionId::BinanceSpot),
+            instrument: btc_usdt(),
+            cid,
+            side: Side::Buy,
+            state: RequestCancel::from(1),
+        }])
+        .await;
+    assert_eq!(cancelled, vec![Ok(order_cancelled(cid))]);
+
+    // 9. Cancel all open orders
+    assert_eq!(
+        client.cancel_orders_all().await.unwrap(),
+        vec![order_cancelled(cid)]
+    );
+}
+
+fn btc_usdt() -> Instrument {
+    Instrument::from(("btc", "usdt", InstrumentKind::Spot))
+}
+
+fn order_open(cid: ClientOrderId) -> Order<Open> {
+    Order {
+        exchange: Exchange::from(ExecutionId::BinanceSpot),
+        instrument: btc_usdt(),
+        cid,
+        side: Side::Buy,
+        state: Open {
+            id: OrderId::from(1),
+            price: 20000.0,
+            quantity: 0.5,
+            filled_quantity: 0.0,
+        },
+    }
+}
+
+fn order_cancelled(cid: ClientOrderId) -> Order<Cancelled> {
+    Order {
+        exchange: Exchange::from(ExecutionId::BinanceSpot),
+        instrument: btc_usdt(),
+        cid,
+        side: Side::Buy,
+        state: Cancelled::from(1),
+    }
+}
+
+async fn next_event(event_rx: &mut mpsc::UnboundedReceiver<AccountEvent>) -> AccountEvent {
+    tokio::time::timeout(Duration::from_secs(5), event_rx.recv())
+        .await
+        .expect("timed out waiting for AccountEvent")
+        .expect("AccountEvent channel closed")
+}
+
+// Run a minimal mock Binance Spot Http server, returning it's base url
+async fn run_mock_http_server() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+
+    tokio::spawn(async move {
+        loop {
+            let (mut stream, _) = listener.accept().await.unwrap();
+
+            // Read the request headers (no requests contain a body)
+            let mut buffer = Vec::new();
+            let mut chunk = [0u8; 1024];
+            while !buffer.windows(4).any(|window| window == b"\r\n\r\n") {
+                let bytes = stream.read(&mut chunk).await.unwrap();
+                if bytes == 0 {
+                    break;
+                }
+                buffer.extend_from_slice(&chunk[..bytes]);
+            }
+            let request = String::from_utf8_lossy(&buffer).to_string();
+
+            let (status, body) = respond(&request);
+            let response = format!(
+                "HTTP/1.1 {status}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            );
+            stream.write_all(response.as_bytes()).await.unwrap();
+            stream.shutdown().await.unwrap();
+        }
+    });
+
+    url
+}
+
+// Route a raw Http request to a mock Binance Spot response
+fn respond(request: &str) -> (&'static str, String) {
+    let mut request_line = request.lines().next().unwrap().split_whitespace();
+    let method = request_line.next().unwrap();
+    let target = request_line.next().unwrap();
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+
+    // Every request must contain the API key header
+    assert!(
+        request
+            .lines()
+            .any(|line| line.eq_ignore_ascii_case(&format!("x-mbx-apikey: {API_KEY}"))),
+        "missing API key header: {request}"
+    );
+
+    // Listen key management requests are not signed
+    if path == "/api/v3/userDataStream" {
+        return ("200 OK", r#"{"listenKey":"listen_key"}"#.to_string());
+    }
+
+    // Every other request must be correctly signed
+    assert_valid_signature(query);
+
+    match (method, path) {
+        ("POST", "/api/v3/order") if query.contains("quantity=1000") => (
+            "400 Bad Request",
+            r#"{"code":-2010,"msg":"Account has insufficient balance for requested action."}"#
+                .to_string(),
+        ),
+        ("POST", "/api/v3/order") => {
+            assert!(query.contains("symbol=BTCUSDT"));
+            assert!(query.contains("side=BUY"));
+            assert!(query.contains("type=LIMIT"));
+            assert!(query.contains("timeInForce=GTC"));
+            assert!(query.contains(&format!("newClientOrderId={CID}")));
+            (
+                "200 OK",
+                format!(r#"{{"symbol":"BTCUSDT","orderId":1,"orderListId":-1,"clientOrderId":"{CID}","transactTime":1507725176595}}"#),
+            )
+        }
+        ("GET", "/api/v3/order") => {
+            assert!(query.contains("orderId=1"));
+            (
+                "200 OK",
+                format!(r#"{{"symbol":"BTCUSDT","orderId":1,"clientOrderId":"{CID}","price":"20000.0","origQty":"0.5","executedQty":"0.2","status":"PARTIALLY_FILLED","timeInForce":"GTC","type":"LIMIT","side":"BUY","stopPrice":"0.0"}}"#),
+            )
+        }
+        ("POST", "/api/v3/order/cancelReplace") => {
+            assert!(query.contains("cancelReplaceMode=STOP_ON_FAILURE"));
+            assert!(query.contains("cancelOrderId=1"));
+            assert!(query.contains("type=LIMIT"));
+            assert!(query.contains("timeInForce=GTC"));
+            assert!(query.contains("quantity=0.3"));
+            assert!(query.contains("price=19000"));
+            (
+                "200 OK",
+                format!(
+                    r#"{{"cancelResult":"SUCCESS","newOrderResult":"SUCCESS","newOrderResponse":{{"symbol":"BTCUSDT","orderId":3,"orderListId":-1,"clientOrderId":"{CID}","transactTime":1507725176595}}}}"#
+                ),
+            )
+        }
+        ("DELETE", "/api/v3/order") => {
+            assert!(query.contains("orderId=1"));
+            (
+                "200 OK",
+                format!(r#"{{"symbol":"BTCUSDT","origClientOrderId":"{CID}","orderId":1,"orderListId":-1,"clientOrderId":"cancel","status":"CANCELED","side":"BUY"}}"#),
+            )
+        }
+        ("GET", "/api/v3/openOrders") => (
+            "200 OK",
+            format!(
+                r#"[
+                    {{"symbol":"BTCUSDT","orderId":1,"clientOrderId":"{CID}","price":"20000.0","origQty":"0.5","executedQty":"0.0","status":"NEW","timeInForce":"GTC","type":"LIMIT","side":"BUY","stopPrice":"0.0"}},
+                    {{"symbol":"BTCUSDT","orderId":2,"clientOrderId":"web_123","price":"20000.0","origQty":"0.5","executedQty":"0.0","status":"NEW","timeInForce":"GTC","type":"LIMIT","side":"BUY","stopPrice":"0.0"}}
+                ]"#
+            ),
+        ),
+        ("DELETE", "/api/v3/openOrders") => {
+            assert!(query.contains("symbol=BTCUSDT"));
+            (
+                "200 OK",
+                format!(r#"[{{"symbol":"BTCUSDT","origClientOrderId":"{CID}","orderId":1,"orderListId":-1,"clientOrderId":"cancel","status":"CANCELED","side":"BUY"}}]"#),
+            )
+        }
+        ("GET", "/api/v3/account") => (
+            "200 OK",
+            r#"{"canTrade":true,"balances":[{"asset":"BTC","free":"1.5","locked":"0.5"},{"asset":"USDT","free":"1000.0","locked":"0.0"}]}"#
+                .to_string(),
+        ),
+        _ => (
+            "404 Not Found",
+            r#"{"code":-1000,"msg":"unknown endpoint"}"#.to_string(),
+        ),
+    }
+}
+
+// Check the signature query parameter is the HMAC-SHA256 of the preceding query string
+fn assert_valid_signature(query: &str) {
+    let (payload, signature) = query
+        .rsplit_once("&signature=")
+        .unwrap_or_else(|| panic!("missing signature: {query}"));
+
+    assert!(payload.contains("timestamp="), "missing timestamp: {query}");
+
+    let mut mac = Hmac::<sha2::Sha256>::new_from_slice(API_SECRET.as_bytes()).unwrap();
+    mac.update(payload.as_bytes());
+    let expected = hex_encode(&mac.finalize().into_bytes());
+
+    assert_eq!(signature, expected, "invalid signature: {query}");
+}
+
+fn hex_encode(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+// Run a minimal mock Binance Spot user data stream WebSocket server, returning it's base url
+async fn run_mock_websocket_server() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("ws://{}/ws", listener.local_addr().unwrap());
+
+    tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut websocket = tokio_tungstenite::accept_async(stream).await.unwrap();
+
+        let messages = [
+            format!(
+                r#"{{"e":"executionReport","E":1499405658658,"s":"BTCUSDT","c":"{CID}","S":"BUY","o":"LIMIT","f":"GTC","q":"0.5","p":"20000.0","C":"","x":"TRADE","X":"FILLED","i":1,"l":"0.5","z":"0.5","L":"20000.0","n":"0.001","N":"BNB","T":1499405658657,"t":1042}}"#
+            ),
+            r#"{"e":"outboundAccountPosition","E":1564034571105,"u":1564034571073,"B":[{"a":"USDT","f":"900.0","l":"100.0"}]}"#.to_string(),
+        ];
+
+        for message in messages {
+            websocket.send(Message::Text(message)).await.unwrap();
+        }
+
+        // Keep the connection open for the remainder of the test
+        std::future::pending::<()>().await;
+    });
+
+    url
+}
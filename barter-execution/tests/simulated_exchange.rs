@@ -812,7 +812,7 @@ async fn test_11_cancel_all_orders(
 ) {
     let cancelled = client.cancel_orders_all().await.unwrap();
 
-    let expected_cancelled = vec![
+    let expected_cancelled = [
         order_cancelled(
             // Bids are cancelled first
             Instrument::from(("btc", "usdt", InstrumentKind::Perpetual)),
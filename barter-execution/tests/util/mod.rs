@@ -90,6 +90,7 @@ where
             kind: OrderKind::Limit,
             price,
             quantity,
+            trigger: None,
         },
     }
 }
@@ -17,6 +17,7 @@
     missing_copy_implementations,
     rust_2018_idioms
 )]
+#![allow(clippy::result_large_err)]
 
 use crate::{error::SocketError, protocol::StreamParser};
 use futures::Stream;
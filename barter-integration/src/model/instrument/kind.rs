@@ -5,21 +5,18 @@ use std::fmt::{Display, Formatter};
 
 /// Defines the type of [`Instrument`](super::Instrument) which is being traded on a
 /// given `base_quote` market.
-#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
 #[serde(rename_all = "snake_case")]
 pub enum InstrumentKind {
+    #[default]
     Spot,
     Future(FutureContract),
     Perpetual,
     Option(OptionContract),
 }
 
-impl Default for InstrumentKind {
-    fn default() -> Self {
-        Self::Spot
-    }
-}
-
 impl Display for InstrumentKind {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -158,6 +158,14 @@ async fn listen_to_engine_events(mut event_rx: mpsc::UnboundedReceiver<Event>) {
             Event::OrderUpdate => {
                 // OrderUpdate Event occurred in Engine
             }
+            Event::OrderRejected(rejected_order) => {
+                // OrderRejected Event occurred in Engine
+                println!("{rejected_order:?}");
+            }
+            Event::OrderFailed(failed_order) => {
+                // OrderFailed Event occurred in Engine
+                println!("{failed_order:?}");
+            }
             Event::Fill(fill_event) => {
                 // Fill Event occurred in Engine
                 println!("{fill_event:?}");
@@ -15,4 +15,19 @@ pub enum DataError {
 
     #[error("Barter-Data: {0}")]
     Data(#[from] barter_data::error::DataError),
+
+    #[error("I/O: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("CSV: {0}")]
+    Csv(#[from] csv::Error),
+
+    #[error("Recorder: {0}")]
+    Recorder(#[from] barter_data::recorder::RecorderError),
+
+    #[error("unsupported market data file: {0}")]
+    UnsupportedFile(String),
+
+    #[error("invalid CSV market record: {0}")]
+    InvalidCsvRecord(String),
 }
@@ -0,0 +1,468 @@
+use crate::data::{error::DataError, Feed, MarketGenerator};
+use barter_data::{
+    event::{DataKind, MarketEvent},
+    recorder::{reader::RecordReader, RecordFormat},
+    subscription::{candle::Candle, trade::PublicTrade},
+};
+use barter_integration::model::{
+    instrument::{kind::InstrumentKind, Instrument},
+    Exchange, Side,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::{
+    cmp::Reverse,
+    collections::{BTreeMap, BinaryHeap, VecDeque},
+    ffi::OsStr,
+    fmt::{Debug, Formatter},
+    path::{Path, PathBuf},
+};
+use tracing::warn;
+
+type MarketEventResult = Result<MarketEvent<Instrument, DataKind>, DataError>;
+
+/// Historical [`Feed`] of market events read lazily from market data files on disk.
+///
+/// Supported files are identified by their extension:
+/// - "jsonl" & "msgpack": files written by the Barter-Data
+///   [`Recorder`](barter_data::recorder::Recorder).
+/// - "csv": flat trade & candle records, see [`CsvMarketEvent`].
+///
+/// Each source of files is read in order, and the next event from every source is k-way merged
+/// by `exchange_time`, so the [`Feed`] is globally time ordered as long as the events within each
+/// source are. Events with an equal `exchange_time` are yielded in source order.
+///
+/// [`Feed::Unhealthy`] is yielded for an unreadable event (which is then skipped), and
+/// [`Feed::Finished`] is yielded once every source is exhausted.
+#[derive(Debug)]
+pub struct MarketFileFeed {
+    sources: Vec<MarketFileSource>,
+    heads: Vec<Option<MarketEvent<Instrument, DataKind>>>,
+    queue: BinaryHeap<Reverse<(DateTime<Utc>, usize)>>,
+    pending: Vec<usize>,
+}
+
+impl MarketGenerator<MarketEvent<Instrument, DataKind>> for MarketFileFeed {
+    fn next(&mut self) -> Feed<MarketEvent<Instrument, DataKind>> {
+        // Refill the head event of every source consumed since the last call
+        while let Some(source) = self.pending.pop() {
+            match self.sources[source].next_event() {
+                Some(Ok(event)) => {
+                    self.queue.push(Reverse((event.exchange_time, source)));
+                    self.heads[source] = Some(event);
+                }
+                Some(Err(error)) => {
+                    warn!(
+                        %error,
+                        source = ?self.sources[source],
+                        action = "skipping unreadable MarketEvent",
+                        "failed to read historical MarketEvent"
+                    );
+                    self.pending.push(source);
+                    return Feed::Unhealthy;
+                }
+                None => {}
+            }
+        }
+
+        let Some(Reverse((_, source))) = self.queue.pop() else {
+            return Feed::Finished;
+        };
+
+        self.pending.push(source);
+        self.heads[source].take().map_or(Feed::Finished, Feed::Next)
+    }
+}
+
+impl MarketFileFeed {
+    /// Construct a [`MarketFileFeed`] that merges the market events of every provided file,
+    /// treating each file as an independent time ordered source.
+    pub fn new<Paths, P>(paths: Paths) -> Result<Self, DataError>
+    where
+        Paths: IntoIterator<Item = P>,
+        P: AsRef<Path>,
+    {
+        let sources = paths
+            .into_iter()
+            .map(|path| MarketFileSource::new(vec![path.as_ref().to_path_buf()]))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self::from_sources(sources))
+    }
+
+    /// Construct a [`MarketFileFeed`] from every supported file beneath the provided directory
+    /// (eg/ the directory of a Barter-Data [`Recorder`](barter_data::recorder::Recorder)).
+    ///
+    /// The files within each directory are read one after another in file name order as a
+    /// single source, and every directory source is merged by `exchange_time`.
+    pub fn from_directory<P>(directory: P) -> Result<Self, DataError>
+    where
+        P: AsRef<Path>,
+    {
+        let mut directories = BTreeMap::<PathBuf, Vec<PathBuf>>::new();
+        collect_market_files(directory.as_ref(), &mut directories)?;
+
+        let sources = directories
+            .into_values()
+            .map(|mut paths| {
+                paths.sort();
+                MarketFileSource::new(paths)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self::from_sources(sources))
+    }
+
+    fn from_sources(sources: Vec<MarketFileSource>) -> Self {
+        Self {
+            heads: sources.iter().map(|_| None).collect(),
+            queue: BinaryHeap::with_capacity(sources.len()),
+            pending: (0..sources.len()).rev().collect(),
+            sources,
+        }
+    }
+}
+
+/// Sequence of market data files that are read lazily one after another.
+struct MarketFileSource {
+    paths: VecDeque<PathBuf>,
+    current: Option<Box<dyn Iterator<Item = MarketEventResult> + Send>>,
+}
+
+impl Debug for MarketFileSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MarketFileSource")
+            .field("paths", &self.paths)
+            .finish_non_exhaustive()
+    }
+}
+
+impl MarketFileSource {
+    /// Construct a new [`MarketFileSource`], validating that every file is a supported market
+    /// data file that exists.
+    fn new(paths: Vec<PathBuf>) -> Result<Self, DataError> {
+        for path in &paths {
+            if !is_market_file(path) {
+                return Err(DataError::UnsupportedFile(path.display().to_string()));
+            }
+            std::fs::metadata(path)?;
+        }
+
+        Ok(Self {
+            paths: VecDeque::from(paths),
+            current: None,
+        })
+    }
+
+    /// Read the next market event, opening the next file once the current file is exhausted.
+    fn next_event(&mut self) -> Option<MarketEventResult> {
+        loop {
+            if let Some(current) = &mut self.current {
+                match current.next() {
+                    Some(result) => return Some(result),
+                    None => self.current = None,
+                }
+            }
+
+            let path = self.paths.pop_front()?;
+            match open_market_file(&path) {
+                Ok(events) => self.current = Some(events),
+                Err(error) => return Some(Err(error)),
+            }
+        }
+    }
+}
+
+/// Determine if the provided path has the extension of a supported market data file.
+fn is_market_file(path: &Path) -> bool {
+    path.extension() == Some(OsStr::new("csv")) || RecordFormat::from_path(path).is_some()
+}
+
+/// Open a market data file as a lazy `Iterator` of market events.
+fn open_market_file(
+    path: &Path,
+) -> Result<Box<dyn Iterator<Item = MarketEventResult> + Send>, DataError> {
+    if path.extension() == Some(OsStr::new("csv")) {
+        let events = csv::Reader::from_path(path)?
+            .into_deserialize::<CsvMarketEvent>()
+            .map(|record| {
+                record
+                    .map_err(DataError::from)
+                    .and_then(MarketEvent::try_from)
+            });
+        Ok(Box::new(events))
+    } else {
+        let events = RecordReader::open(path)?.map(|event| event.map_err(DataError::from));
+        Ok(Box::new(events))
+    }
+}
+
+/// Recursively collect every supported market data file beneath the provided directory, grouped
+/// by their parent directory.
+fn collect_market_files(
+    directory: &Path,
+    files: &mut BTreeMap<PathBuf, Vec<PathBuf>>,
+) -> Result<(), DataError> {
+    for entry in std::fs::read_dir(directory)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_market_files(&path, files)?;
+        } else if is_market_file(&path) {
+            files.entry(directory.to_path_buf()).or_default().push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Flat CSV record of a trade or candle [`MarketEvent`], read by the [`MarketFileFeed`].
+///
+/// The trade columns (`id`, `price`, `amount`, `side`) are required for a "trade" record, and the
+/// candle columns (`close_time`, `open`, `high`, `low`, `close`, `volume`, `trade_count`) are
+/// required for a "candle" record. Only "spot" & "perpetual" `instrument_kind`s are supported.
+///
+/// ### Raw Payload Examples
+/// ```csv
+/// exchange_time,received_time,exchange,base,quote,instrument_kind,kind,id,price,amount,side,close_time,open,high,low,close,volume,trade_count
+/// 2024-01-01T00:00:00Z,2024-01-01T00:00:00.005Z,binance_spot,btc,usdt,spot,trade,1,42000.0,0.5,buy,,,,,,,
+/// 2024-01-01T00:01:00Z,2024-01-01T00:01:00.005Z,binance_spot,btc,usdt,spot,candle,,,,,2024-01-01T00:01:00Z,42000.0,42100.0,41900.0,42050.0,12.5,120
+/// ```
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct CsvMarketEvent {
+    pub exchange_time: DateTime<Utc>,
+    pub received_time: DateTime<Utc>,
+    pub exchange: String,
+    pub base: String,
+    pub quote: String,
+    pub instrument_kind: InstrumentKind,
+    pub kind: CsvMarketKind,
+    pub id: Option<String>,
+    pub price: Option<f64>,
+    pub amount: Option<f64>,
+    pub side: Option<Side>,
+    pub close_time: Option<DateTime<Utc>>,
+    pub open: Option<f64>,
+    pub high: Option<f64>,
+    pub low: Option<f64>,
+    pub close: Option<f64>,
+    pub volume: Option<f64>,
+    pub trade_count: Option<u64>,
+}
+
+/// Kind of market event described by a [`CsvMarketEvent`].
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CsvMarketKind {
+    Trade,
+    Candle,
+}
+
+impl TryFrom<CsvMarketEvent> for MarketEvent<Instrument, DataKind> {
+    type Error = DataError;
+
+    fn try_from(record: CsvMarketEvent) -> Result<Self, Self::Error> {
+        let kind = match record.kind {
+            CsvMarketKind::Trade => DataKind::Trade(PublicTrade {
+                id: required(record.id, "id")?,
+                price: required(record.price, "price")?,
+                amount: required(record.amount, "amount")?,
+                side: required(record.side, "side")?,
+            }),
+            CsvMarketKind::Candle => DataKind::Candle(Candle {
+                close_time: required(record.close_time, "close_time")?,
+                open: required(record.open, "open")?,
+                high: required(record.high, "high")?,
+                low: required(record.low, "low")?,
+                close: required(record.close, "close")?,
+                volume: required(record.volume, "volume")?,
+                trade_count: required(record.trade_count, "trade_count")?,
+            }),
+        };
+
+        Ok(MarketEvent {
+            exchange_time: record.exchange_time,
+            received_time: record.received_time,
+            exchange: Exchange::from(record.exchange),
+            instrument: Instrument::from((record.base, record.quote, record.instrument_kind)),
+            kind,
+        })
+    }
+}
+
+/// Return the value of a required [`CsvMarketEvent`] column, or an error if it is empty.
+fn required<T>(value: Option<T>, column: &'static str) -> Result<T, DataError> {
+    value.ok_or_else(|| DataError::InvalidCsvRecord(format!("missing required column: {column}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_data::recorder::{Recorder, RecorderConfig};
+    use chrono::TimeZone;
+
+    const CSV_HEADER: &str = "exchange_time,received_time,exchange,base,quote,instrument_kind,kind,id,price,amount,side,close_time,open,high,low,close,volume,trade_count";
+
+    fn test_directory(name: &str) -> PathBuf {
+        let directory = std::env::temp_dir().join(format!(
+            "barter-market-file-feed-{name}-{}-{}",
+            std::process::id(),
+            Utc::now().timestamp_nanos_opt().unwrap_or_default()
+        ));
+        std::fs::create_dir_all(&directory).unwrap();
+        directory
+    }
+
+    fn trade(seconds: u32, id: &str, base: &str) -> MarketEvent<Instrument, DataKind> {
+        let time = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, seconds).unwrap();
+        MarketEvent {
+            exchange_time: time,
+            received_time: time,
+            exchange: Exchange::from("binance_spot"),
+            instrument: Instrument::from((base, "usdt", InstrumentKind::Spot)),
+            kind: DataKind::Trade(PublicTrade {
+                id: id.to_string(),
+                price: 100.0,
+                amount: 1.0,
+                side: Side::Buy,
+            }),
+        }
+    }
+
+    fn trade_ids(feed: &mut MarketFileFeed) -> Vec<Option<String>> {
+        std::iter::from_fn(|| match feed.next() {
+            Feed::Next(event) => match event.kind {
+                DataKind::Trade(trade) => Some(Some(trade.id)),
+                _ => panic!("unexpected DataKind"),
+            },
+            Feed::Unhealthy => Some(None),
+            Feed::Finished => None,
+        })
+        .collect()
+    }
+
+    #[test]
+    fn should_merge_recorded_directory_by_exchange_time() {
+        let directory = test_directory("recorded");
+        let mut recorder = Recorder::new(RecorderConfig {
+            directory: directory.clone(),
+            format: RecordFormat::MessagePack,
+            max_file_bytes: Some(1),
+        });
+        for event in [
+            trade(0, "btc_0", "btc"),
+            trade(1, "eth_1", "eth"),
+            trade(2, "btc_2", "btc"),
+            trade(2, "eth_2", "eth"),
+            trade(3, "btc_3", "btc"),
+        ] {
+            recorder.write(&event).unwrap();
+        }
+        drop(recorder);
+
+        let mut feed = MarketFileFeed::from_directory(&directory).unwrap();
+        assert_eq!(
+            trade_ids(&mut feed),
+            vec![
+                Some("btc_0".to_string()),
+                Some("eth_1".to_string()),
+                Some("btc_2".to_string()),
+                Some("eth_2".to_string()),
+                Some("btc_3".to_string()),
+            ]
+        );
+        assert_eq!(feed.next(), Feed::Finished);
+
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn should_merge_jsonl_and_csv_files_and_skip_invalid_records() {
+        let directory = test_directory("files");
+
+        let jsonl = directory.join("btc.jsonl");
+        let records = [trade(0, "btc_0", "btc"), trade(3, "btc_3", "btc")]
+            .iter()
+            .map(|event| serde_json::to_string(event).unwrap())
+            .collect::<Vec<_>>()
+            .join("\n");
+        std::fs::write(&jsonl, records).unwrap();
+
+        let csv = directory.join("eth.csv");
+        std::fs::write(
+            &csv,
+            format!(
+                "{CSV_HEADER}\n\
+                2024-01-01T00:00:01Z,2024-01-01T00:00:01Z,binance_spot,eth,usdt,spot,trade,eth_1,100.0,1.0,buy,,,,,,,\n\
+                2024-01-01T00:00:02Z,2024-01-01T00:00:02Z,binance_spot,eth,usdt,spot,trade,,100.0,1.0,buy,,,,,,,\n\
+                2024-01-01T00:00:04Z,2024-01-01T00:00:04Z,binance_spot,eth,usdt,spot,trade,eth_4,100.0,1.0,sell,,,,,,,\n"
+            ),
+        )
+        .unwrap();
+
+        let mut feed = MarketFileFeed::new([&jsonl, &csv]).unwrap();
+        assert_eq!(
+            trade_ids(&mut feed),
+            vec![
+                Some("btc_0".to_string()),
+                Some("eth_1".to_string()),
+                // CSV record missing a required "id" is skipped
+                None,
+                Some("btc_3".to_string()),
+                Some("eth_4".to_string()),
+            ]
+        );
+
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn should_parse_csv_candle_record() {
+        let record = CsvMarketEvent {
+            exchange_time: Utc.with_ymd_and_hms(2024, 1, 1, 0, 1, 0).unwrap(),
+            received_time: Utc.with_ymd_and_hms(2024, 1, 1, 0, 1, 0).unwrap(),
+            exchange: "binance_spot".to_string(),
+            base: "btc".to_string(),
+            quote: "usdt".to_string(),
+            instrument_kind: InstrumentKind::Spot,
+            kind: CsvMarketKind::Candle,
+            id: None,
+            price: None,
+            amount: None,
+            side: None,
+            close_time: Some(Utc.with_ymd_and_hms(2024, 1, 1, 0, 1, 0).unwrap()),
+            open: Some(1.0),
+            high: Some(2.0),
+            low: Some(0.5),
+            close: Some(1.5),
+            volume: Some(10.0),
+            trade_count: Some(3),
+        };
+
+        let actual = MarketEvent::try_from(record.clone()).unwrap();
+        assert_eq!(
+            actual.kind,
+            DataKind::Candle(Candle {
+                close_time: Utc.with_ymd_and_hms(2024, 1, 1, 0, 1, 0).unwrap(),
+                open: 1.0,
+                high: 2.0,
+                low: 0.5,
+                close: 1.5,
+                volume: 10.0,
+                trade_count: 3,
+            })
+        );
+
+        let missing_close = CsvMarketEvent {
+            close: None,
+            ..record
+        };
+        assert!(MarketEvent::try_from(missing_close).is_err());
+    }
+
+    #[test]
+    fn should_reject_unsupported_market_file() {
+        assert!(matches!(
+            MarketFileFeed::new(["market_data.parquet"]),
+            Err(DataError::UnsupportedFile(_))
+        ));
+    }
+}
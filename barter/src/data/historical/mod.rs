@@ -1,5 +1,8 @@
 use crate::data::{Feed, MarketGenerator};
 
+/// File-backed historical [`Feed`] that lazily merges recorded market data files from disk.
+pub mod file;
+
 /// Historical [`Feed`] of market events.
 #[derive(Debug)]
 pub struct MarketFeed<Iter, Event>
@@ -1,3 +1,5 @@
+use barter_data::event::{DataKind, MarketEvent};
+use barter_integration::model::instrument::Instrument;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
@@ -43,3 +45,27 @@ impl Default for MarketMeta {
         }
     }
 }
+
+impl MarketMeta {
+    /// Determines the [`MarketMeta`] of an input [`MarketEvent`]. Returns `None` if the
+    /// [`DataKind`] does not contain a close price (eg/ [`DataKind::FundingRate`]).
+    pub fn from_market_event(market: &MarketEvent<Instrument, DataKind>) -> Option<Self> {
+        let close = match &market.kind {
+            DataKind::Trade(trade) => trade.price,
+            DataKind::Candle(candle) => candle.close,
+            DataKind::OrderBookL1(book_l1) => book_l1.volume_weighed_mid_price(),
+            DataKind::OrderBook(book) => book.volume_weighed_mid_price()?,
+            DataKind::Liquidation(_)
+            | DataKind::FundingRate(_)
+            | DataKind::MarkPrice(_)
+            | DataKind::IndexPrice(_)
+            | DataKind::Ticker(_)
+            | DataKind::OpenInterest(_) => return None,
+        };
+
+        Some(Self {
+            close,
+            time: market.exchange_time,
+        })
+    }
+}
@@ -9,7 +9,7 @@ use crate::{
         FillUpdater, MarketUpdater, OrderGenerator,
     },
     statistic::summary::{PositionSummariser, TableBuilder},
-    strategy::SignalGenerator,
+    strategy::PortfolioSignalGenerator,
 };
 use barter_data::event::{DataKind, MarketEvent};
 use barter_integration::model::{instrument::Instrument, Market, MarketId};
@@ -24,9 +24,9 @@ use uuid::Uuid;
 /// Barter Engine module specific errors.
 pub mod error;
 
-/// Contains the trading event loop for a Trader capable of trading a single market pair. A Trader
-/// has it's own Data handler, Strategy & Execution handler, as well as shared access to a global
-/// Portfolio instance.
+/// Contains the trading event loop for a Trader capable of trading a single market pair, or a set
+/// of markets with a portfolio-level strategy. A Trader has it's own Data handler, Strategy &
+/// Execution handler, as well as shared access to a global Portfolio instance.
 pub mod trader;
 
 /// Commands that can be actioned by an [`Engine`] and it's associated [`Trader`]s.
@@ -55,7 +55,7 @@ where
     Statistic: Serialize + Send,
     Portfolio: MarketUpdater + OrderGenerator + FillUpdater + Send,
     Data: MarketGenerator<MarketEvent<Instrument, DataKind>> + Send,
-    Strategy: SignalGenerator + Send,
+    Strategy: PortfolioSignalGenerator + Send,
     Execution: ExecutionClient + Send,
 {
     /// Unique identifier for an [`Engine`] in Uuid v4 format. Used as a unique identifier seed for
@@ -75,8 +75,8 @@ where
     pub statistics_summary: Statistic,
 }
 
-/// Multi-threaded Trading Engine capable of trading with an arbitrary number of [`Trader`]s, one
-/// for each unique [`Market`].
+/// Multi-threaded Trading Engine capable of trading with an arbitrary number of [`Trader`]s, each
+/// trading a unique [`Market`] (or a unique set of [`Market`]s with a portfolio-level strategy).
 ///
 /// Each [`Trader`] operates on it's own thread and has it's own Data handler, Strategy &
 /// Execution Handler, as well as shared access to a global Portfolio instance. A graceful remote
@@ -95,7 +95,7 @@ where
         + Send
         + 'static,
     Data: MarketGenerator<MarketEvent<Instrument, DataKind>> + Send + 'static,
-    Strategy: SignalGenerator + Send,
+    Strategy: PortfolioSignalGenerator + Send,
     Execution: ExecutionClient + Send,
 {
     /// Unique identifier for an [`Engine`] in Uuid v4 format. Used as a unique identifier seed for
@@ -129,7 +129,7 @@ where
         + Send
         + 'static,
     Data: MarketGenerator<MarketEvent<Instrument, DataKind>> + Send,
-    Strategy: SignalGenerator + Send + 'static,
+    Strategy: PortfolioSignalGenerator + Send + 'static,
     Execution: ExecutionClient + Send + 'static,
 {
     /// Constructs a new trading [`Engine`] instance using the provided [`EngineLego`].
@@ -258,7 +258,14 @@ where
         tokio::time::sleep(std::time::Duration::from_secs(1)).await;
 
         // Distribute Command::Terminate to all the Engine's Traders
+        // '--> Traders with a portfolio-level strategy share one command_tx across many Markets
+        let mut terminated: Vec<&mpsc::Sender<Command>> = Vec::new();
         for (market, command_tx) in self.trader_command_txs.iter() {
+            if terminated.iter().any(|sent| sent.same_channel(command_tx)) {
+                continue;
+            }
+            terminated.push(command_tx);
+
             if command_tx
                 .send(Command::Terminate(message.clone()))
                 .await
@@ -364,7 +371,7 @@ where
     Statistic: Serialize + Send,
     Portfolio: MarketUpdater + OrderGenerator + FillUpdater + Send,
     Data: MarketGenerator<MarketEvent<Instrument, DataKind>> + Send,
-    Strategy: SignalGenerator + Send,
+    Strategy: PortfolioSignalGenerator + Send,
     Execution: ExecutionClient + Send,
 {
     engine_id: Option<Uuid>,
@@ -387,7 +394,7 @@ where
         + FillUpdater
         + Send,
     Data: MarketGenerator<MarketEvent<Instrument, DataKind>> + Send,
-    Strategy: SignalGenerator + Send,
+    Strategy: PortfolioSignalGenerator + Send,
     Execution: ExecutionClient + Send,
 {
     fn new() -> Self {
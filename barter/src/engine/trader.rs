@@ -2,9 +2,9 @@ use super::{error::EngineError, Command};
 use crate::{
     data::{Feed, MarketGenerator},
     event::{Event, MessageTransmitter},
-    execution::ExecutionClient,
+    execution::{ExecutionClient, FillEvent, OrderFailed},
     portfolio::{FillUpdater, MarketUpdater, OrderGenerator},
-    strategy::{SignalForceExit, SignalGenerator},
+    strategy::{PortfolioSignalGenerator, SignalForceExit},
 };
 use barter_data::event::{DataKind, MarketEvent};
 use barter_integration::model::{instrument::Instrument, Market};
@@ -23,14 +23,15 @@ where
     Statistic: Serialize + Send,
     Portfolio: MarketUpdater + OrderGenerator + FillUpdater,
     Data: MarketGenerator<MarketEvent<Instrument, DataKind>>,
-    Strategy: SignalGenerator,
+    Strategy: PortfolioSignalGenerator,
     Execution: ExecutionClient,
 {
     /// Identifier for the [`Engine`](super::Engine) this [`Trader`] is associated with
     /// (1-to-many relationship).
     pub engine_id: Uuid,
-    /// Communicates the unique [`Market`] this [`Trader`] is bartering on.
-    pub market: Market,
+    /// Communicates the unique [`Market`]s this [`Trader`] is bartering on. A [`Trader`] with a
+    /// portfolio-level strategy barters on every [`Market`] in it's merged Data feed.
+    pub markets: Vec<Market>,
     /// mpsc::Receiver for receiving [`Command`]s from a remote source.
     pub command_rx: mpsc::Receiver<Command>,
     /// [`Event`] transmitter for sending every [`Event`] the [`Trader`] encounters to an external sink.
@@ -40,7 +41,7 @@ where
     pub portfolio: Arc<Mutex<Portfolio>>,
     /// Data handler that implements [`MarketGenerator`].
     pub data: Data,
-    /// Strategy that implements [`SignalGenerator`].
+    /// Strategy that implements [`PortfolioSignalGenerator`].
     pub strategy: Strategy,
     /// Execution handler that implements [`ExecutionClient`].
     pub execution: Execution,
@@ -52,6 +53,11 @@ where
 /// relationship with an Engine/Portfolio. A graceful remote shutdown is made possible by sending
 /// a [`Command::Terminate`] to the Trader's
 /// mpsc::Receiver command_rx.
+///
+/// A Trader with a [`PortfolioSignalGenerator`] strategy can instead trade a set of [`Market`]s
+/// using a merged Data feed, where the strategy may generate [`Signal`](crate::strategy::Signal)s
+/// for any of them (eg/ pairs trading). The [`Engine`](super::Engine) `trader_command_txs` should
+/// then map every one of these [`Market`]s to the same Trader command transmitter.
 #[derive(Debug)]
 pub struct Trader<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
 where
@@ -59,14 +65,15 @@ where
     Statistic: Serialize + Send,
     Portfolio: MarketUpdater + OrderGenerator + FillUpdater,
     Data: MarketGenerator<MarketEvent<Instrument, DataKind>> + Send,
-    Strategy: SignalGenerator + Send,
+    Strategy: PortfolioSignalGenerator + Send,
     Execution: ExecutionClient + Send,
 {
     /// Identifier for the [`Engine`](super::Engine) this [`Trader`] is associated with
     /// (1-to-many relationship).
     engine_id: Uuid,
-    /// Communicates the unique [`Market`] this [`Trader`] is bartering on.
-    market: Market,
+    /// Communicates the unique [`Market`]s this [`Trader`] is bartering on. A [`Trader`] with a
+    /// portfolio-level strategy barters on every [`Market`] in it's merged Data feed.
+    markets: Vec<Market>,
     /// `mpsc::Receiver` for receiving [`Command`]s from a remote source.
     command_rx: mpsc::Receiver<Command>,
     /// [`Event`] transmitter for sending every [`Event`] the [`Trader`] encounters to an external
@@ -79,7 +86,7 @@ where
     portfolio: Arc<Mutex<Portfolio>>,
     /// Data handler that implements [`MarketGenerator`].
     data: Data,
-    /// Strategy that implements [`SignalGenerator`].
+    /// Strategy that implements [`PortfolioSignalGenerator`].
     strategy: Strategy,
     /// Execution handler that implements [`ExecutionClient`].
     execution: Execution,
@@ -93,20 +100,20 @@ where
     Statistic: Serialize + Send,
     Portfolio: MarketUpdater + OrderGenerator + FillUpdater,
     Data: MarketGenerator<MarketEvent<Instrument, DataKind>> + Send,
-    Strategy: SignalGenerator + Send,
+    Strategy: PortfolioSignalGenerator + Send,
     Execution: ExecutionClient + Send,
 {
     /// Constructs a new [`Trader`] instance using the provided [`TraderLego`].
     pub fn new(lego: TraderLego<EventTx, Statistic, Portfolio, Data, Strategy, Execution>) -> Self {
         info!(
             engine_id = %lego.engine_id,
-            market = ?lego.market,
+            markets = ?lego.markets,
             "constructed new Trader instance"
         );
 
         Self {
             engine_id: lego.engine_id,
-            market: lego.market,
+            markets: lego.markets,
             command_rx: lego.command_rx,
             event_tx: lego.event_tx,
             event_q: VecDeque::with_capacity(4),
@@ -141,6 +148,10 @@ where
                 }
             }
 
+            // Populate event_q with any Fills & OrderFailed generated asynchronously by the
+            // ExecutionClient
+            self.push_execution_updates();
+
             // If the Feed<MarketEvent> yields, populate event_q with the next MarketEvent
             match self.data.next() {
                 Feed::Next(market) => {
@@ -150,97 +161,176 @@ where
                 Feed::Unhealthy => {
                     warn!(
                         engine_id = %self.engine_id,
-                        market = ?self.market,
+                        markets = ?self.markets,
                         action = "continuing while waiting for healthy Feed",
                         "MarketFeed unhealthy"
                     );
                     continue 'trading;
                 }
-                Feed::Finished => break 'trading,
+                Feed::Finished => {
+                    self.flush_execution();
+                    break 'trading;
+                }
             }
 
             // Handle Events in the event_q
-            // '--> While loop will break when event_q is empty and requires another MarketEvent
-            while let Some(event) = self.event_q.pop_front() {
-                match event {
-                    Event::Market(market) => {
-                        if let Some(signal) = self.strategy.generate_signal(&market) {
-                            self.event_tx.send(Event::Signal(signal.clone()));
-                            self.event_q.push_back(Event::Signal(signal));
-                        }
+            self.process_event_q();
 
-                        if let Some(position_update) = self
-                            .portfolio
-                            .lock()
-                            .update_from_market(&market)
-                            .expect("failed to update Portfolio from market")
-                        {
-                            self.event_tx.send(Event::PositionUpdate(position_update));
-                        }
+            debug!(
+                engine_id = &*self.engine_id.to_string(),
+                markets = &*format!("{:?}", self.markets),
+                "Trader trading loop stopped"
+            );
+        }
+    }
+
+    /// Handle every [`Event`] in the event_q.
+    ///
+    /// While loop will break when event_q is empty and requires another MarketEvent.
+    fn process_event_q(&mut self) {
+        while let Some(event) = self.event_q.pop_front() {
+            match event {
+                Event::Market(market) => {
+                    self.execution.update_from_market(&market);
+
+                    for signal in self.strategy.generate_signals(&market) {
+                        self.event_tx.send(Event::Signal(signal.clone()));
+                        self.event_q.push_back(Event::Signal(signal));
                     }
 
-                    Event::Signal(signal) => {
-                        if let Some(order) = self
-                            .portfolio
-                            .lock()
-                            .generate_order(&signal)
-                            .expect("failed to generate order")
-                        {
-                            self.event_tx.send(Event::OrderNew(order.clone()));
-                            self.event_q.push_back(Event::OrderNew(order));
+                    let market_side_effect_events = self
+                        .portfolio
+                        .lock()
+                        .update_from_market(&market)
+                        .expect("failed to update Portfolio from market");
+
+                    // Populate event_q with any SignalForceExit triggered by protective stops
+                    for event in market_side_effect_events {
+                        if let Event::SignalForceExit(signal_force_exit) = &event {
+                            self.event_q
+                                .push_back(Event::SignalForceExit(signal_force_exit.clone()));
                         }
+                        self.event_tx.send(event);
                     }
+                }
 
-                    Event::SignalForceExit(signal_force_exit) => {
-                        if let Some(order) = self
-                            .portfolio
-                            .lock()
-                            .generate_exit_order(signal_force_exit)
-                            .expect("failed to generate forced exit order")
-                        {
+                Event::Signal(signal) => {
+                    match self
+                        .portfolio
+                        .lock()
+                        .generate_order(&signal)
+                        .expect("failed to generate order")
+                    {
+                        Some(Ok(order)) => {
                             self.event_tx.send(Event::OrderNew(order.clone()));
                             self.event_q.push_back(Event::OrderNew(order));
                         }
+                        Some(Err(rejected)) => {
+                            self.event_tx.send(Event::OrderRejected(rejected));
+                        }
+                        None => {}
                     }
+                }
 
-                    Event::OrderNew(order) => {
-                        let fill = self
-                            .execution
-                            .generate_fill(&order)
-                            .expect("failed to generate Fill");
+                Event::SignalForceExit(signal_force_exit) => {
+                    if let Some(order) = self
+                        .portfolio
+                        .lock()
+                        .generate_exit_order(signal_force_exit)
+                        .expect("failed to generate forced exit order")
+                    {
+                        self.event_tx.send(Event::OrderNew(order.clone()));
+                        self.event_q.push_back(Event::OrderNew(order));
+                    }
+                }
 
-                        self.event_tx.send(Event::Fill(fill.clone()));
-                        self.event_q.push_back(Event::Fill(fill));
+                Event::OrderNew(order) => match self.execution.execute_order(&order) {
+                    Ok(fills) => self.push_fills(fills),
+                    Err(error) => {
+                        self.push_order_failed(OrderFailed::new(order, error.to_string()))
                     }
+                },
 
-                    Event::Fill(fill) => {
-                        let fill_side_effect_events = self
-                            .portfolio
-                            .lock()
-                            .update_from_fill(&fill)
-                            .expect("failed to update Portfolio from fill");
+                Event::OrderFailed(failed) => {
+                    warn!(
+                        engine_id = %self.engine_id,
+                        order = ?failed.order,
+                        reason = %failed.reason,
+                        action = "updating Portfolio and continuing",
+                        "failed to execute OrderEvent"
+                    );
 
-                        self.event_tx.send_many(fill_side_effect_events);
-                    }
-                    _ => {}
+                    self.portfolio
+                        .lock()
+                        .update_from_order_failed(&failed)
+                        .expect("failed to update Portfolio from failed order");
                 }
+
+                Event::Fill(fill) => {
+                    let fill_side_effect_events = self
+                        .portfolio
+                        .lock()
+                        .update_from_fill(&fill)
+                        .expect("failed to update Portfolio from fill");
+
+                    self.event_tx.send_many(fill_side_effect_events);
+                }
+                _ => {}
             }
+        }
+    }
 
-            debug!(
-                engine_id = &*self.engine_id.to_string(),
-                market = &*format!("{:?}", self.market),
-                "Trader trading loop stopped"
+    /// Resolve every order still in-flight with the [`ExecutionClient`], applying the resulting
+    /// [`FillEvent`]s & [`OrderFailed`]s before the trading loop stops.
+    fn flush_execution(&mut self) {
+        self.execution.flush();
+        self.push_execution_updates();
+        self.process_event_q();
+
+        if self.execution.has_pending_orders() {
+            warn!(
+                engine_id = %self.engine_id,
+                markets = ?self.markets,
+                action = "stopping Trader with orders still in-flight",
+                "ExecutionClient has unresolved orders"
             );
         }
     }
 
+    /// Populate the event_q with the [`OrderFailed`]s & [`FillEvent`]s generated asynchronously
+    /// by the [`ExecutionClient`] since the last call.
+    fn push_execution_updates(&mut self) {
+        for failed in self.execution.next_failures() {
+            self.push_order_failed(failed);
+        }
+
+        let fills = self.execution.next_fills();
+        self.push_fills(fills);
+    }
+
+    /// Send every [`FillEvent`] to the external sink, and populate the event_q so they are
+    /// applied to the Portfolio.
+    fn push_fills(&mut self, fills: Vec<FillEvent>) {
+        for fill in fills {
+            self.event_tx.send(Event::Fill(fill.clone()));
+            self.event_q.push_back(Event::Fill(fill));
+        }
+    }
+
+    /// Send the [`OrderFailed`] to the external sink, and populate the event_q so it is applied
+    /// to the Portfolio.
+    fn push_order_failed(&mut self, failed: OrderFailed) {
+        self.event_tx.send(Event::OrderFailed(failed.clone()));
+        self.event_q.push_back(Event::OrderFailed(failed));
+    }
+
     /// Returns a [`Command`] if one has been received.
     fn receive_remote_command(&mut self) -> Option<Command> {
         match self.command_rx.try_recv() {
             Ok(command) => {
                 debug!(
                     engine_id = &*self.engine_id.to_string(),
-                    market = &*format!("{:?}", self.market),
+                    markets = &*format!("{:?}", self.markets),
                     command = &*format!("{:?}", command),
                     "Trader received remote command"
                 );
@@ -270,11 +360,11 @@ where
     Statistic: Serialize + Send,
     Portfolio: MarketUpdater + OrderGenerator + FillUpdater,
     Data: MarketGenerator<MarketEvent<Instrument, DataKind>>,
-    Strategy: SignalGenerator,
+    Strategy: PortfolioSignalGenerator,
     Execution: ExecutionClient,
 {
     engine_id: Option<Uuid>,
-    market: Option<Market>,
+    markets: Vec<Market>,
     command_rx: Option<mpsc::Receiver<Command>>,
     event_tx: Option<EventTx>,
     portfolio: Option<Arc<Mutex<Portfolio>>>,
@@ -291,13 +381,13 @@ where
     Statistic: Serialize + Send,
     Portfolio: MarketUpdater + OrderGenerator + FillUpdater,
     Data: MarketGenerator<MarketEvent<Instrument, DataKind>> + Send,
-    Strategy: SignalGenerator + Send,
+    Strategy: PortfolioSignalGenerator + Send,
     Execution: ExecutionClient + Send,
 {
     fn new() -> Self {
         Self {
             engine_id: None,
-            market: None,
+            markets: Vec::new(),
             command_rx: None,
             event_tx: None,
             portfolio: None,
@@ -315,11 +405,17 @@ where
         }
     }
 
-    pub fn market(self, value: Market) -> Self {
-        Self {
-            market: Some(value),
-            ..self
-        }
+    pub fn market(mut self, value: Market) -> Self {
+        self.markets.push(value);
+        self
+    }
+
+    pub fn markets<Markets>(mut self, values: Markets) -> Self
+    where
+        Markets: IntoIterator<Item = Market>,
+    {
+        self.markets.extend(values);
+        self
     }
 
     pub fn command_rx(self, value: mpsc::Receiver<Command>) -> Self {
@@ -371,9 +467,11 @@ where
             engine_id: self
                 .engine_id
                 .ok_or(EngineError::BuilderIncomplete("engine_id"))?,
-            market: self
-                .market
-                .ok_or(EngineError::BuilderIncomplete("market"))?,
+            markets: if self.markets.is_empty() {
+                return Err(EngineError::BuilderIncomplete("market"));
+            } else {
+                self.markets
+            },
             command_rx: self
                 .command_rx
                 .ok_or(EngineError::BuilderIncomplete("command_rx"))?,
@@ -1,7 +1,8 @@
 use crate::{
-    execution::FillEvent,
+    execution::{FillEvent, OrderFailed},
     portfolio::{
         position::{Position, PositionExit, PositionUpdate},
+        risk::OrderRejected,
         Balance, OrderEvent,
     },
     strategy::{Signal, SignalForceExit},
@@ -16,7 +17,9 @@ use tracing::warn;
 /// Events that occur when bartering. [`MarketEvent`], [`Signal`], [`OrderEvent`], and
 /// [`FillEvent`] are vital to the [`Trader`](crate::engine::trader::Trader) event loop, dictating
 /// the trading sequence. The [`PositionExit`] Event is a representation of work done by the
-/// system, and is useful for analysing performance & reconciliations.
+/// system, and is useful for analysing performance & reconciliations. The [`OrderRejected`] Event
+/// provides an audit trail of why risk management vetoed an [`OrderEvent`], and the
+/// [`OrderFailed`] Event of why execution of an [`OrderEvent`] failed.
 #[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
 pub enum Event {
     Market(MarketEvent<Instrument, DataKind>),
@@ -24,6 +27,8 @@ pub enum Event {
     SignalForceExit(SignalForceExit),
     OrderNew(OrderEvent),
     OrderUpdate,
+    OrderRejected(OrderRejected),
+    OrderFailed(OrderFailed),
     Fill(FillEvent),
     PositionNew(Position),
     PositionUpdate(PositionUpdate),
@@ -0,0 +1,731 @@
+use crate::{
+    execution::{error::ExecutionError, ExecutionClient, Fees, FillEvent, OrderFailed},
+    portfolio::{OrderEvent, OrderType},
+    strategy::Decision,
+};
+use barter_data::event::{DataKind, MarketEvent};
+use barter_execution::{
+    error::ExecutionError as ClientError,
+    model::{
+        order::{Open, Order, OrderId, OrderKind, RequestOpen},
+        trade::Trade,
+        AccountEvent, AccountEventKind, ClientOrderId,
+    },
+    simulated::{exchange::SimulatedExchange, SimulatedEvent},
+};
+use barter_integration::model::{instrument::Instrument, Side};
+use chrono::{DateTime, Duration, Utc};
+use std::collections::HashMap;
+use tokio::{
+    runtime::Handle,
+    sync::{mpsc, oneshot},
+};
+use tracing::{debug, warn};
+use uuid::Uuid;
+
+/// Maximum age of a buffered [`Trade`] awaiting the [`OrderId`] of it's order, relative to the
+/// latest [`AccountEvent`] received, before it is dropped as belonging to an unknown order.
+pub const UNMATCHED_TRADE_MAX_AGE: Duration = Duration::seconds(60);
+
+/// Receiver for the response of a [`BridgeVenue`] to an [`Order<RequestOpen>`].
+pub type OpenResponse = oneshot::Receiver<Vec<Result<Order<Open>, ClientError>>>;
+
+/// Venue that an [`ExecutionBridge`] synchronously sends [`Order<RequestOpen>`]s & market data to.
+pub trait BridgeVenue {
+    /// Send an [`Order<RequestOpen>`] to the venue. A venue with request latency may only
+    /// respond after subsequent calls to [`BridgeVenue::update_from_market`].
+    fn open_order(&mut self, request: Order<RequestOpen>) -> OpenResponse;
+
+    /// Update the venue with the latest [`MarketEvent`] consumed by the
+    /// [`Trader`](crate::engine::trader::Trader).
+    fn update_from_market(&mut self, _market: &MarketEvent<Instrument, DataKind>) {}
+
+    /// Resolve every request & [`AccountEvent`] still in flight at the venue.
+    fn flush(&mut self) {}
+}
+
+/// [`BridgeVenue`] that awaits every request to an asynchronous
+/// [`barter_execution::ExecutionClient`] on the provided Tokio runtime.
+#[derive(Debug)]
+pub struct ClientVenue<Client> {
+    client: Client,
+    runtime: Handle,
+}
+
+impl<Client> BridgeVenue for ClientVenue<Client>
+where
+    Client: barter_execution::ExecutionClient,
+{
+    fn open_order(&mut self, request: Order<RequestOpen>) -> OpenResponse {
+        let (response_tx, response_rx) = oneshot::channel();
+        let response = self
+            .runtime
+            .block_on(self.client.open_orders(vec![request]));
+        let _ = response_tx.send(response);
+        response_rx
+    }
+}
+
+impl BridgeVenue for SimulatedExchange {
+    fn open_order(&mut self, request: Order<RequestOpen>) -> OpenResponse {
+        let (response_tx, response_rx) = oneshot::channel();
+        self.process(SimulatedEvent::OpenOrders((vec![request], response_tx)));
+        response_rx
+    }
+
+    fn update_from_market(&mut self, market: &MarketEvent<Instrument, DataKind>) {
+        self.process(SimulatedEvent::Market(market.clone()));
+    }
+
+    fn flush(&mut self) {
+        SimulatedExchange::flush(self)
+    }
+}
+
+/// Synchronous [`ExecutionClient`] that drives a [`BridgeVenue`], allowing a
+/// [`Trader`](crate::engine::trader::Trader) to execute against a live venue via an asynchronous
+/// [`barter_execution::ExecutionClient`], or against an inline [`SimulatedExchange`].
+///
+/// Each [`OrderEvent`] is mapped to an [`Order<RequestOpen>`] and sent to the venue.
+/// [`AccountEventKind::Trade`]s received via the account event stream are aggregated per order,
+/// and a single [`FillEvent`] is yielded once the order is fully filled, or cancelled after being
+/// partially filled. Orders the venue fails to open are yielded as [`OrderFailed`]s by
+/// [`ExecutionClient::next_failures`]. Only one order per market may be in flight at a time,
+/// since the Portfolio cannot account for an order until it is filled.
+///
+/// An [`ExecutionBridge`] constructed via [`ExecutionBridge::simulated`] processes every request
+/// & [`MarketEvent`] on the [`SimulatedExchange`] inline, so backtests are deterministic.
+///
+/// **Note:**
+/// Each [`ExecutionBridge`] must own the [`AccountEvent`] stream of it's venue, since every
+/// [`AccountEvent`] it consumes is removed from the stream.
+#[derive(Debug)]
+pub struct ExecutionBridge<Venue> {
+    venue: Venue,
+    account_rx: mpsc::UnboundedReceiver<AccountEvent>,
+    open_responses: Vec<(ClientOrderId, OpenResponse)>,
+    orders: HashMap<ClientOrderId, PendingOrder>,
+    order_ids: HashMap<OrderId, ClientOrderId>,
+    unmatched_trades: Vec<(DateTime<Utc>, Trade)>,
+    latest_event_time: Option<DateTime<Utc>>,
+    failures: Vec<OrderFailed>,
+}
+
+/// [`OrderEvent`] that has been sent to the exchange, and the aggregated state of every
+/// [`Trade`] that has partially filled it.
+#[derive(Clone, PartialEq, Debug)]
+struct PendingOrder {
+    order: OrderEvent,
+    filled_quantity: f64,
+    fill_value_gross: f64,
+    fees: f64,
+}
+
+impl<Venue> ExecutionClient for ExecutionBridge<Venue>
+where
+    Venue: BridgeVenue,
+{
+    fn execute_order(&mut self, order: &OrderEvent) -> Result<Vec<FillEvent>, ExecutionError> {
+        // Prevent duplicate orders for a market while it's previous order awaits a FillEvent
+        if self.orders.values().any(|pending| {
+            pending.order.exchange == order.exchange && pending.order.instrument == order.instrument
+        }) {
+            return Err(ExecutionError::OrderInFlight);
+        }
+
+        let request = order_request(order)?;
+        let cid = request.cid;
+
+        debug!(%cid, ?order, "opening order via ExecutionBridge");
+        self.orders.insert(
+            cid,
+            PendingOrder {
+                order: order.clone(),
+                filled_quantity: 0.0,
+                fill_value_gross: 0.0,
+                fees: 0.0,
+            },
+        );
+
+        // Send the Order<RequestOpen> to the venue, which may respond immediately
+        let response = self.venue.open_order(request);
+        self.open_responses.push((cid, response));
+
+        // Generate FillEvents for any orders the venue filled immediately
+        Ok(self.next_fills())
+    }
+
+    fn next_fills(&mut self) -> Vec<FillEvent> {
+        // Process open order responses, recording an OrderFailed for every order that failed
+        self.process_open_responses();
+
+        // Process AccountEvents, generating a FillEvent for every completed order
+        let mut fills = Vec::new();
+        while let Ok(event) = self.account_rx.try_recv() {
+            self.latest_event_time = self.latest_event_time.max(Some(event.received_time));
+
+            match event.kind {
+                AccountEventKind::OrdersNew(orders) | AccountEventKind::OrdersOpen(orders) => {
+                    for order in orders {
+                        self.register_order_id(order.state.id, order.cid);
+                    }
+                }
+                AccountEventKind::Trade(trade) => {
+                    fills.extend(self.apply_trade(event.received_time, trade));
+                }
+                AccountEventKind::OrdersCancelled(cancelled) => {
+                    fills.extend(cancelled.into_iter().filter_map(|order| {
+                        self.order_ids.remove(&order.state.id);
+                        let pending = self.orders.remove(&order.cid)?;
+                        (pending.filled_quantity > 0.0)
+                            .then(|| pending.into_fill(event.received_time))
+                    }));
+                }
+                _ => {}
+            }
+        }
+
+        // Re-attempt any Trades received before their associated OrderId was known, dropping
+        // those that have remained unmatched for longer than the UNMATCHED_TRADE_MAX_AGE
+        let unmatched_trades = std::mem::take(&mut self.unmatched_trades);
+        for (time, trade) in unmatched_trades {
+            if self
+                .latest_event_time
+                .is_some_and(|latest| latest - time > UNMATCHED_TRADE_MAX_AGE)
+            {
+                warn!(
+                    ?trade,
+                    action = "dropping Trade",
+                    "ExecutionBridge failed to match Trade to a pending order"
+                );
+                continue;
+            }
+            fills.extend(self.apply_trade(time, trade));
+        }
+
+        fills
+    }
+
+    fn next_failures(&mut self) -> Vec<OrderFailed> {
+        std::mem::take(&mut self.failures)
+    }
+
+    fn update_from_market(&mut self, market: &MarketEvent<Instrument, DataKind>) {
+        self.venue.update_from_market(market);
+    }
+
+    fn flush(&mut self) {
+        self.venue.flush();
+    }
+
+    fn has_pending_orders(&self) -> bool {
+        !self.orders.is_empty()
+    }
+}
+
+impl<Client> ExecutionBridge<ClientVenue<Client>>
+where
+    Client: barter_execution::ExecutionClient,
+{
+    /// Construct a new [`ExecutionBridge`] using the provided initialised client, it's
+    /// [`AccountEvent`] stream, and a [`Handle`] to the Tokio runtime used to execute requests.
+    ///
+    /// **Note:**
+    /// Requests are awaited synchronously via [`Handle::block_on`], so the [`ExecutionBridge`]
+    /// must not be used from within an asynchronous context. Use [`ExecutionBridge::simulated`]
+    /// to execute against an event-time [`SimulatedExchange`].
+    pub fn new(
+        client: Client,
+        account_rx: mpsc::UnboundedReceiver<AccountEvent>,
+        runtime: Handle,
+    ) -> Self {
+        Self::with_venue(ClientVenue { client, runtime }, account_rx)
+    }
+}
+
+impl ExecutionBridge<SimulatedExchange> {
+    /// Construct a new [`ExecutionBridge`] that drives the provided [`SimulatedExchange`] inline,
+    /// forwarding every [`MarketEvent`] it is updated with.
+    ///
+    /// The [`SimulatedExchange`] must use an event-time
+    /// [`SimulatedClock`](barter_execution::simulated::exchange::clock::SimulatedClock), since
+    /// request & [`AccountEvent`] latency can only elapse as [`MarketEvent`]s advance the clock.
+    pub fn simulated(
+        exchange: SimulatedExchange,
+        account_rx: mpsc::UnboundedReceiver<AccountEvent>,
+    ) -> Result<Self, ExecutionError> {
+        if !exchange.account.clock.is_event_time() {
+            return Err(ExecutionError::SimulatedClockNotEventTime);
+        }
+
+        Ok(Self::with_venue(exchange, account_rx))
+    }
+}
+
+impl<Venue> ExecutionBridge<Venue>
+where
+    Venue: BridgeVenue,
+{
+    /// Construct a new [`ExecutionBridge`] using the provided [`BridgeVenue`] and it's
+    /// [`AccountEvent`] stream.
+    pub fn with_venue(venue: Venue, account_rx: mpsc::UnboundedReceiver<AccountEvent>) -> Self {
+        Self {
+            venue,
+            account_rx,
+            open_responses: Vec::new(),
+            orders: HashMap::new(),
+            order_ids: HashMap::new(),
+            unmatched_trades: Vec::new(),
+            latest_event_time: None,
+            failures: Vec::new(),
+        }
+    }
+
+    /// Process every open order response received from the venue, registering the [`OrderId`]
+    /// of opened orders and recording an [`OrderFailed`] for every order that failed to open.
+    fn process_open_responses(&mut self) {
+        let open_responses = std::mem::take(&mut self.open_responses);
+        for (cid, mut response) in open_responses {
+            let result = match response.try_recv() {
+                Ok(results) => results.into_iter().next().unwrap_or_else(|| {
+                    Err(ClientError::Simulated(
+                        "venue returned no open order response".to_string(),
+                    ))
+                }),
+                Err(oneshot::error::TryRecvError::Empty) => {
+                    self.open_responses.push((cid, response));
+                    continue;
+                }
+                Err(oneshot::error::TryRecvError::Closed) => Err(ClientError::Simulated(
+                    "venue dropped the open order request".to_string(),
+                )),
+            };
+
+            match result {
+                Ok(open) => self.register_order_id(open.state.id, cid),
+                Err(error) => {
+                    warn!(%cid, ?error, "ExecutionBridge failed to open order");
+                    if let Some(pending) = self.orders.remove(&cid) {
+                        self.failures
+                            .push(OrderFailed::new(pending.order, error.to_string()));
+                    }
+                }
+            }
+        }
+    }
+
+    /// Associate an exchange [`OrderId`] with the [`ClientOrderId`] of a pending order.
+    fn register_order_id(&mut self, id: OrderId, cid: ClientOrderId) {
+        if self.orders.contains_key(&cid) {
+            self.order_ids.insert(id, cid);
+        }
+    }
+
+    /// Apply a [`Trade`] to it's associated pending order, returning a [`FillEvent`] if the order
+    /// has now been fully filled.
+    fn apply_trade(&mut self, time: DateTime<Utc>, trade: Trade) -> Option<FillEvent> {
+        let Some(cid) = self.order_ids.get(&trade.order_id).copied() else {
+            // Only buffer the Trade if a pending order does not yet have a known OrderId,
+            // otherwise it relates to an order not opened via this ExecutionBridge
+            if self.orders.len() > self.order_ids.len() {
+                self.unmatched_trades.push((time, trade));
+            } else {
+                debug!(
+                    ?trade,
+                    "ExecutionBridge ignoring Trade for unrecognised order"
+                );
+            }
+            return None;
+        };
+
+        let pending = self.orders.get_mut(&cid)?;
+        pending.apply_trade(&trade);
+
+        if !pending.is_filled() {
+            return None;
+        }
+
+        self.order_ids.remove(&trade.order_id);
+        self.orders
+            .remove(&cid)
+            .map(|pending| pending.into_fill(time))
+    }
+}
+
+impl PendingOrder {
+    /// Aggregate the [`Trade`] quantity, value, and fees.
+    fn apply_trade(&mut self, trade: &Trade) {
+        self.filled_quantity += trade.quantity;
+        self.fill_value_gross += trade.price * trade.quantity;
+
+        // Normalise fees to be denominated in the quote asset
+        self.fees += if trade.fees.symbol == trade.instrument.base {
+            trade.fees.fees * trade.price
+        } else {
+            if trade.fees.symbol != trade.instrument.quote {
+                warn!(
+                    fees = ?trade.fees,
+                    "ExecutionBridge cannot convert Trade fees to quote asset - using raw amount"
+                );
+            }
+            trade.fees.fees
+        };
+    }
+
+    /// Determine if the order has been fully filled.
+    fn is_filled(&self) -> bool {
+        let remaining = self.order.quantity.abs() - self.filled_quantity;
+        remaining <= self.order.quantity.abs() * 1e-9
+    }
+
+    /// Generate a [`FillEvent`] from the aggregated order [`Trade`]s.
+    fn into_fill(self, time: DateTime<Utc>) -> FillEvent {
+        FillEvent {
+            time,
+            exchange: self.order.exchange,
+            instrument: self.order.instrument,
+            market_meta: self.order.market_meta,
+            decision: self.order.decision,
+            quantity: self.filled_quantity.copysign(self.order.quantity),
+            fill_value_gross: self.fill_value_gross,
+            fees: Fees {
+                exchange: self.fees,
+                slippage: 0.0,
+                network: 0.0,
+            },
+            stops: self.order.stops,
+        }
+    }
+}
+
+/// Map an [`OrderEvent`] to a barter-execution [`Order<RequestOpen>`] with a new
+/// [`ClientOrderId`]. Orders are priced using the [`OrderEvent`] `market_meta` close.
+pub fn order_request(order: &OrderEvent) -> Result<Order<RequestOpen>, ExecutionError> {
+    let kind = match order.order_type {
+        OrderType::Market => OrderKind::Market,
+        OrderType::Limit => OrderKind::Limit,
+        unsupported => return Err(ExecutionError::UnsupportedOrderType(unsupported)),
+    };
+
+    Ok(Order {
+        exchange: order.exchange.clone(),
+        instrument: order.instrument.clone(),
+        cid: ClientOrderId(Uuid::new_v4()),
+        side: order_side(order.decision),
+        state: RequestOpen {
+            kind,
+            price: order.market_meta.close,
+            quantity: order.quantity.abs(),
+            trigger: None,
+        },
+    })
+}
+
+/// Determine the [`Side`] of the order required to execute a [`Decision`].
+pub fn order_side(decision: Decision) -> Side {
+    match decision {
+        Decision::Long | Decision::CloseShort => Side::Buy,
+        Decision::Short | Decision::CloseLong => Side::Sell,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::order_event;
+    use barter_execution::model::trade::{SymbolFees, TradeId};
+    use barter_integration::model::instrument::kind::InstrumentKind;
+
+    /// [`BridgeVenue`] that responds to every [`Order<RequestOpen>`] with the configured result,
+    /// or holds the response until released if `respond` is false.
+    #[derive(Debug)]
+    struct MockVenue {
+        respond: bool,
+        error: Option<ClientError>,
+        held: Vec<oneshot::Sender<Vec<Result<Order<Open>, ClientError>>>>,
+        markets: usize,
+    }
+
+    impl BridgeVenue for MockVenue {
+        fn open_order(&mut self, request: Order<RequestOpen>) -> OpenResponse {
+            let (response_tx, response_rx) = oneshot::channel();
+            if !self.respond {
+                self.held.push(response_tx);
+                return response_rx;
+            }
+
+            let response = match self.error.clone() {
+                Some(error) => Err(error),
+                None => Ok(order_open(request.cid)),
+            };
+            let _ = response_tx.send(vec![response]);
+            response_rx
+        }
+
+        fn update_from_market(&mut self, _: &MarketEvent<Instrument, DataKind>) {
+            self.markets += 1;
+        }
+    }
+
+    fn bridge(
+        respond: bool,
+        error: Option<ClientError>,
+    ) -> (
+        ExecutionBridge<MockVenue>,
+        mpsc::UnboundedSender<AccountEvent>,
+    ) {
+        let (account_tx, account_rx) = mpsc::unbounded_channel();
+        let venue = MockVenue {
+            respond,
+            error,
+            held: Vec::new(),
+            markets: 0,
+        };
+        (ExecutionBridge::with_venue(venue, account_rx), account_tx)
+    }
+
+    fn account_event(kind: AccountEventKind) -> AccountEvent {
+        AccountEvent {
+            received_time: Utc::now(),
+            exchange: barter_integration::model::Exchange::from("simulated"),
+            kind,
+        }
+    }
+
+    fn trade(order_id: &str, price: f64, quantity: f64, fees: SymbolFees) -> AccountEvent {
+        account_event(AccountEventKind::Trade(Trade {
+            id: TradeId::from("trade"),
+            order_id: OrderId::from(order_id),
+            instrument: order_event().instrument,
+            side: Side::Buy,
+            price,
+            quantity,
+            fees,
+        }))
+    }
+
+    #[test]
+    fn test_order_request() {
+        struct TestCase {
+            decision: Decision,
+            quantity: f64,
+            order_type: OrderType,
+            expected: Result<(Side, OrderKind, f64), ExecutionError>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: Long Market order
+                decision: Decision::Long,
+                quantity: 1.0,
+                order_type: OrderType::Market,
+                expected: Ok((Side::Buy, OrderKind::Market, 1.0)),
+            },
+            TestCase {
+                // TC1: Short Limit order with -ve quantity
+                decision: Decision::Short,
+                quantity: -2.0,
+                order_type: OrderType::Limit,
+                expected: Ok((Side::Sell, OrderKind::Limit, 2.0)),
+            },
+            TestCase {
+                // TC2: CloseLong Market order with -ve quantity
+                decision: Decision::CloseLong,
+                quantity: -1.0,
+                order_type: OrderType::Market,
+                expected: Ok((Side::Sell, OrderKind::Market, 1.0)),
+            },
+            TestCase {
+                // TC3: CloseShort Market order with +ve quantity
+                decision: Decision::CloseShort,
+                quantity: 1.0,
+                order_type: OrderType::Market,
+                expected: Ok((Side::Buy, OrderKind::Market, 1.0)),
+            },
+            TestCase {
+                // TC4: Bracket order is unsupported
+                decision: Decision::Long,
+                quantity: 1.0,
+                order_type: OrderType::Bracket,
+                expected: Err(ExecutionError::UnsupportedOrderType(OrderType::Bracket)),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let mut order = order_event();
+            order.decision = test.decision;
+            order.quantity = test.quantity;
+            order.order_type = test.order_type;
+            order.market_meta.close = 100.0;
+
+            let actual = order_request(&order);
+            match (actual, test.expected) {
+                (Ok(actual), Ok((side, kind, quantity))) => {
+                    assert_eq!(actual.side, side, "TC{} failed", index);
+                    assert_eq!(actual.state.kind, kind, "TC{} failed", index);
+                    assert_eq!(actual.state.quantity, quantity, "TC{} failed", index);
+                    assert_eq!(actual.state.price, 100.0, "TC{} failed", index);
+                }
+                (Err(_), Err(_)) => {}
+                (actual, expected) => {
+                    panic!("TC{index} failed: actual: {actual:?}, expected: {expected:?}")
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_next_fills_aggregates_trades_into_single_fill() {
+        let (mut bridge, account_tx) = bridge(false, None);
+
+        let mut order = order_event();
+        order.decision = Decision::Short;
+        order.quantity = -2.0;
+        order.order_type = OrderType::Limit;
+        order.market_meta.close = 100.0;
+
+        assert!(bridge.execute_order(&order).unwrap().is_empty());
+        let cid = *bridge.orders.keys().next().unwrap();
+
+        // Trade received before the OrderId is known is buffered until OrdersNew arrives
+        account_tx
+            .send(trade("1", 100.0, 1.0, SymbolFees::new("usdt", 1.0)))
+            .unwrap();
+        assert!(bridge.next_fills().is_empty());
+        assert_eq!(bridge.unmatched_trades.len(), 1);
+
+        let mut open = order_open(cid);
+        open.state.quantity = 2.0;
+        account_tx
+            .send(account_event(AccountEventKind::OrdersNew(vec![open])))
+            .unwrap();
+        assert!(bridge.next_fills().is_empty());
+
+        // Final Trade fills the order, with base denominated fees converted to quote
+        account_tx
+            .send(trade("1", 110.0, 1.0, SymbolFees::new("eth", 0.01)))
+            .unwrap();
+        let fills = bridge.next_fills();
+
+        assert_eq!(fills.len(), 1);
+        assert_eq!(fills[0].decision, Decision::Short);
+        assert_eq!(fills[0].quantity, -2.0);
+        assert_eq!(fills[0].fill_value_gross, 210.0);
+        assert_eq!(fills[0].fees.exchange, 1.0 + 0.01 * 110.0);
+        assert!(bridge.orders.is_empty());
+        assert!(bridge.order_ids.is_empty());
+    }
+
+    #[test]
+    fn test_next_fills_partially_filled_order_cancelled() {
+        let (mut bridge, account_tx) = bridge(true, None);
+
+        let mut order = order_event();
+        order.quantity = 2.0;
+        order.order_type = OrderType::Limit;
+        bridge.execute_order(&order).unwrap();
+        let cid = *bridge.orders.keys().next().unwrap();
+
+        // OrderId is registered from the immediate open order response
+        assert_eq!(bridge.order_ids.get(&OrderId::from("1")), Some(&cid));
+        account_tx
+            .send(trade("1", 100.0, 0.5, SymbolFees::new("usdt", 0.0)))
+            .unwrap();
+        assert!(bridge.next_fills().is_empty());
+
+        account_tx
+            .send(account_event(AccountEventKind::OrdersCancelled(vec![
+                Order::from(order_open(cid)),
+            ])))
+            .unwrap();
+        let fills = bridge.next_fills();
+
+        assert_eq!(fills.len(), 1);
+        assert_eq!(fills[0].quantity, 0.5);
+        assert_eq!(fills[0].fill_value_gross, 50.0);
+        assert!(bridge.orders.is_empty());
+    }
+
+    #[test]
+    fn test_execute_order_rejects_order_in_flight_for_same_market() {
+        let (mut bridge, _account_tx) = bridge(true, None);
+
+        // MarketEvents are forwarded to the venue
+        bridge.update_from_market(&crate::test_util::market_event_trade(Side::Buy));
+        assert_eq!(bridge.venue.markets, 1);
+
+        let order = order_event();
+        assert!(bridge.execute_order(&order).is_ok());
+        assert!(bridge.has_pending_orders());
+        assert!(matches!(
+            bridge.execute_order(&order),
+            Err(ExecutionError::OrderInFlight)
+        ));
+
+        // Orders for a different market are not blocked
+        let mut other = order_event();
+        other.instrument = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+        assert!(bridge.execute_order(&other).is_ok());
+        assert_eq!(bridge.orders.len(), 2);
+    }
+
+    #[test]
+    fn test_next_failures_yields_order_failed_to_open() {
+        let error = ClientError::InsufficientBalance("usdt".into());
+        let (mut bridge, _account_tx) = bridge(true, Some(error.clone()));
+
+        let order = order_event();
+        assert!(bridge.execute_order(&order).unwrap().is_empty());
+        assert!(!bridge.has_pending_orders());
+
+        let failures = bridge.next_failures();
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].order, order);
+        assert_eq!(failures[0].reason, error.to_string());
+        assert!(bridge.next_failures().is_empty());
+
+        // Failed order no longer blocks a new order for the same market
+        assert!(bridge.execute_order(&order).is_ok());
+    }
+
+    #[test]
+    fn test_next_fills_drops_stale_unmatched_trades() {
+        let (mut bridge, account_tx) = bridge(false, None);
+        bridge.execute_order(&order_event()).unwrap();
+
+        // Trade for an unknown OrderId is buffered while a pending order has no OrderId
+        let mut stale = trade("foreign", 100.0, 1.0, SymbolFees::new("usdt", 0.0));
+        stale.received_time = Utc::now() - UNMATCHED_TRADE_MAX_AGE - Duration::seconds(1);
+        account_tx.send(stale).unwrap();
+        assert!(bridge.next_fills().is_empty());
+        assert_eq!(bridge.unmatched_trades.len(), 1);
+
+        // Trade is dropped once it is older than the UNMATCHED_TRADE_MAX_AGE
+        account_tx
+            .send(account_event(AccountEventKind::Balance(
+                barter_execution::model::balance::SymbolBalance::new(
+                    "usdt",
+                    barter_execution::model::balance::Balance::new(0.0, 0.0),
+                ),
+            )))
+            .unwrap();
+        assert!(bridge.next_fills().is_empty());
+        assert!(bridge.unmatched_trades.is_empty());
+        assert!(bridge.has_pending_orders());
+    }
+
+    fn order_open(cid: ClientOrderId) -> Order<Open> {
+        Order {
+            exchange: barter_integration::model::Exchange::from("simulated"),
+            instrument: order_event().instrument,
+            cid,
+            side: Side::Buy,
+            state: Open {
+                id: OrderId::from("1"),
+                price: 100.0,
+                quantity: 2.0,
+                filled_quantity: 0.0,
+            },
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use crate::portfolio::OrderType;
 use thiserror::Error;
 
 /// All errors generated in the barter::execution module.
@@ -5,4 +6,15 @@ use thiserror::Error;
 pub enum ExecutionError {
     #[error("Failed to build struct due to missing attributes: {0}")]
     BuilderIncomplete(&'static str),
+
+    #[error("Failed to execute order due to unsupported OrderType: {0:?}")]
+    UnsupportedOrderType(OrderType),
+
+    #[error("Failed to execute order since an order for the same market is still in-flight")]
+    OrderInFlight,
+
+    #[error(
+        "SimulatedExchange driven by an ExecutionBridge requires an event-time SimulatedClock"
+    )]
+    SimulatedClockNotEventTime,
 }
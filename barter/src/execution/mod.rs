@@ -1,4 +1,9 @@
-use crate::{data::MarketMeta, portfolio::OrderEvent, strategy::Decision};
+use crate::{
+    data::MarketMeta,
+    portfolio::{stop::StopConfig, OrderEvent},
+    strategy::Decision,
+};
+use barter_data::event::{DataKind, MarketEvent};
 use barter_integration::model::{instrument::Instrument, Exchange};
 use chrono::{DateTime, Utc};
 use error::ExecutionError;
@@ -10,10 +15,61 @@ pub mod error;
 /// Handlers for simulated and live [`OrderEvent`] execution.
 pub mod simulated;
 
-/// Generates a result [`FillEvent`] by executing an [`OrderEvent`].
+/// [`ExecutionClient`] bridge that drives any asynchronous
+/// [`barter_execution::ExecutionClient`] (eg/ live venue or `SimulatedExchange`).
+pub mod bridge;
+
+/// Executes [`OrderEvent`]s and generates the resulting [`FillEvent`]s.
 pub trait ExecutionClient {
-    /// Return a [`FillEvent`] from executing the input [`OrderEvent`].
-    fn generate_fill(&self, order: &OrderEvent) -> Result<FillEvent, ExecutionError>;
+    /// Execute the input [`OrderEvent`], returning any [`FillEvent`]s generated immediately.
+    fn execute_order(&mut self, order: &OrderEvent) -> Result<Vec<FillEvent>, ExecutionError>;
+
+    /// Return any [`FillEvent`]s generated asynchronously since the last call (eg/ resting orders
+    /// that have since been filled by the exchange).
+    fn next_fills(&mut self) -> Vec<FillEvent> {
+        Vec::new()
+    }
+
+    /// Update the [`ExecutionClient`] with the latest [`MarketEvent`] consumed by the
+    /// [`Trader`](crate::engine::trader::Trader) (eg/ to forward to a simulated exchange).
+    fn update_from_market(&mut self, _market: &MarketEvent<Instrument, DataKind>) {}
+
+    /// Return any [`OrderFailed`]s for executed orders that have since failed asynchronously
+    /// (eg/ rejected by the exchange).
+    fn next_failures(&mut self) -> Vec<OrderFailed> {
+        Vec::new()
+    }
+
+    /// Resolve every request still in-flight without waiting on wall-clock time (eg/ the
+    /// remaining latency of a simulated exchange), so the resulting [`FillEvent`]s &
+    /// [`OrderFailed`]s are returned by the next calls to [`Self::next_fills`] &
+    /// [`Self::next_failures`].
+    fn flush(&mut self) {}
+
+    /// Determines if any executed orders are still awaiting their [`FillEvent`]s.
+    fn has_pending_orders(&self) -> bool {
+        false
+    }
+}
+
+/// [`OrderEvent`] that an [`ExecutionClient`] failed to execute (eg/ rejected by the exchange),
+/// along with the reason it failed.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OrderFailed {
+    pub time: DateTime<Utc>,
+    pub order: OrderEvent,
+    pub reason: String,
+}
+
+impl OrderFailed {
+    /// Constructs a new [`OrderFailed`] for the input [`OrderEvent`] & failure reason.
+    pub fn new(order: OrderEvent, reason: String) -> Self {
+        Self {
+            time: Utc::now(),
+            order,
+            reason,
+        }
+    }
 }
 
 /// Fills are journals of work done by an Execution handler. These are sent back to the portfolio
@@ -33,6 +89,8 @@ pub struct FillEvent {
     pub fill_value_gross: f64,
     /// All fee types incurred when executing an [`OrderEvent`], and their associated [`FeeAmount`].
     pub fees: Fees,
+    /// Protective exits propagated from the source [`OrderEvent`].
+    pub stops: Option<StopConfig>,
 }
 
 impl FillEvent {
@@ -60,6 +118,28 @@ impl Fees {
     pub fn calculate_total_fees(&self) -> f64 {
         self.exchange + self.network + self.slippage
     }
+
+    /// Scales every [FeeAmount] in [Fees] by the provided factor. Used to attribute [Fees] to a
+    /// fraction of a [`Position`](crate::portfolio::position::Position) quantity.
+    pub fn scale(&self, factor: f64) -> Fees {
+        Fees {
+            exchange: self.exchange * factor,
+            slippage: self.slippage * factor,
+            network: self.network * factor,
+        }
+    }
+}
+
+impl std::ops::Add for Fees {
+    type Output = Fees;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Fees {
+            exchange: self.exchange + rhs.exchange,
+            slippage: self.slippage + rhs.slippage,
+            network: self.network + rhs.network,
+        }
+    }
 }
 
 /// Communicative type alias for Fee amount as f64.
@@ -76,6 +156,7 @@ pub struct FillEventBuilder {
     pub quantity: Option<f64>,
     pub fill_value_gross: Option<f64>,
     pub fees: Option<Fees>,
+    pub stops: Option<StopConfig>,
 }
 
 impl FillEventBuilder {
@@ -139,6 +220,13 @@ impl FillEventBuilder {
         }
     }
 
+    pub fn stops(self, value: StopConfig) -> Self {
+        Self {
+            stops: Some(value),
+            ..self
+        }
+    }
+
     pub fn build(self) -> Result<FillEvent, ExecutionError> {
         Ok(FillEvent {
             time: self.time.ok_or(ExecutionError::BuilderIncomplete("time"))?,
@@ -161,6 +249,7 @@ impl FillEventBuilder {
                 .fill_value_gross
                 .ok_or(ExecutionError::BuilderIncomplete("fill_value_gross"))?,
             fees: self.fees.ok_or(ExecutionError::BuilderIncomplete("fees"))?,
+            stops: self.stops,
         })
     }
 }
@@ -21,7 +21,21 @@ pub struct SimulatedExecution {
 }
 
 impl ExecutionClient for SimulatedExecution {
-    fn generate_fill(&self, order: &OrderEvent) -> Result<FillEvent, ExecutionError> {
+    fn execute_order(&mut self, order: &OrderEvent) -> Result<Vec<FillEvent>, ExecutionError> {
+        Ok(vec![self.generate_fill(order)?])
+    }
+}
+
+impl SimulatedExecution {
+    /// Constructs a new [`SimulatedExecution`] component.
+    pub fn new(cfg: Config) -> Self {
+        Self {
+            fees_pct: cfg.simulated_fees_pct,
+        }
+    }
+
+    /// Return a [`FillEvent`] from executing the input [`OrderEvent`].
+    pub fn generate_fill(&self, order: &OrderEvent) -> Result<FillEvent, ExecutionError> {
         // Assume (for now) that all orders are filled at the market price
         let fill_value_gross = SimulatedExecution::calculate_fill_value_gross(order);
 
@@ -34,17 +48,9 @@ impl ExecutionClient for SimulatedExecution {
             quantity: order.quantity,
             fill_value_gross,
             fees: self.calculate_fees(&fill_value_gross),
+            stops: order.stops,
         })
     }
-}
-
-impl SimulatedExecution {
-    /// Constructs a new [`SimulatedExecution`] component.
-    pub fn new(cfg: Config) -> Self {
-        Self {
-            fees_pct: cfg.simulated_fees_pct,
-        }
-    }
 
     /// Calculates the simulated gross fill value (excluding TotalFees) based on the input [`OrderEvent`].
     fn calculate_fill_value_gross(order: &OrderEvent) -> f64 {
@@ -116,7 +122,7 @@ mod tests {
 
         let actual = SimulatedExecution::calculate_fill_value_gross(&input_order);
 
-        let expected = (100.0 * 10.0) as f64;
+        let expected = 100.0 * 10.0;
 
         assert_eq!(actual, expected)
     }
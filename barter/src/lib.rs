@@ -6,7 +6,7 @@
 //! * **Fast**: Barter provides a multi-threaded trading Engine framework built in high-performance Rust (in-rust-we-trust).
 //! * **Easy**: Barter provides a modularised data architecture that focuses on simplicity.
 //! * **Customisable**: A set of traits define how every Barter component communicates, providing a highly extensible
-//! framework for trading.
+//!   framework for trading.
 //!
 //! See [`Readme`].
 //!
@@ -18,22 +18,24 @@
 //! it provides several de-coupled components that interact via a set of traits:
 
 //! * **Data**: Continuer & MarketGenerator traits govern the generation of a MarketEvents data feed that acts as the system
-//! heartbeat. For example, a LiveCandleHandler implementation is provided utilising [`Barter-Data`]'s WebSocket functionality to
-//! provide a live market Candle data feed to the system.
+//!   heartbeat. For example, a LiveCandleHandler implementation is provided utilising [`Barter-Data`]'s WebSocket functionality to
+//!   provide a live market Candle data feed to the system.
 //! * **Strategy**: The SignalGenerator trait governs potential generation of SignalEvents after analysing incoming
-//! MarketEvents. SignalEvents are advisory signals sent to the Portfolio for analysis.
+//!   MarketEvents. SignalEvents are advisory signals sent to the Portfolio for analysis. The PortfolioSignalGenerator trait
+//!   enables portfolio-level strategies that see the merged MarketEvents of many markets, and generate SignalEvents for any
+//!   of them (eg/ pairs trading).
 //! * **Portfolio**: MarketUpdater, OrderGenerator, and FillUpdater govern global state Portfolio implementations. A
-//! Portfolio may generate OrderEvents after receiving advisory SignalEvents from a Strategy. The Portfolio's state
-//! updates after receiving MarketEvents and FillEvents.
+//!   Portfolio may generate OrderEvents after receiving advisory SignalEvents from a Strategy. The Portfolio's state
+//!   updates after receiving MarketEvents and FillEvents.
 //! * **Execution**: The FillGenerator trait governs the generation of FillEvents after receiving OrderEvents from the
-//! Portfolio. For example, a SimulatedExecution handler implementation is provided for simulating any exchange execution
-//! behaviour required in dry-trading or backtesting runs.
+//!   Portfolio. For example, a SimulatedExecution handler implementation is provided for simulating any exchange execution
+//!   behaviour required in dry-trading or backtesting runs.
 //! * **Statistic**: Provides metrics such as Sharpe Ratio, Calmar Ratio, and Max Drawdown to analyse trading session
-//! performance. One-pass dispersion algorithms analyse each closed Position and efficiently calculates a trading summary.
-//! * **Trader**: Capable of trading a single market pair using a customisable selection of it's own Data, Strategy &
-//! Execution instances, as well as shared access to a global Portfolio.
+//!   performance. One-pass dispersion algorithms analyse each closed Position and efficiently calculates a trading summary.
+//! * **Trader**: Capable of trading a single market pair (or a set of markets with a portfolio-level strategy) using a
+//!   customisable selection of it's own Data, Strategy & Execution instances, as well as shared access to a global Portfolio.
 //! * **Engine**: Multi-threaded trading Engine capable of trading with an arbitrary number of Trader market pairs. Each
-//! contained Trader instance operates on its own thread.
+//!   contained Trader instance operates on its own thread.
 //!
 //! [`Barter`]: https://github.com/barter-rs/barter-rs
 //! [`Barter-Data`]: https://crates.io/crates/barter-data
@@ -105,6 +107,7 @@
 //!     allocator: DefaultAllocator{ default_order_value: 100.0 },
 //!     risk: DefaultRisk{},
 //!     starting_cash: 10000.0,
+//!     position_scaling: false,
 //!     statistic_config: StatisticConfig {
 //!         starting_equity: 10000.0 ,
 //!         trading_days_per_year: 365,
@@ -157,7 +160,7 @@
 //!
 //! let order_event = test_util::order_event();
 //!
-//! let fill_event = execution.generate_fill(&order_event);
+//! let fill_events = execution.execute_order(&order_event);
 //! ```
 //!
 //! ### Statistic
@@ -199,7 +202,7 @@
     rust_2018_idioms,
     // missing_docs
 )]
-#![allow(clippy::type_complexity)]
+#![allow(clippy::type_complexity, clippy::result_large_err)]
 #![allow(clippy::module_inception)]
 
 /// Defines a MarketEvent, and provides the Continuer and MarketGenerator traits for
@@ -208,8 +211,8 @@
 /// system heartbeat.
 pub mod data;
 
-/// Defines a SignalEvent and SignalForceExit, as well as the SignalGenerator trait for handling the
-/// generation of them. Contains an example RSIStrategy implementation that analyses a MarketEvent
+/// Defines a SignalEvent and SignalForceExit, as well as the SignalGenerator & PortfolioSignalGenerator
+/// traits for handling the generation of them. Contains an example RSIStrategy implementation that analyses a MarketEvent
 /// and may generate a new advisory SignalEvent to be analysed by the Portfolio OrderGenerator.
 pub mod strategy;
 
@@ -308,6 +311,7 @@ pub mod test_util {
             instrument: Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
             signals: Default::default(),
             market_meta: Default::default(),
+            stops: None,
         }
     }
 
@@ -321,6 +325,7 @@ pub mod test_util {
             decision: Decision::default(),
             quantity: 1.0,
             order_type: OrderType::default(),
+            stops: None,
         }
     }
 
@@ -335,6 +340,7 @@ pub mod test_util {
             quantity: 1.0,
             fill_value_gross: 100.0,
             fees: Fees::default(),
+            stops: None,
         }
     }
 
@@ -359,6 +365,8 @@ pub mod test_util {
             current_value_gross: 100.0,
             unrealised_profit_loss: 0.0,
             realised_profit_loss: 0.0,
+            reduced_enter_value_gross: 0.0,
+            stops: None,
         }
     }
 }
@@ -1,10 +1,16 @@
 use crate::{
     portfolio::{position::Position, OrderEvent},
+    statistic::summary::{pnl::PnLReturnSummary, PositionSummariser},
     strategy::{Decision, SignalStrength},
 };
+use barter_data::event::{DataKind, MarketEvent};
+use barter_integration::model::{instrument::Instrument, MarketId};
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
 
-/// Allocates an appropriate [`OrderEvent`] quantity.
+/// Allocates an appropriate [`OrderEvent`] quantity. May also configure (or override the
+/// [`Signal`](crate::strategy::Signal) configured) protective exits of an entry [`OrderEvent`]
+/// via it's `stops`.
 pub trait OrderAllocator {
     /// Returns an [`OrderEvent`] with a calculated order quantity based on the input order,
     /// [`SignalStrength`] and potential existing [`Position`].
@@ -14,6 +20,14 @@ pub trait OrderAllocator {
         position: Option<&Position>,
         signal_strength: SignalStrength,
     );
+
+    /// Updates any allocation state (eg/ rolling volatility) using the latest input
+    /// [`MarketEvent`]. Stateless allocators need not implement this.
+    fn update_from_market(&mut self, _market: &MarketEvent<Instrument, DataKind>) {}
+
+    /// Updates any allocation state (eg/ running win rate) using a newly exited [`Position`].
+    /// Stateless allocators need not implement this.
+    fn update_from_exit(&mut self, _position: &Position) {}
 }
 
 /// Default allocation manager that implements [`OrderAllocator`]. Order size is calculated by
@@ -47,10 +61,253 @@ impl OrderAllocator for DefaultAllocator {
     }
 }
 
+/// Configuration for constructing a [`VolatilityAllocator`] via the new() constructor method.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct VolatilityConfig {
+    /// Target volatility of each [`Position`]'s value, in quote currency per candle close.
+    pub target_volatility: f64,
+    /// Number of candle close-to-close changes used to estimate each market's volatility.
+    pub lookback: usize,
+    /// Exchange lot size (quantity step) that entry quantities are rounded down to.
+    pub lot_size: f64,
+}
+
+/// Volatility targeting allocation manager that implements [`OrderAllocator`]. Entry order size
+/// is calculated so the [`Position`] value has the target volatility, using the standard
+/// deviation of the market's recent close-to-close changes, and scaled by [`SignalStrength`].
+///
+/// Closes are only sampled from [`DataKind::Candle`] [`MarketEvent`]s, so every change spans the
+/// same interval regardless of the rate of other market data (eg/ trades or order book updates).
+///
+/// Until a market has a full lookback of closes its entry orders are sized to zero. Exit orders
+/// are allocated the same as the [`DefaultAllocator`].
+#[derive(Clone, PartialEq, Debug)]
+pub struct VolatilityAllocator {
+    config: VolatilityConfig,
+    closes: HashMap<MarketId, VecDeque<f64>>,
+}
+
+impl OrderAllocator for VolatilityAllocator {
+    fn allocate_order(
+        &self,
+        order: &mut OrderEvent,
+        position: Option<&Position>,
+        signal_strength: SignalStrength,
+    ) {
+        let entry_quantity = || {
+            let market_id = MarketId::new(&order.exchange, &order.instrument);
+            match self.volatility(&market_id) {
+                Some(volatility) if volatility > 0.0 => round_to_lot_size(
+                    self.config.target_volatility / volatility * signal_strength.0,
+                    self.config.lot_size,
+                ),
+                _ => 0.0,
+            }
+        };
+
+        match order.decision {
+            // Entry
+            Decision::Long => order.quantity = entry_quantity(),
+
+            // Entry
+            Decision::Short => order.quantity = -entry_quantity(),
+
+            // Exit
+            _ => order.quantity = 0.0 - position.as_ref().unwrap().quantity,
+        }
+    }
+
+    fn update_from_market(&mut self, market: &MarketEvent<Instrument, DataKind>) {
+        // Only sample candle closes, since other market data arrives at an irregular rate
+        let DataKind::Candle(candle) = &market.kind else {
+            return;
+        };
+
+        let closes = self
+            .closes
+            .entry(MarketId::new(&market.exchange, &market.instrument))
+            .or_insert_with(|| VecDeque::with_capacity(self.config.lookback + 1));
+
+        // Retain the lookback number of close-to-close changes
+        if closes.len() > self.config.lookback {
+            closes.pop_front();
+        }
+        closes.push_back(candle.close);
+    }
+}
+
+impl VolatilityAllocator {
+    /// Constructs a new [`VolatilityAllocator`] component using the provided configuration struct.
+    pub fn new(config: VolatilityConfig) -> Self {
+        Self {
+            config,
+            closes: HashMap::new(),
+        }
+    }
+
+    /// Calculates the standard deviation of a market's close-to-close changes over the lookback.
+    /// Returns `None` if the market does not yet have a full lookback of closes.
+    pub fn volatility(&self, market_id: &MarketId) -> Option<f64> {
+        let closes = self.closes.get(market_id)?;
+        if self.config.lookback < 2 || closes.len() <= self.config.lookback {
+            return None;
+        }
+
+        let changes = closes
+            .iter()
+            .zip(closes.iter().skip(1))
+            .map(|(prev, next)| next - prev)
+            .collect::<Vec<f64>>();
+
+        let count = changes.len() as f64;
+        let mean = changes.iter().sum::<f64>() / count;
+        let variance = changes
+            .iter()
+            .map(|change| (change - mean).powi(2))
+            .sum::<f64>()
+            / (count - 1.0);
+
+        Some(variance.sqrt())
+    }
+}
+
+/// Configuration for constructing a [`KellyAllocator`] via the new() constructor method.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct KellyConfig {
+    /// Portfolio capital before any [`Position`]s are exited.
+    pub starting_capital: f64,
+    /// Fraction of the full Kelly criterion that is allocated (eg/ 0.5 for half-Kelly).
+    pub kelly_fraction: f64,
+    /// Number of exited [`Position`]s a market requires before it is sized using the Kelly
+    /// criterion.
+    pub min_trades: u64,
+    /// Order value used to size entries until a market has the min_trades.
+    pub default_order_value: f64,
+    /// Exchange lot size (quantity step) that entry quantities are rounded down to.
+    pub lot_size: f64,
+}
+
+/// Fractional Kelly allocation manager that implements [`OrderAllocator`]. Entry order value is
+/// the fraction of capital given by the Kelly criterion, using the running win rate & payoff
+/// ratio of the market's [`PnLReturnSummary`], and scaled by the kelly_fraction &
+/// [`SignalStrength`].
+///
+/// Capital is updated with the Portfolio total balance every time a [`Position`] is exited. Exit
+/// orders are allocated the same as the [`DefaultAllocator`].
+#[derive(Clone, PartialEq, Debug)]
+pub struct KellyAllocator {
+    config: KellyConfig,
+    capital: f64,
+    statistics: HashMap<MarketId, PnLReturnSummary>,
+}
+
+impl OrderAllocator for KellyAllocator {
+    fn allocate_order(
+        &self,
+        order: &mut OrderEvent,
+        position: Option<&Position>,
+        signal_strength: SignalStrength,
+    ) {
+        let entry_quantity = || {
+            let market_id = MarketId::new(&order.exchange, &order.instrument);
+            let order_value = match self.kelly_criterion(&market_id) {
+                Some(kelly) => self.capital * kelly.max(0.0) * self.config.kelly_fraction,
+                None => self.config.default_order_value,
+            };
+
+            round_to_lot_size(
+                order_value / order.market_meta.close * signal_strength.0,
+                self.config.lot_size,
+            )
+        };
+
+        match order.decision {
+            // Entry
+            Decision::Long => order.quantity = entry_quantity(),
+
+            // Entry
+            Decision::Short => order.quantity = -entry_quantity(),
+
+            // Exit
+            _ => order.quantity = 0.0 - position.as_ref().unwrap().quantity,
+        }
+    }
+
+    fn update_from_exit(&mut self, position: &Position) {
+        if let Some(exit_balance) = position.meta.exit_balance {
+            self.capital = exit_balance.total;
+        }
+
+        self.statistics
+            .entry(MarketId::new(&position.exchange, &position.instrument))
+            .or_default()
+            .update(position);
+    }
+}
+
+impl KellyAllocator {
+    /// Constructs a new [`KellyAllocator`] component using the provided configuration struct.
+    pub fn new(config: KellyConfig) -> Self {
+        Self {
+            config,
+            capital: config.starting_capital,
+            statistics: HashMap::new(),
+        }
+    }
+
+    /// Calculates the full Kelly criterion `W - (1 - W) / R` for a market, where `W` is the win
+    /// rate and `R` is the payoff ratio of mean winning to mean losing PnL return. Returns `None`
+    /// if the market does not yet have the min_trades.
+    pub fn kelly_criterion(&self, market_id: &MarketId) -> Option<f64> {
+        let statistics = self.statistics.get(market_id)?;
+        let trades = statistics.total.count;
+        if trades == 0 || trades < self.config.min_trades {
+            return None;
+        }
+
+        let wins = trades - statistics.losses.count;
+        let win_rate = wins as f64 / trades as f64;
+
+        // Without any losses (or wins) the payoff ratio is unbounded (or zero)
+        if statistics.losses.count == 0 || wins == 0 {
+            return Some(win_rate);
+        }
+
+        let mean_win = (statistics.total.sum - statistics.losses.sum) / wins as f64;
+        let mean_loss = statistics.losses.mean.abs();
+        if mean_loss == 0.0 {
+            return Some(win_rate);
+        }
+
+        let payoff_ratio = mean_win / mean_loss;
+        if payoff_ratio <= 0.0 {
+            return Some(0.0);
+        }
+
+        Some(win_rate - (1.0 - win_rate) / payoff_ratio)
+    }
+}
+
+/// Rounds the magnitude of an order quantity down to a multiple of the exchange lot size. A lot
+/// size that is not positive leaves the quantity unchanged.
+pub fn round_to_lot_size(quantity: f64, lot_size: f64) -> f64 {
+    if lot_size <= 0.0 {
+        return quantity;
+    }
+
+    // Tolerate floating point error (eg/ 0.3 / 0.1 = 2.9999999999999996)
+    let lots = (quantity.abs() / lot_size + 1e-9).floor();
+    quantity.signum() * lots * lot_size
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test_util::{order_event, position};
+    use crate::{
+        portfolio::Balance,
+        test_util::{market_event_candle, market_event_trade, order_event, position},
+    };
+    use barter_integration::model::Side;
 
     #[test]
     fn should_allocate_order_to_exit_open_long_position() {
@@ -121,7 +378,7 @@ mod tests {
         allocator.allocate_order(&mut input_order, None, input_signal_strength);
 
         let actual_result = input_order.quantity;
-        let expected_result = (default_order_value / order_close) * input_signal_strength.0 as f64;
+        let expected_result = (default_order_value / order_close) * input_signal_strength.0;
 
         assert_eq!(actual_result, expected_result)
     }
@@ -144,7 +401,7 @@ mod tests {
 
         let actual_result = input_order.quantity;
         let expected_order_size = ((default_order_value / order_close) * 10000.0).floor() / 10000.0;
-        let expected_result = expected_order_size * input_signal_strength.0 as f64;
+        let expected_result = expected_order_size * input_signal_strength.0;
 
         assert_ne!(actual_result, 0.0);
         assert_eq!(actual_result, expected_result)
@@ -167,7 +424,7 @@ mod tests {
         allocator.allocate_order(&mut input_order, None, input_signal_strength);
 
         let actual_result = input_order.quantity;
-        let expected_result = -(default_order_value / order_close) * input_signal_strength.0 as f64;
+        let expected_result = -(default_order_value / order_close) * input_signal_strength.0;
 
         assert_eq!(actual_result, expected_result)
     }
@@ -195,4 +452,235 @@ mod tests {
         assert_ne!(actual_result, 0.0);
         assert_eq!(actual_result, expected_result)
     }
+
+    fn market_event_candle_with_close(close: f64) -> MarketEvent<Instrument, DataKind> {
+        // MarketEvent for the same market as the test_util order_event()
+        let order = order_event();
+        let mut market = market_event_candle();
+        market.exchange = order.exchange;
+        market.instrument = order.instrument;
+        if let DataKind::Candle(candle) = &mut market.kind {
+            candle.close = close;
+        }
+        market
+    }
+
+    fn exited_position(realised_profit_loss: f64, exit_balance_total: f64) -> Position {
+        let mut position = position();
+        position.enter_value_gross = 100.0;
+        position.realised_profit_loss = realised_profit_loss;
+        position.meta.exit_balance = Some(Balance {
+            time: position.meta.update_time,
+            total: exit_balance_total,
+            available: exit_balance_total,
+        });
+        position
+    }
+
+    #[test]
+    fn should_allocate_zero_quantity_volatility_order_without_full_lookback() {
+        let mut allocator = VolatilityAllocator::new(VolatilityConfig {
+            target_volatility: 10.0,
+            lookback: 4,
+            lot_size: 0.1,
+        });
+
+        for price in [100.0, 102.0, 100.0] {
+            allocator.update_from_market(&market_event_candle_with_close(price));
+        }
+
+        let mut input_order = order_event();
+        input_order.decision = Decision::Long;
+
+        allocator.allocate_order(&mut input_order, None, SignalStrength(1.0));
+
+        assert_eq!(input_order.quantity, 0.0)
+    }
+
+    #[test]
+    fn should_only_sample_candle_closes_for_volatility() {
+        let mut allocator = VolatilityAllocator::new(VolatilityConfig {
+            target_volatility: 10.0,
+            lookback: 2,
+            lot_size: 0.1,
+        });
+
+        // MarketEvent for the same market as the test_util order_event()
+        let order = order_event();
+        let mut trade = market_event_trade(Side::Buy);
+        trade.exchange = order.exchange.clone();
+        trade.instrument = order.instrument.clone();
+
+        // Trades between candle closes are not sampled
+        for close in [100.0, 102.0] {
+            allocator.update_from_market(&market_event_candle_with_close(close));
+            allocator.update_from_market(&trade);
+        }
+
+        let market_id = MarketId::new(&order.exchange, &order.instrument);
+        assert_eq!(allocator.volatility(&market_id), None);
+
+        allocator.update_from_market(&market_event_candle_with_close(100.0));
+        assert!(allocator.volatility(&market_id).is_some());
+    }
+
+    #[test]
+    fn should_allocate_volatility_targeted_orders_rounded_to_lot_size() {
+        let mut allocator = VolatilityAllocator::new(VolatilityConfig {
+            target_volatility: 10.0,
+            lookback: 4,
+            lot_size: 0.1,
+        });
+
+        // Oldest close falls out of the lookback window
+        for price in [50.0, 100.0, 102.0, 100.0, 102.0, 100.0] {
+            allocator.update_from_market(&market_event_candle_with_close(price));
+        }
+
+        // Close-to-close changes of [2, -2, 2, -2] have a sample std. dev. of sqrt(16 / 3)
+        let order = order_event();
+        let volatility = allocator
+            .volatility(&MarketId::new(&order.exchange, &order.instrument))
+            .unwrap();
+        assert!((volatility - (16.0_f64 / 3.0).sqrt()).abs() < 1e-9);
+
+        // 10.0 / 2.3094 = 4.3301 contracts, rounded down to a 0.1 lot size
+        let mut input_order = order_event();
+        input_order.decision = Decision::Long;
+        allocator.allocate_order(&mut input_order, None, SignalStrength(1.0));
+        assert!((input_order.quantity - 4.3).abs() < 1e-9);
+
+        let mut input_order = order_event();
+        input_order.decision = Decision::Short;
+        allocator.allocate_order(&mut input_order, None, SignalStrength(0.5));
+        assert!((input_order.quantity - -2.1).abs() < 1e-9);
+    }
+
+    #[test]
+    fn should_allocate_default_order_value_kelly_order_without_min_trades() {
+        let mut allocator = KellyAllocator::new(KellyConfig {
+            starting_capital: 1000.0,
+            kelly_fraction: 0.5,
+            min_trades: 3,
+            default_order_value: 200.0,
+            lot_size: 1.0,
+        });
+
+        allocator.update_from_exit(&exited_position(20.0, 1020.0));
+        allocator.update_from_exit(&exited_position(-10.0, 1010.0));
+
+        let mut input_order = order_event();
+        input_order.decision = Decision::Long;
+        input_order.market_meta.close = 100.0;
+
+        allocator.allocate_order(&mut input_order, None, SignalStrength(1.0));
+
+        assert_eq!(input_order.quantity, 2.0)
+    }
+
+    #[test]
+    fn should_allocate_fractional_kelly_order_using_win_rate_and_payoff() {
+        let mut allocator = KellyAllocator::new(KellyConfig {
+            starting_capital: 1000.0,
+            kelly_fraction: 0.5,
+            min_trades: 3,
+            default_order_value: 200.0,
+            lot_size: 1.0,
+        });
+
+        // Win rate of 2/3, and payoff ratio of 0.2 / 0.1 = 2.0
+        allocator.update_from_exit(&exited_position(20.0, 1020.0));
+        allocator.update_from_exit(&exited_position(-10.0, 1010.0));
+        allocator.update_from_exit(&exited_position(20.0, 1030.0));
+
+        // Kelly = 2/3 - (1/3) / 2.0 = 0.5
+        let order = order_event();
+        let kelly = allocator
+            .kelly_criterion(&MarketId::new(&order.exchange, &order.instrument))
+            .unwrap();
+        assert!((kelly - 0.5).abs() < 1e-9);
+
+        // Order value = 1030.0 * 0.5 * 0.5 = 257.5, so 2.575 contracts, rounded down to 2.0
+        let mut input_order = order_event();
+        input_order.decision = Decision::Short;
+        input_order.market_meta.close = 100.0;
+
+        allocator.allocate_order(&mut input_order, None, SignalStrength(1.0));
+
+        assert_eq!(input_order.quantity, -2.0)
+    }
+
+    #[test]
+    fn should_allocate_zero_quantity_kelly_order_with_negative_edge() {
+        let mut allocator = KellyAllocator::new(KellyConfig {
+            starting_capital: 1000.0,
+            kelly_fraction: 1.0,
+            min_trades: 2,
+            default_order_value: 200.0,
+            lot_size: 0.0,
+        });
+
+        allocator.update_from_exit(&exited_position(5.0, 1005.0));
+        allocator.update_from_exit(&exited_position(-10.0, 995.0));
+
+        let mut input_order = order_event();
+        input_order.decision = Decision::Long;
+
+        allocator.allocate_order(&mut input_order, None, SignalStrength(1.0));
+
+        assert_eq!(input_order.quantity, 0.0)
+    }
+
+    #[test]
+    fn round_to_lot_size_rounds_magnitude_down_to_lot_multiple() {
+        struct TestCase {
+            quantity: f64,
+            lot_size: f64,
+            expected: f64,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: positive quantity rounded down
+                quantity: 1.2345,
+                lot_size: 0.01,
+                expected: 1.23,
+            },
+            TestCase {
+                // TC1: negative quantity rounded towards zero
+                quantity: -1.2345,
+                lot_size: 0.01,
+                expected: -1.23,
+            },
+            TestCase {
+                // TC2: exact multiple unaffected by floating point error
+                quantity: 0.3,
+                lot_size: 0.1,
+                expected: 0.3,
+            },
+            TestCase {
+                // TC3: quantity smaller than the lot size
+                quantity: 0.5,
+                lot_size: 1.0,
+                expected: 0.0,
+            },
+            TestCase {
+                // TC4: no lot size
+                quantity: 1.2345,
+                lot_size: 0.0,
+                expected: 1.2345,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = round_to_lot_size(test.quantity, test.lot_size);
+            assert!(
+                (actual - test.expected).abs() < 1e-9,
+                "TC{} failed: {} != {}",
+                index,
+                actual,
+                test.expected
+            );
+        }
+    }
 }
@@ -16,6 +16,12 @@ pub enum PortfolioError {
     #[error("Cannot exit Position with an entry decision FillEvent.")]
     CannotExitPositionWithEntryFill,
 
+    #[error("Cannot increase Position with an entry decision FillEvent of the opposite Side.")]
+    CannotIncreasePositionWithOppositeSide,
+
+    #[error("Cannot reduce Position with a FillEvent that does not exit part of it's quantity.")]
+    CannotReducePosition,
+
     #[error("Cannot generate PositionExit from Position that has not been exited")]
     PositionExit,
 
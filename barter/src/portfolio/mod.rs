@@ -1,8 +1,8 @@
 use crate::{
     data::MarketMeta,
     event::Event,
-    execution::FillEvent,
-    portfolio::{error::PortfolioError, position::PositionUpdate},
+    execution::{FillEvent, OrderFailed},
+    portfolio::{error::PortfolioError, risk::OrderRejected, stop::StopConfig},
     strategy::{Decision, Signal, SignalForceExit},
 };
 use barter_data::event::{DataKind, MarketEvent};
@@ -32,21 +32,31 @@ pub mod repository;
 /// Logic for evaluating the risk associated with a proposed [`OrderEvent`].
 pub mod risk;
 
+/// Protective stop-loss, take-profit & trailing stop exits for an open
+/// [`Position`](position::Position).
+pub mod stop;
+
 /// Updates the Portfolio from an input [`MarketEvent`].
 pub trait MarketUpdater {
     /// Determines if the Portfolio has an open Position relating to the input [`MarketEvent`]. If
-    /// so it updates it using the market data, and returns a [`PositionUpdate`] detailing the
-    /// changes.
+    /// so it updates it using the market data, and returns the generated [`Event`]s. These are a
+    /// [`PositionUpdate`](position::PositionUpdate) detailing the changes, followed by a
+    /// [`SignalForceExit`] if the market data breached one of the Position's protective stops.
     fn update_from_market(
         &mut self,
         market: &MarketEvent<Instrument, DataKind>,
-    ) -> Result<Option<PositionUpdate>, PortfolioError>;
+    ) -> Result<Vec<Event>, PortfolioError>;
 }
 
 /// May generate an [`OrderEvent`] from an input advisory [`Signal`].
 pub trait OrderGenerator {
-    /// May generate an [`OrderEvent`] after analysing an input advisory [`Signal`].
-    fn generate_order(&mut self, signal: &Signal) -> Result<Option<OrderEvent>, PortfolioError>;
+    /// May generate an [`OrderEvent`] after analysing an input advisory [`Signal`]. If the
+    /// [`OrderEvent`] is vetoed by risk management, an [`OrderRejected`] detailing why is returned
+    /// instead.
+    fn generate_order(
+        &mut self,
+        signal: &Signal,
+    ) -> Result<Option<Result<OrderEvent, OrderRejected>>, PortfolioError>;
 
     /// Generates an exit [`OrderEvent`] if there is an open [`Position`](position::Position)
     /// associated with the input [`SignalForceExit`]'s [`PositionId`](position::PositionId).
@@ -54,6 +64,10 @@ pub trait OrderGenerator {
         &mut self,
         signal: SignalForceExit,
     ) -> Result<Option<OrderEvent>, PortfolioError>;
+
+    /// Updates the Portfolio using an [`OrderFailed`] detailing a generated [`OrderEvent`] that
+    /// failed to execute (eg/ rejected by the exchange), so no [`FillEvent`] will follow.
+    fn update_from_order_failed(&mut self, failed: &OrderFailed) -> Result<(), PortfolioError>;
 }
 
 /// Updates the Portfolio from an input [`FillEvent`].
@@ -79,6 +93,8 @@ pub struct OrderEvent {
     pub quantity: f64,
     /// MARKET, LIMIT etc
     pub order_type: OrderType,
+    /// Protective exits for the [`Position`](position::Position) opened by an entry order.
+    pub stops: Option<StopConfig>,
 }
 
 impl OrderEvent {
@@ -92,19 +108,16 @@ impl OrderEvent {
 }
 
 /// Type of order the portfolio wants the execution::handler to place.
-#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
 pub enum OrderType {
+    #[default]
     Market,
     Limit,
     Bracket,
 }
 
-impl Default for OrderType {
-    fn default() -> Self {
-        Self::Market
-    }
-}
-
 /// Builder to construct OrderEvent instances.
 #[derive(Debug, Default)]
 pub struct OrderEventBuilder {
@@ -115,6 +128,7 @@ pub struct OrderEventBuilder {
     pub decision: Option<Decision>,
     pub quantity: Option<f64>,
     pub order_type: Option<OrderType>,
+    pub stops: Option<StopConfig>,
 }
 
 impl OrderEventBuilder {
@@ -171,6 +185,13 @@ impl OrderEventBuilder {
         }
     }
 
+    pub fn stops(self, value: StopConfig) -> Self {
+        Self {
+            stops: Some(value),
+            ..self
+        }
+    }
+
     pub fn build(self) -> Result<OrderEvent, PortfolioError> {
         Ok(OrderEvent {
             time: self.time.ok_or(PortfolioError::BuilderIncomplete("time"))?,
@@ -192,6 +213,7 @@ impl OrderEventBuilder {
             order_type: self
                 .order_type
                 .ok_or(PortfolioError::BuilderIncomplete("order_type"))?,
+            stops: self.stops,
         })
     }
 }
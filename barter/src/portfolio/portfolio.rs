@@ -3,16 +3,16 @@ use super::{
     error::PortfolioError,
     position::{
         determine_position_id, Position, PositionEnterer, PositionExiter, PositionId,
-        PositionUpdate, PositionUpdater,
+        PositionScaler, PositionUpdater,
     },
     repository::{error::RepositoryError, BalanceHandler, PositionHandler, StatisticHandler},
-    risk::OrderEvaluator,
+    risk::{OrderEvaluator, OrderRejected, RiskContext},
     Balance, FillUpdater, MarketUpdater, OrderEvent, OrderGenerator, OrderType,
 };
 use crate::{
     data::MarketMeta,
     event::Event,
-    execution::FillEvent,
+    execution::{FillEvent, OrderFailed},
     statistic::summary::{Initialiser, PositionSummariser},
     strategy::{Decision, Signal, SignalForceExit, SignalStrength},
 };
@@ -48,6 +48,9 @@ where
     pub risk: RiskManager,
     /// Cash balance a [`MetaPortfolio`] starts with.
     pub starting_cash: f64,
+    /// Enables increasing an open [`Position`] with further entry [`Signal`]s of the same side, and
+    /// partially reducing it with exit [`Signal`]s weaker than [`SignalStrength`] 1.0.
+    pub position_scaling: bool,
     /// Configuration used to initialise the Statistics for every Market's performance tracked by a
     /// [`MetaPortfolio`].
     pub statistic_config: Statistic::Config,
@@ -66,6 +69,8 @@ where
 {
     /// Identifier for the [`Engine`](crate::engine::Engine) this Portfolio is associated with (1-to-1 relationship).
     engine_id: Uuid,
+    /// [`Market`]s being tracked by the [`MetaPortfolio`].
+    markets: Vec<Market>,
     /// Repository for the [`MetaPortfolio`] to persist it's state in. Implements
     /// [`PositionHandler`], [`BalanceHandler`], and [`StatisticHandler`]
     repository: Repository,
@@ -73,6 +78,9 @@ where
     allocation_manager: Allocator,
     /// Risk manager implements [`OrderEvaluator`].
     risk_manager: RiskManager,
+    /// Enables increasing an open [`Position`] with further entry [`Signal`]s of the same side, and
+    /// partially reducing it with exit [`Signal`]s weaker than [`SignalStrength`] 1.0.
+    position_scaling: bool,
     _statistic_marker: PhantomData<Statistic>,
 }
 
@@ -87,7 +95,13 @@ where
     fn update_from_market(
         &mut self,
         market: &MarketEvent<Instrument, DataKind>,
-    ) -> Result<Option<PositionUpdate>, PortfolioError> {
+    ) -> Result<Vec<Event>, PortfolioError> {
+        // Allocate Vector<Event> to contain any update_from_market generated events
+        let mut generated_events: Vec<Event> = Vec::with_capacity(2);
+
+        // Update any allocation state that depends on the latest market data
+        self.allocation_manager.update_from_market(market);
+
         // Determine the position_id associated to the input MarketEvent
         let position_id =
             determine_position_id(self.engine_id, &market.exchange, &market.instrument);
@@ -98,11 +112,33 @@ where
             if let Some(position_update) = position.update(market) {
                 // Save updated open Position in the repository
                 self.repository.set_open_position(position)?;
-                return Ok(Some(position_update));
+
+                // Force an exit of the Position if it's protective stops were breached
+                let stop_triggered = position_update.stop_triggered.is_some();
+                generated_events.push(Event::PositionUpdate(position_update));
+                if stop_triggered {
+                    generated_events.push(Event::SignalForceExit(SignalForceExit::new(
+                        market.exchange.clone(),
+                        market.instrument.clone(),
+                    )));
+                }
             }
         }
 
-        Ok(None)
+        // Update any risk state that depends on the latest Portfolio equity
+        let balance = self.repository.get_balance(self.engine_id)?;
+        let open_positions = self
+            .repository
+            .get_open_positions(self.engine_id, self.markets.iter())?;
+        self.risk_manager.update_from_market(
+            market.exchange_time,
+            RiskContext {
+                balance,
+                open_positions: &open_positions,
+            },
+        );
+
+        Ok(generated_events)
     }
 }
 
@@ -114,24 +150,32 @@ where
     RiskManager: OrderEvaluator,
     Statistic: Initialiser + PositionSummariser,
 {
-    fn generate_order(&mut self, signal: &Signal) -> Result<Option<OrderEvent>, PortfolioError> {
+    fn generate_order(
+        &mut self,
+        signal: &Signal,
+    ) -> Result<Option<Result<OrderEvent, OrderRejected>>, PortfolioError> {
         // Determine the position_id & associated Option<Position> related to input SignalEvent
         let position_id =
             determine_position_id(self.engine_id, &signal.exchange, &signal.instrument);
         let position = self.repository.get_open_position(&position_id)?;
 
-        // If signal is advising to open a new Position rather than close one, check we have cash
-        if position.is_none() && self.no_cash_to_enter_new_position()? {
-            return Ok(None);
-        }
-
         // Parse signals from Strategy to determine net signal decision & associated strength
         let position = position.as_ref();
-        let (signal_decision, signal_strength) =
-            match parse_signal_decisions(&position, &signal.signals) {
-                None => return Ok(None),
-                Some(net_signal) => net_signal,
-            };
+        let net_signal = if self.position_scaling {
+            parse_signal_decisions_with_scaling(&position, &signal.signals)
+        } else {
+            parse_signal_decisions(&position, &signal.signals)
+        };
+        let (signal_decision, signal_strength) = match net_signal {
+            None => return Ok(None),
+            Some(net_signal) => net_signal,
+        };
+
+        // If signal is advising to open or increase a Position rather than close one, check we
+        // have cash
+        if signal_decision.is_entry() && self.no_cash_to_enter_new_position()? {
+            return Ok(None);
+        }
 
         // Construct mutable OrderEvent that can be modified by Allocation & Risk management
         let mut order = OrderEvent {
@@ -142,14 +186,36 @@ where
             decision: *signal_decision,
             quantity: 0.0,
             order_type: OrderType::default(),
+            stops: signal_decision.is_entry().then_some(signal.stops).flatten(),
         };
 
         // Manage OrderEvent size allocation
         self.allocation_manager
             .allocate_order(&mut order, position, *signal_strength);
 
-        // Manage global risk when evaluating OrderEvent - keep the same, refine or cancel
-        Ok(self.risk_manager.evaluate_order(order))
+        // With position scaling enabled, exits only close the fraction of the open Position
+        // quantity given by the SignalStrength (capped at 1.0)
+        if self.position_scaling && signal_decision.is_exit() {
+            order.quantity *= signal_strength.0.clamp(0.0, 1.0);
+        }
+
+        // Allocation may size the OrderEvent to nothing (eg/ SignalStrength of 0.0)
+        if order.quantity == 0.0 {
+            return Ok(None);
+        }
+
+        // Snapshot the Portfolio state used to evaluate the OrderEvent risk
+        let balance = self.repository.get_balance(self.engine_id)?;
+        let open_positions = self
+            .repository
+            .get_open_positions(self.engine_id, self.markets.iter())?;
+        let context = RiskContext {
+            balance,
+            open_positions: &open_positions,
+        };
+
+        // Manage global risk when evaluating OrderEvent - keep the same, refine or reject
+        Ok(Some(self.risk_manager.evaluate_order(order, context)))
     }
 
     fn generate_exit_order(
@@ -184,8 +250,38 @@ where
             decision: position.determine_exit_decision(),
             quantity: 0.0 - position.quantity,
             order_type: OrderType::Market,
+            stops: None,
         }))
     }
+
+    fn update_from_order_failed(&mut self, failed: &OrderFailed) -> Result<(), PortfolioError> {
+        info!(
+            engine_id = %self.engine_id,
+            order = ?failed.order,
+            reason = %failed.reason,
+            "OrderEvent failed to execute"
+        );
+
+        // Only a failed exit OrderEvent can leave a forced exit pending
+        if !failed.order.decision.is_exit() {
+            return Ok(());
+        }
+
+        // Clear any pending forced exit so the open Position's stops can re-trigger
+        let position_id = determine_position_id(
+            self.engine_id,
+            &failed.order.exchange,
+            &failed.order.instrument,
+        );
+        if let Some(mut position) = self.repository.get_open_position(&position_id)? {
+            if let Some(stops) = position.stops.as_mut().filter(|stops| stops.exit_pending) {
+                stops.exit_pending = false;
+                self.repository.set_open_position(position)?;
+            }
+        }
+
+        Ok(())
+    }
 }
 
 impl<Repository, Allocator, RiskManager, Statistic> FillUpdater
@@ -209,24 +305,65 @@ where
 
         // Determine FillEvent context based on existence or absence of an open Position
         match self.repository.remove_position(&position_id)? {
+            // INCREASE SCENARIO - Entry FillEvent for Symbol-Exchange combination with open Position
+            Some(mut position) if fill.decision.is_entry() => {
+                // Increase Position (in place mutation), & add the PositionUpdate event to Vec<Event>
+                let position_update = position.increase(fill)?;
+                generated_events.push(Event::PositionUpdate(position_update));
+
+                // Update Portfolio Balance.available on Position increase
+                balance.available += -fill.fill_value_gross - fill.fees.calculate_total_fees();
+
+                // Persist increased open Position in Repository
+                self.repository.set_open_position(position)?;
+            }
+
+            // REDUCE SCENARIO - Exit FillEvent for part of an open Position's quantity
+            Some(mut position)
+                if self.position_scaling && fill.quantity.abs() < position.quantity.abs() =>
+            {
+                // Reduce Position (in place mutation), & add the PositionUpdate event to Vec<Event>
+                let (reduced_position, position_update) = position.reduce(balance, fill)?;
+                generated_events.push(Event::PositionUpdate(position_update));
+
+                // Re-arm the stops of the remaining Position if a forced exit was pending
+                if let Some(stops) = &mut position.stops {
+                    stops.exit_pending = false;
+                }
+
+                // Update Portfolio balance on partial Position exit
+                // '--> available balance adds reduced enter_fees_total since included in PnL calc
+                balance.available += reduced_position.enter_value_gross
+                    + reduced_position.realised_profit_loss
+                    + reduced_position.enter_fees_total;
+                balance.total += reduced_position.realised_profit_loss;
+
+                // Persist remaining open Position, which accumulates the reduced portion's
+                // realised PnL until it is exited & included in the Market statistics
+                self.repository.set_open_position(position)?;
+            }
+
             // EXIT SCENARIO - FillEvent for Symbol-Exchange combination with open Position
             Some(mut position) => {
                 // Exit Position (in place mutation), & add the PositionExit event to Vec<Event>
+                let reduced_profit_loss = position.realised_profit_loss;
                 let position_exit = position.exit(balance, fill)?;
                 generated_events.push(Event::PositionExit(position_exit));
 
                 // Update Portfolio balance on Position exit
+                // '--> realised PnL of any partial reductions is already included in the balance
                 // '--> available balance adds enter_total_fees since included in result PnL calc
-                balance.available += position.enter_value_gross
-                    + position.realised_profit_loss
-                    + position.enter_fees_total;
-                balance.total += position.realised_profit_loss;
+                let exit_profit_loss = position.realised_profit_loss - reduced_profit_loss;
+                balance.available +=
+                    position.enter_value_gross + exit_profit_loss + position.enter_fees_total;
+                balance.total += exit_profit_loss;
 
                 // Update statistics for exited Position market
                 let market_id = MarketId::new(&fill.exchange, &fill.instrument);
 
                 let mut stats = self.repository.get_statistics(&market_id)?;
                 stats.update(&position);
+                self.allocation_manager.update_from_exit(&position);
 
                 // Persist exited Position & Updated Market statistics in Repository
                 self.repository.set_statistics(market_id, stats)?;
@@ -339,14 +476,17 @@ where
         // Construct MetaPortfolio instance
         let mut portfolio = Self {
             engine_id: lego.engine_id,
+            markets: lego.markets,
             repository: lego.repository,
             allocation_manager: lego.allocator,
             risk_manager: lego.risk,
+            position_scaling: lego.position_scaling,
             _statistic_marker: PhantomData,
         };
 
         // Persist initial state in the repository
-        portfolio.bootstrap_repository(lego.starting_cash, &lego.markets, lego.statistic_config)?;
+        let markets = portfolio.markets.clone();
+        portfolio.bootstrap_repository(lego.starting_cash, &markets, lego.statistic_config)?;
 
         Ok(portfolio)
     }
@@ -409,6 +549,7 @@ where
     repository: Option<Repository>,
     allocation_manager: Option<Allocator>,
     risk_manager: Option<RiskManager>,
+    position_scaling: Option<bool>,
     statistic_config: Option<Statistic::Config>,
     _statistic_marker: Option<PhantomData<Statistic>>,
 }
@@ -429,6 +570,7 @@ where
             repository: None,
             allocation_manager: None,
             risk_manager: None,
+            position_scaling: None,
             statistic_config: None,
             _statistic_marker: None,
         }
@@ -476,6 +618,13 @@ where
         }
     }
 
+    pub fn position_scaling(self, value: bool) -> Self {
+        Self {
+            position_scaling: Some(value),
+            ..self
+        }
+    }
+
     pub fn statistic_config(self, value: Statistic::Config) -> Self {
         Self {
             statistic_config: Some(value),
@@ -491,6 +640,9 @@ where
             engine_id: self
                 .engine_id
                 .ok_or(PortfolioError::BuilderIncomplete("engine_id"))?,
+            markets: self
+                .markets
+                .ok_or(PortfolioError::BuilderIncomplete("markets"))?,
             repository: self
                 .repository
                 .ok_or(PortfolioError::BuilderIncomplete("repository"))?,
@@ -500,16 +652,16 @@ where
             risk_manager: self
                 .risk_manager
                 .ok_or(PortfolioError::BuilderIncomplete("risk_manager"))?,
+            position_scaling: self.position_scaling.unwrap_or_default(),
             _statistic_marker: PhantomData,
         };
 
         // Persist initial state in the Repository
+        let markets = portfolio.markets.clone();
         portfolio.bootstrap_repository(
             self.starting_cash
                 .ok_or(PortfolioError::BuilderIncomplete("starting_cash"))?,
-            &self
-                .markets
-                .ok_or(PortfolioError::BuilderIncomplete("markets"))?,
+            &markets,
             self.statistic_config
                 .ok_or(PortfolioError::BuilderIncomplete("statistic_config"))?,
         )?;
@@ -547,19 +699,48 @@ pub fn parse_signal_decisions<'a>(
     }
 }
 
+/// Parses an incoming [`Signal`]'s signals map for a [`MetaPortfolio`] with position scaling
+/// enabled. Determines what the net signal [`Decision`] will be, and it's associated
+/// [`SignalStrength`].
+///
+/// Unlike [`parse_signal_decisions`], an entry signal of the same side as an existing
+/// [`Position`] nets to an increase of that [`Position`] if there is no close signal.
+pub fn parse_signal_decisions_with_scaling<'a>(
+    position: &'a Option<&Position>,
+    signals: &'a HashMap<Decision, SignalStrength>,
+) -> Option<(&'a Decision, &'a SignalStrength)> {
+    match position {
+        Some(position) => {
+            let (close, increase) = match position.side {
+                Side::Buy => (Decision::CloseLong, Decision::Long),
+                Side::Sell => (Decision::CloseShort, Decision::Short),
+            };
+
+            signals
+                .get_key_value(&close)
+                .or_else(|| signals.get_key_value(&increase))
+        }
+        None => parse_signal_decisions(position, signals),
+    }
+}
+
 #[cfg(test)]
+#[allow(clippy::field_reassign_with_default)]
 pub mod tests {
     use super::*;
 
     use crate::{
         execution::Fees,
         portfolio::{
-            allocator::DefaultAllocator, position::PositionBuilder,
-            repository::error::RepositoryError, risk::DefaultRisk,
+            allocator::DefaultAllocator,
+            position::PositionBuilder,
+            repository::error::RepositoryError,
+            risk::DefaultRisk,
+            stop::{PositionStops, StopConfig, StopTrigger},
         },
         statistic::summary::pnl::PnLReturnSummary,
         strategy::SignalForceExit,
-        test_util::{fill_event, market_event_trade, position, signal},
+        test_util::{fill_event, market_event_trade, order_event, position, signal},
     };
     use barter_integration::model::{
         instrument::{kind::InstrumentKind, Instrument},
@@ -592,7 +773,7 @@ pub mod tests {
         fn set_open_position(&mut self, position: Position) -> Result<(), RepositoryError> {
             self.position = Some(
                 Position::builder()
-                    .side(position.side.clone())
+                    .side(position.side)
                     .current_symbol_price(position.current_symbol_price)
                     .current_value_gross(position.current_value_gross)
                     .enter_fees_total(position.enter_fees_total)
@@ -704,6 +885,7 @@ pub mod tests {
             engine_id: builder
                 .engine_id
                 .ok_or(PortfolioError::BuilderIncomplete("engine_id"))?,
+            markets: builder.markets.unwrap_or_default(),
             repository: builder
                 .repository
                 .ok_or(PortfolioError::BuilderIncomplete("repository"))?,
@@ -713,6 +895,7 @@ pub mod tests {
             risk_manager: builder
                 .risk_manager
                 .ok_or(PortfolioError::BuilderIncomplete("risk_manager"))?,
+            position_scaling: builder.position_scaling.unwrap_or_default(),
             _statistic_marker: Default::default(),
         })
     }
@@ -742,6 +925,14 @@ pub mod tests {
             }))
         });
         mock_repository.set_open_position = Some(|_| Ok(()));
+        mock_repository.get_balance = Some(|_| {
+            Ok(Balance {
+                time: Utc::now(),
+                total: 100.0,
+                available: 0.0,
+            })
+        });
+        mock_repository.get_open_positions = Some(|_, _| Ok(vec![position()]));
         let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
 
         // Input MarketEvent
@@ -754,10 +945,10 @@ pub mod tests {
             _ => todo!(),
         };
 
-        let result_pos_update = portfolio
-            .update_from_market(&input_market)
-            .unwrap()
-            .unwrap();
+        let result = portfolio.update_from_market(&input_market).unwrap();
+        let Event::PositionUpdate(result_pos_update) = &result[0] else {
+            panic!("expected PositionUpdate, found: {:?}", result[0]);
+        };
         let updated_position = portfolio.repository.position.unwrap();
 
         assert_eq!(updated_position.current_symbol_price.unwrap(), 200.0);
@@ -774,6 +965,106 @@ pub mod tests {
         );
     }
 
+    #[test]
+    fn update_from_market_with_long_position_breaching_take_profit() {
+        // Build Portfolio
+        let mut mock_repository = MockRepository::<PnLReturnSummary>::default();
+        mock_repository.get_open_position = Some(|_| {
+            Ok(Some({
+                let mut input_position = position();
+                input_position.side = Side::Buy;
+                input_position.quantity = 1.0;
+                input_position.stops = Some(PositionStops::new(
+                    StopConfig {
+                        take_profit: Some(0.5),
+                        ..StopConfig::default()
+                    },
+                    Side::Buy,
+                    100.0,
+                ));
+                input_position
+            }))
+        });
+        mock_repository.set_open_position = Some(|_| Ok(()));
+        mock_repository.get_balance = Some(|_| {
+            Ok(Balance {
+                time: Utc::now(),
+                total: 100.0,
+                available: 0.0,
+            })
+        });
+        mock_repository.get_open_positions = Some(|_, _| Ok(vec![position()]));
+        let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
+
+        // Input MarketEvent
+        let mut input_market = market_event_trade(Side::Buy);
+        match input_market.kind {
+            // +100.0 on input_position.current_symbol_price
+            DataKind::Candle(ref mut candle) => candle.close = 200.0,
+            DataKind::Trade(ref mut trade) => trade.price = 200.0,
+            _ => todo!(),
+        };
+
+        let result = portfolio.update_from_market(&input_market).unwrap();
+
+        assert_eq!(result.len(), 2);
+        match &result[0] {
+            Event::PositionUpdate(update) => {
+                assert_eq!(update.stop_triggered, Some(StopTrigger::TakeProfit))
+            }
+            event => panic!("expected PositionUpdate, found: {event:?}"),
+        }
+        match &result[1] {
+            Event::SignalForceExit(signal) => {
+                assert_eq!(signal.exchange, input_market.exchange);
+                assert_eq!(signal.instrument, input_market.instrument);
+            }
+            event => panic!("expected SignalForceExit, found: {event:?}"),
+        }
+    }
+
+    #[test]
+    fn update_from_order_failed_with_exit_order_clears_pending_forced_exit() {
+        // Build Portfolio with an open Position awaiting a forced exit
+        let mut mock_repository = MockRepository::<PnLReturnSummary>::default();
+        mock_repository.get_open_position = Some(|_| {
+            Ok(Some({
+                let mut input_position = position();
+                let mut stops = PositionStops::new(
+                    StopConfig {
+                        stop_loss: Some(0.1),
+                        ..StopConfig::default()
+                    },
+                    Side::Buy,
+                    100.0,
+                );
+                stops.exit_pending = true;
+                input_position.stops = Some(stops);
+                input_position
+            }))
+        });
+        mock_repository.set_open_position = Some(|position| {
+            assert!(!position.stops.unwrap().exit_pending);
+            Ok(())
+        });
+        let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
+
+        // Failed entry OrderEvent leaves the pending forced exit untouched
+        let mut order = order_event();
+        order.decision = Decision::Long;
+        portfolio
+            .update_from_order_failed(&OrderFailed::new(order.clone(), "rejected".to_string()))
+            .unwrap();
+        assert!(portfolio.repository.position.is_none());
+
+        // Failed exit OrderEvent clears the pending forced exit
+        order.decision = Decision::CloseLong;
+        portfolio
+            .update_from_order_failed(&OrderFailed::new(order, "rejected".to_string()))
+            .unwrap();
+        assert!(portfolio.repository.position.is_some());
+    }
+
     #[test]
     fn update_from_market_with_long_position_decreasing_in_value() {
         // Build Portfolio
@@ -791,6 +1082,14 @@ pub mod tests {
             }))
         });
         mock_repository.set_open_position = Some(|_| Ok(()));
+        mock_repository.get_balance = Some(|_| {
+            Ok(Balance {
+                time: Utc::now(),
+                total: 100.0,
+                available: 0.0,
+            })
+        });
+        mock_repository.get_open_positions = Some(|_, _| Ok(vec![position()]));
         let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
 
         // Input MarketEvent
@@ -802,10 +1101,10 @@ pub mod tests {
             _ => todo!(),
         };
 
-        let result_pos_update = portfolio
-            .update_from_market(&input_market)
-            .unwrap()
-            .unwrap();
+        let result = portfolio.update_from_market(&input_market).unwrap();
+        let Event::PositionUpdate(result_pos_update) = &result[0] else {
+            panic!("expected PositionUpdate, found: {:?}", result[0]);
+        };
         let updated_position = portfolio.repository.position.unwrap();
 
         assert_eq!(updated_position.current_symbol_price.unwrap(), 50.0);
@@ -835,6 +1134,14 @@ pub mod tests {
             }))
         });
         mock_repository.set_open_position = Some(|_| Ok(()));
+        mock_repository.get_balance = Some(|_| {
+            Ok(Balance {
+                time: Utc::now(),
+                total: 100.0,
+                available: 0.0,
+            })
+        });
+        mock_repository.get_open_positions = Some(|_, _| Ok(vec![position()]));
         let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
 
         // Input MarketEvent
@@ -847,10 +1154,10 @@ pub mod tests {
             _ => todo!(),
         };
 
-        let result_pos_update = portfolio
-            .update_from_market(&input_market)
-            .unwrap()
-            .unwrap();
+        let result = portfolio.update_from_market(&input_market).unwrap();
+        let Event::PositionUpdate(result_pos_update) = &result[0] else {
+            panic!("expected PositionUpdate, found: {:?}", result[0]);
+        };
         let updated_position = portfolio.repository.position.unwrap();
 
         assert_eq!(updated_position.current_symbol_price.unwrap(), 50.0);
@@ -880,6 +1187,14 @@ pub mod tests {
             }))
         });
         mock_repository.set_open_position = Some(|_| Ok(()));
+        mock_repository.get_balance = Some(|_| {
+            Ok(Balance {
+                time: Utc::now(),
+                total: 100.0,
+                available: 0.0,
+            })
+        });
+        mock_repository.get_open_positions = Some(|_, _| Ok(vec![position()]));
         let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
 
         // Input MarketEvent
@@ -892,10 +1207,10 @@ pub mod tests {
             _ => todo!(),
         };
 
-        let result_pos_update = portfolio
-            .update_from_market(&input_market)
-            .unwrap()
-            .unwrap();
+        let result = portfolio.update_from_market(&input_market).unwrap();
+        let Event::PositionUpdate(result_pos_update) = &result[0] else {
+            panic!("expected PositionUpdate, found: {:?}", result[0]);
+        };
         let updated_position = portfolio.repository.position.unwrap();
 
         assert_eq!(updated_position.current_symbol_price.unwrap(), 200.0);
@@ -915,6 +1230,7 @@ pub mod tests {
     fn generate_no_order_with_no_position_and_no_cash() {
         // Build Portfolio
         let mut mock_repository = MockRepository::<PnLReturnSummary>::default();
+        mock_repository.get_open_positions = Some(|_, _| Ok(vec![]));
         mock_repository.get_open_position = Some(|_| Ok(None));
         mock_repository.get_balance = Some(|_| {
             Ok(Balance {
@@ -937,6 +1253,7 @@ pub mod tests {
     fn generate_no_order_with_position_and_no_cash() {
         // Build Portfolio
         let mut mock_repository = MockRepository::<PnLReturnSummary>::default();
+        mock_repository.get_open_positions = Some(|_, _| Ok(vec![]));
         mock_repository.get_open_position = Some(|_| Ok(Some(position())));
         mock_repository.get_balance = Some(|_| {
             Ok(Balance {
@@ -959,6 +1276,7 @@ pub mod tests {
     fn generate_order_long_with_no_position_and_input_net_long_signal() {
         // Build Portfolio
         let mut mock_repository = MockRepository::<PnLReturnSummary>::default();
+        mock_repository.get_open_positions = Some(|_, _| Ok(vec![]));
         mock_repository.get_open_position = Some(|_| Ok(None));
         mock_repository.get_balance = Some(|_| {
             Ok(Balance {
@@ -975,7 +1293,11 @@ pub mod tests {
             .signals
             .insert(Decision::Long, SignalStrength(1.0));
 
-        let actual = portfolio.generate_order(&input_signal).unwrap().unwrap();
+        let actual = portfolio
+            .generate_order(&input_signal)
+            .unwrap()
+            .unwrap()
+            .unwrap();
 
         assert_eq!(actual.decision, Decision::Long)
     }
@@ -984,6 +1306,7 @@ pub mod tests {
     fn generate_order_short_with_no_position_and_input_net_short_signal() {
         // Build Portfolio
         let mut mock_repository = MockRepository::<PnLReturnSummary>::default();
+        mock_repository.get_open_positions = Some(|_, _| Ok(vec![]));
         mock_repository.get_open_position = Some(|_| Ok(None));
         mock_repository.get_balance = Some(|_| {
             Ok(Balance {
@@ -1001,7 +1324,11 @@ pub mod tests {
             .signals
             .insert(Decision::Short, SignalStrength(1.0));
 
-        let actual = portfolio.generate_order(&input_signal).unwrap().unwrap();
+        let actual = portfolio
+            .generate_order(&input_signal)
+            .unwrap()
+            .unwrap()
+            .unwrap();
 
         assert_eq!(actual.decision, Decision::Short)
     }
@@ -1010,6 +1337,7 @@ pub mod tests {
     fn generate_order_close_long_with_long_position_and_input_net_close_long_signal() {
         // Build Portfolio
         let mut mock_repository = MockRepository::<PnLReturnSummary>::default();
+        mock_repository.get_open_positions = Some(|_, _| Ok(vec![]));
         mock_repository.get_open_position = Some(|_| {
             Ok(Some({
                 let mut position = position();
@@ -1033,7 +1361,11 @@ pub mod tests {
             .signals
             .insert(Decision::CloseLong, SignalStrength(1.0));
 
-        let actual = portfolio.generate_order(&input_signal).unwrap().unwrap();
+        let actual = portfolio
+            .generate_order(&input_signal)
+            .unwrap()
+            .unwrap()
+            .unwrap();
 
         assert_eq!(actual.decision, Decision::CloseLong)
     }
@@ -1042,6 +1374,7 @@ pub mod tests {
     fn generate_order_close_short_with_short_position_and_input_net_close_short_signal() {
         // Build Portfolio
         let mut mock_repository = MockRepository::<PnLReturnSummary>::default();
+        mock_repository.get_open_positions = Some(|_, _| Ok(vec![]));
         mock_repository.get_open_position = Some(|_| {
             Ok(Some({
                 let mut position = position();
@@ -1065,7 +1398,11 @@ pub mod tests {
             .signals
             .insert(Decision::CloseShort, SignalStrength(1.0));
 
-        let actual = portfolio.generate_order(&input_signal).unwrap().unwrap();
+        let actual = portfolio
+            .generate_order(&input_signal)
+            .unwrap()
+            .unwrap()
+            .unwrap();
 
         assert_eq!(actual.decision, Decision::CloseShort)
     }
@@ -1431,6 +1768,319 @@ pub mod tests {
         assert_eq!(updated_value, 200.0 + (100.0 - 150.0 - 6.0));
     }
 
+    #[test]
+    fn update_from_fill_increasing_long_position() {
+        // Build Portfolio
+        let mut mock_repository = MockRepository::<PnLReturnSummary>::default();
+        mock_repository.get_balance = Some(|_| {
+            Ok(Balance {
+                time: Utc::now(),
+                total: 200.0,
+                available: 100.0,
+            })
+        });
+        mock_repository.remove_position = Some(|_| Ok(Some(position())));
+        mock_repository.set_open_position = Some(|position| {
+            assert_eq!(position.quantity, 2.0);
+            assert_eq!(position.enter_avg_price_gross, 150.0);
+            Ok(())
+        });
+        mock_repository.set_balance = Some(|_, _| Ok(()));
+        let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
+
+        // Input FillEvent
+        let mut input_fill = fill_event();
+        input_fill.decision = Decision::Long;
+        input_fill.quantity = 1.0;
+        input_fill.fill_value_gross = 200.0;
+        input_fill.fees = Fees {
+            exchange: 1.0,
+            slippage: 1.0,
+            network: 1.0,
+        };
+
+        let result = portfolio.update_from_fill(&input_fill).unwrap();
+        let updated_balance = portfolio.repository.balance.unwrap();
+
+        assert!(matches!(result[0], Event::PositionUpdate(_)));
+        // cash -= fill_value_gross + fees
+        assert_eq!(updated_balance.available, 100.0 - 200.0 - 3.0);
+        assert_eq!(updated_balance.total, 200.0);
+    }
+
+    #[test]
+    fn update_from_fill_partially_reducing_long_position_in_profit() {
+        // Build Portfolio
+        let mut mock_repository = MockRepository::<PnLReturnSummary>::default();
+        mock_repository.get_balance = Some(|_| {
+            Ok(Balance {
+                time: Utc::now(),
+                total: 200.0,
+                available: 0.0,
+            })
+        });
+        mock_repository.remove_position = Some(|_| {
+            Ok(Some({
+                let mut input_position = position();
+                input_position.side = Side::Buy;
+                input_position.quantity = 2.0;
+                input_position.enter_fees_total = 2.0;
+                input_position.enter_avg_price_gross = 100.0;
+                input_position.enter_value_gross = 200.0;
+                input_position
+            }))
+        });
+        // Reduced portion is not recorded as an exited Position, nor in the Market statistics
+        mock_repository.set_open_position = Some(|position| {
+            assert_eq!(position.quantity, 1.0);
+            assert_eq!(position.enter_fees_total, 1.0);
+            assert_eq!(position.realised_profit_loss, 150.0 - 100.0 - 2.0);
+            Ok(())
+        });
+        mock_repository.set_balance = Some(|_, _| Ok(()));
+        let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
+        portfolio.position_scaling = true;
+
+        // Input FillEvent
+        let mut input_fill = fill_event();
+        input_fill.decision = Decision::CloseLong;
+        input_fill.quantity = -1.0;
+        input_fill.fill_value_gross = 150.0;
+        input_fill.fees = Fees {
+            exchange: 1.0,
+            slippage: 0.0,
+            network: 0.0,
+        };
+
+        let result = portfolio.update_from_fill(&input_fill).unwrap();
+        let updated_balance = portfolio.repository.balance.unwrap();
+
+        match &result[0] {
+            Event::PositionUpdate(update) => {
+                assert_eq!(update.realised_profit_loss, 150.0 - 100.0 - 2.0)
+            }
+            event => panic!("expected PositionUpdate, found: {event:?}"),
+        }
+        // cash += reduced enter_value_gross + realised_profit_loss + reduced enter_fees_total
+        assert_eq!(
+            updated_balance.available,
+            0.0 + 100.0 + (150.0 - 100.0 - 2.0) + 1.0
+        );
+        // value += realised_profit_loss
+        assert_eq!(updated_balance.total, 200.0 + (150.0 - 100.0 - 2.0));
+    }
+
+    #[test]
+    fn update_from_fill_exiting_previously_reduced_long_position() {
+        // Build Portfolio
+        let mut mock_repository = MockRepository::<PnLReturnSummary>::default();
+        mock_repository.get_balance = Some(|_| {
+            Ok(Balance {
+                time: Utc::now(),
+                total: 248.0,
+                available: 149.0,
+            })
+        });
+        mock_repository.remove_position = Some(|_| {
+            Ok(Some({
+                let mut input_position = position();
+                input_position.side = Side::Buy;
+                input_position.quantity = 1.0;
+                input_position.enter_fees_total = 1.0;
+                input_position.enter_avg_price_gross = 100.0;
+                input_position.enter_value_gross = 100.0;
+                input_position.realised_profit_loss = 48.0;
+                input_position.reduced_enter_value_gross = 100.0;
+                input_position
+            }))
+        });
+        mock_repository.get_statistics = Some(|_| Ok(PnLReturnSummary::default()));
+        mock_repository.set_statistics = Some(|_, statistics| {
+            assert_eq!(statistics.total.count, 1);
+            Ok(())
+        });
+        mock_repository.set_exited_position = Some(|_, position| {
+            // Exited Position covers the whole entry, including the reduced quantity
+            assert_eq!(position.realised_profit_loss, 48.0 + (150.0 - 100.0 - 2.0));
+            assert_eq!(
+                position.calculate_profit_loss_return(),
+                (48.0 + (150.0 - 100.0 - 2.0)) / 200.0
+            );
+            Ok(())
+        });
+        mock_repository.set_balance = Some(|_, _| Ok(()));
+        let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
+        portfolio.position_scaling = true;
+
+        // Input FillEvent
+        let mut input_fill = fill_event();
+        input_fill.decision = Decision::CloseLong;
+        input_fill.quantity = -1.0;
+        input_fill.fill_value_gross = 150.0;
+        input_fill.fees = Fees {
+            exchange: 1.0,
+            slippage: 0.0,
+            network: 0.0,
+        };
+
+        let result = portfolio.update_from_fill(&input_fill).unwrap();
+        let updated_balance = portfolio.repository.balance.unwrap();
+
+        assert!(matches!(result[0], Event::PositionExit(_)));
+        // Only the realised PnL of the final exit is added to the Balance
+        assert_eq!(
+            updated_balance.available,
+            149.0 + 100.0 + (150.0 - 100.0 - 2.0) + 1.0
+        );
+        assert_eq!(updated_balance.total, 248.0 + (150.0 - 100.0 - 2.0));
+    }
+
+    #[test]
+    fn generate_order_long_with_long_position_and_position_scaling() {
+        // Build Portfolio
+        let mut mock_repository = MockRepository::<PnLReturnSummary>::default();
+        mock_repository.get_open_positions = Some(|_, _| Ok(vec![]));
+        mock_repository.get_open_position = Some(|_| Ok(Some(position())));
+        mock_repository.get_balance = Some(|_| {
+            Ok(Balance {
+                time: Utc::now(),
+                total: 100.0,
+                available: 100.0,
+            })
+        });
+        let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
+        portfolio.position_scaling = true;
+
+        // Input SignalEvent
+        let mut input_signal = signal();
+        input_signal.market_meta.close = 100.0;
+        input_signal
+            .signals
+            .insert(Decision::Long, SignalStrength(1.0));
+
+        let actual = portfolio
+            .generate_order(&input_signal)
+            .unwrap()
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(actual.decision, Decision::Long);
+        assert_eq!(actual.quantity, 1.0);
+    }
+
+    #[test]
+    fn generate_order_partial_close_long_with_position_scaling() {
+        // Build Portfolio
+        let mut mock_repository = MockRepository::<PnLReturnSummary>::default();
+        mock_repository.get_open_positions = Some(|_, _| Ok(vec![]));
+        mock_repository.get_open_position = Some(|_| {
+            Ok(Some({
+                let mut position = position();
+                position.side = Side::Buy;
+                position.quantity = 100.0;
+                position
+            }))
+        });
+        mock_repository.get_balance = Some(|_| {
+            Ok(Balance {
+                time: Utc::now(),
+                total: 100.0,
+                available: 100.0,
+            })
+        });
+        let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
+        portfolio.position_scaling = true;
+
+        // Input SignalEvent
+        let mut input_signal = signal();
+        input_signal
+            .signals
+            .insert(Decision::CloseLong, SignalStrength(0.25));
+
+        let actual = portfolio
+            .generate_order(&input_signal)
+            .unwrap()
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(actual.decision, Decision::CloseLong);
+        assert_eq!(actual.quantity, -25.0);
+    }
+
+    #[test]
+    fn generate_order_full_close_long_without_position_scaling() {
+        // Build Portfolio
+        let mut mock_repository = MockRepository::<PnLReturnSummary>::default();
+        mock_repository.get_open_positions = Some(|_, _| Ok(vec![]));
+        mock_repository.get_open_position = Some(|_| {
+            Ok(Some({
+                let mut position = position();
+                position.side = Side::Buy;
+                position.quantity = 100.0;
+                position
+            }))
+        });
+        mock_repository.get_balance = Some(|_| {
+            Ok(Balance {
+                time: Utc::now(),
+                total: 100.0,
+                available: 100.0,
+            })
+        });
+        let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
+
+        // Input SignalEvent
+        let mut input_signal = signal();
+        input_signal
+            .signals
+            .insert(Decision::CloseLong, SignalStrength(0.0));
+
+        let actual = portfolio
+            .generate_order(&input_signal)
+            .unwrap()
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(actual.decision, Decision::CloseLong);
+        assert_eq!(actual.quantity, -100.0);
+    }
+
+    #[test]
+    fn parse_signal_decisions_with_scaling_to_net_increase_long() {
+        // Some(Position)
+        let mut position = position();
+        position.side = Side::Buy;
+        let position = Some(position);
+        let position = position.as_ref();
+
+        // Signals HashMap
+        let mut signals = HashMap::with_capacity(4);
+        signals.insert(Decision::Long, SignalStrength(1.0));
+        signals.insert(Decision::CloseShort, SignalStrength(1.0));
+
+        let actual = parse_signal_decisions_with_scaling(&position, &signals);
+
+        assert_eq!(actual.unwrap().0, &Decision::Long);
+    }
+
+    #[test]
+    fn parse_signal_decisions_with_scaling_to_net_close_short_with_conflicting_signals() {
+        // Some(Position)
+        let mut position = position();
+        position.side = Side::Sell;
+        let position = Some(position);
+        let position = position.as_ref();
+
+        // Signals HashMap
+        let mut signals = HashMap::with_capacity(4);
+        signals.insert(Decision::CloseShort, SignalStrength(1.0));
+        signals.insert(Decision::Short, SignalStrength(1.0));
+
+        let actual = parse_signal_decisions_with_scaling(&position, &signals);
+
+        assert_eq!(actual.unwrap().0, &Decision::CloseShort);
+    }
+
     #[test]
     fn parse_signal_decisions_to_net_close_long() {
         // Some(Position)
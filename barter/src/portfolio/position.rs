@@ -1,6 +1,11 @@
 use crate::{
+    data::MarketMeta,
     execution::{FeeAmount, Fees, FillEvent},
-    portfolio::{error::PortfolioError, Balance},
+    portfolio::{
+        error::PortfolioError,
+        stop::{PositionStops, StopTrigger},
+        Balance,
+    },
     strategy::Decision,
 };
 use barter_data::event::{DataKind, MarketEvent};
@@ -30,6 +35,23 @@ pub trait PositionExiter {
     fn exit(&mut self, balance: Balance, fill: &FillEvent) -> Result<PositionExit, PortfolioError>;
 }
 
+/// Scales an open [`Position`] by increasing it, or partially reducing it.
+pub trait PositionScaler {
+    /// Increases an open [`Position`] using an entry [`FillEvent`] of the same [`Side`], returning
+    /// a [`PositionUpdate`] that communicates the open [`Position`]'s change in state.
+    fn increase(&mut self, fill: &FillEvent) -> Result<PositionUpdate, PortfolioError>;
+
+    /// Partially reduces an open [`Position`] using an exit [`FillEvent`] for less than it's open
+    /// quantity, given the input Portfolio equity. The realised P&L of the reduced portion is
+    /// accumulated by the open [`Position`]. Returns the reduced portion, and a [`PositionUpdate`]
+    /// that communicates the remaining open [`Position`]'s change in state.
+    fn reduce(
+        &mut self,
+        balance: Balance,
+        fill: &FillEvent,
+    ) -> Result<(Position, PositionUpdate), PortfolioError>;
+}
+
 /// Communicates a String represents a unique [`Position`] identifier.
 pub type PositionId = String;
 
@@ -68,12 +90,14 @@ pub struct Position {
     pub quantity: f64,
 
     /// All fees types incurred from entering a [`Position`], and their associated [`FeeAmount`].
+    /// Attributed pro rata to the open quantity if the [`Position`] is partially reduced.
     pub enter_fees: Fees,
 
     /// Total of enter_fees incurred. Sum of every [`FeeAmount`] in [`Fees`] when entering a [`Position`].
     pub enter_fees_total: FeeAmount,
 
-    /// Enter average price excluding the entry_fees_total.
+    /// Enter average price excluding the entry_fees_total. Quantity weighted average of every
+    /// entry if the [`Position`] has been increased.
     pub enter_avg_price_gross: f64,
 
     /// abs(Quantity) * enter_avg_price_gross.
@@ -100,8 +124,20 @@ pub struct Position {
     /// Unrealised P&L whilst the [`Position`] is open.
     pub unrealised_profit_loss: f64,
 
-    /// Realised P&L after the [`Position`] has closed.
+    /// Realised P&L of any partial reductions whilst the [`Position`] is open, and of the whole
+    /// [`Position`] after it has closed.
     pub realised_profit_loss: f64,
+
+    /// Enter value gross attributed to the quantity exited by partial reductions of the
+    /// [`Position`], so the PnL return of the closed [`Position`] covers it's whole entry.
+    #[serde(default)]
+    pub reduced_enter_value_gross: f64,
+
+    /// Protective exit levels that trigger a forced exit of the [`Position`] when breached.
+    /// Remain armed until the [`Position`] is exited, and follow the enter_avg_price_gross if the
+    /// [`Position`] is increased.
+    #[serde(default)]
+    pub stops: Option<PositionStops>,
 }
 
 impl PositionEnterer for Position {
@@ -113,6 +149,9 @@ impl PositionEnterer for Position {
             exit_balance: None,
         };
 
+        // Entry Side
+        let side = Position::parse_entry_side(fill)?;
+
         // Enter fees
         let enter_fees_total = fill.fees.calculate_total_fees();
 
@@ -127,7 +166,7 @@ impl PositionEnterer for Position {
             exchange: fill.exchange.clone(),
             instrument: fill.instrument.clone(),
             meta: metadata,
-            side: Position::parse_entry_side(fill)?,
+            side,
             quantity: fill.quantity,
             enter_fees: fill.fees,
             enter_fees_total,
@@ -141,6 +180,10 @@ impl PositionEnterer for Position {
             current_value_gross: fill.fill_value_gross,
             unrealised_profit_loss,
             realised_profit_loss: 0.0,
+            reduced_enter_value_gross: 0.0,
+            stops: fill
+                .stops
+                .map(|config| PositionStops::new(config, side, enter_avg_price_gross)),
         })
     }
 }
@@ -148,13 +191,7 @@ impl PositionEnterer for Position {
 impl PositionUpdater for Position {
     fn update(&mut self, market: &MarketEvent<Instrument, DataKind>) -> Option<PositionUpdate> {
         // Determine close from MarketEvent
-        let close = match &market.kind {
-            DataKind::Trade(trade) => trade.price,
-            DataKind::Candle(candle) => candle.close,
-            DataKind::OrderBookL1(book_l1) => book_l1.volume_weighed_mid_price(),
-            DataKind::OrderBook(book) => book.volume_weighed_mid_price()?,
-            DataKind::Liquidation(_) => return None,
-        };
+        let close = MarketMeta::from_market_event(market)?.close;
 
         self.meta.update_time = market.exchange_time;
 
@@ -166,8 +203,17 @@ impl PositionUpdater for Position {
         // Unreal profit & loss
         self.unrealised_profit_loss = self.calculate_unrealised_profit_loss();
 
+        // Check protective stops, which only trigger a forced exit once until that exit fails
+        let stop_triggered = self
+            .stops
+            .as_mut()
+            .and_then(|stops| stops.update(self.side, close));
+
         // Return a PositionUpdate event that communicates the change in state
-        Some(PositionUpdate::from(self))
+        Some(PositionUpdate {
+            stop_triggered,
+            ..PositionUpdate::from(self)
+        })
     }
 }
 
@@ -189,12 +235,13 @@ impl PositionExiter for Position {
         self.exit_value_gross = fill.fill_value_gross;
         self.exit_avg_price_gross = Position::calculate_avg_price_gross(fill);
 
-        // Result profit & loss
-        self.realised_profit_loss = self.calculate_realised_profit_loss();
+        // Result profit & loss, including any realised by partial reductions of the Position
+        let exit_profit_loss = self.calculate_realised_profit_loss();
+        self.realised_profit_loss += exit_profit_loss;
         self.unrealised_profit_loss = self.realised_profit_loss;
 
         // Metadata
-        balance.total += self.realised_profit_loss;
+        balance.total += exit_profit_loss;
         self.meta.update_time = fill.time;
         self.meta.exit_balance = Some(balance);
 
@@ -202,6 +249,84 @@ impl PositionExiter for Position {
     }
 }
 
+impl PositionScaler for Position {
+    fn increase(&mut self, fill: &FillEvent) -> Result<PositionUpdate, PortfolioError> {
+        if Position::parse_entry_side(fill)? != self.side {
+            return Err(PortfolioError::CannotIncreasePositionWithOppositeSide);
+        }
+
+        // Enter fees
+        self.enter_fees = self.enter_fees + fill.fees;
+        self.enter_fees_total += fill.fees.calculate_total_fees();
+
+        // Enter value & weighted average price
+        let prev_enter_avg_price_gross = self.enter_avg_price_gross;
+        self.quantity += fill.quantity;
+        self.enter_value_gross += fill.fill_value_gross;
+        self.enter_avg_price_gross = self.enter_value_gross / self.quantity.abs();
+
+        // Protective stop levels follow the new weighted average enter price
+        if let Some(stops) = &mut self.stops {
+            stops.rebase(prev_enter_avg_price_gross, self.enter_avg_price_gross);
+        }
+
+        // Market value gross & unreal profit & loss at the fill price
+        self.current_symbol_price = Position::calculate_avg_price_gross(fill);
+        self.current_value_gross = self.current_symbol_price * self.quantity.abs();
+        self.unrealised_profit_loss = self.calculate_unrealised_profit_loss();
+
+        self.meta.update_time = fill.time;
+
+        Ok(PositionUpdate::from(self))
+    }
+
+    fn reduce(
+        &mut self,
+        balance: Balance,
+        fill: &FillEvent,
+    ) -> Result<(Position, PositionUpdate), PortfolioError> {
+        // Fraction of the open quantity being reduced
+        let fraction = (fill.quantity / self.quantity).abs();
+        if fill.decision != self.determine_exit_decision()
+            || fill.quantity.signum() == self.quantity.signum()
+            || fraction >= 1.0
+        {
+            return Err(PortfolioError::CannotReducePosition);
+        }
+
+        // Exit the reduced portion as a Position with pro rata enter value & fees attributed
+        let mut reduced = self.clone();
+        reduced.quantity = -fill.quantity;
+        reduced.realised_profit_loss = 0.0;
+        reduced.enter_fees = self.enter_fees.scale(fraction);
+        reduced.enter_fees_total = self.enter_fees_total * fraction;
+        reduced.enter_value_gross = self.enter_value_gross * fraction;
+        reduced.exit(balance, fill)?;
+
+        // Remaining open quantity retains the weighted average enter price
+        self.quantity += fill.quantity;
+        self.enter_fees = self.enter_fees.scale(1.0 - fraction);
+        self.enter_fees_total -= reduced.enter_fees_total;
+        self.enter_value_gross -= reduced.enter_value_gross;
+
+        // Accumulate the realised profit & loss of the reduced portion
+        self.reduced_enter_value_gross += reduced.enter_value_gross;
+        self.realised_profit_loss += reduced.realised_profit_loss;
+
+        // Market value gross & unreal profit & loss at the fill price
+        self.current_symbol_price = reduced.exit_avg_price_gross;
+        self.current_value_gross = self.current_symbol_price * self.quantity.abs();
+        self.unrealised_profit_loss = self.calculate_unrealised_profit_loss();
+
+        self.meta.update_time = fill.time;
+
+        let mut position_update = PositionUpdate::from(&mut *self);
+        position_update.realised_profit_loss = reduced.realised_profit_loss;
+
+        Ok((reduced, position_update))
+    }
+}
+
 impl Position {
     /// Returns a [`PositionBuilder`] instance.
     pub fn builder() -> PositionBuilder {
@@ -255,9 +380,9 @@ impl Position {
     }
 
     /// Calculate the PnL return of a closed [`Position`] - assumed [`Position::realised_profit_loss`] is
-    /// appropriately calculated.
+    /// appropriately calculated. Includes the enter value of any partially reduced quantity.
     pub fn calculate_profit_loss_return(&self) -> f64 {
-        self.realised_profit_loss / self.enter_value_gross
+        self.realised_profit_loss / (self.enter_value_gross + self.reduced_enter_value_gross)
     }
 }
 
@@ -282,6 +407,8 @@ pub struct PositionBuilder {
     pub current_value_gross: Option<f64>,
     pub unrealised_profit_loss: Option<f64>,
     pub realised_profit_loss: Option<f64>,
+    pub reduced_enter_value_gross: Option<f64>,
+    pub stops: Option<PositionStops>,
 }
 
 impl PositionBuilder {
@@ -415,6 +542,20 @@ impl PositionBuilder {
         }
     }
 
+    pub fn reduced_enter_value_gross(self, value: f64) -> Self {
+        Self {
+            reduced_enter_value_gross: Some(value),
+            ..self
+        }
+    }
+
+    pub fn stops(self, value: PositionStops) -> Self {
+        Self {
+            stops: Some(value),
+            ..self
+        }
+    }
+
     pub fn build(self) -> Result<Position, PortfolioError> {
         Ok(Position {
             position_id: self
@@ -467,6 +608,8 @@ impl PositionBuilder {
             realised_profit_loss: self
                 .realised_profit_loss
                 .ok_or(PortfolioError::BuilderIncomplete("realised_profit_loss"))?,
+            reduced_enter_value_gross: self.reduced_enter_value_gross.unwrap_or_default(),
+            stops: self.stops,
         })
     }
 }
@@ -495,19 +638,28 @@ impl Default for PositionMeta {
     }
 }
 
-/// [`Position`] update event. Occurs as a result of receiving new [`MarketEvent`] data.
+/// [`Position`] update event. Occurs as a result of receiving new [`MarketEvent`] data, or a
+/// [`FillEvent`] that increases or partially reduces the [`Position`].
 #[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub struct PositionUpdate {
     /// Unique identifier for a [`Position`], generated from an exchange, symbol, and enter_time.
     pub position_id: String,
     /// Event timestamp of the last event to trigger a [`Position`] update.
     pub update_time: DateTime<Utc>,
+    /// +ve or -ve quantity of symbol contracts open.
+    pub quantity: f64,
+    /// Enter average price excluding the entry_fees_total.
+    pub enter_avg_price_gross: f64,
     /// Symbol current close price.
     pub current_symbol_price: f64,
     /// abs(Quantity) * current_symbol_price.
     pub current_value_gross: f64,
     /// Unrealised P&L whilst the [`Position`] is open.
     pub unrealised_profit_loss: f64,
+    /// Realised P&L of the partial reduction that triggered this update, otherwise 0.0.
+    pub realised_profit_loss: f64,
+    /// Protective exit level breached by the market data that triggered this update, if any.
+    pub stop_triggered: Option<StopTrigger>,
 }
 
 impl From<&mut Position> for PositionUpdate {
@@ -515,9 +667,13 @@ impl From<&mut Position> for PositionUpdate {
         Self {
             position_id: updated_position.position_id.clone(),
             update_time: updated_position.meta.update_time,
+            quantity: updated_position.quantity,
+            enter_avg_price_gross: updated_position.enter_avg_price_gross,
             current_symbol_price: updated_position.current_symbol_price,
             current_value_gross: updated_position.current_value_gross,
             unrealised_profit_loss: updated_position.unrealised_profit_loss,
+            realised_profit_loss: 0.0,
+            stop_triggered: None,
         }
     }
 }
@@ -573,7 +729,10 @@ impl TryFrom<&mut Position> for PositionExit {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test_util::{fill_event, market_event_trade, position};
+    use crate::{
+        portfolio::stop::StopConfig,
+        test_util::{fill_event, market_event_trade, position},
+    };
     use barter_integration::model::Side;
 
     #[test]
@@ -662,7 +821,7 @@ mod tests {
             network: 1.0,
         };
 
-        if let Err(_) = Position::enter(Uuid::new_v4(), &input_fill) {
+        if Position::enter(Uuid::new_v4(), &input_fill).is_err() {
             Ok(())
         } else {
             Err(String::from(
@@ -684,7 +843,7 @@ mod tests {
             network: 1.0,
         };
 
-        if let Err(_) = Position::enter(Uuid::new_v4(), &input_fill) {
+        if Position::enter(Uuid::new_v4(), &input_fill).is_err() {
             Ok(())
         } else {
             Err(String::from(
@@ -706,7 +865,7 @@ mod tests {
             network: 1.0,
         };
 
-        if let Err(_) = Position::enter(Uuid::new_v4(), &input_fill) {
+        if Position::enter(Uuid::new_v4(), &input_fill).is_err() {
             Ok(())
         } else {
             Err(String::from(
@@ -728,7 +887,7 @@ mod tests {
             network: 1.0,
         };
 
-        if let Err(_) = Position::enter(Uuid::new_v4(), &input_fill) {
+        if Position::enter(Uuid::new_v4(), &input_fill).is_err() {
             Ok(())
         } else {
             Err(String::from(
@@ -1285,7 +1444,7 @@ mod tests {
         };
 
         // Exit Position
-        if let Err(_) = position.exit(current_balance, &input_fill) {
+        if position.exit(current_balance, &input_fill).is_err() {
             Ok(())
         } else {
             Err(String::from(
@@ -1331,7 +1490,7 @@ mod tests {
         };
 
         // Exit Position
-        if let Err(_) = position.exit(current_balance, &input_fill) {
+        if position.exit(current_balance, &input_fill).is_err() {
             Ok(())
         } else {
             Err(String::from(
@@ -1340,6 +1499,242 @@ mod tests {
         }
     }
 
+    #[test]
+    fn enter_long_position_with_stops_and_trigger_stop_loss_on_update() {
+        // Input FillEvent with protective stops
+        let mut input_fill = fill_event();
+        input_fill.decision = Decision::Long;
+        input_fill.quantity = 1.0;
+        input_fill.fill_value_gross = 100.0;
+        input_fill.stops = Some(StopConfig {
+            stop_loss: Some(0.1),
+            take_profit: Some(0.5),
+            trailing_stop: None,
+        });
+
+        let mut position = Position::enter(Uuid::new_v4(), &input_fill).unwrap();
+        let stops = position.stops.unwrap();
+        assert_eq!(stops.stop_loss, Some(90.0));
+        assert_eq!(stops.take_profit, Some(150.0));
+
+        // Input MarketEvent that does not breach any stop
+        let mut input_market = market_event_trade(Side::Buy);
+        if let DataKind::Trade(ref mut trade) = input_market.kind {
+            trade.price = 95.0;
+        }
+        let update = position.update(&input_market).unwrap();
+        assert_eq!(update.stop_triggered, None);
+        assert!(position.stops.is_some());
+
+        // Input MarketEvent that breaches the stop-loss
+        if let DataKind::Trade(ref mut trade) = input_market.kind {
+            trade.price = 89.0;
+        }
+        let update = position.update(&input_market).unwrap();
+        assert_eq!(update.stop_triggered, Some(StopTrigger::StopLoss));
+
+        // Forced exit is not re-triggered while it is pending
+        assert!(position.stops.unwrap().exit_pending);
+        let update = position.update(&input_market).unwrap();
+        assert_eq!(update.stop_triggered, None);
+    }
+
+    #[test]
+    fn increase_long_position_with_stops_rebases_stop_levels() {
+        // Input FillEvent with protective stops
+        let mut input_fill = fill_event();
+        input_fill.decision = Decision::Long;
+        input_fill.quantity = 1.0;
+        input_fill.fill_value_gross = 100.0;
+        input_fill.stops = Some(StopConfig {
+            stop_loss: Some(0.1),
+            take_profit: Some(0.5),
+            trailing_stop: None,
+        });
+        let mut position = Position::enter(Uuid::new_v4(), &input_fill).unwrap();
+
+        // Input FillEvent increasing the Position at a higher price
+        input_fill.fill_value_gross = 300.0;
+        input_fill.stops = None;
+        position.increase(&input_fill).unwrap();
+
+        // Stop levels keep their distance from the new weighted average enter price of 200.0
+        let stops = position.stops.unwrap();
+        assert_eq!(position.enter_avg_price_gross, 200.0);
+        assert_eq!(stops.stop_loss, Some(180.0));
+        assert_eq!(stops.take_profit, Some(300.0));
+    }
+
+    #[test]
+    fn increase_long_position_with_weighted_average_enter_price() {
+        // Initial Position
+        let mut position = position();
+        position.side = Side::Buy;
+        position.quantity = 1.0;
+        position.enter_fees = Fees {
+            exchange: 1.0,
+            slippage: 0.0,
+            network: 0.0,
+        };
+        position.enter_fees_total = 1.0;
+        position.enter_avg_price_gross = 100.0;
+        position.enter_value_gross = 100.0;
+
+        // Input FillEvent
+        let mut input_fill = fill_event();
+        input_fill.decision = Decision::Long;
+        input_fill.quantity = 3.0;
+        input_fill.fill_value_gross = 600.0;
+        input_fill.fees = Fees {
+            exchange: 2.0,
+            slippage: 0.0,
+            network: 0.0,
+        };
+
+        let update = position.increase(&input_fill).unwrap();
+
+        assert_eq!(position.quantity, 4.0);
+        assert_eq!(position.enter_value_gross, 700.0);
+        assert_eq!(position.enter_avg_price_gross, 175.0);
+        assert_eq!(position.enter_fees.exchange, 3.0);
+        assert_eq!(position.enter_fees_total, 3.0);
+        assert_eq!(position.current_symbol_price, 200.0);
+        assert_eq!(position.current_value_gross, 800.0);
+        // current_value_gross - enter_value_gross - 2 * enter_fees_total
+        assert_eq!(position.unrealised_profit_loss, 800.0 - 700.0 - 6.0);
+
+        assert_eq!(update.quantity, 4.0);
+        assert_eq!(update.enter_avg_price_gross, 175.0);
+        assert_eq!(update.realised_profit_loss, 0.0);
+    }
+
+    #[test]
+    fn increase_long_position_with_short_entry_fill_and_return_err() {
+        let mut position = position();
+        position.side = Side::Buy;
+
+        let mut input_fill = fill_event();
+        input_fill.decision = Decision::Short;
+        input_fill.quantity = -1.0;
+
+        assert!(matches!(
+            position.increase(&input_fill),
+            Err(PortfolioError::CannotIncreasePositionWithOppositeSide)
+        ));
+    }
+
+    #[test]
+    fn reduce_short_position_with_attributed_fees_and_realised_pnl() {
+        // Initial Position
+        let mut position = position();
+        position.side = Side::Sell;
+        position.quantity = -4.0;
+        position.enter_fees = Fees {
+            exchange: 4.0,
+            slippage: 0.0,
+            network: 0.0,
+        };
+        position.enter_fees_total = 4.0;
+        position.enter_avg_price_gross = 100.0;
+        position.enter_value_gross = 400.0;
+
+        // Input Portfolio Current Balance
+        let current_balance = Balance {
+            time: Utc::now(),
+            total: 10000.0,
+            available: 10000.0,
+        };
+
+        // Input FillEvent buying back a quarter of the Position
+        let mut input_fill = fill_event();
+        input_fill.decision = Decision::CloseShort;
+        input_fill.quantity = 1.0;
+        input_fill.fill_value_gross = 80.0;
+        input_fill.fees = Fees {
+            exchange: 1.0,
+            slippage: 0.0,
+            network: 0.0,
+        };
+
+        let (reduced, update) = position.reduce(current_balance, &input_fill).unwrap();
+
+        // Reduced portion is exited with a quarter of the enter value & fees attributed
+        assert_eq!(reduced.quantity, -1.0);
+        assert_eq!(reduced.enter_value_gross, 100.0);
+        assert_eq!(reduced.enter_fees_total, 1.0);
+        assert_eq!(reduced.exit_value_gross, 80.0);
+        assert_eq!(reduced.exit_fees_total, 1.0);
+        // enter_value_gross - exit_value_gross - total_fees
+        assert_eq!(reduced.realised_profit_loss, 100.0 - 80.0 - 2.0);
+        assert_eq!(
+            reduced.meta.exit_balance.unwrap().total,
+            current_balance.total + (100.0 - 80.0 - 2.0)
+        );
+
+        // Remaining Position retains the enter price & remaining attributed fees
+        assert_eq!(position.quantity, -3.0);
+        assert_eq!(position.enter_avg_price_gross, 100.0);
+        assert_eq!(position.enter_value_gross, 300.0);
+        assert_eq!(position.enter_fees.exchange, 3.0);
+        assert_eq!(position.enter_fees_total, 3.0);
+        assert_eq!(position.current_value_gross, 240.0);
+
+        // Remaining Position accumulates the realised PnL of the reduced portion
+        assert_eq!(position.realised_profit_loss, 100.0 - 80.0 - 2.0);
+        assert_eq!(position.reduced_enter_value_gross, 100.0);
+
+        assert_eq!(update.quantity, -3.0);
+        assert_eq!(update.realised_profit_loss, 100.0 - 80.0 - 2.0);
+
+        // Input FillEvent buying back the remaining Position
+        input_fill.quantity = 3.0;
+        input_fill.fill_value_gross = 240.0;
+        input_fill.fees.exchange = 3.0;
+        let exit_balance = Balance {
+            total: current_balance.total + (100.0 - 80.0 - 2.0),
+            ..current_balance
+        };
+
+        let exit = position.exit(exit_balance, &input_fill).unwrap();
+
+        // Closed Position realised PnL & return covers the whole entry
+        let exit_profit_loss = 300.0 - 240.0 - 6.0;
+        assert_eq!(
+            exit.realised_profit_loss,
+            (100.0 - 80.0 - 2.0) + exit_profit_loss
+        );
+        assert_eq!(
+            exit.exit_balance.total,
+            exit_balance.total + exit_profit_loss
+        );
+        assert_eq!(
+            position.calculate_profit_loss_return(),
+            ((100.0 - 80.0 - 2.0) + exit_profit_loss) / 400.0
+        );
+    }
+
+    #[test]
+    fn reduce_position_with_full_quantity_exit_fill_and_return_err() {
+        let mut position = position();
+        position.side = Side::Buy;
+        position.quantity = 1.0;
+
+        let mut input_fill = fill_event();
+        input_fill.decision = Decision::CloseLong;
+        input_fill.quantity = -1.0;
+
+        let balance = Balance {
+            time: Utc::now(),
+            total: 100.0,
+            available: 100.0,
+        };
+
+        assert!(matches!(
+            position.reduce(balance, &input_fill),
+            Err(PortfolioError::CannotReducePosition)
+        ));
+    }
+
     #[test]
     fn calculate_avg_price_gross_correctly_with_positive_quantity() {
         let mut input_fill = fill_event();
@@ -1390,7 +1785,7 @@ mod tests {
         input_fill.decision = Decision::CloseLong;
         input_fill.quantity = -1.0;
 
-        if let Err(_) = Position::parse_entry_side(&input_fill) {
+        if Position::parse_entry_side(&input_fill).is_err() {
             Ok(())
         } else {
             Err(String::from(
@@ -1405,7 +1800,7 @@ mod tests {
         input_fill.decision = Decision::CloseShort;
         input_fill.quantity = 1.0;
 
-        if let Err(_) = Position::parse_entry_side(&input_fill) {
+        if Position::parse_entry_side(&input_fill).is_err() {
             Ok(())
         } else {
             Err(String::from(
@@ -1421,7 +1816,7 @@ mod tests {
         input_fill.decision = Decision::Long;
         input_fill.quantity = -1.0;
 
-        if let Err(_) = Position::parse_entry_side(&input_fill) {
+        if Position::parse_entry_side(&input_fill).is_err() {
             Ok(())
         } else {
             Err(String::from(
@@ -1437,7 +1832,7 @@ mod tests {
         input_fill.decision = Decision::Short;
         input_fill.quantity = 1.0;
 
-        if let Err(_) = Position::parse_entry_side(&input_fill) {
+        if Position::parse_entry_side(&input_fill).is_err() {
             Ok(())
         } else {
             Err(String::from(
@@ -1476,7 +1871,7 @@ mod tests {
 
         let expected_pnl = vec![8.0, -12.0, 8.0, -12.0];
 
-        for (position, expected) in inputs.into_iter().zip(expected_pnl.into_iter()) {
+        for (position, expected) in inputs.into_iter().zip(expected_pnl) {
             let actual = position.calculate_unrealised_profit_loss();
             assert_eq!(actual, expected);
         }
@@ -1516,7 +1911,7 @@ mod tests {
 
         let expected_pnl = vec![18.0, -22.0, 18.0, -22.0];
 
-        for (position, expected) in inputs.into_iter().zip(expected_pnl.into_iter()) {
+        for (position, expected) in inputs.into_iter().zip(expected_pnl) {
             let actual = position.calculate_realised_profit_loss();
             assert_eq!(actual, expected);
         }
@@ -1548,7 +1943,7 @@ mod tests {
 
         let expected_return = vec![0.08, -0.12, 0.08, -0.12];
 
-        for (position, expected) in inputs.into_iter().zip(expected_return.into_iter()) {
+        for (position, expected) in inputs.into_iter().zip(expected_return) {
             let actual = position.calculate_profit_loss_return();
             assert_eq!(actual, expected);
         }
@@ -84,7 +84,7 @@ where
         let position = self.get_open_position(position_id)?;
 
         self.conn
-            .del(position_id)
+            .del::<_, ()>(position_id)
             .map_err(|_| RepositoryError::DeleteError)?;
 
         Ok(position)
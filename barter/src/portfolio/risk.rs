@@ -1,16 +1,107 @@
+use crate::portfolio::{position::Position, Balance, OrderEvent, OrderType};
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 
-use crate::portfolio::{OrderEvent, OrderType};
-
 /// Evaluates the risk associated with an [`OrderEvent`] to determine if it should be actioned. It
 /// can also amend the order (eg/ [`OrderType`]) to better fit the risk strategy required for
 /// profitability.
 pub trait OrderEvaluator {
     const DEFAULT_ORDER_TYPE: OrderType;
 
-    /// May return an amended [`OrderEvent`] if the associated risk is appropriate. Returns `None`
-    /// if the risk is too high.
-    fn evaluate_order(&self, order: OrderEvent) -> Option<OrderEvent>;
+    /// May return an amended [`OrderEvent`] if the associated risk is appropriate. Returns an
+    /// [`OrderRejected`] detailing why the [`OrderEvent`] was vetoed if the risk is too high.
+    fn evaluate_order(
+        &mut self,
+        order: OrderEvent,
+        context: RiskContext<'_>,
+    ) -> Result<OrderEvent, OrderRejected>;
+
+    /// Updates any risk state (eg/ daily equity baseline) using the Portfolio state at the time
+    /// of the latest [`MarketEvent`](barter_data::event::MarketEvent). Stateless risk managers
+    /// need not implement this.
+    fn update_from_market(&mut self, _time: DateTime<Utc>, _context: RiskContext<'_>) {}
+}
+
+/// Snapshot of the Portfolio state an [`OrderEvaluator`] uses to evaluate the risk of a proposed
+/// [`OrderEvent`].
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct RiskContext<'a> {
+    /// Current Portfolio [`Balance`].
+    pub balance: Balance,
+    /// Every open [`Position`] held by the Portfolio, across all of it's markets.
+    pub open_positions: &'a [Position],
+}
+
+impl RiskContext<'_> {
+    /// Returns the open [`Position`] associated with the input [`OrderEvent`]'s market, if any.
+    pub fn position(&self, order: &OrderEvent) -> Option<&Position> {
+        self.open_positions.iter().find(|position| {
+            position.exchange == order.exchange && position.instrument == order.instrument
+        })
+    }
+
+    /// Sum of the absolute notional value of every open [`Position`].
+    pub fn gross_exposure(&self) -> f64 {
+        self.open_positions
+            .iter()
+            .map(|position| position.current_value_gross.abs())
+            .sum()
+    }
+
+    /// Sum of the signed notional value of every open [`Position`] (longs +ve, shorts -ve).
+    pub fn net_exposure(&self) -> f64 {
+        self.open_positions.iter().map(signed_notional).sum()
+    }
+
+    /// Portfolio equity, being the total [`Balance`] plus the unrealised profit & loss of every
+    /// open [`Position`].
+    pub fn equity(&self) -> f64 {
+        self.balance.total
+            + self
+                .open_positions
+                .iter()
+                .map(|position| position.unrealised_profit_loss)
+                .sum::<f64>()
+    }
+}
+
+/// [`OrderEvent`] vetoed by an [`OrderEvaluator`], along with the [`RejectionReason`].
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OrderRejected {
+    pub time: DateTime<Utc>,
+    pub order: OrderEvent,
+    pub reason: RejectionReason,
+}
+
+impl OrderRejected {
+    /// Constructs a new [`OrderRejected`] for the input [`OrderEvent`] & [`RejectionReason`].
+    pub fn new(order: OrderEvent, reason: RejectionReason) -> Self {
+        Self {
+            time: Utc::now(),
+            order,
+            reason,
+        }
+    }
+}
+
+/// Reason an [`OrderEvaluator`] vetoed an [`OrderEvent`].
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub enum RejectionReason {
+    /// [`OrderEvent`] notional value exceeds the max notional per order.
+    MaxOrderNotional { notional: f64, limit: f64 },
+    /// Resulting [`Position`] notional value exceeds the max notional per market.
+    MaxPositionNotional { notional: f64, limit: f64 },
+    /// Resulting Portfolio gross exposure exceeds the max gross exposure.
+    MaxGrossExposure { exposure: f64, limit: f64 },
+    /// Resulting absolute Portfolio net exposure exceeds the max net exposure.
+    MaxNetExposure { exposure: f64, limit: f64 },
+    /// Portfolio already holds the max number of open [`Position`]s.
+    MaxOpenPositions { open: usize, limit: usize },
+    /// Loss since the start of the trading day has reached the daily loss limit, tripping the
+    /// kill switch.
+    DailyLossLimit { loss: f64, limit: f64 },
+    /// Kill switch has been tripped, so no further exposure may be taken on.
+    KillSwitch,
 }
 
 /// Default risk manager that implements [`OrderEvaluator`].
@@ -20,17 +111,456 @@ pub struct DefaultRisk {}
 impl OrderEvaluator for DefaultRisk {
     const DEFAULT_ORDER_TYPE: OrderType = OrderType::Market;
 
-    fn evaluate_order(&self, mut order: OrderEvent) -> Option<OrderEvent> {
-        if self.risk_too_high(&order) {
-            return None;
-        }
+    fn evaluate_order(
+        &mut self,
+        mut order: OrderEvent,
+        _: RiskContext<'_>,
+    ) -> Result<OrderEvent, OrderRejected> {
         order.order_type = DefaultRisk::DEFAULT_ORDER_TYPE;
-        Some(order)
+        Ok(order)
+    }
+}
+
+/// Configurable limits enforced by a [`LimitRiskManager`]. A limit of `None` is not enforced.
+///
+/// Notional values & losses are denominated in the quote currency of the Portfolio [`Balance`].
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+pub struct RiskLimits {
+    /// Max notional value of a single [`OrderEvent`].
+    pub max_order_notional: Option<f64>,
+    /// Max notional value of the [`Position`] held in a single market.
+    pub max_position_notional: Option<f64>,
+    /// Max sum of the absolute notional value of every open [`Position`].
+    pub max_gross_exposure: Option<f64>,
+    /// Max absolute sum of the signed notional value of every open [`Position`].
+    pub max_net_exposure: Option<f64>,
+    /// Max number of open [`Position`]s held across the Portfolio.
+    pub max_open_positions: Option<usize>,
+    /// Max loss of Portfolio equity since the start of the (UTC) trading day. Reaching it trips
+    /// the kill switch.
+    pub max_daily_loss: Option<f64>,
+}
+
+/// Risk manager that implements [`OrderEvaluator`] by enforcing the configured [`RiskLimits`].
+///
+/// Limits are only applied to entry [`OrderEvent`]s that take on exposure - exit
+/// [`OrderEvent`]s always pass so that risk can be reduced. The daily loss is measured from the
+/// Portfolio equity at the first market update of each (UTC) trading day, and is checked on every
+/// market update & [`OrderEvent`]. Once the daily loss limit is reached the kill switch trips,
+/// and every entry [`OrderEvent`] is rejected until it is manually reset via
+/// [`LimitRiskManager::reset_kill_switch`].
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct LimitRiskManager {
+    limits: RiskLimits,
+    kill_switch: bool,
+    trading_day: Option<NaiveDate>,
+    trading_day_start_equity: f64,
+}
+
+impl OrderEvaluator for LimitRiskManager {
+    const DEFAULT_ORDER_TYPE: OrderType = OrderType::Market;
+
+    fn evaluate_order(
+        &mut self,
+        mut order: OrderEvent,
+        context: RiskContext<'_>,
+    ) -> Result<OrderEvent, OrderRejected> {
+        // Keep the kill switch current for every OrderEvent, including exits
+        self.update_trading_day(order.market_meta.time, &context);
+        let daily_loss = self.check_daily_loss(&context);
+
+        if order.decision.is_entry() {
+            if let Some(reason) = daily_loss.or_else(|| self.check_limits(&order, &context)) {
+                return Err(OrderRejected::new(order, reason));
+            }
+        }
+
+        order.order_type = LimitRiskManager::DEFAULT_ORDER_TYPE;
+        Ok(order)
+    }
+
+    fn update_from_market(&mut self, time: DateTime<Utc>, context: RiskContext<'_>) {
+        // Track the Portfolio equity at the start of the trading day for the daily loss limit
+        self.update_trading_day(time, &context);
+        self.check_daily_loss(&context);
+    }
+}
+
+impl LimitRiskManager {
+    /// Constructs a new [`LimitRiskManager`] enforcing the provided [`RiskLimits`].
+    pub fn new(limits: RiskLimits) -> Self {
+        Self {
+            limits,
+            kill_switch: false,
+            trading_day: None,
+            trading_day_start_equity: 0.0,
+        }
+    }
+
+    /// Determines if the kill switch has been tripped.
+    pub fn is_killed(&self) -> bool {
+        self.kill_switch
+    }
+
+    /// Resets a tripped kill switch so that entry [`OrderEvent`]s are evaluated again.
+    pub fn reset_kill_switch(&mut self) {
+        self.kill_switch = false;
+    }
+
+    /// Resets the trading day start equity if the input time is on a new (UTC) trading day.
+    fn update_trading_day(&mut self, time: DateTime<Utc>, context: &RiskContext<'_>) {
+        let day = time.date_naive();
+        if self.trading_day != Some(day) {
+            self.trading_day = Some(day);
+            self.trading_day_start_equity = context.equity();
+        }
+    }
+
+    /// Trips the kill switch if the daily loss limit has been reached, returning the
+    /// [`RejectionReason`] for any entry [`OrderEvent`] while the kill switch is tripped.
+    fn check_daily_loss(&mut self, context: &RiskContext<'_>) -> Option<RejectionReason> {
+        if self.kill_switch {
+            return Some(RejectionReason::KillSwitch);
+        }
+
+        let limit = self.limits.max_daily_loss?;
+        let loss = self.trading_day_start_equity - context.equity();
+        if loss >= limit {
+            self.kill_switch = true;
+            return Some(RejectionReason::DailyLossLimit { loss, limit });
+        }
+
+        None
+    }
+
+    /// Returns the [`RejectionReason`] for the first [`RiskLimits`] breached by the input entry
+    /// [`OrderEvent`], if any.
+    fn check_limits(
+        &self,
+        order: &OrderEvent,
+        context: &RiskContext<'_>,
+    ) -> Option<RejectionReason> {
+        let order_notional = order.quantity * order.market_meta.close;
+        let position = context.position(order);
+
+        if let Some(limit) = self.limits.max_order_notional {
+            let notional = order_notional.abs();
+            if notional > limit {
+                return Some(RejectionReason::MaxOrderNotional { notional, limit });
+            }
+        }
+
+        if let Some(limit) = self.limits.max_position_notional {
+            let notional = (position.map_or(0.0, signed_notional) + order_notional).abs();
+            if notional > limit {
+                return Some(RejectionReason::MaxPositionNotional { notional, limit });
+            }
+        }
+
+        if let Some(limit) = self.limits.max_gross_exposure {
+            let exposure = context.gross_exposure() + order_notional.abs();
+            if exposure > limit {
+                return Some(RejectionReason::MaxGrossExposure { exposure, limit });
+            }
+        }
+
+        if let Some(limit) = self.limits.max_net_exposure {
+            let exposure = (context.net_exposure() + order_notional).abs();
+            if exposure > limit {
+                return Some(RejectionReason::MaxNetExposure { exposure, limit });
+            }
+        }
+
+        if let Some(limit) = self.limits.max_open_positions {
+            let open = context.open_positions.len();
+            if position.is_none() && open >= limit {
+                return Some(RejectionReason::MaxOpenPositions { open, limit });
+            }
+        }
+
+        None
     }
 }
 
-impl DefaultRisk {
-    fn risk_too_high(&self, _: &OrderEvent) -> bool {
-        false
+/// Signed notional value of a [`Position`] (longs +ve, shorts -ve).
+fn signed_notional(position: &Position) -> f64 {
+    position.quantity.signum() * position.current_value_gross.abs()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        data::MarketMeta,
+        strategy::Decision,
+        test_util::{order_event, position},
+    };
+    use barter_integration::model::Side;
+    use chrono::Duration;
+
+    fn balance(total: f64) -> Balance {
+        Balance {
+            time: Utc::now(),
+            total,
+            available: total,
+        }
+    }
+
+    fn order(decision: Decision, quantity: f64, close: f64) -> OrderEvent {
+        OrderEvent {
+            decision,
+            quantity,
+            market_meta: MarketMeta {
+                close,
+                time: Utc::now(),
+            },
+            ..order_event()
+        }
+    }
+
+    fn open_position(exchange: &'static str, quantity: f64, value: f64, pnl: f64) -> Position {
+        let mut position = position();
+        position.exchange = exchange.into();
+        position.side = if quantity > 0.0 {
+            Side::Buy
+        } else {
+            Side::Sell
+        };
+        position.quantity = quantity;
+        position.current_value_gross = value;
+        position.unrealised_profit_loss = pnl;
+        position
+    }
+
+    #[test]
+    fn evaluate_order_with_limits_not_breached() {
+        let mut risk = LimitRiskManager::new(RiskLimits {
+            max_order_notional: Some(1000.0),
+            max_position_notional: Some(1000.0),
+            max_gross_exposure: Some(1000.0),
+            max_net_exposure: Some(1000.0),
+            max_open_positions: Some(1),
+            max_daily_loss: Some(100.0),
+        });
+
+        let context = RiskContext {
+            balance: balance(1000.0),
+            open_positions: &[],
+        };
+
+        let actual = risk.evaluate_order(order(Decision::Long, 10.0, 100.0), context);
+
+        assert_eq!(actual.unwrap().order_type, OrderType::Market);
+    }
+
+    #[test]
+    fn evaluate_order_rejects_entry_orders_that_breach_limits() {
+        struct TestCase {
+            limits: RiskLimits,
+            open_positions: Vec<Position>,
+            order: OrderEvent,
+            expected: RejectionReason,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: order notional too large
+                limits: RiskLimits {
+                    max_order_notional: Some(500.0),
+                    ..RiskLimits::default()
+                },
+                open_positions: vec![],
+                order: order(Decision::Long, 10.0, 100.0),
+                expected: RejectionReason::MaxOrderNotional {
+                    notional: 1000.0,
+                    limit: 500.0,
+                },
+            },
+            TestCase {
+                // TC1: increased market position too large
+                limits: RiskLimits {
+                    max_position_notional: Some(1000.0),
+                    ..RiskLimits::default()
+                },
+                open_positions: vec![open_position("binance", 8.0, 800.0, 0.0)],
+                order: order(Decision::Long, 4.0, 100.0),
+                expected: RejectionReason::MaxPositionNotional {
+                    notional: 1200.0,
+                    limit: 1000.0,
+                },
+            },
+            TestCase {
+                // TC2: gross exposure too large, despite opposing sides netting off
+                limits: RiskLimits {
+                    max_gross_exposure: Some(1000.0),
+                    max_net_exposure: Some(1000.0),
+                    ..RiskLimits::default()
+                },
+                open_positions: vec![open_position("ftx", 8.0, 800.0, 0.0)],
+                order: order(Decision::Short, -4.0, 100.0),
+                expected: RejectionReason::MaxGrossExposure {
+                    exposure: 1200.0,
+                    limit: 1000.0,
+                },
+            },
+            TestCase {
+                // TC3: net short exposure too large
+                limits: RiskLimits {
+                    max_net_exposure: Some(1000.0),
+                    ..RiskLimits::default()
+                },
+                open_positions: vec![open_position("ftx", -8.0, 800.0, 0.0)],
+                order: order(Decision::Short, -4.0, 100.0),
+                expected: RejectionReason::MaxNetExposure {
+                    exposure: 1200.0,
+                    limit: 1000.0,
+                },
+            },
+            TestCase {
+                // TC4: too many open positions
+                limits: RiskLimits {
+                    max_open_positions: Some(1),
+                    ..RiskLimits::default()
+                },
+                open_positions: vec![open_position("ftx", 1.0, 100.0, 0.0)],
+                order: order(Decision::Long, 1.0, 100.0),
+                expected: RejectionReason::MaxOpenPositions { open: 1, limit: 1 },
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let mut risk = LimitRiskManager::new(test.limits);
+            let context = RiskContext {
+                balance: balance(1000.0),
+                open_positions: &test.open_positions,
+            };
+
+            let actual = risk.evaluate_order(test.order.clone(), context);
+
+            match actual {
+                Err(rejected) => {
+                    assert_eq!(rejected.order, test.order, "TC{} failed", index);
+                    assert_eq!(rejected.reason, test.expected, "TC{} failed", index);
+                }
+                Ok(order) => panic!("TC{} failed, expected rejection: {:?}", index, order),
+            }
+        }
+    }
+
+    #[test]
+    fn evaluate_order_allows_exit_orders_that_breach_limits() {
+        let mut risk = LimitRiskManager::new(RiskLimits {
+            max_order_notional: Some(1.0),
+            max_open_positions: Some(0),
+            ..RiskLimits::default()
+        });
+
+        let open_positions = vec![open_position("binance", 10.0, 1000.0, 0.0)];
+        let context = RiskContext {
+            balance: balance(1000.0),
+            open_positions: &open_positions,
+        };
+
+        let actual = risk.evaluate_order(order(Decision::CloseLong, -10.0, 100.0), context);
+
+        assert!(actual.is_ok());
+    }
+
+    #[test]
+    fn evaluate_order_trips_kill_switch_when_daily_loss_limit_reached() {
+        let mut risk = LimitRiskManager::new(RiskLimits {
+            max_daily_loss: Some(100.0),
+            ..RiskLimits::default()
+        });
+
+        // Start of trading day equity is 1000.0
+        let context = RiskContext {
+            balance: balance(1000.0),
+            open_positions: &[],
+        };
+        assert!(risk
+            .evaluate_order(order(Decision::Long, 1.0, 100.0), context)
+            .is_ok());
+
+        // Equity falls by 150.0 due to an unrealised loss
+        let open_positions = vec![open_position("binance", 1.0, 100.0, -150.0)];
+        let context = RiskContext {
+            balance: balance(1000.0),
+            open_positions: &open_positions,
+        };
+        let actual = risk.evaluate_order(order(Decision::Long, 1.0, 100.0), context);
+        assert_eq!(
+            actual.unwrap_err().reason,
+            RejectionReason::DailyLossLimit {
+                loss: 150.0,
+                limit: 100.0
+            }
+        );
+        assert!(risk.is_killed());
+
+        // Kill switch remains tripped on the next trading day, but exits are allowed
+        let mut next_day_order = order(Decision::Long, 1.0, 100.0);
+        next_day_order.market_meta.time = Utc::now() + Duration::days(1);
+        let actual = risk.evaluate_order(next_day_order, context);
+        assert_eq!(actual.unwrap_err().reason, RejectionReason::KillSwitch);
+        assert!(risk
+            .evaluate_order(order(Decision::CloseLong, -1.0, 100.0), context)
+            .is_ok());
+
+        // Kill switch can be manually reset
+        risk.reset_kill_switch();
+        assert!(!risk.is_killed());
+    }
+
+    #[test]
+    fn update_from_market_tracks_daily_loss_from_start_of_trading_day() {
+        let mut risk = LimitRiskManager::new(RiskLimits {
+            max_daily_loss: Some(100.0),
+            ..RiskLimits::default()
+        });
+        let start_of_day = Utc::now();
+
+        // First market update of the trading day sets the start equity to 1000.0
+        let context = RiskContext {
+            balance: balance(1000.0),
+            open_positions: &[],
+        };
+        risk.update_from_market(start_of_day, context);
+
+        // Equity falls by 150.0 before the first OrderEvent of the day, tripping the kill switch
+        let open_positions = vec![open_position("binance", 1.0, 100.0, -150.0)];
+        let context = RiskContext {
+            balance: balance(1000.0),
+            open_positions: &open_positions,
+        };
+        risk.update_from_market(start_of_day, context);
+        assert!(risk.is_killed());
+
+        let actual = risk.evaluate_order(order(Decision::Long, 1.0, 100.0), context);
+        assert_eq!(actual.unwrap_err().reason, RejectionReason::KillSwitch);
+    }
+
+    #[test]
+    fn evaluate_order_trips_kill_switch_on_exit_orders() {
+        let mut risk = LimitRiskManager::new(RiskLimits {
+            max_daily_loss: Some(100.0),
+            ..RiskLimits::default()
+        });
+
+        let context = RiskContext {
+            balance: balance(1000.0),
+            open_positions: &[],
+        };
+        risk.update_from_market(Utc::now(), context);
+
+        // Exit OrderEvent is allowed, but the breached daily loss limit trips the kill switch
+        let context = RiskContext {
+            balance: balance(850.0),
+            open_positions: &[],
+        };
+        assert!(risk
+            .evaluate_order(order(Decision::CloseLong, -1.0, 100.0), context)
+            .is_ok());
+        assert!(risk.is_killed());
     }
 }
@@ -0,0 +1,286 @@
+use barter_integration::model::Side;
+use serde::{Deserialize, Serialize};
+
+/// Protective exit configuration for a new [`Position`](super::position::Position), provided by a
+/// [`Signal`](crate::strategy::Signal) or an [`OrderAllocator`](super::allocator::OrderAllocator).
+///
+/// Each distance is a fraction of the [`Position`](super::position::Position) entry price
+/// (eg/ 0.05 for 5%), since the entry price is not known until the entry is filled.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+pub struct StopConfig {
+    /// Distance of the fixed stop-loss below (long) or above (short) the entry price.
+    pub stop_loss: Option<f64>,
+    /// Distance of the fixed take-profit above (long) or below (short) the entry price.
+    pub take_profit: Option<f64>,
+    /// Distance of the trailing stop below (long) or above (short) the most favourable price
+    /// since entry.
+    pub trailing_stop: Option<f64>,
+}
+
+/// Protective exit price levels of an open [`Position`](super::position::Position), derived
+/// from a [`StopConfig`] on entry.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct PositionStops {
+    /// Price at which a stop-loss exit is triggered.
+    pub stop_loss: Option<f64>,
+    /// Price at which a take-profit exit is triggered.
+    pub take_profit: Option<f64>,
+    /// Trailing stop that follows the most favourable price since entry.
+    pub trailing_stop: Option<TrailingStop>,
+    /// Set once a breached level has triggered a forced exit, suppressing further triggers
+    /// until the exit order fails or only partially exits the Position.
+    #[serde(default)]
+    pub exit_pending: bool,
+}
+
+/// Trailing stop that follows the most favourable price since entry at a fixed distance.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct TrailingStop {
+    /// Distance as a fraction of the extreme_price (eg/ 0.05 for 5%).
+    pub distance: f64,
+    /// Most favourable price since entry (highest for long, lowest for short).
+    pub extreme_price: f64,
+}
+
+/// Protective exit level breached by the latest market price.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub enum StopTrigger {
+    StopLoss,
+    TakeProfit,
+    TrailingStop,
+}
+
+impl PositionStops {
+    /// Constructs the [`PositionStops`] price levels using the provided [`StopConfig`], and the
+    /// [`Side`] & entry price of the associated [`Position`](super::position::Position).
+    pub fn new(config: StopConfig, side: Side, entry_price: f64) -> Self {
+        // Direction of favourable price movement for the Position Side
+        let direction = match side {
+            Side::Buy => 1.0,
+            Side::Sell => -1.0,
+        };
+
+        Self {
+            stop_loss: config
+                .stop_loss
+                .map(|distance| entry_price * (1.0 - direction * distance)),
+            take_profit: config
+                .take_profit
+                .map(|distance| entry_price * (1.0 + direction * distance)),
+            trailing_stop: config.trailing_stop.map(|distance| TrailingStop {
+                distance,
+                extreme_price: entry_price,
+            }),
+            exit_pending: false,
+        }
+    }
+
+    /// Moves the fixed stop-loss & take-profit levels to a new entry price (eg/ after the
+    /// [`Position`](super::position::Position) is increased), keeping their fractional distance
+    /// from the entry price. The trailing stop continues to follow the most favourable price.
+    pub fn rebase(&mut self, prev_entry_price: f64, entry_price: f64) {
+        let scale = entry_price / prev_entry_price;
+        self.stop_loss = self.stop_loss.map(|level| level * scale);
+        self.take_profit = self.take_profit.map(|level| level * scale);
+    }
+
+    /// Updates the trailing stop using the latest price, and determines if the latest price has
+    /// breached any of the protective exit levels. Only the first breach triggers a forced exit,
+    /// until [`PositionStops::exit_pending`] is cleared.
+    pub fn update(&mut self, side: Side, price: f64) -> Option<StopTrigger> {
+        if let Some(trailing_stop) = &mut self.trailing_stop {
+            trailing_stop.update(side, price);
+        }
+
+        // Forced exit already triggered & awaiting it's exit FillEvent or failure
+        if self.exit_pending {
+            return None;
+        }
+
+        let adverse = |level: f64| match side {
+            Side::Buy => price <= level,
+            Side::Sell => price >= level,
+        };
+        let favourable = |level: f64| match side {
+            Side::Buy => price >= level,
+            Side::Sell => price <= level,
+        };
+
+        let trigger = if self.stop_loss.is_some_and(adverse) {
+            Some(StopTrigger::StopLoss)
+        } else if self.take_profit.is_some_and(favourable) {
+            Some(StopTrigger::TakeProfit)
+        } else if self
+            .trailing_stop
+            .is_some_and(|trailing_stop| adverse(trailing_stop.price(side)))
+        {
+            Some(StopTrigger::TrailingStop)
+        } else {
+            None
+        };
+
+        self.exit_pending = trigger.is_some();
+        trigger
+    }
+}
+
+impl TrailingStop {
+    /// Current trailing stop price for a [`Position`](super::position::Position) of the
+    /// provided [`Side`].
+    pub fn price(&self, side: Side) -> f64 {
+        match side {
+            Side::Buy => self.extreme_price * (1.0 - self.distance),
+            Side::Sell => self.extreme_price * (1.0 + self.distance),
+        }
+    }
+
+    /// Moves the extreme_price to the latest price if it is more favourable.
+    fn update(&mut self, side: Side, price: f64) {
+        self.extreme_price = match side {
+            Side::Buy => self.extreme_price.max(price),
+            Side::Sell => self.extreme_price.min(price),
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn position_stops_trigger_on_breached_levels() {
+        struct TestCase {
+            side: Side,
+            config: StopConfig,
+            prices: Vec<f64>,
+            expected: Vec<Option<StopTrigger>>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: long stop-loss
+                side: Side::Buy,
+                config: StopConfig {
+                    stop_loss: Some(0.1),
+                    ..StopConfig::default()
+                },
+                prices: vec![95.0, 90.0],
+                expected: vec![None, Some(StopTrigger::StopLoss)],
+            },
+            TestCase {
+                // TC1: short stop-loss
+                side: Side::Sell,
+                config: StopConfig {
+                    stop_loss: Some(0.1),
+                    ..StopConfig::default()
+                },
+                prices: vec![105.0, 111.0],
+                expected: vec![None, Some(StopTrigger::StopLoss)],
+            },
+            TestCase {
+                // TC2: long take-profit
+                side: Side::Buy,
+                config: StopConfig {
+                    take_profit: Some(0.2),
+                    ..StopConfig::default()
+                },
+                prices: vec![110.0, 120.0],
+                expected: vec![None, Some(StopTrigger::TakeProfit)],
+            },
+            TestCase {
+                // TC3: short take-profit
+                side: Side::Sell,
+                config: StopConfig {
+                    take_profit: Some(0.2),
+                    ..StopConfig::default()
+                },
+                prices: vec![90.0, 79.0],
+                expected: vec![None, Some(StopTrigger::TakeProfit)],
+            },
+            TestCase {
+                // TC4: long trailing stop follows the highest price
+                side: Side::Buy,
+                config: StopConfig {
+                    trailing_stop: Some(0.1),
+                    ..StopConfig::default()
+                },
+                prices: vec![95.0, 150.0, 136.0, 135.0],
+                expected: vec![None, None, None, Some(StopTrigger::TrailingStop)],
+            },
+            TestCase {
+                // TC5: short trailing stop follows the lowest price
+                side: Side::Sell,
+                config: StopConfig {
+                    trailing_stop: Some(0.1),
+                    ..StopConfig::default()
+                },
+                prices: vec![105.0, 50.0, 54.0, 56.0],
+                expected: vec![None, None, None, Some(StopTrigger::TrailingStop)],
+            },
+            TestCase {
+                // TC6: stop-loss takes priority over a breached trailing stop
+                side: Side::Buy,
+                config: StopConfig {
+                    stop_loss: Some(0.1),
+                    take_profit: None,
+                    trailing_stop: Some(0.05),
+                },
+                prices: vec![80.0],
+                expected: vec![Some(StopTrigger::StopLoss)],
+            },
+            TestCase {
+                // TC7: breached stop-loss only triggers once while the exit is pending
+                side: Side::Buy,
+                config: StopConfig {
+                    stop_loss: Some(0.1),
+                    ..StopConfig::default()
+                },
+                prices: vec![90.0, 85.0, 80.0],
+                expected: vec![Some(StopTrigger::StopLoss), None, None],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let mut stops = PositionStops::new(test.config, test.side, 100.0);
+            let actual = test
+                .prices
+                .into_iter()
+                .map(|price| stops.update(test.side, price))
+                .collect::<Vec<_>>();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn position_stops_retrigger_once_exit_pending_cleared() {
+        let config = StopConfig {
+            stop_loss: Some(0.1),
+            ..StopConfig::default()
+        };
+
+        let mut stops = PositionStops::new(config, Side::Buy, 100.0);
+        assert_eq!(stops.update(Side::Buy, 90.0), Some(StopTrigger::StopLoss));
+        assert!(stops.exit_pending);
+        assert_eq!(stops.update(Side::Buy, 90.0), None);
+
+        // Failed exit clears the pending exit, re-arming the stops
+        stops.exit_pending = false;
+        assert_eq!(stops.update(Side::Buy, 90.0), Some(StopTrigger::StopLoss));
+    }
+
+    #[test]
+    fn position_stops_rebase_to_new_entry_price() {
+        let config = StopConfig {
+            stop_loss: Some(0.5),
+            take_profit: Some(0.25),
+            trailing_stop: Some(0.05),
+        };
+
+        let mut stops = PositionStops::new(config, Side::Sell, 100.0);
+        stops.rebase(100.0, 50.0);
+
+        assert_eq!(stops.stop_loss, Some(75.0));
+        assert_eq!(stops.take_profit, Some(37.5));
+        assert_eq!(stops.trailing_stop.unwrap().extreme_price, 100.0);
+    }
+}
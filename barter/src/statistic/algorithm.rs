@@ -50,7 +50,7 @@ mod tests {
             count: f64,
         }
 
-        let inputs = vec![
+        let inputs = [
             Input {
                 prev_mean: 0.0,
                 next_value: 0.1,
@@ -85,7 +85,7 @@ mod tests {
 
         let expected = vec![0.1, -0.05, -0.05, 0.0125, 0.04, 0.05];
 
-        for (input, expected) in inputs.iter().zip(expected.into_iter()) {
+        for (input, expected) in inputs.iter().zip(expected) {
             let actual =
                 welford_online::calculate_mean(input.prev_mean, input.next_value, input.count);
             let mean_diff = actual - expected;
@@ -175,7 +175,7 @@ mod tests {
             16200000000.0,
         ];
 
-        for (input, expected) in inputs.iter().zip(expected.into_iter()) {
+        for (input, expected) in inputs.iter().zip(expected) {
             let actual_m = welford_online::calculate_recurrence_relation_m(
                 input.prev_m,
                 input.prev_mean,
@@ -190,7 +190,7 @@ mod tests {
     #[test]
     fn calculate_sample_variance() {
         // fn calculate_sample_variance(recurrence_relation_m: f64, count: u64) -> f64
-        let inputs = vec![
+        let inputs = [
             (0.0, 1),
             (1050.0, 5),
             (1012.5, 123223),
@@ -205,7 +205,7 @@ mod tests {
             4.304592996427187,
         ];
 
-        for (input, expected) in inputs.iter().zip(expected.into_iter()) {
+        for (input, expected) in inputs.iter().zip(expected) {
             let actual_variance = welford_online::calculate_sample_variance(input.0, input.1);
             assert_eq!(actual_variance, expected);
         }
@@ -214,7 +214,7 @@ mod tests {
     #[test]
     fn calculate_population_variance() {
         // fn calculate_population_variance(recurrence_relation_m: f64, count: u64) -> f64
-        let inputs = vec![
+        let inputs = [
             (0.0, 1),
             (1050.0, 5),
             (1012.5, 123223),
@@ -229,7 +229,7 @@ mod tests {
             4.304407709194215,
         ];
 
-        for (input, expected) in inputs.iter().zip(expected.into_iter()) {
+        for (input, expected) in inputs.iter().zip(expected) {
             let actual_variance = welford_online::calculate_population_variance(input.0, input.1);
             assert_eq!(actual_variance, expected);
         }
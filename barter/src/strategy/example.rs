@@ -47,6 +47,7 @@ impl SignalGenerator for RSIStrategy {
                 time: market.exchange_time,
             },
             signals,
+            stops: None,
         })
     }
 }
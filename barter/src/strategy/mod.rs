@@ -1,4 +1,4 @@
-use crate::data::MarketMeta;
+use crate::{data::MarketMeta, portfolio::stop::StopConfig};
 use barter_data::event::{DataKind, MarketEvent};
 use barter_integration::model::{instrument::Instrument, Exchange, Market};
 use chrono::{DateTime, Utc};
@@ -14,6 +14,26 @@ pub trait SignalGenerator {
     fn generate_signal(&mut self, market: &MarketEvent<Instrument, DataKind>) -> Option<Signal>;
 }
 
+/// May generate advisory [`Signal`]s for any [`Market`] as a result of analysing an input
+/// [`MarketEvent`] from the merged feed of every [`Market`] a portfolio-level strategy trades.
+///
+/// Enables multi-leg & cross-market strategies such as pairs trading, basis trades and
+/// cross-sectional momentum, where a single strategy instance must see every [`Market`] it trades.
+/// Every single-market [`SignalGenerator`] is also a [`PortfolioSignalGenerator`].
+pub trait PortfolioSignalGenerator {
+    /// Return the [`Signal`]s (for any [`Market`]) generated given the input [`MarketEvent`].
+    fn generate_signals(&mut self, market: &MarketEvent<Instrument, DataKind>) -> Vec<Signal>;
+}
+
+impl<Strategy> PortfolioSignalGenerator for Strategy
+where
+    Strategy: SignalGenerator,
+{
+    fn generate_signals(&mut self, market: &MarketEvent<Instrument, DataKind>) -> Vec<Signal> {
+        self.generate_signal(market).into_iter().collect()
+    }
+}
+
 /// Advisory [`Signal`] for a [`Market`] detailing the [`SignalStrength`] associated with each
 /// possible [`Decision`]. Interpreted by an [`OrderGenerator`](crate::portfolio::OrderGenerator).
 #[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
@@ -24,23 +44,23 @@ pub struct Signal {
     pub signals: HashMap<Decision, SignalStrength>,
     /// Metadata propagated from the [`MarketEvent`] that yielded this [`Signal`].
     pub market_meta: MarketMeta,
+    /// Protective exits for a [`Position`](crate::portfolio::position::Position) entered as a
+    /// result of this [`Signal`].
+    pub stops: Option<StopConfig>,
 }
 
 /// Describes the type of advisory signal the strategy is endorsing.
-#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
 pub enum Decision {
+    #[default]
     Long,
     CloseLong,
     Short,
     CloseShort,
 }
 
-impl Default for Decision {
-    fn default() -> Self {
-        Self::Long
-    }
-}
-
 impl Decision {
     /// Determines if a [`Decision`] is Long.
     pub fn is_long(&self) -> bool {
@@ -110,48 +130,48 @@ mod tests {
     #[test]
     fn should_return_decision_is_long() {
         let decision = Decision::Long;
-        assert_eq!(decision.is_long(), true)
+        assert!(decision.is_long())
     }
 
     #[test]
     fn should_return_decision_is_not_long() {
         let decision = Decision::Short;
-        assert_eq!(decision.is_long(), false)
+        assert!(!decision.is_long())
     }
 
     #[test]
     fn should_return_decision_is_short() {
         let decision = Decision::Short;
-        assert_eq!(decision.is_short(), true)
+        assert!(decision.is_short())
     }
 
     #[test]
     fn should_return_decision_is_not_short() {
         let decision = Decision::Long;
-        assert_eq!(decision.is_short(), false)
+        assert!(!decision.is_short())
     }
 
     #[test]
     fn should_return_decision_is_entry() {
         let decision = Decision::Long;
-        assert_eq!(decision.is_entry(), true)
+        assert!(decision.is_entry())
     }
 
     #[test]
     fn should_return_decision_is_not_entry() {
         let decision = Decision::CloseLong;
-        assert_eq!(decision.is_entry(), false)
+        assert!(!decision.is_entry())
     }
 
     #[test]
     fn should_return_decision_is_exit() {
         let decision = Decision::CloseShort;
-        assert_eq!(decision.is_exit(), true)
+        assert!(decision.is_exit())
     }
 
     #[test]
     fn should_return_decision_is_not_exit() {
         let decision = Decision::Long;
-        assert_eq!(decision.is_exit(), false)
+        assert!(!decision.is_exit())
     }
 }
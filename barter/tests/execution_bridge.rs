@@ -0,0 +1,316 @@
+use barter::{
+    data::{historical, MarketMeta},
+    engine::trader::Trader,
+    event::{Event, EventTx},
+    execution::{
+        bridge::{BridgeVenue, ExecutionBridge},
+        ExecutionClient, FillEvent,
+    },
+    portfolio::{
+        allocator::DefaultAllocator, portfolio::MetaPortfolio,
+        repository::in_memory::InMemoryRepository, risk::DefaultRisk, OrderType,
+    },
+    statistic::summary::trading::{Config as StatisticConfig, TradingSummary},
+    strategy::{Decision, PortfolioSignalGenerator, Signal, SignalStrength},
+    test_util::order_event,
+};
+use barter_data::{
+    event::{DataKind, MarketEvent},
+    subscription::{
+        book::{Level, OrderBookL1},
+        trade::PublicTrade,
+    },
+};
+use barter_execution::{
+    model::{balance::Balance, AccountEvent},
+    simulated::{
+        exchange::{
+            account::{balance::ClientBalances, latency::LatencyModel, ClientAccount},
+            clock::SimulatedClock,
+            SimulatedExchange,
+        },
+        execution::SimulatedExecution,
+        SimulatedEvent,
+    },
+};
+use barter_integration::model::{
+    instrument::{kind::InstrumentKind, symbol::Symbol, Instrument},
+    Exchange, Market, Side,
+};
+use chrono::{DateTime, Utc};
+use parking_lot::Mutex;
+use std::{collections::HashMap, sync::Arc, time::Duration};
+use tokio::sync::mpsc;
+
+#[test]
+fn execution_bridge_generates_fill_from_inline_simulated_exchange_trades() {
+    let start = Utc::now();
+    let (event_account_tx, event_account_rx) = mpsc::unbounded_channel();
+    let mut bridge = ExecutionBridge::simulated(
+        simulated_exchange(start, event_account_tx),
+        event_account_rx,
+    )
+    .unwrap();
+
+    // Execute a Long Limit OrderEvent priced at the MarketMeta close
+    let mut order = order_event();
+    order.instrument = instrument();
+    order.decision = Decision::Long;
+    order.quantity = 2.0;
+    order.order_type = OrderType::Limit;
+    order.market_meta.close = 1000.0;
+    assert!(bridge.execute_order(&order).unwrap().is_empty());
+
+    // Open request is in flight until a MarketEvent advances the clock past it's latency
+    assert!(bridge.next_fills().is_empty());
+    assert!(bridge.has_pending_orders());
+
+    // Two PublicTrades each partially fill the order, generating a single FillEvent
+    bridge.update_from_market(&market_trade(start + chrono::Duration::milliseconds(100)));
+    assert!(bridge.next_fills().is_empty());
+    bridge.update_from_market(&market_trade(start + chrono::Duration::milliseconds(200)));
+
+    let fills = bridge.next_fills();
+    assert_eq!(fills.len(), 1);
+    assert_eq!(fills[0].decision, Decision::Long);
+    assert_eq!(fills[0].quantity, 2.0);
+    assert_eq!(fills[0].fill_value_gross, 2000.0);
+    assert_eq!(fills[0].fees.exchange, 0.001 * 2.0 * 1000.0);
+    assert_eq!(fills[0].market_meta, order.market_meta);
+    assert!(!bridge.has_pending_orders());
+}
+
+#[test]
+fn execution_bridge_simulated_rejects_real_time_clock() {
+    let (event_account_tx, event_account_rx) = mpsc::unbounded_channel();
+    let mut exchange = simulated_exchange(Utc::now(), event_account_tx);
+    exchange.account.clock = SimulatedClock::RealTime;
+
+    assert!(matches!(
+        ExecutionBridge::simulated(exchange, event_account_rx),
+        Err(barter::execution::error::ExecutionError::SimulatedClockNotEventTime)
+    ));
+}
+
+#[test]
+fn execution_bridge_generates_fill_from_async_execution_client() {
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let (event_account_tx, event_account_rx) = mpsc::unbounded_channel();
+    let (event_simulated_tx, event_simulated_rx) = mpsc::unbounded_channel();
+
+    // Build real-time SimulatedExchange & run on it's own Tokio task
+    runtime.spawn(
+        SimulatedExchange::builder()
+            .event_simulated_rx(event_simulated_rx)
+            .account(
+                ClientAccount::builder()
+                    .latency(Duration::from_millis(10))
+                    .fees_percent(0.001)
+                    .event_account_tx(event_account_tx)
+                    .instruments(vec![instrument()])
+                    .balances(ClientBalances(HashMap::from([
+                        (Symbol::from("eth"), Balance::new(10.0, 10.0)),
+                        (Symbol::from("usdt"), Balance::new(10_000.0, 10_000.0)),
+                    ])))
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap()
+            .run(),
+    );
+
+    let mut bridge = ExecutionBridge::new(
+        SimulatedExecution {
+            request_tx: event_simulated_tx.clone(),
+        },
+        event_account_rx,
+        runtime.handle().clone(),
+    );
+
+    // Execute a Long Limit OrderEvent, which is awaited until it is resting
+    let mut order = order_event();
+    order.instrument = instrument();
+    order.decision = Decision::Long;
+    order.quantity = 2.0;
+    order.order_type = OrderType::Limit;
+    order.market_meta.close = 1000.0;
+    assert!(bridge.execute_order(&order).unwrap().is_empty());
+
+    // Two PublicTrades each partially fill the order
+    for id in ["1", "2"] {
+        event_simulated_tx
+            .send(SimulatedEvent::MarketTrade((
+                instrument(),
+                PublicTrade {
+                    id: id.to_string(),
+                    price: 1000.0,
+                    amount: 1.0,
+                    side: Side::Sell,
+                },
+            )))
+            .unwrap();
+    }
+
+    let fill = next_fill(&mut bridge);
+    assert_eq!(fill.decision, Decision::Long);
+    assert_eq!(fill.quantity, 2.0);
+    assert_eq!(fill.fill_value_gross, 2000.0);
+    assert_eq!(fill.fees.exchange, 0.001 * 2.0 * 1000.0);
+}
+
+/// Strategy that signals to go long on every [`MarketEvent`].
+struct AlwaysLongStrategy;
+
+impl PortfolioSignalGenerator for AlwaysLongStrategy {
+    fn generate_signals(&mut self, market: &MarketEvent<Instrument, DataKind>) -> Vec<Signal> {
+        vec![Signal {
+            time: market.exchange_time,
+            exchange: market.exchange.clone(),
+            instrument: market.instrument.clone(),
+            signals: HashMap::from([(Decision::Long, SignalStrength(1.0))]),
+            market_meta: MarketMeta {
+                close: 1000.0,
+                time: market.exchange_time,
+            },
+            stops: None,
+        }]
+    }
+}
+
+#[test]
+fn trader_with_execution_bridge_fills_single_entry_from_forwarded_market_data() {
+    let start = Utc::now();
+    let (event_account_tx, event_account_rx) = mpsc::unbounded_channel();
+
+    let engine_id = uuid::Uuid::new_v4();
+    let market = Market::new("simulated", instrument());
+    let portfolio = Arc::new(Mutex::new(
+        MetaPortfolio::builder()
+            .engine_id(engine_id)
+            .markets(vec![market.clone()])
+            .starting_cash(10_000.0)
+            .repository(InMemoryRepository::<TradingSummary>::new())
+            .allocation_manager(DefaultAllocator {
+                default_order_value: 100.0,
+            })
+            .risk_manager(DefaultRisk {})
+            .statistic_config(StatisticConfig {
+                starting_equity: 10_000.0,
+                trading_days_per_year: 365,
+                risk_free_return: 0.0,
+            })
+            .build_and_init()
+            .expect("failed to build & initialise MetaPortfolio"),
+    ));
+
+    // Every OrderBookL1 is forwarded to the SimulatedExchange to provide liquidity
+    let markets = (0..3).map(move |index| {
+        let time = start + chrono::Duration::milliseconds(100 * index);
+        MarketEvent {
+            exchange_time: time,
+            received_time: time,
+            exchange: Exchange::from("simulated"),
+            instrument: instrument(),
+            kind: DataKind::OrderBookL1(OrderBookL1 {
+                last_update_time: time,
+                best_bid: Level::new(999.0, 10.0),
+                best_ask: Level::new(1000.0, 10.0),
+            }),
+        }
+    });
+
+    let (event_tx, mut event_rx) = mpsc::unbounded_channel();
+    let (_command_tx, command_rx) = mpsc::channel(10);
+    let trader: Trader<_, TradingSummary, _, _, _, _> = Trader::builder()
+        .engine_id(engine_id)
+        .market(market)
+        .command_rx(command_rx)
+        .event_tx(EventTx::new(event_tx))
+        .portfolio(portfolio)
+        .data(historical::MarketFeed::new(markets))
+        .strategy(AlwaysLongStrategy)
+        .execution(
+            ExecutionBridge::simulated(
+                simulated_exchange(start, event_account_tx),
+                event_account_rx,
+            )
+            .unwrap(),
+        )
+        .build()
+        .expect("failed to build trader");
+
+    // SimulatedExchange is driven inline, so the Trader runs deterministically to completion
+    trader.run();
+
+    let fills = std::iter::from_fn(|| event_rx.try_recv().ok())
+        .filter_map(|event| match event {
+            Event::Fill(fill) => Some(fill),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(fills.len(), 1);
+    assert_eq!(fills[0].decision, Decision::Long);
+    assert_eq!(fills[0].quantity, 0.1);
+    assert_eq!(fills[0].fill_value_gross, 100.0);
+}
+
+fn instrument() -> Instrument {
+    Instrument::from(("eth", "usdt", InstrumentKind::Spot))
+}
+
+/// Build an event-time [`SimulatedExchange`] with a constant 10ms request latency.
+fn simulated_exchange(
+    start: DateTime<Utc>,
+    event_account_tx: mpsc::UnboundedSender<AccountEvent>,
+) -> SimulatedExchange {
+    let (_event_simulated_tx, event_simulated_rx) = mpsc::unbounded_channel();
+    SimulatedExchange::builder()
+        .event_simulated_rx(event_simulated_rx)
+        .account(
+            ClientAccount::builder()
+                .clock(SimulatedClock::EventTime(start))
+                .request_latency(LatencyModel::Constant(Duration::from_millis(10)))
+                .fees_percent(0.001)
+                .event_account_tx(event_account_tx)
+                .instruments(vec![instrument()])
+                .balances(ClientBalances(HashMap::from([
+                    (Symbol::from("eth"), Balance::new(10.0, 10.0)),
+                    (Symbol::from("usdt"), Balance::new(10_000.0, 10_000.0)),
+                ])))
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap()
+}
+
+/// Build a [`MarketEvent`] containing a 1.0 quantity Sell [`PublicTrade`] at 1000.0.
+fn market_trade(time: DateTime<Utc>) -> MarketEvent<Instrument, DataKind> {
+    MarketEvent {
+        exchange_time: time,
+        received_time: time,
+        exchange: Exchange::from("simulated"),
+        instrument: instrument(),
+        kind: DataKind::Trade(PublicTrade {
+            id: time.timestamp_millis().to_string(),
+            price: 1000.0,
+            amount: 1.0,
+            side: Side::Sell,
+        }),
+    }
+}
+
+fn next_fill<Venue>(bridge: &mut ExecutionBridge<Venue>) -> FillEvent
+where
+    Venue: BridgeVenue,
+{
+    for _ in 0..100 {
+        if let Some(fill) = bridge.next_fills().pop() {
+            return fill;
+        }
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    panic!("timed out waiting for FillEvent")
+}
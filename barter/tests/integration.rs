@@ -1,23 +1,36 @@
 use barter::{
     data::historical,
+    data::MarketMeta,
     engine::{trader::Trader, Engine},
-    event::EventTx,
+    event::{Event, EventTx},
     execution::{
         simulated::{Config as ExecutionConfig, SimulatedExecution},
         Fees,
     },
     portfolio::{
-        allocator::DefaultAllocator, portfolio::MetaPortfolio,
-        repository::in_memory::InMemoryRepository, risk::DefaultRisk,
+        allocator::DefaultAllocator,
+        portfolio::MetaPortfolio,
+        repository::in_memory::InMemoryRepository,
+        risk::{DefaultRisk, LimitRiskManager, RejectionReason, RiskLimits},
     },
     statistic::summary::{
         trading::{Config as StatisticConfig, TradingSummary},
         Initialiser,
     },
-    strategy::example::{Config as StrategyConfig, RSIStrategy},
+    strategy::{
+        example::{Config as StrategyConfig, RSIStrategy},
+        Decision, PortfolioSignalGenerator, Signal, SignalStrength,
+    },
     test_util::market_event_trade,
 };
-use barter_integration::model::{instrument::kind::InstrumentKind, Market, Side};
+use barter_data::{
+    event::{DataKind, MarketEvent},
+    subscription::trade::PublicTrade,
+};
+use barter_integration::model::{
+    instrument::{kind::InstrumentKind, Instrument},
+    Market, Side,
+};
 use parking_lot::Mutex;
 use std::{collections::HashMap, sync::Arc, time::Duration};
 use tokio::sync::mpsc;
@@ -116,3 +129,233 @@ async fn engine_with_historic_data_stops_after_candles_finished() {
         "failed because Engine's command_rx.await is blocking the Engine from stopping"
     )
 }
+
+/// Portfolio-level strategy that goes long the "follower" [`Market`] whenever a trade is
+/// received for the "leader" [`Market`].
+struct LeaderFollowerStrategy {
+    leader: Market,
+    follower: Market,
+}
+
+impl PortfolioSignalGenerator for LeaderFollowerStrategy {
+    fn generate_signals(&mut self, market: &MarketEvent<Instrument, DataKind>) -> Vec<Signal> {
+        let DataKind::Trade(trade) = &market.kind else {
+            return vec![];
+        };
+
+        if market.exchange != self.leader.exchange || market.instrument != self.leader.instrument {
+            return vec![];
+        }
+
+        vec![Signal {
+            time: market.exchange_time,
+            exchange: self.follower.exchange.clone(),
+            instrument: self.follower.instrument.clone(),
+            signals: HashMap::from([(Decision::Long, SignalStrength(1.0))]),
+            market_meta: MarketMeta {
+                close: trade.price,
+                time: market.exchange_time,
+            },
+            stops: None,
+        }]
+    }
+}
+
+#[tokio::test]
+async fn engine_with_portfolio_strategy_generates_orders_for_any_market() {
+    let (_command_tx, command_rx) = mpsc::channel(20);
+    let (event_tx, mut event_rx) = mpsc::unbounded_channel();
+    let event_tx = EventTx::new(event_tx);
+    let engine_id = Uuid::new_v4();
+
+    // Single Trader trades both Markets using a portfolio-level strategy
+    let leader = Market::new("binance", ("btc", "usdt", InstrumentKind::Spot));
+    let follower = Market::new("binance", ("eth", "usdt", InstrumentKind::Spot));
+    let markets = vec![leader.clone(), follower.clone()];
+
+    let portfolio = Arc::new(Mutex::new(
+        MetaPortfolio::builder()
+            .engine_id(engine_id)
+            .markets(markets.clone())
+            .starting_cash(10_000.0)
+            .repository(InMemoryRepository::new())
+            .allocation_manager(DefaultAllocator {
+                default_order_value: 100.0,
+            })
+            .risk_manager(DefaultRisk {})
+            .statistic_config(StatisticConfig {
+                starting_equity: 10_000.0,
+                trading_days_per_year: 365,
+                risk_free_return: 0.0,
+            })
+            .build_and_init()
+            .expect("failed to build & initialise MetaPortfolio"),
+    ));
+
+    let mut leader_trade = market_event_trade(Side::Buy);
+    leader_trade.exchange = leader.exchange.clone();
+    leader_trade.instrument = leader.instrument.clone();
+    leader_trade.kind = DataKind::Trade(PublicTrade {
+        id: "leader".to_string(),
+        price: 1000.0,
+        amount: 1.0,
+        side: Side::Buy,
+    });
+
+    let (trader_command_tx, trader_command_rx) = mpsc::channel(10);
+    let trader = Trader::builder()
+        .engine_id(engine_id)
+        .markets(markets.clone())
+        .command_rx(trader_command_rx)
+        .event_tx(event_tx)
+        .portfolio(Arc::clone(&portfolio))
+        .data(historical::MarketFeed::new([leader_trade].into_iter()))
+        .strategy(LeaderFollowerStrategy {
+            leader: leader.clone(),
+            follower: follower.clone(),
+        })
+        .execution(SimulatedExecution::new(ExecutionConfig {
+            simulated_fees_pct: Fees {
+                exchange: 0.1,
+                slippage: 0.05,
+                network: 0.0,
+            },
+        }))
+        .build()
+        .expect("failed to build trader");
+
+    // Every Market traded by the portfolio-level Trader is routed to the same command_tx
+    let trader_command_txs = markets
+        .into_iter()
+        .map(|market| (market, trader_command_tx.clone()))
+        .collect::<HashMap<_, _>>();
+
+    let engine = Engine::builder()
+        .engine_id(engine_id)
+        .command_rx(command_rx)
+        .portfolio(portfolio)
+        .traders(vec![trader])
+        .trader_command_txs(trader_command_txs)
+        .statistics_summary(TradingSummary::init(StatisticConfig {
+            starting_equity: 1000.0,
+            trading_days_per_year: 365,
+            risk_free_return: 0.0,
+        }))
+        .build()
+        .expect("failed to build engine");
+
+    tokio::time::timeout(Duration::from_millis(100), engine.run())
+        .await
+        .expect("Engine failed to stop after historical data finished");
+
+    let mut follower_orders = 0;
+    while let Ok(event) = event_rx.try_recv() {
+        if let Event::OrderNew(order) = event {
+            assert_eq!(order.exchange, follower.exchange);
+            assert_eq!(order.instrument, follower.instrument);
+            follower_orders += 1;
+        }
+    }
+    assert_eq!(follower_orders, 1);
+}
+
+#[tokio::test]
+async fn engine_with_risk_limits_reports_rejected_orders() {
+    let (_command_tx, command_rx) = mpsc::channel(20);
+    let (event_tx, mut event_rx) = mpsc::unbounded_channel();
+    let event_tx = EventTx::new(event_tx);
+    let engine_id = Uuid::new_v4();
+
+    let leader = Market::new("binance", ("btc", "usdt", InstrumentKind::Spot));
+    let follower = Market::new("binance", ("eth", "usdt", InstrumentKind::Spot));
+    let markets = vec![leader.clone(), follower.clone()];
+
+    // Risk manager vetoes every OrderEvent sized by the DefaultAllocator
+    let portfolio = Arc::new(Mutex::new(
+        MetaPortfolio::builder()
+            .engine_id(engine_id)
+            .markets(markets.clone())
+            .starting_cash(10_000.0)
+            .repository(InMemoryRepository::new())
+            .allocation_manager(DefaultAllocator {
+                default_order_value: 100.0,
+            })
+            .risk_manager(LimitRiskManager::new(RiskLimits {
+                max_order_notional: Some(50.0),
+                ..RiskLimits::default()
+            }))
+            .statistic_config(StatisticConfig {
+                starting_equity: 10_000.0,
+                trading_days_per_year: 365,
+                risk_free_return: 0.0,
+            })
+            .build_and_init()
+            .expect("failed to build & initialise MetaPortfolio"),
+    ));
+
+    let mut leader_trade = market_event_trade(Side::Buy);
+    leader_trade.exchange = leader.exchange.clone();
+    leader_trade.instrument = leader.instrument.clone();
+
+    let (trader_command_tx, trader_command_rx) = mpsc::channel(10);
+    let trader = Trader::builder()
+        .engine_id(engine_id)
+        .markets(markets.clone())
+        .command_rx(trader_command_rx)
+        .event_tx(event_tx)
+        .portfolio(Arc::clone(&portfolio))
+        .data(historical::MarketFeed::new([leader_trade].into_iter()))
+        .strategy(LeaderFollowerStrategy {
+            leader: leader.clone(),
+            follower: follower.clone(),
+        })
+        .execution(SimulatedExecution::new(ExecutionConfig {
+            simulated_fees_pct: Fees {
+                exchange: 0.1,
+                slippage: 0.05,
+                network: 0.0,
+            },
+        }))
+        .build()
+        .expect("failed to build trader");
+
+    let trader_command_txs = markets
+        .into_iter()
+        .map(|market| (market, trader_command_tx.clone()))
+        .collect::<HashMap<_, _>>();
+
+    let engine = Engine::builder()
+        .engine_id(engine_id)
+        .command_rx(command_rx)
+        .portfolio(portfolio)
+        .traders(vec![trader])
+        .trader_command_txs(trader_command_txs)
+        .statistics_summary(TradingSummary::init(StatisticConfig {
+            starting_equity: 1000.0,
+            trading_days_per_year: 365,
+            risk_free_return: 0.0,
+        }))
+        .build()
+        .expect("failed to build engine");
+
+    tokio::time::timeout(Duration::from_millis(100), engine.run())
+        .await
+        .expect("Engine failed to stop after historical data finished");
+
+    let mut rejected_orders = 0;
+    while let Ok(event) = event_rx.try_recv() {
+        match event {
+            Event::OrderNew(order) => panic!("expected OrderEvent to be rejected: {order:?}"),
+            Event::OrderRejected(rejected) => {
+                assert_eq!(rejected.order.instrument, follower.instrument);
+                assert!(matches!(
+                    rejected.reason,
+                    RejectionReason::MaxOrderNotional { limit, .. } if limit == 50.0
+                ));
+                rejected_orders += 1;
+            }
+            _ => {}
+        }
+    }
+    assert_eq!(rejected_orders, 1);
+}